@@ -0,0 +1,264 @@
+//! Compute unit benchmarks of the `pinocchio-benches` fixture program.
+//!
+//! The program binary must be built with the `bpf-entrypoint` feature before
+//! running the benchmarks:
+//!
+//! ```bash
+//! cargo-build-sbf --manifest-path benches/Cargo.toml --features bpf-entrypoint
+//! SBF_OUT_DIR=target/deploy cargo bench --manifest-path benches/harness/Cargo.toml
+//! ```
+//!
+//! Results are written to `target/benches/compute_units.md`, including the
+//! delta to the previous run.
+
+use {
+    mollusk_svm::{program::keyed_account_for_system_program, Mollusk},
+    mollusk_svm_bencher::MolluskComputeUnitBencher,
+    mollusk_svm_programs_token::token,
+    pinocchio_benches::{BenchInstruction, ID, VAULT_SEED},
+    solana_account::Account,
+    solana_instruction::{AccountMeta, Instruction},
+    solana_pubkey::Pubkey,
+};
+
+/// Number of accounts used to benchmark the entrypoint parsing.
+const ACCOUNT_COUNTS: [u8; 6] = [0, 1, 8, 16, 32, 64];
+
+const LAMPORTS: u64 = 1_000_000_000;
+
+const SYSTEM_PROGRAM_ID: Pubkey = Pubkey::new_from_array([0; 32]);
+
+type Bench = (String, Instruction, Vec<(Pubkey, Account)>);
+
+fn system_account(lamports: u64) -> Account {
+    Account {
+        lamports,
+        owner: SYSTEM_PROGRAM_ID,
+        ..Account::default()
+    }
+}
+
+fn mint_account(mint_authority: &Pubkey, supply: u64, decimals: u8) -> Account {
+    let mut data = vec![0; 82];
+    // mint authority
+    data[0..4].copy_from_slice(&1u32.to_le_bytes());
+    data[4..36].copy_from_slice(mint_authority.as_ref());
+    data[36..44].copy_from_slice(&supply.to_le_bytes());
+    data[44] = decimals;
+    // is initialized
+    data[45] = 1;
+
+    Account {
+        lamports: LAMPORTS,
+        data,
+        owner: token::ID,
+        ..Account::default()
+    }
+}
+
+fn token_account(mint: &Pubkey, owner: &Pubkey, amount: u64) -> Account {
+    let mut data = vec![0; 165];
+    data[0..32].copy_from_slice(mint.as_ref());
+    data[32..64].copy_from_slice(owner.as_ref());
+    data[64..72].copy_from_slice(&amount.to_le_bytes());
+    // state: initialized
+    data[108] = 1;
+
+    Account {
+        lamports: LAMPORTS,
+        data,
+        owner: token::ID,
+        ..Account::default()
+    }
+}
+
+fn instruction_data(instruction: BenchInstruction, args: &[u8]) -> Vec<u8> {
+    let mut data = vec![instruction as u8];
+    data.extend_from_slice(args);
+    data
+}
+
+fn noop_benches() -> Vec<Bench> {
+    ACCOUNT_COUNTS
+        .iter()
+        .map(|&count| {
+            let accounts = (0..count)
+                .map(|_| (Pubkey::new_unique(), system_account(LAMPORTS)))
+                .collect::<Vec<_>>();
+            let metas = accounts
+                .iter()
+                .map(|(address, _)| AccountMeta::new_readonly(*address, false))
+                .collect();
+
+            (
+                format!("entrypoint_{count}_accounts"),
+                Instruction::new_with_bytes(
+                    ID,
+                    &instruction_data(BenchInstruction::Noop, &[]),
+                    metas,
+                ),
+                accounts,
+            )
+        })
+        .collect()
+}
+
+fn system_benches() -> Vec<Bench> {
+    let from = Pubkey::new_unique();
+    let to = Pubkey::new_unique();
+    let (vault, bump) = Pubkey::find_program_address(&[VAULT_SEED], &ID);
+    let lamports = 1_000u64.to_le_bytes();
+
+    let mut signed_args = vec![bump];
+    signed_args.extend_from_slice(&lamports);
+
+    vec![
+        (
+            "system_transfer".to_string(),
+            Instruction::new_with_bytes(
+                ID,
+                &instruction_data(BenchInstruction::SystemTransfer, &lamports),
+                vec![
+                    AccountMeta::new(from, true),
+                    AccountMeta::new(to, false),
+                    AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                ],
+            ),
+            vec![
+                (from, system_account(LAMPORTS)),
+                (to, system_account(LAMPORTS)),
+                keyed_account_for_system_program(),
+            ],
+        ),
+        (
+            "system_transfer_signed".to_string(),
+            Instruction::new_with_bytes(
+                ID,
+                &instruction_data(BenchInstruction::SystemTransferSigned, &signed_args),
+                vec![
+                    AccountMeta::new(vault, false),
+                    AccountMeta::new(to, false),
+                    AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                ],
+            ),
+            vec![
+                (vault, system_account(LAMPORTS)),
+                (to, system_account(LAMPORTS)),
+                keyed_account_for_system_program(),
+            ],
+        ),
+    ]
+}
+
+fn token_benches() -> Vec<Bench> {
+    let mint = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+    let source = Pubkey::new_unique();
+    let destination = Pubkey::new_unique();
+    let amount = 100u64.to_le_bytes();
+
+    let mut checked_args = amount.to_vec();
+    checked_args.push(9);
+
+    let accounts = |names: &[Pubkey]| {
+        names
+            .iter()
+            .map(|address| {
+                let account = if address == &mint {
+                    mint_account(&authority, 1_000_000, 9)
+                } else if address == &authority {
+                    system_account(LAMPORTS)
+                } else {
+                    token_account(&mint, &authority, 1_000)
+                };
+                (*address, account)
+            })
+            .chain([token::keyed_account()])
+            .collect::<Vec<_>>()
+    };
+
+    vec![
+        (
+            "token_transfer".to_string(),
+            Instruction::new_with_bytes(
+                ID,
+                &instruction_data(BenchInstruction::TokenTransfer, &amount),
+                vec![
+                    AccountMeta::new(source, false),
+                    AccountMeta::new(destination, false),
+                    AccountMeta::new_readonly(authority, true),
+                    AccountMeta::new_readonly(token::ID, false),
+                ],
+            ),
+            accounts(&[source, destination, authority]),
+        ),
+        (
+            "token_transfer_checked".to_string(),
+            Instruction::new_with_bytes(
+                ID,
+                &instruction_data(BenchInstruction::TokenTransferChecked, &checked_args),
+                vec![
+                    AccountMeta::new(source, false),
+                    AccountMeta::new_readonly(mint, false),
+                    AccountMeta::new(destination, false),
+                    AccountMeta::new_readonly(authority, true),
+                    AccountMeta::new_readonly(token::ID, false),
+                ],
+            ),
+            accounts(&[source, mint, destination, authority]),
+        ),
+        (
+            "token_mint_to".to_string(),
+            Instruction::new_with_bytes(
+                ID,
+                &instruction_data(BenchInstruction::TokenMintTo, &amount),
+                vec![
+                    AccountMeta::new(mint, false),
+                    AccountMeta::new(destination, false),
+                    AccountMeta::new_readonly(authority, true),
+                    AccountMeta::new_readonly(token::ID, false),
+                ],
+            ),
+            accounts(&[mint, destination, authority]),
+        ),
+        (
+            "token_burn".to_string(),
+            Instruction::new_with_bytes(
+                ID,
+                &instruction_data(BenchInstruction::TokenBurn, &amount),
+                vec![
+                    AccountMeta::new(source, false),
+                    AccountMeta::new(mint, false),
+                    AccountMeta::new_readonly(authority, true),
+                    AccountMeta::new_readonly(token::ID, false),
+                ],
+            ),
+            accounts(&[source, mint, authority]),
+        ),
+    ]
+}
+
+fn main() {
+    let mut mollusk = Mollusk::new(&ID, "pinocchio_benches");
+    token::add_program(&mut mollusk);
+
+    let benches = noop_benches()
+        .into_iter()
+        .chain(system_benches())
+        .chain(token_benches())
+        .collect::<Vec<_>>();
+
+    let out_dir = concat!(env!("CARGO_MANIFEST_DIR"), "/../../target/benches");
+
+    benches
+        .iter()
+        .fold(
+            MolluskComputeUnitBencher::new(mollusk),
+            |bencher, (name, instruction, accounts)| {
+                bencher.bench((name.as_str(), instruction, accounts.as_slice()))
+            },
+        )
+        .must_pass(true)
+        .out_dir(out_dir)
+        .execute();
+}
@@ -0,0 +1,8 @@
+use {
+    crate::process_instruction,
+    pinocchio::{default_allocator, nostd_panic_handler, program_entrypoint},
+};
+
+program_entrypoint!(process_instruction);
+default_allocator!();
+nostd_panic_handler!();
@@ -0,0 +1,287 @@
+//! Fixture program used to measure the compute units consumed by `pinocchio`.
+//!
+//! Each [`BenchInstruction`] exercises a single code path: entrypoint
+//! parsing, cross-program invocations and the token instruction builders.
+//! The compute units are measured by the harness in `benches/harness`, which
+//! executes the program binary built with the `bpf-entrypoint` feature.
+
+#![cfg_attr(any(target_os = "solana", target_arch = "bpf"), no_std)]
+
+#[cfg(feature = "bpf-entrypoint")]
+mod entrypoint;
+
+use {
+    pinocchio::{
+        cpi::{Seed, Signer},
+        error::ProgramError,
+        AccountView, Address, ProgramResult,
+    },
+    pinocchio_system::instructions::Transfer as SystemTransfer,
+    pinocchio_token::instructions::{Burn, MintTo, Transfer, TransferChecked},
+};
+
+pinocchio::address::declare_id!("8oueNekaE5LR6Dg7ndJ2mKvXP2RE8NiVaARfZRpzRpdk");
+
+/// Seed of the PDA used to benchmark signed invocations.
+pub const VAULT_SEED: &[u8] = b"vault";
+
+/// Instructions of the fixture program.
+///
+/// The first byte of the instruction data is the instruction discriminator.
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum BenchInstruction {
+    /// Return without doing any work.
+    ///
+    /// This measures the entrypoint parsing of any number of accounts.
+    Noop,
+
+    /// Transfer lamports through the System program.
+    ///
+    /// ### Accounts:
+    ///   0. `[WRITE, SIGNER]` Funding account.
+    ///   1. `[WRITE]` Recipient account.
+    ///   2. `[]` System program.
+    ///
+    /// ### Instruction data:
+    ///   - `[1..9]`: lamports (`u64`).
+    SystemTransfer,
+
+    /// Transfer lamports from a PDA through the System program.
+    ///
+    /// ### Accounts:
+    ///   0. `[WRITE]` Vault PDA derived from [`VAULT_SEED`].
+    ///   1. `[WRITE]` Recipient account.
+    ///   2. `[]` System program.
+    ///
+    /// ### Instruction data:
+    ///   - `[1]`: bump seed of the vault.
+    ///   - `[2..10]`: lamports (`u64`).
+    SystemTransferSigned,
+
+    /// Transfer tokens using the `Transfer` builder.
+    ///
+    /// ### Accounts:
+    ///   0. `[WRITE]` Source token account.
+    ///   1. `[WRITE]` Destination token account.
+    ///   2. `[SIGNER]` Owner of the source account.
+    ///   3. `[]` Token program.
+    ///
+    /// ### Instruction data:
+    ///   - `[1..9]`: amount (`u64`).
+    TokenTransfer,
+
+    /// Transfer tokens using the `TransferChecked` builder.
+    ///
+    /// ### Accounts:
+    ///   0. `[WRITE]` Source token account.
+    ///   1. `[]` Token mint.
+    ///   2. `[WRITE]` Destination token account.
+    ///   3. `[SIGNER]` Owner of the source account.
+    ///   4. `[]` Token program.
+    ///
+    /// ### Instruction data:
+    ///   - `[1..9]`: amount (`u64`).
+    ///   - `[9]`: decimals.
+    TokenTransferChecked,
+
+    /// Mint tokens using the `MintTo` builder.
+    ///
+    /// ### Accounts:
+    ///   0. `[WRITE]` Token mint.
+    ///   1. `[WRITE]` Destination token account.
+    ///   2. `[SIGNER]` Mint authority.
+    ///   3. `[]` Token program.
+    ///
+    /// ### Instruction data:
+    ///   - `[1..9]`: amount (`u64`).
+    TokenMintTo,
+
+    /// Burn tokens using the `Burn` builder.
+    ///
+    /// ### Accounts:
+    ///   0. `[WRITE]` Source token account.
+    ///   1. `[WRITE]` Token mint.
+    ///   2. `[SIGNER]` Owner of the source account.
+    ///   3. `[]` Token program.
+    ///
+    /// ### Instruction data:
+    ///   - `[1..9]`: amount (`u64`).
+    TokenBurn,
+}
+
+impl TryFrom<u8> for BenchInstruction {
+    type Error = ProgramError;
+
+    #[inline(always)]
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(BenchInstruction::Noop),
+            1 => Ok(BenchInstruction::SystemTransfer),
+            2 => Ok(BenchInstruction::SystemTransferSigned),
+            3 => Ok(BenchInstruction::TokenTransfer),
+            4 => Ok(BenchInstruction::TokenTransferChecked),
+            5 => Ok(BenchInstruction::TokenMintTo),
+            6 => Ok(BenchInstruction::TokenBurn),
+            _ => Err(ProgramError::InvalidInstructionData),
+        }
+    }
+}
+
+/// Process an instruction of the fixture program.
+pub fn process_instruction(
+    _program_id: &Address,
+    accounts: &[AccountView],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let [discriminator, data @ ..] = instruction_data else {
+        return Err(ProgramError::InvalidInstructionData);
+    };
+
+    match BenchInstruction::try_from(*discriminator)? {
+        BenchInstruction::Noop => Ok(()),
+        BenchInstruction::SystemTransfer => {
+            let [from, to, _system_program] = accounts else {
+                return Err(ProgramError::NotEnoughAccountKeys);
+            };
+
+            SystemTransfer {
+                from,
+                to,
+                lamports: read_u64(data)?,
+            }
+            .invoke()
+        }
+        BenchInstruction::SystemTransferSigned => {
+            let [from, to, _system_program] = accounts else {
+                return Err(ProgramError::NotEnoughAccountKeys);
+            };
+            let [bump, data @ ..] = data else {
+                return Err(ProgramError::InvalidInstructionData);
+            };
+
+            let bump = [*bump];
+            let seeds = [Seed::from(VAULT_SEED), Seed::from(&bump)];
+
+            SystemTransfer {
+                from,
+                to,
+                lamports: read_u64(data)?,
+            }
+            .invoke_signed(&[Signer::from(&seeds)])
+        }
+        BenchInstruction::TokenTransfer => {
+            let [from, to, authority, _token_program] = accounts else {
+                return Err(ProgramError::NotEnoughAccountKeys);
+            };
+
+            Transfer {
+                from,
+                to,
+                authority,
+                amount: read_u64(data)?,
+            }
+            .invoke()
+        }
+        BenchInstruction::TokenTransferChecked => {
+            let [from, mint, to, authority, _token_program] = accounts else {
+                return Err(ProgramError::NotEnoughAccountKeys);
+            };
+            let [amount @ .., decimals] = data else {
+                return Err(ProgramError::InvalidInstructionData);
+            };
+
+            TransferChecked {
+                from,
+                mint,
+                to,
+                authority,
+                amount: read_u64(amount)?,
+                decimals: *decimals,
+            }
+            .invoke()
+        }
+        BenchInstruction::TokenMintTo => {
+            let [mint, account, mint_authority, _token_program] = accounts else {
+                return Err(ProgramError::NotEnoughAccountKeys);
+            };
+
+            MintTo {
+                mint,
+                account,
+                mint_authority,
+                amount: read_u64(data)?,
+            }
+            .invoke()
+        }
+        BenchInstruction::TokenBurn => {
+            let [account, mint, authority, _token_program] = accounts else {
+                return Err(ProgramError::NotEnoughAccountKeys);
+            };
+
+            Burn {
+                account,
+                mint,
+                authority,
+                amount: read_u64(data)?,
+            }
+            .invoke()
+        }
+    }
+}
+
+#[inline(always)]
+fn read_u64(data: &[u8]) -> Result<u64, ProgramError> {
+    data.try_into()
+        .map(u64::from_le_bytes)
+        .map_err(|_| ProgramError::InvalidInstructionData)
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        pinocchio::{
+            entrypoint::{
+                input::{serialize_input, InputAccount},
+                process_entrypoint,
+            },
+            MAX_TX_ACCOUNTS, SUCCESS,
+        },
+    };
+
+    #[test]
+    fn noop_parses_accounts() {
+        for count in [0u8, 1, 8, 32, 64, 128] {
+            let accounts = (0..count)
+                .map(|i| {
+                    InputAccount::new(Address::new_from_array([i; 32]), ID, 1, &[0; 16]).writable()
+                })
+                .collect::<std::vec::Vec<_>>();
+
+            let mut input = serialize_input(&ID, &accounts, &[BenchInstruction::Noop as u8]);
+            let result = unsafe {
+                process_entrypoint::<MAX_TX_ACCOUNTS>(input.as_mut_ptr(), process_instruction)
+            };
+
+            assert_eq!(result, SUCCESS);
+        }
+    }
+
+    #[test]
+    fn invalid_instruction_data() {
+        for instruction_data in [&[][..], &[7], &[BenchInstruction::TokenTransfer as u8, 1]] {
+            let mut input = serialize_input(&ID, &[], instruction_data);
+            let result = unsafe {
+                process_entrypoint::<MAX_TX_ACCOUNTS>(input.as_mut_ptr(), process_instruction)
+            };
+
+            let expected = if instruction_data.len() > 1 {
+                ProgramError::NotEnoughAccountKeys
+            } else {
+                ProgramError::InvalidInstructionData
+            };
+            assert_eq!(result, u64::from(expected));
+        }
+    }
+}
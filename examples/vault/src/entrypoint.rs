@@ -0,0 +1,8 @@
+use {
+    crate::process_instruction,
+    pinocchio::{lazy_program_entrypoint, no_allocator, nostd_panic_handler},
+};
+
+lazy_program_entrypoint!(process_instruction);
+no_allocator!();
+nostd_panic_handler!();
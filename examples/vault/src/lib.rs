@@ -0,0 +1,470 @@
+//! Example program using `pinocchio` end-to-end.
+//!
+//! The program manages token vaults: each authority owns a vault PDA, which
+//! can be resized and holds token accounts. It exercises the lazy entrypoint,
+//! PDA creation, account resizing, System and Token program CPIs, and the
+//! `Rent` and `Clock` sysvars, serving both as an integration test target and
+//! as a compute units baseline.
+
+#![cfg_attr(any(target_os = "solana", target_arch = "bpf"), no_std)]
+
+#[cfg(feature = "bpf-entrypoint")]
+mod entrypoint;
+mod processor;
+pub mod state;
+
+use {
+    core::mem::MaybeUninit,
+    pinocchio::{
+        entrypoint::{InstructionContext, MaybeAccount},
+        error::ProgramError,
+        AccountView, ProgramResult,
+    },
+};
+
+pinocchio::address::declare_id!("8VTo8fDqNUnTKvaa7SPs4NzBUmCd8VoRN4gbnXMfEkVF");
+
+/// Maximum number of accounts of an instruction.
+const MAX_ACCOUNTS: usize = 5;
+
+/// Instructions of the vault program.
+///
+/// The first byte of the instruction data is the instruction discriminator.
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum VaultInstruction {
+    /// Create the vault PDA of an authority.
+    ///
+    /// The vault is funded with the minimum balance to be rent-exempt and
+    /// records the slot of its creation.
+    ///
+    /// ### Accounts:
+    ///   0. `[WRITE, SIGNER]` Authority of the vault, paying for its creation.
+    ///   1. `[WRITE]` Vault PDA derived from [`state::VAULT_SEED`] and the
+    ///      authority address, with the canonical bump seed.
+    ///   2. `[]` System program.
+    Initialize,
+
+    /// Resize the data of the vault.
+    ///
+    /// The authority tops up the vault balance when the new length requires a
+    /// larger rent-exempt minimum.
+    ///
+    /// ### Accounts:
+    ///   0. `[WRITE, SIGNER]` Authority of the vault.
+    ///   1. `[WRITE]` Vault PDA.
+    ///   2. `[]` System program.
+    ///
+    /// ### Instruction data:
+    ///   - `[1..9]`: new length of the vault data (`u64`).
+    Resize,
+
+    /// Deposit tokens into a token account owned by the vault.
+    ///
+    /// ### Accounts:
+    ///   0. `[SIGNER]` Owner of the source token account.
+    ///   1. `[WRITE]` Source token account.
+    ///   2. `[WRITE]` Token account owned by the vault.
+    ///   3. `[]` Vault PDA.
+    ///   4. `[]` Token program.
+    ///
+    /// ### Instruction data:
+    ///   - `[1..9]`: amount (`u64`).
+    Deposit,
+
+    /// Withdraw tokens from a token account owned by the vault.
+    ///
+    /// ### Accounts:
+    ///   0. `[SIGNER]` Authority of the vault.
+    ///   1. `[]` Vault PDA.
+    ///   2. `[WRITE]` Token account owned by the vault.
+    ///   3. `[WRITE]` Destination token account.
+    ///   4. `[]` Token program.
+    ///
+    /// ### Instruction data:
+    ///   - `[1..9]`: amount (`u64`).
+    Withdraw,
+}
+
+impl TryFrom<u8> for VaultInstruction {
+    type Error = ProgramError;
+
+    #[inline(always)]
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(VaultInstruction::Initialize),
+            1 => Ok(VaultInstruction::Resize),
+            2 => Ok(VaultInstruction::Deposit),
+            3 => Ok(VaultInstruction::Withdraw),
+            _ => Err(ProgramError::InvalidInstructionData),
+        }
+    }
+}
+
+/// Process an instruction of the vault program.
+///
+/// The lazy entrypoint only exposes the instruction data after all accounts
+/// are read, so accounts are read upfront. Since no instruction accepts the
+/// same account twice, duplicated accounts are rejected.
+pub fn process_instruction(mut context: InstructionContext) -> ProgramResult {
+    let count = context.remaining() as usize;
+
+    if count > MAX_ACCOUNTS {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    let mut accounts = [const { MaybeUninit::<AccountView>::uninit() }; MAX_ACCOUNTS];
+
+    for account in accounts.iter_mut().take(count) {
+        match context.next_account()? {
+            MaybeAccount::Account(account_view) => {
+                account.write(account_view);
+            }
+            MaybeAccount::Duplicated(_) => return Err(ProgramError::InvalidArgument),
+        }
+    }
+
+    // SAFETY: The first `count` accounts are initialized.
+    let accounts =
+        unsafe { core::slice::from_raw_parts(accounts.as_ptr() as *const AccountView, count) };
+
+    let [discriminator, data @ ..] = context.instruction_data()? else {
+        return Err(ProgramError::InvalidInstructionData);
+    };
+
+    match VaultInstruction::try_from(*discriminator)? {
+        VaultInstruction::Initialize => processor::initialize(accounts, data),
+        VaultInstruction::Resize => processor::resize(accounts, data),
+        VaultInstruction::Deposit => processor::deposit(accounts, data),
+        VaultInstruction::Withdraw => processor::withdraw(accounts, data),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::state::{Vault, VAULT_SEED},
+        pinocchio::{
+            cpi::Signer,
+            entrypoint::{
+                deserialize,
+                input::{serialize_input, InputAccount},
+            },
+            instruction::{InstructionAccount, InstructionView},
+            sysvars::clock::CLOCK_ID,
+            test_syscalls::{set_syscalls, HostSyscalls, Invocation, InvokeRecorder, Syscalls},
+            Address,
+        },
+        std::{vec, vec::Vec},
+    };
+
+    const AUTHORITY: Address = Address::new_from_array([1; 32]);
+
+    const MINT: Address = Address::new_from_array([2; 32]);
+
+    const SOURCE: Address = Address::new_from_array([3; 32]);
+
+    const VAULT_TOKEN: Address = Address::new_from_array([4; 32]);
+
+    const LAMPORTS: u64 = 1_000_000_000;
+
+    /// Rent-exempt minimum balance per byte, including the account overhead.
+    const LAMPORTS_PER_BYTE: u64 = 6_960;
+
+    /// Runtime serving the `Rent` and `Clock` sysvars, and applying the effects
+    /// of `CreateAccount`.
+    struct Runtime {
+        sysvars: HostSyscalls,
+    }
+
+    impl Syscalls for Runtime {
+        fn get_sysvar(&mut self, sysvar_id: &Address, dst: &mut [u8], offset: u64) -> u64 {
+            self.sysvars.get_sysvar(sysvar_id, dst, offset)
+        }
+
+        fn invoke(
+            &mut self,
+            instruction: &InstructionView,
+            account_views: &[&AccountView],
+            _signers: &[Signer],
+        ) -> ProgramResult {
+            // Apply the effects of `CreateAccount`, which the program relies
+            // on to initialize the vault.
+            if instruction.program_id == &pinocchio_system::ID && instruction.data[0] == 0 {
+                let [from, to] = account_views else {
+                    return Err(ProgramError::NotEnoughAccountKeys);
+                };
+                let data = instruction.data;
+                let lamports = u64::from_le_bytes(data[4..12].try_into().unwrap());
+                let space = u64::from_le_bytes(data[12..20].try_into().unwrap());
+                let owner = Address::new_from_array(data[20..52].try_into().unwrap());
+
+                from.set_lamports(from.lamports() - lamports);
+                to.set_lamports(lamports);
+                to.resize(space as usize)?;
+                unsafe { to.assign(&owner) };
+            }
+
+            Ok(())
+        }
+    }
+
+    /// Register the runtime for the current thread, returning the recorder of
+    /// the cross-program invocations.
+    fn runtime() -> InvokeRecorder {
+        let mut clock = Vec::new();
+        for value in [42u64, 1, 2, 3, 4] {
+            clock.extend_from_slice(&value.to_le_bytes());
+        }
+
+        set_syscalls(Runtime {
+            sysvars: HostSyscalls::default()
+                .with_rent(LAMPORTS_PER_BYTE / 2, 2.0)
+                .with_sysvar(CLOCK_ID, &clock),
+        });
+
+        InvokeRecorder::new()
+    }
+
+    /// Process the instruction, then call `f` with the accounts as left by
+    /// the program.
+    fn process(
+        accounts: &[InputAccount],
+        instruction_data: &[u8],
+        f: impl FnOnce(ProgramResult, &[AccountView]),
+    ) {
+        let mut input = serialize_input(&ID, accounts, instruction_data);
+        let result =
+            process_instruction(unsafe { InstructionContext::new_unchecked(input.as_mut_ptr()) });
+
+        let mut views = [const { MaybeUninit::<AccountView>::uninit() }; MAX_ACCOUNTS];
+        let (_, count, _) = unsafe { deserialize(input.as_mut_ptr(), &mut views) };
+        let views =
+            unsafe { core::slice::from_raw_parts(views.as_ptr() as *const AccountView, count) };
+
+        f(result, views);
+    }
+
+    /// Return the vault address of the authority and its canonical bump.
+    fn vault_address() -> (Address, u8) {
+        Address::find_program_address(&[VAULT_SEED, AUTHORITY.as_ref()], &ID)
+    }
+
+    fn vault_data(bump: u8) -> Vec<u8> {
+        let mut data = vec![0; Vault::LEN];
+        unsafe { Vault::from_bytes_unchecked_mut(&mut data) }.initialize(&AUTHORITY, bump, 42);
+        data
+    }
+
+    fn token_account_data(owner: &Address, amount: u64) -> Vec<u8> {
+        let mut data = vec![0; 165];
+        data[0..32].copy_from_slice(MINT.as_ref());
+        data[32..64].copy_from_slice(owner.as_ref());
+        data[64..72].copy_from_slice(&amount.to_le_bytes());
+        // state: initialized
+        data[108] = 1;
+        data
+    }
+
+    #[test]
+    fn initialize() {
+        let invokes = runtime();
+        let (vault, bump) = vault_address();
+        let balance = (128 + Vault::LEN as u64) * LAMPORTS_PER_BYTE;
+
+        process(
+            &[
+                InputAccount::new(AUTHORITY, pinocchio_system::ID, LAMPORTS, &[])
+                    .signer()
+                    .writable(),
+                InputAccount::new(vault.clone(), pinocchio_system::ID, 0, &[]).writable(),
+                InputAccount::new(pinocchio_system::ID, Address::default(), 1, &[]).executable(),
+            ],
+            &[VaultInstruction::Initialize as u8],
+            |result, accounts| {
+                assert_eq!(result, Ok(()));
+
+                let state = Vault::from_account_view(&accounts[1]).unwrap();
+                assert_eq!(state.authority(), &AUTHORITY);
+                assert_eq!(state.bump(), bump);
+                assert_eq!(state.created_at(), 42);
+                assert_eq!(accounts[1].lamports(), balance);
+            },
+        );
+
+        let data = [
+            &[0, 0, 0, 0][..],
+            &balance.to_le_bytes(),
+            &(Vault::LEN as u64).to_le_bytes(),
+            ID.as_ref(),
+        ]
+        .concat();
+        invokes.assert_invoked(&[Invocation::new(&InstructionView {
+            program_id: &pinocchio_system::ID,
+            accounts: &[
+                InstructionAccount::writable_signer(&AUTHORITY),
+                InstructionAccount::writable_signer(&vault),
+            ],
+            data: &data,
+        })
+        .with_signer(&[VAULT_SEED, AUTHORITY.as_ref(), &[bump]])]);
+    }
+
+    #[test]
+    fn initialize_with_non_canonical_bump() {
+        let invokes = runtime();
+        let (_, bump) = vault_address();
+        let vault = Address::derive_address(&[VAULT_SEED, AUTHORITY.as_ref()], Some(bump - 1), &ID);
+
+        process(
+            &[
+                InputAccount::new(AUTHORITY, pinocchio_system::ID, LAMPORTS, &[])
+                    .signer()
+                    .writable(),
+                InputAccount::new(vault.clone(), pinocchio_system::ID, 0, &[]).writable(),
+                InputAccount::new(pinocchio_system::ID, Address::default(), 1, &[]).executable(),
+            ],
+            &[VaultInstruction::Initialize as u8],
+            |result, _| assert_eq!(result, Err(ProgramError::InvalidSeeds)),
+        );
+
+        invokes.assert_invoked(&[]);
+    }
+
+    #[test]
+    fn resize() {
+        let invokes = runtime();
+        let (vault, bump) = vault_address();
+        let data = vault_data(bump);
+        let balance = (128 + Vault::LEN as u64) * LAMPORTS_PER_BYTE;
+
+        process(
+            &[
+                InputAccount::new(AUTHORITY, pinocchio_system::ID, LAMPORTS, &[])
+                    .signer()
+                    .writable(),
+                InputAccount::new(vault.clone(), ID, balance, &data).writable(),
+                InputAccount::new(pinocchio_system::ID, Address::default(), 1, &[]).executable(),
+            ],
+            &[&[VaultInstruction::Resize as u8][..], &100u64.to_le_bytes()].concat(),
+            |result, accounts| {
+                assert_eq!(result, Ok(()));
+                assert_eq!(accounts[1].data_len(), 100);
+                assert_eq!(
+                    Vault::from_account_view(&accounts[1]).unwrap().authority(),
+                    &AUTHORITY
+                );
+            },
+        );
+
+        // Tops up the vault balance for the additional bytes.
+        let lamports = (100 - Vault::LEN as u64) * LAMPORTS_PER_BYTE;
+        let data = [&[2, 0, 0, 0][..], &lamports.to_le_bytes()].concat();
+        invokes.assert_invoked(&[Invocation::new(&InstructionView {
+            program_id: &pinocchio_system::ID,
+            accounts: &[
+                InstructionAccount::writable_signer(&AUTHORITY),
+                InstructionAccount::writable(&vault),
+            ],
+            data: &data,
+        })]);
+    }
+
+    #[test]
+    fn deposit_and_withdraw() {
+        // The Token program builders invoke through `solana_instruction_view`,
+        // so their CPIs are not recorded; only the results are checked.
+        let _invokes = runtime();
+        let (vault, bump) = vault_address();
+        let data = vault_data(bump);
+        let source = token_account_data(&AUTHORITY, 100);
+        let vault_token = token_account_data(&vault, 0);
+
+        let accounts = [
+            InputAccount::new(AUTHORITY, pinocchio_system::ID, LAMPORTS, &[]).signer(),
+            InputAccount::new(SOURCE, pinocchio_token::ID, 1, &source).writable(),
+            InputAccount::new(VAULT_TOKEN, pinocchio_token::ID, 1, &vault_token).writable(),
+            InputAccount::new(vault.clone(), ID, LAMPORTS, &data),
+            InputAccount::new(pinocchio_token::ID, Address::default(), 1, &[]).executable(),
+        ];
+
+        process(
+            &accounts,
+            &[&[VaultInstruction::Deposit as u8][..], &10u64.to_le_bytes()].concat(),
+            |result, _| assert_eq!(result, Ok(())),
+        );
+
+        // `Withdraw` expects the vault before the token accounts and sends the
+        // tokens back to the source account.
+        let [authority, source, vault_token, vault_account, token_program] = accounts;
+        process(
+            &[authority, vault_account, vault_token, source, token_program],
+            &[
+                &[VaultInstruction::Withdraw as u8][..],
+                &10u64.to_le_bytes(),
+            ]
+            .concat(),
+            |result, _| assert_eq!(result, Ok(())),
+        );
+    }
+
+    #[test]
+    fn withdraw_with_wrong_authority() {
+        let invokes = runtime();
+        let other = Address::new_from_array([9; 32]);
+        let (vault, bump) = vault_address();
+        let data = vault_data(bump);
+        let vault_token = token_account_data(&vault, 10);
+
+        process(
+            &[
+                InputAccount::new(other, pinocchio_system::ID, LAMPORTS, &[]).signer(),
+                InputAccount::new(vault.clone(), ID, LAMPORTS, &data),
+                InputAccount::new(VAULT_TOKEN, pinocchio_token::ID, 1, &vault_token).writable(),
+                InputAccount::new(
+                    Address::new_from_array([5; 32]),
+                    pinocchio_token::ID,
+                    1,
+                    &vault_token,
+                )
+                .writable(),
+                InputAccount::new(pinocchio_token::ID, Address::default(), 1, &[]).executable(),
+            ],
+            &[
+                &[VaultInstruction::Withdraw as u8][..],
+                &10u64.to_le_bytes(),
+            ]
+            .concat(),
+            |result, _| assert_eq!(result, Err(ProgramError::IncorrectAuthority)),
+        );
+
+        invokes.assert_invoked(&[]);
+    }
+
+    #[test]
+    fn invalid_instruction() {
+        for (accounts, instruction_data, expected) in [
+            (0, &[][..], ProgramError::InvalidInstructionData),
+            (0, &[4][..], ProgramError::InvalidInstructionData),
+            (
+                0,
+                &[VaultInstruction::Deposit as u8][..],
+                ProgramError::NotEnoughAccountKeys,
+            ),
+            (
+                6,
+                &[VaultInstruction::Deposit as u8][..],
+                ProgramError::InvalidArgument,
+            ),
+        ] {
+            let accounts = (0..accounts)
+                .map(|i| InputAccount::new(Address::new_from_array([i; 32]), ID, 1, &[]))
+                .collect::<Vec<_>>();
+
+            process(&accounts, instruction_data, |result, _| {
+                assert_eq!(result, Err(expected))
+            });
+        }
+    }
+}
@@ -0,0 +1,169 @@
+use {
+    crate::state::{Vault, VAULT_SEED},
+    pinocchio::{
+        cpi::{Seed, Signer},
+        error::ProgramError,
+        sysvars::{clock::Clock, rent::Rent, Sysvar},
+        AccountView, Address, ProgramResult,
+    },
+    pinocchio_system::{create_pda_account, instructions::Transfer},
+    pinocchio_token::{instructions::Transfer as TokenTransfer, state::TokenAccount},
+};
+
+/// Process an [`Initialize`](crate::VaultInstruction::Initialize) instruction.
+pub fn initialize(accounts: &[AccountView], data: &[u8]) -> ProgramResult {
+    let [authority, vault, _system_program] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    if !data.is_empty() {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    if !authority.is_signer() {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    // Only the PDA of the canonical bump is accepted, so each authority has a
+    // single vault.
+    let (expected, bump) =
+        Address::find_program_address(&[VAULT_SEED, authority.address().as_ref()], &crate::ID);
+
+    if vault.address() != &expected {
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    if vault.owned_by(&crate::ID) {
+        return Err(ProgramError::AccountAlreadyInitialized);
+    }
+
+    let bump = [bump];
+    let seeds = [
+        Seed::from(VAULT_SEED),
+        Seed::from(authority.address().as_ref()),
+        Seed::from(&bump),
+    ];
+
+    create_pda_account(
+        vault,
+        Vault::LEN,
+        &crate::ID,
+        authority,
+        &seeds,
+        &crate::ID,
+        None,
+    )?;
+
+    Vault::from_account_view_mut(vault)?.initialize(
+        authority.address(),
+        bump[0],
+        Clock::get()?.slot,
+    );
+
+    Ok(())
+}
+
+/// Process a [`Resize`](crate::VaultInstruction::Resize) instruction.
+pub fn resize(accounts: &[AccountView], data: &[u8]) -> ProgramResult {
+    let [authority, vault, _system_program] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    let new_len = usize::try_from(read_u64(data)?).map_err(|_| ProgramError::InvalidArgument)?;
+
+    check_authority(authority, vault)?;
+
+    if new_len < Vault::LEN {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    let minimum_balance = Rent::get()?.try_minimum_balance(new_len)?;
+
+    if let Some(lamports) = minimum_balance.checked_sub(vault.lamports()) {
+        if lamports > 0 {
+            Transfer {
+                from: authority,
+                to: vault,
+                lamports,
+            }
+            .invoke()?;
+        }
+    }
+
+    vault.resize(new_len)
+}
+
+/// Process a [`Deposit`](crate::VaultInstruction::Deposit) instruction.
+pub fn deposit(accounts: &[AccountView], data: &[u8]) -> ProgramResult {
+    let [owner, source, vault_token, vault, _token_program] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    let amount = read_u64(data)?;
+
+    // Ensures the vault is initialized.
+    drop(Vault::from_account_view(vault)?);
+    check_token_owner(vault_token, vault)?;
+
+    TokenTransfer {
+        from: source,
+        to: vault_token,
+        authority: owner,
+        amount,
+    }
+    .invoke()
+}
+
+/// Process a [`Withdraw`](crate::VaultInstruction::Withdraw) instruction.
+pub fn withdraw(accounts: &[AccountView], data: &[u8]) -> ProgramResult {
+    let [authority, vault, vault_token, destination, _token_program] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    let amount = read_u64(data)?;
+
+    let bump = [check_authority(authority, vault)?];
+    check_token_owner(vault_token, vault)?;
+
+    let seeds = [
+        Seed::from(VAULT_SEED),
+        Seed::from(authority.address().as_ref()),
+        Seed::from(&bump),
+    ];
+
+    TokenTransfer {
+        from: vault_token,
+        to: destination,
+        authority: vault,
+        amount,
+    }
+    .invoke_signed(&[Signer::from(&seeds)])
+}
+
+/// Check that the authority signed the instruction and matches the vault,
+/// returning the bump seed of the vault.
+#[inline(always)]
+fn check_authority(authority: &AccountView, vault: &AccountView) -> Result<u8, ProgramError> {
+    if !authority.is_signer() {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    let state = Vault::from_account_view(vault)?;
+
+    if state.authority() != authority.address() {
+        return Err(ProgramError::IncorrectAuthority);
+    }
+    Ok(state.bump())
+}
+
+/// Check that the token account is owned by the vault.
+#[inline(always)]
+fn check_token_owner(token_account: &AccountView, vault: &AccountView) -> ProgramResult {
+    if TokenAccount::from_account_view(token_account)?.owner() != vault.address() {
+        return Err(ProgramError::IllegalOwner);
+    }
+    Ok(())
+}
+
+#[inline(always)]
+fn read_u64(data: &[u8]) -> Result<u64, ProgramError> {
+    data.try_into()
+        .map(u64::from_le_bytes)
+        .map_err(|_| ProgramError::InvalidInstructionData)
+}
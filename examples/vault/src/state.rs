@@ -0,0 +1,109 @@
+use pinocchio::{
+    account::{Ref, RefMut},
+    error::ProgramError,
+    AccountView, Address,
+};
+
+/// Seed prefix of the vault PDA.
+///
+/// The vault is derived from `[VAULT_SEED, authority, bump]`.
+pub const VAULT_SEED: &[u8] = b"vault";
+
+/// Vault account data.
+#[repr(C)]
+pub struct Vault {
+    /// Authority allowed to resize the vault and withdraw tokens.
+    authority: Address,
+
+    /// Bump seed of the vault PDA.
+    bump: u8,
+
+    /// Slot when the vault was created.
+    created_at: [u8; 8],
+}
+
+impl Vault {
+    pub const LEN: usize = core::mem::size_of::<Vault>();
+
+    /// Return a `Vault` from the given account view.
+    ///
+    /// This method performs owner and length validation on `AccountView`, safe
+    /// borrowing the account data.
+    #[inline]
+    pub fn from_account_view(account_view: &AccountView) -> Result<Ref<Vault>, ProgramError> {
+        Self::check(account_view)?;
+        Ok(Ref::map(account_view.try_borrow()?, |data| unsafe {
+            Self::from_bytes_unchecked(data)
+        }))
+    }
+
+    /// Return a mutable `Vault` from the given account view.
+    ///
+    /// This method performs owner and length validation on `AccountView`, safe
+    /// borrowing the account data.
+    #[inline]
+    pub fn from_account_view_mut(
+        account_view: &AccountView,
+    ) -> Result<RefMut<Vault>, ProgramError> {
+        Self::check(account_view)?;
+        Ok(RefMut::map(account_view.try_borrow_mut()?, |data| unsafe {
+            Self::from_bytes_unchecked_mut(data)
+        }))
+    }
+
+    /// Return a `Vault` from the given bytes.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that `bytes` contains at least `Vault::LEN`
+    /// bytes. `Vault` has an alignment of 1 byte.
+    #[inline(always)]
+    pub unsafe fn from_bytes_unchecked(bytes: &[u8]) -> &Self {
+        &*(bytes.as_ptr() as *const Vault)
+    }
+
+    /// Return a mutable `Vault` from the given bytes.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that `bytes` contains at least `Vault::LEN`
+    /// bytes. `Vault` has an alignment of 1 byte.
+    #[inline(always)]
+    pub unsafe fn from_bytes_unchecked_mut(bytes: &mut [u8]) -> &mut Self {
+        &mut *(bytes.as_mut_ptr() as *mut Vault)
+    }
+
+    #[inline(always)]
+    pub fn authority(&self) -> &Address {
+        &self.authority
+    }
+
+    #[inline(always)]
+    pub fn bump(&self) -> u8 {
+        self.bump
+    }
+
+    #[inline(always)]
+    pub fn created_at(&self) -> u64 {
+        u64::from_le_bytes(self.created_at)
+    }
+
+    #[inline(always)]
+    pub fn initialize(&mut self, authority: &Address, bump: u8, created_at: u64) {
+        self.authority = Address::new_from_array(authority.to_bytes());
+        self.bump = bump;
+        self.created_at = created_at.to_le_bytes();
+    }
+
+    /// The vault data can be resized, so only its minimum length is checked.
+    #[inline(always)]
+    fn check(account_view: &AccountView) -> Result<(), ProgramError> {
+        if account_view.data_len() < Self::LEN {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        if !account_view.owned_by(&crate::ID) {
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+        Ok(())
+    }
+}
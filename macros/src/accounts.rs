@@ -0,0 +1,183 @@
+use {
+    proc_macro2::TokenStream,
+    quote::{quote, quote_spanned},
+    syn::{
+        parse::ParseStream, punctuated::Punctuated, spanned::Spanned, Data, DeriveInput, Error,
+        Expr, Field, Fields, GenericParam, Ident, Token,
+    },
+};
+
+/// A constraint declared in an `account` attribute.
+pub(crate) enum Constraint {
+    Signer(Ident),
+    Writable(Ident),
+    Executable(Ident),
+    Owner(Expr),
+    Address(Expr),
+}
+
+impl Constraint {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let name: Ident = input.parse()?;
+
+        match name.to_string().as_str() {
+            "signer" => Ok(Constraint::Signer(name)),
+            "writable" => Ok(Constraint::Writable(name)),
+            "executable" => Ok(Constraint::Executable(name)),
+            "owner" => {
+                input.parse::<Token![=]>()?;
+                Ok(Constraint::Owner(input.parse()?))
+            }
+            "address" => {
+                input.parse::<Token![=]>()?;
+                Ok(Constraint::Address(input.parse()?))
+            }
+            _ => Err(Error::new(
+                name.span(),
+                "unknown constraint, expected one of `signer`, `writable`, `executable`, \
+                 `owner` or `address`",
+            )),
+        }
+    }
+
+    /// Return the check of the constraint on the `account` binding.
+    fn check(&self, account: &Ident) -> TokenStream {
+        let error = quote!(::pinocchio::error::ProgramError);
+
+        match self {
+            Constraint::Signer(name) => quote_spanned! {name.span()=>
+                if !#account.is_signer() {
+                    return ::core::result::Result::Err(#error::MissingRequiredSignature);
+                }
+            },
+            Constraint::Writable(name) => quote_spanned! {name.span()=>
+                if !#account.is_writable() {
+                    return ::core::result::Result::Err(#error::Immutable);
+                }
+            },
+            Constraint::Executable(name) => quote_spanned! {name.span()=>
+                if !#account.executable() {
+                    return ::core::result::Result::Err(#error::InvalidAccountData);
+                }
+            },
+            Constraint::Owner(owner) => quote_spanned! {owner.span()=>
+                if !#account.owned_by(&#owner) {
+                    return ::core::result::Result::Err(#error::InvalidAccountOwner);
+                }
+            },
+            Constraint::Address(address) => quote_spanned! {address.span()=>
+                if #account.address() != &#address {
+                    return ::core::result::Result::Err(#error::InvalidArgument);
+                }
+            },
+        }
+    }
+}
+
+/// Parse the constraints of the `account` attributes of a field.
+pub(crate) fn constraints(field: &Field) -> syn::Result<Vec<Constraint>> {
+    let mut constraints = Vec::new();
+
+    for attr in field
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("account"))
+    {
+        constraints.extend(attr.parse_args_with(|input: ParseStream| {
+            Punctuated::<Constraint, Token![,]>::parse_terminated_with(input, Constraint::parse)
+        })?);
+    }
+
+    Ok(constraints)
+}
+
+pub fn expand(input: DeriveInput) -> syn::Result<TokenStream> {
+    let name = &input.ident;
+
+    let Data::Struct(data) = &input.data else {
+        return Err(Error::new(
+            name.span(),
+            "`Accounts` can only be derived for structs",
+        ));
+    };
+    let Fields::Named(fields) = &data.fields else {
+        return Err(Error::new(
+            name.span(),
+            "`Accounts` can only be derived for structs with named fields",
+        ));
+    };
+
+    let mut lifetimes = input
+        .generics
+        .params
+        .iter()
+        .filter_map(|param| match param {
+            GenericParam::Lifetime(lifetime) => Some(&lifetime.lifetime),
+            _ => None,
+        });
+    let (Some(lifetime), None) = (lifetimes.next(), lifetimes.next()) else {
+        return Err(Error::new(
+            input.generics.span(),
+            "`Accounts` requires a struct with a single lifetime parameter",
+        ));
+    };
+    if input.generics.type_params().next().is_some()
+        || input.generics.const_params().next().is_some()
+    {
+        return Err(Error::new(
+            input.generics.span(),
+            "`Accounts` does not support type or const parameters",
+        ));
+    }
+
+    let mut idents = Vec::with_capacity(fields.named.len());
+    let mut checks = Vec::new();
+    let mut items = Vec::with_capacity(fields.named.len());
+
+    for field in &fields.named {
+        // Named fields always have an identifier.
+        let ident = field.ident.as_ref().unwrap();
+        let constraints = constraints(field)?;
+
+        checks.extend(constraints.iter().map(|constraint| constraint.check(ident)));
+        items.push(crate::idl::account_item(ident, &constraints, false));
+        idents.push(ident);
+    }
+
+    let idl = if cfg!(feature = "idl-build") {
+        quote! {
+            impl ::pinocchio::idl::IdlAccounts for #name<'_> {
+                fn idl_accounts() -> ::pinocchio::idl::Vec<::pinocchio::idl::AccountItem> {
+                    ::pinocchio::idl::vec![#(#items,)*]
+                }
+            }
+        }
+    } else {
+        TokenStream::new()
+    };
+
+    Ok(quote! {
+        #idl
+
+        impl<#lifetime> ::core::convert::TryFrom<&#lifetime [::pinocchio::AccountView]>
+            for #name<#lifetime>
+        {
+            type Error = ::pinocchio::error::ProgramError;
+
+            #[inline(always)]
+            fn try_from(
+                accounts: &#lifetime [::pinocchio::AccountView],
+            ) -> ::core::result::Result<Self, Self::Error> {
+                let [#(#idents,)* ..] = accounts else {
+                    return ::core::result::Result::Err(
+                        ::pinocchio::error::ProgramError::NotEnoughAccountKeys,
+                    );
+                };
+
+                #(#checks)*
+
+                ::core::result::Result::Ok(Self { #(#idents,)* })
+            }
+        }
+    })
+}
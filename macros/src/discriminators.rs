@@ -0,0 +1,117 @@
+use {
+    proc_macro2::TokenStream,
+    quote::{quote, ToTokens},
+    std::collections::HashSet,
+    syn::{
+        parse::{Parse, ParseStream},
+        punctuated::Punctuated,
+        Error, Expr, Ident, Path, Token,
+    },
+};
+
+/// A discriminator declared as `Name = [..]` or as the path of a type with a
+/// `DISCRIMINATOR` constant.
+enum Entry {
+    Value { name: Ident, value: Expr },
+    Path(Path),
+}
+
+impl Entry {
+    fn name(&self) -> &Ident {
+        match self {
+            // Type paths always have a last segment.
+            Entry::Path(path) => &path.segments.last().unwrap().ident,
+            Entry::Value { name, .. } => name,
+        }
+    }
+
+    fn value(&self) -> TokenStream {
+        match self {
+            Entry::Path(path) => quote!(#path::DISCRIMINATOR),
+            Entry::Value { value, .. } => match value {
+                Expr::Array(_) | Expr::Repeat(_) => quote!(&#value),
+                _ => value.to_token_stream(),
+            },
+        }
+    }
+}
+
+impl Parse for Entry {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        if input.peek(Ident) && input.peek2(Token![=]) {
+            let name = input.parse()?;
+            input.parse::<Token![=]>()?;
+            Ok(Entry::Value {
+                name,
+                value: input.parse()?,
+            })
+        } else {
+            Ok(Entry::Path(input.parse()?))
+        }
+    }
+}
+
+/// Input of the `discriminators!` macro.
+pub struct Input(Punctuated<Entry, Token![,]>);
+
+impl Parse for Input {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        Ok(Input(Punctuated::parse_terminated(input)?))
+    }
+}
+
+pub fn expand(input: Input) -> syn::Result<TokenStream> {
+    let entries = input.0.into_iter().collect::<Vec<_>>();
+    let mut names = HashSet::new();
+
+    for entry in &entries {
+        if !names.insert(entry.name().to_string()) {
+            return Err(Error::new(
+                entry.name().span(),
+                format!("duplicated discriminator `{}`", entry.name()),
+            ));
+        }
+    }
+
+    let discriminators = entries.iter().map(|entry| {
+        let name = entry.name().to_string();
+        let value = entry.value();
+        quote! {
+            ::pinocchio::discriminator::Discriminator {
+                name: #name,
+                value: #value,
+            }
+        }
+    });
+
+    let mut checks = Vec::new();
+
+    for (i, a) in entries.iter().enumerate() {
+        for (j, b) in entries.iter().enumerate().skip(i + 1) {
+            let message = format!(
+                "discriminators of `{}` and `{}` collide",
+                a.name(),
+                b.name()
+            );
+            checks.push(quote! {
+                ::core::assert!(
+                    !::pinocchio::discriminator::collide(
+                        DISCRIMINATORS[#i].value,
+                        DISCRIMINATORS[#j].value,
+                    ),
+                    #message
+                );
+            });
+        }
+    }
+
+    Ok(quote! {
+        /// Instruction discriminators of the program.
+        pub const DISCRIMINATORS: &[::pinocchio::discriminator::Discriminator] =
+            &[#(#discriminators,)*];
+
+        const _: () = {
+            #(#checks)*
+        };
+    })
+}
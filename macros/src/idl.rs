@@ -0,0 +1,360 @@
+use {
+    crate::accounts::{constraints, Constraint},
+    proc_macro2::TokenStream,
+    quote::quote,
+    syn::{
+        spanned::Spanned, Attribute, Data, DeriveInput, Error, Expr, Fields, GenericArgument,
+        Ident, LitStr, PathArguments, Type,
+    },
+};
+
+/// Options declared in the `idl` attributes of a struct.
+#[derive(Default)]
+struct Options {
+    name: Option<LitStr>,
+    discriminator: Option<Expr>,
+    accounts: Option<Type>,
+}
+
+impl Options {
+    fn parse(attrs: &[Attribute], accounts: bool) -> syn::Result<Self> {
+        let mut options = Options::default();
+
+        for attr in attrs.iter().filter(|attr| attr.path().is_ident("idl")) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("name") {
+                    options.name = Some(meta.value()?.parse()?);
+                } else if meta.path.is_ident("discriminator") {
+                    options.discriminator = Some(meta.value()?.parse()?);
+                } else if accounts && meta.path.is_ident("accounts") {
+                    options.accounts = Some(meta.value()?.parse()?);
+                } else {
+                    return Err(meta.error("unknown `idl` option"));
+                }
+                Ok(())
+            })?;
+        }
+
+        Ok(options)
+    }
+}
+
+/// Indicate whether a field is marked with `#[idl(skip)]`.
+fn skip(attrs: &[Attribute]) -> syn::Result<bool> {
+    let mut skip = false;
+
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("idl")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("skip") {
+                skip = true;
+                Ok(())
+            } else {
+                Err(meta.error("unknown `idl` option, expected `skip`"))
+            }
+        })?;
+    }
+
+    Ok(skip)
+}
+
+/// Return the `AccountItem` of an account with the given constraints.
+pub(crate) fn account_item(
+    ident: &Ident,
+    constraints: &[Constraint],
+    optional: bool,
+) -> TokenStream {
+    let name = ident.to_string();
+    let mut writable = false;
+    let mut signer = false;
+    let mut address = quote!(::core::option::Option::None);
+
+    for constraint in constraints {
+        match constraint {
+            Constraint::Signer(_) => signer = true,
+            Constraint::Writable(_) => writable = true,
+            Constraint::Address(expr) => {
+                address = quote! {
+                    ::core::option::Option::Some(
+                        ::pinocchio::Address::new_from_array((#expr).to_bytes()),
+                    )
+                }
+            }
+            Constraint::Executable(_) | Constraint::Owner(_) => (),
+        }
+    }
+
+    quote! {
+        ::pinocchio::idl::AccountItem {
+            name: #name,
+            writable: #writable,
+            signer: #signer,
+            optional: #optional,
+            address: #address,
+        }
+    }
+}
+
+/// Return the last segment of a type path with its generic type arguments.
+fn last_segment(ty: &Type) -> Option<(&Ident, Vec<&Type>)> {
+    let Type::Path(path) = ty else {
+        return None;
+    };
+    let segment = path.path.segments.last()?;
+
+    let arguments = match &segment.arguments {
+        PathArguments::AngleBracketed(arguments) => arguments
+            .args
+            .iter()
+            .filter_map(|argument| match argument {
+                GenericArgument::Type(ty) => Some(ty),
+                _ => None,
+            })
+            .collect(),
+        _ => Vec::new(),
+    };
+
+    Some((&segment.ident, arguments))
+}
+
+/// Indicate whether the type is a reference to an `AccountView`, returning
+/// whether the account is optional.
+fn account_view(ty: &Type) -> Option<bool> {
+    match ty {
+        Type::Reference(reference) => match last_segment(&reference.elem) {
+            Some((ident, _)) if ident == "AccountView" => Some(false),
+            _ => None,
+        },
+        _ => match last_segment(ty) {
+            Some((ident, arguments)) if ident == "Option" && arguments.len() == 1 => {
+                account_view(arguments[0]).map(|_| true)
+            }
+            _ => None,
+        },
+    }
+}
+
+/// Return the IDL `Type` of a Rust type.
+fn idl_type(ty: &Type) -> syn::Result<TokenStream> {
+    let idl = quote!(::pinocchio::idl);
+
+    match ty {
+        Type::Reference(reference) => match &*reference.elem {
+            Type::Slice(slice) => match last_segment(&slice.elem) {
+                Some((ident, _)) if ident == "u8" => Ok(quote!(#idl::Type::Bytes)),
+                _ => {
+                    let inner = idl_type(&slice.elem)?;
+                    Ok(quote!(#idl::Type::Vec(#idl::Box::new(#inner))))
+                }
+            },
+            elem => match last_segment(elem) {
+                Some((ident, _)) if ident == "str" => Ok(quote!(#idl::Type::String)),
+                _ => idl_type(elem),
+            },
+        },
+        Type::Array(array) => {
+            let inner = idl_type(&array.elem)?;
+            let len = &array.len;
+            Ok(quote!(#idl::Type::Array(#idl::Box::new(#inner), #len)))
+        }
+        Type::Paren(paren) => idl_type(&paren.elem),
+        Type::Path(_) => {
+            // Type paths always have a last segment.
+            let (ident, arguments) = last_segment(ty).unwrap();
+
+            let primitive = match ident.to_string().as_str() {
+                "bool" => Some(quote!(Bool)),
+                "u8" => Some(quote!(U8)),
+                "u16" => Some(quote!(U16)),
+                "u32" => Some(quote!(U32)),
+                "u64" => Some(quote!(U64)),
+                "u128" => Some(quote!(U128)),
+                "i8" => Some(quote!(I8)),
+                "i16" => Some(quote!(I16)),
+                "i32" => Some(quote!(I32)),
+                "i64" => Some(quote!(I64)),
+                "i128" => Some(quote!(I128)),
+                "String" => Some(quote!(String)),
+                "Address" | "Pubkey" => Some(quote!(Pubkey)),
+                _ => None,
+            };
+            if let Some(primitive) = primitive {
+                return Ok(quote!(#idl::Type::#primitive));
+            }
+
+            match (ident.to_string().as_str(), arguments.as_slice()) {
+                ("Option", [inner]) => {
+                    let inner = idl_type(inner)?;
+                    Ok(quote!(#idl::Type::Option(#idl::Box::new(#inner))))
+                }
+                ("Vec", [inner]) => match last_segment(inner) {
+                    Some((ident, _)) if ident == "u8" => Ok(quote!(#idl::Type::Bytes)),
+                    _ => {
+                        let inner = idl_type(inner)?;
+                        Ok(quote!(#idl::Type::Vec(#idl::Box::new(#inner))))
+                    }
+                },
+                _ => {
+                    let name = ident.to_string();
+                    Ok(quote!(#idl::Type::Defined(#name)))
+                }
+            }
+        }
+        _ => Err(Error::new(
+            ty.span(),
+            "unsupported IDL type, use `#[idl(skip)]` to omit the field",
+        )),
+    }
+}
+
+/// Convert a `PascalCase` identifier to `snake_case`.
+fn snake_case(ident: &Ident) -> String {
+    let mut name = String::new();
+
+    for (i, c) in ident.to_string().chars().enumerate() {
+        if c.is_uppercase() {
+            if i > 0 {
+                name.push('_');
+            }
+            name.extend(c.to_lowercase());
+        } else {
+            name.push(c);
+        }
+    }
+
+    name
+}
+
+pub fn expand_instruction(input: DeriveInput) -> syn::Result<TokenStream> {
+    let name = &input.ident;
+
+    let Data::Struct(data) = &input.data else {
+        return Err(Error::new(
+            name.span(),
+            "`IdlInstruction` can only be derived for structs",
+        ));
+    };
+
+    let options = Options::parse(&input.attrs, true)?;
+    let Some(discriminator) = options.discriminator else {
+        return Err(Error::new(
+            name.span(),
+            "`IdlInstruction` requires an `#[idl(discriminator = [..])]` attribute",
+        ));
+    };
+    let instruction = options
+        .name
+        .map(|name| name.value())
+        .unwrap_or_else(|| snake_case(name));
+
+    let mut accounts = Vec::new();
+    let mut args = Vec::new();
+
+    for field in &data.fields {
+        let Some(ident) = &field.ident else {
+            return Err(Error::new(
+                field.span(),
+                "`IdlInstruction` can only be derived for structs with named fields",
+            ));
+        };
+        let constraints = constraints(field)?;
+
+        if skip(&field.attrs)? {
+            continue;
+        }
+
+        if let Some(optional) = account_view(&field.ty) {
+            accounts.push(account_item(ident, &constraints, optional));
+        } else {
+            let arg = ident.to_string();
+            let ty = idl_type(&field.ty)?;
+            args.push(quote!(::pinocchio::idl::Field { name: #arg, ty: #ty }));
+        }
+    }
+
+    if !cfg!(feature = "idl-build") {
+        return Ok(TokenStream::new());
+    }
+
+    let accounts = match options.accounts {
+        Some(ty) => quote! {{
+            let mut accounts = <#ty as ::pinocchio::idl::IdlAccounts>::idl_accounts();
+            accounts.extend([#(#accounts,)*]);
+            accounts
+        }},
+        None => quote!(::pinocchio::idl::vec![#(#accounts,)*]),
+    };
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics ::pinocchio::idl::IdlInstruction for #name #ty_generics #where_clause {
+            const NAME: &'static str = #instruction;
+
+            const DISCRIMINATOR: &'static [u8] = &#discriminator;
+
+            fn idl_accounts() -> ::pinocchio::idl::Vec<::pinocchio::idl::AccountItem> {
+                #accounts
+            }
+
+            fn idl_args() -> ::pinocchio::idl::Vec<::pinocchio::idl::Field> {
+                ::pinocchio::idl::vec![#(#args,)*]
+            }
+        }
+    })
+}
+
+pub fn expand_type(input: DeriveInput) -> syn::Result<TokenStream> {
+    let name = &input.ident;
+
+    let Data::Struct(data) = &input.data else {
+        return Err(Error::new(
+            name.span(),
+            "`IdlType` can only be derived for structs",
+        ));
+    };
+    let Fields::Named(fields) = &data.fields else {
+        return Err(Error::new(
+            name.span(),
+            "`IdlType` can only be derived for structs with named fields",
+        ));
+    };
+
+    let options = Options::parse(&input.attrs, false)?;
+    let ty = options
+        .name
+        .map(|name| name.value())
+        .unwrap_or_else(|| name.to_string());
+    let discriminator = match options.discriminator {
+        Some(discriminator) => quote!(::core::option::Option::Some(&#discriminator)),
+        None => quote!(::core::option::Option::None),
+    };
+
+    let mut idl_fields = Vec::with_capacity(fields.named.len());
+
+    for field in &fields.named {
+        if skip(&field.attrs)? {
+            continue;
+        }
+        // Named fields always have an identifier.
+        let field_name = field.ident.as_ref().unwrap().to_string();
+        let field_ty = idl_type(&field.ty)?;
+        idl_fields.push(quote!(::pinocchio::idl::Field { name: #field_name, ty: #field_ty }));
+    }
+
+    if !cfg!(feature = "idl-build") {
+        return Ok(TokenStream::new());
+    }
+
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics ::pinocchio::idl::IdlType for #name #ty_generics #where_clause {
+            const NAME: &'static str = #ty;
+
+            const DISCRIMINATOR: ::core::option::Option<&'static [u8]> = #discriminator;
+
+            fn idl_fields() -> ::pinocchio::idl::Vec<::pinocchio::idl::Field> {
+                ::pinocchio::idl::vec![#(#idl_fields,)*]
+            }
+        }
+    })
+}
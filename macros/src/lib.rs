@@ -0,0 +1,193 @@
+//! Procedural macros for [`pinocchio`](https://docs.rs/pinocchio) programs.
+//!
+//! The macros are re-exported by `pinocchio` when the `derive` feature is
+//! enabled; the generated code refers to items through the `::pinocchio`
+//! path.
+
+mod accounts;
+mod discriminators;
+mod idl;
+mod program;
+
+use {proc_macro::TokenStream, syn::parse_macro_input};
+
+/// Derive the validation of the accounts of an instruction.
+///
+/// The macro implements `TryFrom<&[AccountView]>` for a struct with
+/// `&AccountView` fields. Accounts are assigned to fields in declaration
+/// order, and each field can declare the constraints to check through the
+/// `account` attribute:
+///
+/// | Constraint     | Check                               | Error                      |
+/// |----------------|-------------------------------------|----------------------------|
+/// | `signer`       | `account.is_signer()`               | `MissingRequiredSignature` |
+/// | `writable`     | `account.is_writable()`             | `Immutable`                |
+/// | `executable`   | `account.executable()`              | `InvalidAccountData`       |
+/// | `owner = expr` | `account.owned_by(&expr)`           | `InvalidAccountOwner`      |
+/// | `address = expr` | `account.address() == &expr`      | `InvalidArgument`          |
+///
+/// When the `idl-build` feature is enabled, the macro also implements
+/// `pinocchio::idl::IdlAccounts` from the `signer`, `writable` and `address`
+/// constraints.
+///
+/// Additional accounts are ignored, while a missing account results in a
+/// `NotEnoughAccountKeys` error. Checks are performed in field order, and
+/// in the order they are declared for each field.
+///
+/// ```ignore
+/// use pinocchio::{AccountView, Accounts};
+///
+/// #[derive(Accounts)]
+/// pub struct Deposit<'a> {
+///     #[account(signer, writable)]
+///     pub payer: &'a AccountView,
+///
+///     #[account(writable, owner = crate::ID)]
+///     pub vault: &'a AccountView,
+///
+///     #[account(address = pinocchio_system::ID)]
+///     pub system_program: &'a AccountView,
+/// }
+///
+/// let accounts = Deposit::try_from(accounts)?;
+/// ```
+#[proc_macro_derive(Accounts, attributes(account))]
+pub fn derive_accounts(input: TokenStream) -> TokenStream {
+    accounts::expand(parse_macro_input!(input))
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Derive the IDL description of an instruction.
+///
+/// Fields of type `&AccountView` or `Option<&AccountView>` are the accounts of
+/// the instruction, with the `signer`, `writable` and `address` constraints of
+/// the `account` attribute; the remaining fields are the instruction
+/// arguments. This allows deriving the IDL directly from instruction builder
+/// definitions. The struct must declare the discriminator of the instruction:
+///
+/// ```ignore
+/// use pinocchio::{AccountView, IdlInstruction};
+///
+/// #[derive(IdlInstruction)]
+/// #[idl(discriminator = [2, 0, 0, 0])]
+/// pub struct Transfer<'a> {
+///     #[account(signer, writable)]
+///     pub from: &'a AccountView,
+///
+///     #[account(writable)]
+///     pub to: &'a AccountView,
+///
+///     pub lamports: u64,
+/// }
+/// ```
+///
+/// The `idl` attribute also accepts a `name` to override the default
+/// `snake_case` name of the struct, and `accounts = Type` to prepend the
+/// accounts of a struct deriving `Accounts`. Fields are omitted with
+/// `#[idl(skip)]`.
+///
+/// The macro expands to nothing unless the `idl-build` feature is enabled.
+#[proc_macro_derive(IdlInstruction, attributes(account, idl))]
+pub fn derive_idl_instruction(input: TokenStream) -> TokenStream {
+    idl::expand_instruction(parse_macro_input!(input))
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Derive the IDL description of a type.
+///
+/// Account states declare the discriminator at the start of the account data:
+///
+/// ```ignore
+/// use pinocchio::{Address, IdlType};
+///
+/// #[derive(IdlType)]
+/// #[idl(discriminator = [1])]
+/// pub struct Vault {
+///     pub authority: Address,
+///     pub bump: u8,
+/// }
+/// ```
+///
+/// The `idl` attribute also accepts a `name` to override the name of the
+/// struct. Fields are omitted with `#[idl(skip)]`.
+///
+/// The macro expands to nothing unless the `idl-build` feature is enabled.
+#[proc_macro_derive(IdlType, attributes(idl))]
+pub fn derive_idl_type(input: TokenStream) -> TokenStream {
+    idl::expand_type(parse_macro_input!(input))
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Declare the instruction discriminators of a program.
+///
+/// Discriminators are declared either as `Name = value`, where the value is an
+/// array or a `&[u8]` constant expression, or as the path of a type with a
+/// `DISCRIMINATOR` constant, e.g., an instruction builder generated by
+/// `declare_program!`:
+///
+/// ```ignore
+/// pinocchio::discriminators! {
+///     Initialize = [0],
+///     Deposit = [1],
+///     Withdraw = [2, 0],
+///     vault::instructions::Close,
+/// }
+/// ```
+///
+/// The macro generates a `DISCRIMINATORS` table of
+/// `pinocchio::discriminator::Discriminator`, which tests and tooling can
+/// introspect, and fails the build when two discriminators collide, i.e., when
+/// they are equal or one is a prefix of the other.
+#[proc_macro]
+pub fn discriminators(input: TokenStream) -> TokenStream {
+    discriminators::expand(parse_macro_input!(input))
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Declare the CPI builders and state types of an external program from its
+/// IDL.
+///
+/// The IDL is read at compile time from `idls/<name>.json`, relative to the
+/// crate root, unless a path is specified. Both Anchor (`0.1.0` spec) and
+/// Codama IDLs are supported:
+///
+/// ```ignore
+/// pinocchio::declare_program!(vault);
+/// pinocchio::declare_program!(vault, "idls/vault.codama.json");
+/// ```
+///
+/// The macro generates a `vault` module with:
+///
+/// - `ID`: the address of the program.
+/// - `instructions`: a builder for each instruction, with an `&AccountView`
+///   field per account and a field per argument, and the `invoke` and
+///   `invoke_signed` methods.
+/// - `state`: a `#[repr(C)]` struct for each account state and defined type,
+///   with a getter per field. Account states also have a `DISCRIMINATOR` and
+///   the `from_account_view` method.
+///
+/// ```ignore
+/// vault::instructions::Deposit {
+///     payer,
+///     vault,
+///     amount: 100,
+/// }
+/// .invoke()?;
+///
+/// let state = vault::state::Vault::from_account_view(vault)?;
+/// ```
+///
+/// Only fixed size types are supported, since the generated code does not
+/// allocate memory; instructions and types that use other types (e.g.,
+/// strings or vectors), optional or nested accounts are not generated and
+/// are listed in the documentation of the module.
+#[proc_macro]
+pub fn declare_program(input: TokenStream) -> TokenStream {
+    program::expand(parse_macro_input!(input))
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
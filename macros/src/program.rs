@@ -0,0 +1,1018 @@
+use {
+    proc_macro2::{Span, TokenStream},
+    quote::quote,
+    serde_json::Value,
+    std::{collections::BTreeSet, path::PathBuf},
+    syn::{
+        parse::{Parse, ParseStream},
+        Error, Ident, LitStr, Token,
+    },
+};
+
+/// Input of the `declare_program!` macro.
+pub struct Input {
+    name: Ident,
+    path: Option<LitStr>,
+}
+
+impl Parse for Input {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let name = input.parse()?;
+        let path = if input.parse::<Option<Token![,]>>()?.is_some() && !input.is_empty() {
+            Some(input.parse()?)
+        } else {
+            None
+        };
+
+        Ok(Input { name, path })
+    }
+}
+
+/// Type of an instruction argument or a field.
+///
+/// Only fixed size types are supported, since the generated code does not
+/// allocate memory.
+enum Ty {
+    Bool,
+    Int(&'static str, usize),
+    Pubkey,
+    Array(Box<Ty>, usize),
+    Defined(String),
+}
+
+struct Field {
+    name: String,
+    docs: Vec<String>,
+    ty: Ty,
+}
+
+struct Account {
+    name: String,
+    docs: Vec<String>,
+    writable: bool,
+    signer: bool,
+}
+
+struct Instruction {
+    name: String,
+    docs: Vec<String>,
+    discriminator: Vec<u8>,
+    accounts: Vec<Account>,
+    args: Vec<Field>,
+}
+
+struct TypeDef {
+    name: String,
+    docs: Vec<String>,
+    discriminator: Option<Vec<u8>>,
+    fields: Vec<Field>,
+}
+
+/// Program described by an IDL.
+///
+/// Items that cannot be represented are listed in `unsupported` instead of
+/// failing the whole expansion.
+#[derive(Default)]
+struct Program {
+    address: String,
+    instructions: Vec<Instruction>,
+    types: Vec<TypeDef>,
+    unsupported: Vec<String>,
+}
+
+type Result<T> = std::result::Result<T, String>;
+
+fn str<'a>(value: &'a Value, key: &str) -> Result<&'a str> {
+    value[key]
+        .as_str()
+        .ok_or_else(|| format!("missing `{key}` string"))
+}
+
+fn array<'a>(value: &'a Value, key: &str) -> &'a [Value] {
+    value[key].as_array().map(Vec::as_slice).unwrap_or_default()
+}
+
+fn docs(value: &Value) -> Vec<String> {
+    array(value, "docs")
+        .iter()
+        .filter_map(|doc| doc.as_str().map(str::to_string))
+        .collect()
+}
+
+fn int(format: &str) -> Option<Ty> {
+    Some(match format {
+        "u8" => Ty::Int("u8", 1),
+        "i8" => Ty::Int("i8", 1),
+        "u16" => Ty::Int("u16", 2),
+        "i16" => Ty::Int("i16", 2),
+        "u32" => Ty::Int("u32", 4),
+        "i32" => Ty::Int("i32", 4),
+        "u64" => Ty::Int("u64", 8),
+        "i64" => Ty::Int("i64", 8),
+        "u128" => Ty::Int("u128", 16),
+        "i128" => Ty::Int("i128", 16),
+        _ => return None,
+    })
+}
+
+fn bytes(value: &Value) -> Result<Vec<u8>> {
+    value
+        .as_array()
+        .ok_or("expected a byte array")?
+        .iter()
+        .map(|byte| {
+            byte.as_u64()
+                .and_then(|byte| u8::try_from(byte).ok())
+                .ok_or_else(|| "expected a byte array".to_string())
+        })
+        .collect()
+}
+
+/// Parse an IDL following the Anchor IDL specification.
+fn from_anchor(idl: &Value) -> Result<Program> {
+    fn ty(value: &Value) -> Result<Ty> {
+        if let Some(name) = value.as_str() {
+            return match name {
+                "bool" => Ok(Ty::Bool),
+                "pubkey" | "publicKey" => Ok(Ty::Pubkey),
+                _ => int(name).ok_or_else(|| format!("unsupported type `{name}`")),
+            };
+        }
+        if let Some([inner, len]) = value["array"].as_array().map(Vec::as_slice) {
+            let len = len.as_u64().ok_or("unsupported array length")?;
+            return Ok(Ty::Array(Box::new(ty(inner)?), len as usize));
+        }
+        match &value["defined"] {
+            Value::String(name) => Ok(Ty::Defined(pascal_case(name))),
+            defined @ Value::Object(_) => Ok(Ty::Defined(pascal_case(str(defined, "name")?))),
+            _ => Err(format!("unsupported type `{value}`")),
+        }
+    }
+
+    fn fields(value: &Value) -> Result<Vec<Field>> {
+        if value["kind"] != "struct" {
+            return Err("only struct types are supported".to_string());
+        }
+        array(value, "fields")
+            .iter()
+            .map(|field| {
+                Ok(Field {
+                    name: snake_case(str(field, "name")?),
+                    docs: docs(field),
+                    ty: ty(&field["type"])?,
+                })
+            })
+            .collect()
+    }
+
+    fn instruction(value: &Value) -> Result<Instruction> {
+        let accounts = array(value, "accounts")
+            .iter()
+            .map(|account| {
+                if account.get("accounts").is_some() {
+                    return Err("nested accounts are not supported".to_string());
+                }
+                let flag = |keys: [&str; 2]| keys.iter().any(|key| account[key] == true);
+                if flag(["optional", "isOptional"]) {
+                    return Err("optional accounts are not supported".to_string());
+                }
+
+                Ok(Account {
+                    name: snake_case(str(account, "name")?),
+                    docs: docs(account),
+                    writable: flag(["writable", "isMut"]),
+                    signer: flag(["signer", "isSigner"]),
+                })
+            })
+            .collect::<Result<_>>()?;
+        let args = array(value, "args")
+            .iter()
+            .map(|arg| {
+                Ok(Field {
+                    name: snake_case(str(arg, "name")?),
+                    docs: docs(arg),
+                    ty: ty(&arg["type"])?,
+                })
+            })
+            .collect::<Result<_>>()?;
+
+        Ok(Instruction {
+            name: pascal_case(str(value, "name")?),
+            docs: docs(value),
+            discriminator: bytes(&value["discriminator"])
+                .map_err(|_| "missing instruction discriminator".to_string())?,
+            accounts,
+            args,
+        })
+    }
+
+    let mut program = Program {
+        address: str(idl, "address")
+            .or_else(|_| str(&idl["metadata"], "address"))?
+            .to_string(),
+        ..Program::default()
+    };
+
+    for value in array(idl, "instructions") {
+        let name = str(value, "name")?;
+        match instruction(value) {
+            Ok(instruction) => program.instructions.push(instruction),
+            Err(error) => program
+                .unsupported
+                .push(format!("instruction `{name}`: {error}")),
+        }
+    }
+
+    let accounts = array(idl, "accounts");
+    let mut names = BTreeSet::new();
+
+    for value in accounts.iter().chain(array(idl, "types")) {
+        let name = pascal_case(str(value, "name")?);
+        if !names.insert(name.clone()) {
+            continue;
+        }
+        // The type of an account is either declared inline or in `types`.
+        let definition = if value.get("type").is_some() {
+            value
+        } else {
+            array(idl, "types")
+                .iter()
+                .find(|ty| ty["name"] == value["name"])
+                .unwrap_or(value)
+        };
+        let account = accounts
+            .iter()
+            .find(|account| account["name"] == value["name"]);
+
+        let ty = fields(&definition["type"]).and_then(|fields| {
+            Ok(TypeDef {
+                name: name.clone(),
+                docs: docs(definition),
+                discriminator: account
+                    .map(|account| bytes(&account["discriminator"]))
+                    .transpose()
+                    .map_err(|_| "missing account discriminator".to_string())?,
+                fields,
+            })
+        });
+        match ty {
+            Ok(ty) => program.types.push(ty),
+            Err(error) => program.unsupported.push(format!("type `{name}`: {error}")),
+        }
+    }
+
+    Ok(program)
+}
+
+/// Parse an IDL following the Codama root node specification.
+fn from_codama(idl: &Value) -> Result<Program> {
+    fn ty(value: &Value) -> Result<Ty> {
+        match str(value, "kind")? {
+            "numberTypeNode" => {
+                let format = str(value, "format")?;
+                int(format).ok_or_else(|| format!("unsupported number format `{format}`"))
+            }
+            "booleanTypeNode" => match value["size"]["format"].as_str() {
+                None | Some("u8") => Ok(Ty::Bool),
+                Some(format) => Err(format!("unsupported boolean size `{format}`")),
+            },
+            "publicKeyTypeNode" => Ok(Ty::Pubkey),
+            "fixedSizeTypeNode" if value["type"]["kind"] == "bytesTypeNode" => {
+                let size = value["size"].as_u64().ok_or("missing size")?;
+                Ok(Ty::Array(Box::new(Ty::Int("u8", 1)), size as usize))
+            }
+            "arrayTypeNode" if value["count"]["kind"] == "fixedCountNode" => {
+                let count = value["count"]["value"].as_u64().ok_or("missing count")?;
+                Ok(Ty::Array(Box::new(ty(&value["item"])?), count as usize))
+            }
+            "definedTypeLinkNode" => Ok(Ty::Defined(pascal_case(str(value, "name")?))),
+            kind => Err(format!("unsupported type `{kind}`")),
+        }
+    }
+
+    /// Return the bytes of a constant value.
+    fn constant(ty: &Value, value: &Value) -> Result<Vec<u8>> {
+        match (str(value, "kind")?, str(ty, "kind")?) {
+            ("numberValueNode", "numberTypeNode") => {
+                let Some(Ty::Int(_, size)) = int(str(ty, "format")?) else {
+                    return Err("unsupported constant".to_string());
+                };
+                let number = value["number"]
+                    .as_i64()
+                    .map(i128::from)
+                    .or_else(|| value["number"].as_u64().map(i128::from))
+                    .ok_or("unsupported constant")?;
+                let mut bytes = number.to_le_bytes()[..size].to_vec();
+                if ty["endian"] == "be" {
+                    bytes.reverse();
+                }
+                Ok(bytes)
+            }
+            ("bytesValueNode", _) => {
+                let data = str(value, "data")?;
+                match str(value, "encoding")? {
+                    "base16" => (0..data.len())
+                        .step_by(2)
+                        .map(|i| {
+                            data.get(i..i + 2)
+                                .and_then(|byte| u8::from_str_radix(byte, 16).ok())
+                                .ok_or_else(|| "invalid base16 constant".to_string())
+                        })
+                        .collect(),
+                    "utf8" => Ok(data.as_bytes().to_vec()),
+                    encoding => Err(format!("unsupported `{encoding}` constant")),
+                }
+            }
+            _ => Err("unsupported constant".to_string()),
+        }
+    }
+
+    /// Split the leading constant fields, which form the discriminator, from
+    /// the remaining fields.
+    fn fields(values: &[Value]) -> Result<(Vec<u8>, Vec<Field>)> {
+        let mut discriminator = Vec::new();
+        let mut fields = Vec::new();
+
+        for value in values {
+            if value.get("defaultValue").is_some() && value["defaultValueStrategy"] == "omitted" {
+                if !fields.is_empty() {
+                    return Err("constant fields must precede other fields".to_string());
+                }
+                discriminator.extend(constant(&value["type"], &value["defaultValue"])?);
+            } else {
+                fields.push(Field {
+                    name: snake_case(str(value, "name")?),
+                    docs: docs(value),
+                    ty: ty(&value["type"])?,
+                });
+            }
+        }
+
+        Ok((discriminator, fields))
+    }
+
+    fn instruction(value: &Value) -> Result<Instruction> {
+        let accounts = array(value, "accounts")
+            .iter()
+            .map(|account| {
+                if account["isOptional"] == true {
+                    return Err("optional accounts are not supported".to_string());
+                }
+
+                Ok(Account {
+                    name: snake_case(str(account, "name")?),
+                    docs: docs(account),
+                    writable: account["isWritable"] == true,
+                    signer: account["isSigner"] == true,
+                })
+            })
+            .collect::<Result<_>>()?;
+        let (discriminator, args) = fields(array(value, "arguments"))?;
+
+        Ok(Instruction {
+            name: pascal_case(str(value, "name")?),
+            docs: docs(value),
+            discriminator,
+            accounts,
+            args,
+        })
+    }
+
+    fn type_def(value: &Value, data: &Value, account: bool) -> Result<TypeDef> {
+        if data["kind"] != "structTypeNode" {
+            return Err("only struct types are supported".to_string());
+        }
+        let (discriminator, fields) = fields(array(data, "fields"))?;
+
+        Ok(TypeDef {
+            name: pascal_case(str(value, "name")?),
+            docs: docs(value),
+            discriminator: account.then_some(discriminator),
+            fields,
+        })
+    }
+
+    let root = &idl["program"];
+    let mut program = Program {
+        address: str(root, "publicKey")?.to_string(),
+        ..Program::default()
+    };
+
+    for value in array(root, "instructions") {
+        match instruction(value) {
+            Ok(instruction) => program.instructions.push(instruction),
+            Err(error) => program
+                .unsupported
+                .push(format!("instruction `{}`: {error}", str(value, "name")?)),
+        }
+    }
+
+    let accounts = array(root, "accounts")
+        .iter()
+        .map(|value| (value, &value["data"], true));
+    let types = array(root, "definedTypes")
+        .iter()
+        .map(|value| (value, &value["type"], false));
+
+    for (value, data, account) in accounts.chain(types) {
+        match type_def(value, data, account) {
+            Ok(ty) => program.types.push(ty),
+            Err(error) => program
+                .unsupported
+                .push(format!("type `{}`: {error}", str(value, "name")?)),
+        }
+    }
+
+    Ok(program)
+}
+
+impl Program {
+    /// Remove the items that refer to unsupported types.
+    fn resolve(&mut self) {
+        fn defined(ty: &Ty, types: &BTreeSet<String>) -> bool {
+            match ty {
+                Ty::Array(inner, _) => defined(inner, types),
+                Ty::Defined(name) => types.contains(name),
+                _ => true,
+            }
+        }
+
+        loop {
+            let names = self
+                .types
+                .iter()
+                .map(|ty| ty.name.clone())
+                .collect::<BTreeSet<_>>();
+            let count = self.types.len();
+
+            let (types, unsupported): (Vec<_>, Vec<_>) = self
+                .types
+                .drain(..)
+                .partition(|ty| ty.fields.iter().all(|field| defined(&field.ty, &names)));
+            self.types = types;
+            self.unsupported.extend(
+                unsupported
+                    .iter()
+                    .map(|ty| format!("type `{}`: refers to an unsupported type", ty.name)),
+            );
+
+            if self.types.len() == count {
+                let (instructions, unsupported): (Vec<_>, Vec<_>) =
+                    self.instructions.drain(..).partition(|instruction| {
+                        instruction
+                            .args
+                            .iter()
+                            .all(|field| defined(&field.ty, &names))
+                    });
+                self.instructions = instructions;
+                self.unsupported
+                    .extend(unsupported.iter().map(|instruction| {
+                        format!(
+                            "instruction `{}`: refers to an unsupported type",
+                            instruction.name
+                        )
+                    }));
+                break;
+            }
+        }
+    }
+}
+
+/// Convert a `camelCase`, `PascalCase` or `snake_case` name to `snake_case`.
+fn snake_case(name: &str) -> String {
+    let mut snake = String::new();
+
+    for (i, c) in name.chars().enumerate() {
+        if c.is_uppercase() {
+            if i > 0 && !snake.ends_with('_') {
+                snake.push('_');
+            }
+            snake.extend(c.to_lowercase());
+        } else {
+            snake.push(c);
+        }
+    }
+
+    snake
+}
+
+/// Convert a `camelCase`, `PascalCase` or `snake_case` name to `PascalCase`.
+fn pascal_case(name: &str) -> String {
+    name.split('_')
+        .flat_map(|word| {
+            let mut chars = word.chars();
+            chars
+                .next()
+                .into_iter()
+                .flat_map(char::to_uppercase)
+                .chain(chars)
+        })
+        .collect()
+}
+
+fn ident(name: &str) -> syn::Result<Ident> {
+    syn::parse_str::<Ident>(name)
+        .or_else(|_| syn::parse_str::<Ident>(&format!("r#{name}")))
+        .map_err(|_| Error::new(Span::call_site(), format!("invalid identifier `{name}`")))
+}
+
+/// Base58 alphabet used to encode addresses.
+const ALPHABET: &[u8] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+
+/// Decode a base58 encoded address.
+///
+/// Returns `None` if `address` is not the canonical encoding of the decoded
+/// bytes, e.g. when leading `1`s for zero bytes are missing.
+fn decode_address(address: &str) -> Option<[u8; 32]> {
+    let mut bytes = [0u8; 32];
+
+    for c in address.bytes() {
+        let mut carry = ALPHABET.iter().position(|a| *a == c)? as u32;
+        for byte in bytes.iter_mut().rev() {
+            carry += (*byte as u32) * 58;
+            *byte = carry as u8;
+            carry >>= 8;
+        }
+        if carry != 0 {
+            return None;
+        }
+    }
+
+    (encode_address(&bytes) == address).then_some(bytes)
+}
+
+/// Encode an address in base58.
+fn encode_address(bytes: &[u8; 32]) -> String {
+    let zeros = bytes.iter().take_while(|byte| **byte == 0).count();
+    let mut digits = Vec::new();
+
+    for byte in &bytes[zeros..] {
+        let mut carry = *byte as u32;
+        for digit in digits.iter_mut() {
+            carry += (*digit as u32) << 8;
+            *digit = (carry % 58) as u8;
+            carry /= 58;
+        }
+        while carry > 0 {
+            digits.push((carry % 58) as u8);
+            carry /= 58;
+        }
+    }
+
+    let mut encoded = "1".repeat(zeros);
+    encoded.extend(
+        digits
+            .iter()
+            .rev()
+            .map(|digit| ALPHABET[*digit as usize] as char),
+    );
+    encoded
+}
+
+fn doc_attrs(docs: &[String]) -> TokenStream {
+    quote!(#(#[doc = #docs])*)
+}
+
+/// Return the byte aligned type used to store a value of the type.
+fn storage(ty: &Ty, types: &TokenStream) -> syn::Result<TokenStream> {
+    Ok(match ty {
+        Ty::Bool => quote!(u8),
+        Ty::Int(int, 1) => {
+            let int = ident(int)?;
+            quote!(#int)
+        }
+        Ty::Int(_, size) => quote!([u8; #size]),
+        Ty::Pubkey => quote!(::pinocchio::Address),
+        Ty::Array(inner, len) => {
+            let inner = storage(inner, types)?;
+            quote!([#inner; #len])
+        }
+        Ty::Defined(name) => {
+            let name = ident(name)?;
+            quote!(#types #name)
+        }
+    })
+}
+
+/// Return the type of the values exposed by the generated code.
+fn value(ty: &Ty, types: &TokenStream) -> syn::Result<TokenStream> {
+    match ty {
+        Ty::Bool => Ok(quote!(bool)),
+        Ty::Int(int, _) => {
+            let int = ident(int)?;
+            Ok(quote!(#int))
+        }
+        _ => storage(ty, types),
+    }
+}
+
+/// Convert a value to its stored representation.
+///
+/// Values that are not `Copy` are cloned when `borrowed`.
+fn to_storage(ty: &Ty, value: TokenStream, borrowed: bool) -> TokenStream {
+    match ty {
+        Ty::Bool => quote!(#value as u8),
+        Ty::Int(_, 1) => value,
+        Ty::Int(..) => quote!(#value.to_le_bytes()),
+        _ if borrowed => quote!(#value.clone()),
+        _ => value,
+    }
+}
+
+fn expand_type(ty: &TypeDef) -> syn::Result<TokenStream> {
+    let name = ident(&ty.name)?;
+    let docs = doc_attrs(&ty.docs);
+    let none = TokenStream::new();
+
+    let mut fields = Vec::new();
+    let mut params = Vec::new();
+    let mut values = Vec::new();
+    let mut getters = Vec::new();
+
+    for field in &ty.fields {
+        let field_name = ident(&field.name)?;
+        let field_docs = doc_attrs(&field.docs);
+        let storage = storage(&field.ty, &none)?;
+        let value = value(&field.ty, &none)?;
+
+        fields.push(quote! {
+            #field_docs
+            #field_name: #storage
+        });
+        params.push(quote!(#field_name: #value));
+        values.push(to_storage(&field.ty, quote!(#field_name), false));
+        getters.push(match &field.ty {
+            Ty::Bool => quote! {
+                #field_docs
+                #[inline(always)]
+                pub fn #field_name(&self) -> bool {
+                    self.#field_name != 0
+                }
+            },
+            Ty::Int(_, 1) => quote! {
+                #field_docs
+                #[inline(always)]
+                pub fn #field_name(&self) -> #value {
+                    self.#field_name
+                }
+            },
+            Ty::Int(..) => quote! {
+                #field_docs
+                #[inline(always)]
+                pub fn #field_name(&self) -> #value {
+                    #value::from_le_bytes(self.#field_name)
+                }
+            },
+            _ => quote! {
+                #field_docs
+                #[inline(always)]
+                pub fn #field_name(&self) -> &#value {
+                    &self.#field_name
+                }
+            },
+        });
+    }
+
+    let field_names = ty
+        .fields
+        .iter()
+        .map(|field| ident(&field.name))
+        .collect::<syn::Result<Vec<_>>>()?;
+
+    let account = ty.discriminator.as_ref().map(|discriminator| {
+        quote! {
+            /// Bytes at the start of the account data that identify the type.
+            pub const DISCRIMINATOR: &'static [u8] = &[#(#discriminator),*];
+
+            /// Return the account state from the given account view.
+            ///
+            /// This method performs owner, length and discriminator validation
+            /// on `AccountView`, safe borrowing the account data.
+            #[inline]
+            pub fn from_account_view(
+                account_view: &::pinocchio::AccountView,
+            ) -> ::core::result::Result<
+                ::pinocchio::account::Ref<Self>,
+                ::pinocchio::error::ProgramError,
+            > {
+                if account_view.data_len() < Self::DISCRIMINATOR.len() + Self::LEN {
+                    return ::core::result::Result::Err(
+                        ::pinocchio::error::ProgramError::InvalidAccountData,
+                    );
+                }
+                if !account_view.owned_by(&super::ID) {
+                    return ::core::result::Result::Err(
+                        ::pinocchio::error::ProgramError::InvalidAccountOwner,
+                    );
+                }
+                let data = account_view.try_borrow()?;
+                if !data.starts_with(Self::DISCRIMINATOR) {
+                    return ::core::result::Result::Err(
+                        ::pinocchio::error::ProgramError::InvalidAccountData,
+                    );
+                }
+                ::core::result::Result::Ok(::pinocchio::account::Ref::map(data, |data| unsafe {
+                    Self::from_bytes_unchecked(&data[Self::DISCRIMINATOR.len()..])
+                }))
+            }
+        }
+    });
+
+    Ok(quote! {
+        #docs
+        #[repr(C)]
+        #[derive(Clone)]
+        pub struct #name {
+            #(#fields,)*
+        }
+
+        impl #name {
+            /// The length of the type data.
+            pub const LEN: usize = ::core::mem::size_of::<#name>();
+
+            #account
+
+            /// Create a value from its fields.
+            #[allow(clippy::too_many_arguments)]
+            #[inline(always)]
+            pub fn new(#(#params),*) -> Self {
+                Self {
+                    #(#field_names: #values,)*
+                }
+            }
+
+            /// Return a reference to the value from the given bytes.
+            ///
+            /// # Safety
+            ///
+            /// The caller must ensure that `bytes` contains a valid
+            /// representation of the type, with at least `LEN` bytes.
+            #[inline(always)]
+            pub unsafe fn from_bytes_unchecked(bytes: &[u8]) -> &Self {
+                &*(bytes.as_ptr() as *const Self)
+            }
+
+            #(#getters)*
+        }
+    })
+}
+
+fn expand_instruction(instruction: &Instruction) -> syn::Result<TokenStream> {
+    let name = ident(&instruction.name)?;
+    let docs = doc_attrs(&instruction.docs);
+    let state = quote!(super::state::);
+    let discriminator = &instruction.discriminator;
+
+    let (lifetime, impl_lifetime) = if instruction.accounts.is_empty() {
+        (TokenStream::new(), TokenStream::new())
+    } else {
+        (quote!(<'a>), quote!(<'_>))
+    };
+
+    let mut fields = Vec::new();
+    let mut accounts = Vec::new();
+    let mut views = Vec::new();
+
+    for account in &instruction.accounts {
+        let account_name = ident(&account.name)?;
+        let account_docs = doc_attrs(&account.docs);
+        let (writable, signer) = (account.writable, account.signer);
+
+        fields.push(quote! {
+            #account_docs
+            pub #account_name: &'a ::pinocchio::AccountView
+        });
+        accounts.push(quote! {
+            ::pinocchio::instruction::InstructionAccount::new(
+                self.#account_name.address(),
+                #writable,
+                #signer,
+            )
+        });
+        views.push(quote!(self.#account_name));
+    }
+
+    let mut args = Vec::new();
+    let mut values = Vec::new();
+
+    for arg in &instruction.args {
+        let arg_name = ident(&arg.name)?;
+        let arg_docs = doc_attrs(&arg.docs);
+        let value = value(&arg.ty, &state)?;
+        let storage = storage(&arg.ty, &state)?;
+
+        fields.push(quote! {
+            #arg_docs
+            pub #arg_name: #value
+        });
+        args.push(quote!(#arg_name: #storage));
+        values.push(to_storage(&arg.ty, quote!(self.#arg_name), true));
+    }
+
+    let arg_names = instruction
+        .args
+        .iter()
+        .map(|arg| ident(&arg.name))
+        .collect::<syn::Result<Vec<_>>>()?;
+    let account_count = accounts.len();
+
+    Ok(quote! {
+        #docs
+        pub struct #name #lifetime {
+            #(#fields,)*
+        }
+
+        impl #name #impl_lifetime {
+            /// Bytes at the start of the instruction data that identify the
+            /// instruction.
+            pub const DISCRIMINATOR: &'static [u8] = &[#(#discriminator),*];
+
+            #[inline(always)]
+            pub fn invoke(&self) -> ::pinocchio::ProgramResult {
+                self.invoke_signed(&[])
+            }
+
+            #[inline(always)]
+            pub fn invoke_signed(
+                &self,
+                signers: &[::pinocchio::cpi::Signer],
+            ) -> ::pinocchio::ProgramResult {
+                // Arguments are serialized with their byte aligned
+                // representation, without padding.
+                #[repr(C)]
+                struct Args {
+                    #(#args,)*
+                }
+
+                let args = Args {
+                    #(#arg_names: #values,)*
+                };
+
+                // Instruction accounts
+                let instruction_accounts: [::pinocchio::instruction::InstructionAccount;
+                    #account_count] = [#(#accounts,)*];
+
+                // instruction data
+                // - [0..N]: instruction discriminator
+                // - [N.. ]: instruction arguments
+                let mut instruction_data =
+                    [0; Self::DISCRIMINATOR.len() + ::core::mem::size_of::<Args>()];
+                instruction_data[..Self::DISCRIMINATOR.len()]
+                    .copy_from_slice(Self::DISCRIMINATOR);
+                // SAFETY: `Args` only contains byte aligned fields.
+                instruction_data[Self::DISCRIMINATOR.len()..].copy_from_slice(unsafe {
+                    ::core::slice::from_raw_parts(
+                        &args as *const Args as *const u8,
+                        ::core::mem::size_of::<Args>(),
+                    )
+                });
+
+                let instruction = ::pinocchio::instruction::InstructionView {
+                    program_id: &super::ID,
+                    accounts: &instruction_accounts,
+                    data: &instruction_data,
+                };
+
+                ::pinocchio::cpi::invoke_signed(&instruction, &[#(#views),*], signers)
+            }
+        }
+    })
+}
+
+pub fn expand(input: Input) -> syn::Result<TokenStream> {
+    let relative = input
+        .path
+        .as_ref()
+        .map(LitStr::value)
+        .unwrap_or_else(|| format!("idls/{}.json", input.name));
+    let span = input
+        .path
+        .as_ref()
+        .map(LitStr::span)
+        .unwrap_or_else(|| input.name.span());
+
+    let path = std::env::var("CARGO_MANIFEST_DIR")
+        .map(PathBuf::from)
+        .unwrap_or_default()
+        .join(&relative);
+    let json = std::fs::read_to_string(&path).map_err(|error| {
+        Error::new(
+            span,
+            format!("failed to read IDL `{}`: {error}", path.display()),
+        )
+    })?;
+    let idl = serde_json::from_str::<Value>(&json)
+        .map_err(|error| Error::new(span, format!("invalid IDL: {error}")))?;
+
+    let mut program = if idl["kind"] == "rootNode" {
+        from_codama(&idl)
+    } else {
+        from_anchor(&idl)
+    }
+    .map_err(|error| Error::new(span, format!("invalid IDL: {error}")))?;
+    program.resolve();
+
+    let address = decode_address(&program.address).ok_or_else(|| {
+        Error::new(
+            span,
+            format!("invalid IDL: invalid address `{}`", program.address),
+        )
+    })?;
+
+    let name = &input.name;
+    let path = path.display().to_string();
+    let types = program
+        .types
+        .iter()
+        .map(expand_type)
+        .collect::<syn::Result<Vec<_>>>()?;
+    let instructions = program
+        .instructions
+        .iter()
+        .map(expand_instruction)
+        .collect::<syn::Result<Vec<_>>>()?;
+
+    let unsupported = if program.unsupported.is_empty() {
+        TokenStream::new()
+    } else {
+        let items = program.unsupported.iter().map(|item| format!(" - {item}"));
+        quote! {
+            #[doc = ""]
+            #[doc = " The following items of the IDL are not generated:"]
+            #(#[doc = #items])*
+        }
+    };
+    let doc = format!(" CPI builders and state types of the `{name}` program.");
+
+    Ok(quote! {
+        #[doc = #doc]
+        #unsupported
+        pub mod #name {
+            // Recompile when the IDL changes.
+            const _: &[u8] = include_bytes!(#path);
+
+            /// The program ID.
+            pub const ID: ::pinocchio::Address =
+                ::pinocchio::Address::new_from_array([#(#address),*]);
+
+            /// Instruction builders.
+            pub mod instructions {
+                #(#instructions)*
+            }
+
+            /// Account states and types.
+            pub mod state {
+                #(#types)*
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_case() {
+        assert_eq!(snake_case("transferChecked"), "transfer_checked");
+        assert_eq!(snake_case("TransferChecked"), "transfer_checked");
+        assert_eq!(snake_case("transfer_checked"), "transfer_checked");
+        assert_eq!(pascal_case("transfer_checked"), "TransferChecked");
+        assert_eq!(pascal_case("transferChecked"), "TransferChecked");
+    }
+
+    #[test]
+    fn test_decode_address() {
+        assert_eq!(
+            decode_address("11111111111111111111111111111111"),
+            Some([0; 32])
+        );
+        assert_eq!(
+            decode_address("4vJ9JU1bJJE96FWSJKvHsmmFADCg4gpZQff4P3bkLKi"),
+            Some([1; 32])
+        );
+        assert_eq!(decode_address("0"), None);
+        // Non-canonical encodings of the zero address.
+        assert_eq!(decode_address("1"), None);
+        assert_eq!(decode_address(""), None);
+        assert_eq!(decode_address("111111111111111111111111111111111"), None);
+        // Leading `1`s must match the leading zero bytes.
+        assert_eq!(
+            decode_address("14vJ9JU1bJJE96FWSJKvHsmmFADCg4gpZQff4P3bkLKi"),
+            None
+        );
+    }
+
+    #[test]
+    fn test_encode_address() {
+        assert_eq!(encode_address(&[0; 32]), "11111111111111111111111111111111");
+        assert_eq!(
+            encode_address(&[1; 32]),
+            "4vJ9JU1bJJE96FWSJKvHsmmFADCg4gpZQff4P3bkLKi"
+        );
+
+        let mut bytes = [255; 32];
+        bytes[0] = 0;
+        assert_eq!(decode_address(&encode_address(&bytes)), Some(bytes));
+    }
+}
@@ -0,0 +1,129 @@
+use pinocchio::{
+    entrypoint::{
+        input::{serialize_input, InputAccount},
+        process_entrypoint,
+    },
+    error::ProgramError,
+    AccountView, Address, ProgramResult, SUCCESS,
+};
+
+const PROGRAM_ID: Address = Address::new_from_array([1; 32]);
+
+const SYSTEM_PROGRAM_ID: Address = Address::new_from_array([0; 32]);
+
+#[derive(pinocchio_macros::Accounts)]
+struct Deposit<'a> {
+    #[account(signer, writable)]
+    payer: &'a AccountView,
+
+    #[account(writable, owner = PROGRAM_ID)]
+    vault: &'a AccountView,
+
+    #[account(address = SYSTEM_PROGRAM_ID, executable)]
+    system_program: &'a AccountView,
+}
+
+fn process_instruction(
+    _program_id: &Address,
+    accounts: &[AccountView],
+    _instruction_data: &[u8],
+) -> ProgramResult {
+    let deposit = Deposit::try_from(accounts)?;
+
+    assert_eq!(deposit.payer.address(), accounts[0].address());
+    assert_eq!(deposit.vault.address(), accounts[1].address());
+    assert_eq!(deposit.system_program.address(), accounts[2].address());
+
+    Ok(())
+}
+
+fn run(accounts: &[InputAccount]) -> u64 {
+    let mut input = serialize_input(&PROGRAM_ID, accounts, &[]);
+    unsafe { process_entrypoint::<4>(input.as_mut_ptr(), process_instruction) }
+}
+
+fn payer() -> InputAccount<'static> {
+    InputAccount::new(
+        Address::new_from_array([2; 32]),
+        SYSTEM_PROGRAM_ID,
+        1_000,
+        &[],
+    )
+    .signer()
+    .writable()
+}
+
+fn vault() -> InputAccount<'static> {
+    InputAccount::new(Address::new_from_array([3; 32]), PROGRAM_ID, 1_000, &[]).writable()
+}
+
+fn system_program() -> InputAccount<'static> {
+    InputAccount::new(SYSTEM_PROGRAM_ID, Address::new_from_array([4; 32]), 1, &[]).executable()
+}
+
+#[test]
+fn test_accounts() {
+    assert_eq!(run(&[payer(), vault(), system_program()]), SUCCESS);
+
+    // Additional accounts are ignored.
+    assert_eq!(run(&[payer(), vault(), system_program(), vault()]), SUCCESS);
+}
+
+#[test]
+fn test_accounts_constraints() {
+    let expect = |accounts: &[InputAccount], error: ProgramError| {
+        assert_eq!(run(accounts), u64::from(error));
+    };
+
+    expect(&[payer(), vault()], ProgramError::NotEnoughAccountKeys);
+
+    expect(
+        &[
+            InputAccount::new(
+                Address::new_from_array([2; 32]),
+                SYSTEM_PROGRAM_ID,
+                1_000,
+                &[],
+            )
+            .writable(),
+            vault(),
+            system_program(),
+        ],
+        ProgramError::MissingRequiredSignature,
+    );
+
+    expect(
+        &[
+            payer(),
+            InputAccount::new(Address::new_from_array([3; 32]), PROGRAM_ID, 1_000, &[]),
+            system_program(),
+        ],
+        ProgramError::Immutable,
+    );
+
+    expect(
+        &[
+            payer(),
+            InputAccount::new(
+                Address::new_from_array([3; 32]),
+                SYSTEM_PROGRAM_ID,
+                1_000,
+                &[],
+            )
+            .writable(),
+            system_program(),
+        ],
+        ProgramError::InvalidAccountOwner,
+    );
+
+    expect(&[payer(), vault(), vault()], ProgramError::InvalidArgument);
+
+    expect(
+        &[
+            payer(),
+            vault(),
+            InputAccount::new(SYSTEM_PROGRAM_ID, Address::new_from_array([4; 32]), 1, &[]),
+        ],
+        ProgramError::InvalidAccountData,
+    );
+}
@@ -0,0 +1,46 @@
+use pinocchio::{discriminator::find, discriminators};
+
+mod instructions {
+    pub struct Close;
+
+    impl Close {
+        pub const DISCRIMINATOR: &'static [u8] = &[3];
+    }
+}
+
+const WITHDRAW: &[u8] = &[2, 0];
+
+discriminators! {
+    Initialize = [0],
+    Deposit = [1; 2],
+    Withdraw = WITHDRAW,
+    instructions::Close,
+}
+
+#[test]
+fn table() {
+    let table = DISCRIMINATORS
+        .iter()
+        .map(|discriminator| (discriminator.name, discriminator.value))
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        table,
+        vec![
+            ("Initialize", &[0][..]),
+            ("Deposit", &[1, 1][..]),
+            ("Withdraw", &[2, 0][..]),
+            ("Close", &[3][..]),
+        ]
+    );
+}
+
+#[test]
+fn find_instruction() {
+    assert_eq!(
+        find(DISCRIMINATORS, &[2, 0, 42]).map(|d| d.name),
+        Some("Withdraw")
+    );
+    assert_eq!(find(DISCRIMINATORS, &[3]).map(|d| d.name), Some("Close"));
+    assert_eq!(find(DISCRIMINATORS, &[2, 1]), None);
+}
@@ -0,0 +1,199 @@
+use pinocchio::{
+    idl::{AccountItem, Field, Idl, IdlAccounts, IdlInstruction, IdlType, Type},
+    AccountView, Accounts, Address,
+};
+
+const PROGRAM_ID: Address = Address::new_from_array([1; 32]);
+
+const SYSTEM_PROGRAM_ID: Address = Address::new_from_array([0; 32]);
+
+#[derive(Accounts)]
+#[allow(dead_code)]
+struct Deposit<'a> {
+    #[account(signer, writable)]
+    payer: &'a AccountView,
+
+    #[account(address = SYSTEM_PROGRAM_ID, executable)]
+    system_program: &'a AccountView,
+}
+
+#[derive(pinocchio::IdlInstruction)]
+#[idl(discriminator = [1], accounts = Deposit<'_>)]
+#[allow(dead_code)]
+struct DepositArgs {
+    amount: u64,
+}
+
+#[derive(pinocchio::IdlInstruction)]
+#[idl(discriminator = [12, 0, 0, 0])]
+#[allow(dead_code)]
+struct TransferChecked<'a> {
+    #[account(writable)]
+    from: &'a AccountView,
+
+    #[account(signer)]
+    authority: Option<&'a AccountView>,
+
+    #[idl(skip)]
+    signers: &'a [&'a AccountView],
+
+    amount: u64,
+
+    memo: &'a [u8],
+
+    seeds: [[u8; 32]; 2],
+
+    delegate: Option<&'a Address>,
+}
+
+#[derive(pinocchio::IdlType)]
+#[idl(discriminator = [7, 8], name = "VaultState")]
+#[allow(dead_code)]
+struct Vault {
+    authority: Address,
+    config: Config,
+    balances: Vec<u64>,
+    label: String,
+}
+
+#[derive(pinocchio::IdlType)]
+#[allow(dead_code)]
+struct Config {
+    enabled: bool,
+
+    #[idl(skip)]
+    reserved: [u8; 7],
+}
+
+#[test]
+fn accounts() {
+    assert_eq!(
+        Deposit::idl_accounts(),
+        vec![
+            AccountItem {
+                name: "payer",
+                writable: true,
+                signer: true,
+                optional: false,
+                address: None,
+            },
+            AccountItem {
+                name: "system_program",
+                writable: false,
+                signer: false,
+                optional: false,
+                address: Some(SYSTEM_PROGRAM_ID),
+            },
+        ]
+    );
+}
+
+#[test]
+fn instruction_with_accounts_struct() {
+    assert_eq!(DepositArgs::NAME, "deposit_args");
+    assert_eq!(DepositArgs::DISCRIMINATOR, &[1]);
+    assert_eq!(DepositArgs::idl_accounts(), Deposit::idl_accounts());
+    assert_eq!(
+        DepositArgs::idl_args(),
+        vec![Field {
+            name: "amount",
+            ty: Type::U64,
+        }]
+    );
+}
+
+#[test]
+fn instruction_builder() {
+    assert_eq!(TransferChecked::NAME, "transfer_checked");
+    assert_eq!(TransferChecked::DISCRIMINATOR, &[12, 0, 0, 0]);
+    assert_eq!(
+        TransferChecked::idl_accounts(),
+        vec![
+            AccountItem {
+                name: "from",
+                writable: true,
+                signer: false,
+                optional: false,
+                address: None,
+            },
+            AccountItem {
+                name: "authority",
+                writable: false,
+                signer: true,
+                optional: true,
+                address: None,
+            },
+        ]
+    );
+    assert_eq!(
+        TransferChecked::idl_args(),
+        vec![
+            Field {
+                name: "amount",
+                ty: Type::U64,
+            },
+            Field {
+                name: "memo",
+                ty: Type::Bytes,
+            },
+            Field {
+                name: "seeds",
+                ty: Type::Array(Box::new(Type::Array(Box::new(Type::U8), 32)), 2),
+            },
+            Field {
+                name: "delegate",
+                ty: Type::Option(Box::new(Type::Pubkey)),
+            },
+        ]
+    );
+}
+
+#[test]
+fn types() {
+    assert_eq!(Vault::NAME, "VaultState");
+    assert_eq!(Vault::DISCRIMINATOR, Some(&[7, 8][..]));
+    assert_eq!(
+        Vault::idl_fields(),
+        vec![
+            Field {
+                name: "authority",
+                ty: Type::Pubkey,
+            },
+            Field {
+                name: "config",
+                ty: Type::Defined("Config"),
+            },
+            Field {
+                name: "balances",
+                ty: Type::Vec(Box::new(Type::U64)),
+            },
+            Field {
+                name: "label",
+                ty: Type::String,
+            },
+        ]
+    );
+
+    assert_eq!(Config::DISCRIMINATOR, None);
+    assert_eq!(
+        Config::idl_fields(),
+        vec![Field {
+            name: "enabled",
+            ty: Type::Bool,
+        }]
+    );
+}
+
+#[test]
+fn idl() {
+    let json = Idl::new("vault", "0.1.0", &PROGRAM_ID)
+        .instruction::<DepositArgs>()
+        .account::<Vault>()
+        .ty::<Config>()
+        .to_json();
+
+    assert!(json.contains(r#""name": "deposit_args""#));
+    assert!(json.contains(r#""address": "11111111111111111111111111111111""#));
+    assert!(json.contains(r#""name": "VaultState""#));
+    assert!(json.contains(r#""name": "Config""#));
+}
@@ -0,0 +1,137 @@
+use pinocchio::{
+    declare_program,
+    entrypoint::{
+        deserialize,
+        input::{serialize_input, InputAccount},
+    },
+    error::ProgramError,
+    AccountView, Address,
+};
+
+declare_program!(vault, "tests/idls/vault.json");
+
+declare_program!(counter, "tests/idls/counter.codama.json");
+
+const PROGRAM_ID: Address = Address::new_from_array([1; 32]);
+
+/// Deserialize the accounts and pass them to `f`.
+fn with_accounts(accounts: &[InputAccount], f: impl FnOnce(&[AccountView])) {
+    let mut input = serialize_input(&PROGRAM_ID, accounts, &[]);
+    let mut views = [const { core::mem::MaybeUninit::<AccountView>::uninit() }; 4];
+
+    let (_, count, _) = unsafe { deserialize(input.as_mut_ptr(), &mut views) };
+    let views = unsafe { core::slice::from_raw_parts(views.as_ptr() as *const AccountView, count) };
+
+    f(views);
+}
+
+#[test]
+fn anchor_program() {
+    assert_eq!(vault::ID, PROGRAM_ID);
+    assert_eq!(
+        vault::instructions::Deposit::DISCRIMINATOR,
+        &[242, 35, 198, 137, 82, 225, 242, 182]
+    );
+
+    let config = vault::state::Config::new(true, 250, [1, 2, 3, 4]);
+    assert!(config.locked());
+    assert_eq!(config.fee_bps(), 250);
+    assert_eq!(config.seed(), &[1, 2, 3, 4]);
+    assert_eq!(vault::state::Config::LEN, 7);
+
+    let authority = Address::new_from_array([2; 32]);
+    let state = vault::state::Vault::new(authority.clone(), 1_000, config.clone());
+    let mut data = vault::state::Vault::DISCRIMINATOR.to_vec();
+    data.extend_from_slice(unsafe {
+        core::slice::from_raw_parts(
+            &state as *const vault::state::Vault as *const u8,
+            vault::state::Vault::LEN,
+        )
+    });
+
+    // Accounts may be larger than the state.
+    let mut padded = data.clone();
+    padded.extend_from_slice(&[0; 16]);
+
+    with_accounts(
+        &[
+            InputAccount::new(Address::new_from_array([3; 32]), PROGRAM_ID, 1, &data).writable(),
+            InputAccount::new(Address::new_from_array([4; 32]), PROGRAM_ID, 1, &data[1..]),
+            InputAccount::new(
+                Address::new_from_array([5; 32]),
+                authority.clone(),
+                1,
+                &data,
+            ),
+            InputAccount::new(Address::new_from_array([6; 32]), PROGRAM_ID, 1, &padded),
+        ],
+        |accounts| {
+            let vault = vault::state::Vault::from_account_view(&accounts[0]).unwrap();
+            assert_eq!(vault.authority(), &authority);
+            assert_eq!(vault.balance(), 1_000);
+            assert_eq!(vault.config().fee_bps(), 250);
+            drop(vault);
+
+            assert_eq!(
+                vault::state::Vault::from_account_view(&accounts[1]).err(),
+                Some(ProgramError::InvalidAccountData)
+            );
+            assert_eq!(
+                vault::state::Vault::from_account_view(&accounts[2]).err(),
+                Some(ProgramError::InvalidAccountOwner)
+            );
+
+            let vault = vault::state::Vault::from_account_view(&accounts[3]).unwrap();
+            assert_eq!(vault.balance(), 1_000);
+            drop(vault);
+
+            vault::instructions::Deposit {
+                payer: &accounts[2],
+                vault: &accounts[0],
+                system_program: &accounts[1],
+                amount: 100,
+                config,
+            }
+            .invoke()
+            .unwrap();
+        },
+    );
+}
+
+#[test]
+fn codama_program() {
+    assert_eq!(counter::ID, PROGRAM_ID);
+    assert_eq!(counter::instructions::IncrementBy::DISCRIMINATOR, &[2]);
+    assert_eq!(counter::state::Counter::DISCRIMINATOR, &[1]);
+    assert_eq!(counter::state::Counter::LEN, 36);
+
+    let mut data = vec![1];
+    data.extend_from_slice(&[6; 32]);
+    data.extend_from_slice(&7u32.to_le_bytes());
+
+    // Accounts may be larger than the state.
+    let mut padded = data.clone();
+    padded.extend_from_slice(&[0; 16]);
+
+    with_accounts(
+        &[
+            InputAccount::new(Address::new_from_array([3; 32]), PROGRAM_ID, 1, &data).writable(),
+            InputAccount::new(Address::new_from_array([4; 32]), PROGRAM_ID, 1, &[]).signer(),
+        ],
+        |accounts| {
+            let counter = counter::state::Counter::from_account_view(&accounts[0]).unwrap();
+            assert_eq!(counter.authority(), &Address::new_from_array([6; 32]));
+            assert_eq!(counter.count(), 7);
+            drop(counter);
+
+            counter::instructions::IncrementBy {
+                counter: &accounts[0],
+                authority: &accounts[1],
+                amount: 1,
+                wrapping: false,
+            }
+            .invoke()
+            .unwrap();
+        },
+    );
+}
@@ -0,0 +1,65 @@
+use pinocchio::{
+    cpi::{invoke_signed, Signer},
+    instruction::{InstructionAccount, InstructionView},
+    AccountView, ProgramResult,
+};
+
+/// Close an address lookup table account.
+///
+/// The table must have been deactivated and its deactivation slot must no
+/// longer be in the slot hashes sysvar.
+///
+/// ### Accounts:
+///   0. `[WRITE]` Address lookup table account to close
+///   1. `[SIGNER]` Current authority
+///   2. `[WRITE]` Recipient of closed account lamports
+pub struct CloseLookupTable<'a> {
+    /// Address lookup table account to close.
+    pub lookup_table: &'a AccountView,
+
+    /// Current authority.
+    pub authority: &'a AccountView,
+
+    /// Recipient of closed account lamports.
+    pub recipient: &'a AccountView,
+}
+
+impl CloseLookupTable<'_> {
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    #[inline(always)]
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        self.with_instruction(|instruction, account_views| {
+            invoke_signed(instruction, account_views, signers)
+        })
+    }
+
+    #[inline(always)]
+    pub fn with_instruction<R>(
+        &self,
+        f: impl FnOnce(&InstructionView, &[&AccountView; 3]) -> R,
+    ) -> R {
+        // Instruction accounts
+        let instruction_accounts: [InstructionAccount; 3] = [
+            InstructionAccount::writable(self.lookup_table.address()),
+            InstructionAccount::readonly_signer(self.authority.address()),
+            InstructionAccount::writable(self.recipient.address()),
+        ];
+
+        // instruction data
+        // - [0..4]: instruction discriminator
+        let instruction = InstructionView {
+            program_id: &crate::ID,
+            accounts: &instruction_accounts,
+            data: &[4, 0, 0, 0],
+        };
+
+        f(
+            &instruction,
+            &[self.lookup_table, self.authority, self.recipient],
+        )
+    }
+}
@@ -0,0 +1,87 @@
+use pinocchio::{
+    cpi::{invoke_signed, Signer},
+    instruction::{InstructionAccount, InstructionView},
+    AccountView, ProgramResult,
+};
+
+/// Create an address lookup table.
+///
+/// The table address must be derived from the authority and the recent
+/// slot, see `find_lookup_table_address`.
+///
+/// ### Accounts:
+///   0. `[WRITE]` Uninitialized address lookup table account
+///   1. `[]` Account used to derive and control the new address lookup table
+///   2. `[WRITE, SIGNER]` Account that will fund the new address lookup table
+///   3. `[]` System program
+pub struct CreateLookupTable<'a> {
+    /// Uninitialized address lookup table account.
+    pub lookup_table: &'a AccountView,
+
+    /// Account used to derive and control the new address lookup table.
+    pub authority: &'a AccountView,
+
+    /// Account that will fund the new address lookup table.
+    pub payer: &'a AccountView,
+
+    /// System program.
+    pub system_program: &'a AccountView,
+
+    /// A recent slot used to derive the table address.
+    pub recent_slot: u64,
+
+    /// Bump seed of the table address.
+    pub bump_seed: u8,
+}
+
+impl CreateLookupTable<'_> {
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    #[inline(always)]
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        self.with_instruction(|instruction, account_views| {
+            invoke_signed(instruction, account_views, signers)
+        })
+    }
+
+    #[inline(always)]
+    pub fn with_instruction<R>(
+        &self,
+        f: impl FnOnce(&InstructionView, &[&AccountView; 4]) -> R,
+    ) -> R {
+        // Instruction accounts
+        let instruction_accounts: [InstructionAccount; 4] = [
+            InstructionAccount::writable(self.lookup_table.address()),
+            InstructionAccount::readonly(self.authority.address()),
+            InstructionAccount::writable_signer(self.payer.address()),
+            InstructionAccount::readonly(self.system_program.address()),
+        ];
+
+        // instruction data
+        // - [0..4  ]: instruction discriminator
+        // - [4..12 ]: recent slot
+        // - [12    ]: bump seed
+        let mut instruction_data = [0; 13];
+        instruction_data[4..12].copy_from_slice(&self.recent_slot.to_le_bytes());
+        instruction_data[12] = self.bump_seed;
+
+        let instruction = InstructionView {
+            program_id: &crate::ID,
+            accounts: &instruction_accounts,
+            data: &instruction_data,
+        };
+
+        f(
+            &instruction,
+            &[
+                self.lookup_table,
+                self.authority,
+                self.payer,
+                self.system_program,
+            ],
+        )
+    }
+}
@@ -0,0 +1,55 @@
+use pinocchio::{
+    cpi::{invoke_signed, Signer},
+    instruction::{InstructionAccount, InstructionView},
+    AccountView, ProgramResult,
+};
+
+/// Deactivate an address lookup table, making it unusable and eligible for
+/// closure after a short period of time.
+///
+/// ### Accounts:
+///   0. `[WRITE]` Address lookup table account
+///   1. `[SIGNER]` Current authority
+pub struct DeactivateLookupTable<'a> {
+    /// Address lookup table account.
+    pub lookup_table: &'a AccountView,
+
+    /// Current authority.
+    pub authority: &'a AccountView,
+}
+
+impl DeactivateLookupTable<'_> {
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    #[inline(always)]
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        self.with_instruction(|instruction, account_views| {
+            invoke_signed(instruction, account_views, signers)
+        })
+    }
+
+    #[inline(always)]
+    pub fn with_instruction<R>(
+        &self,
+        f: impl FnOnce(&InstructionView, &[&AccountView; 2]) -> R,
+    ) -> R {
+        // Instruction accounts
+        let instruction_accounts: [InstructionAccount; 2] = [
+            InstructionAccount::writable(self.lookup_table.address()),
+            InstructionAccount::readonly_signer(self.authority.address()),
+        ];
+
+        // instruction data
+        // - [0..4]: instruction discriminator
+        let instruction = InstructionView {
+            program_id: &crate::ID,
+            accounts: &instruction_accounts,
+            data: &[3, 0, 0, 0],
+        };
+
+        f(&instruction, &[self.lookup_table, self.authority])
+    }
+}
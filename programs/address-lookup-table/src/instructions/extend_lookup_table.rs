@@ -0,0 +1,110 @@
+use pinocchio::{
+    cpi::{invoke_signed_with_bounds, Signer},
+    error::ProgramError,
+    instruction::{InstructionAccount, InstructionView},
+    AccountView, Address, ProgramResult,
+};
+
+/// Maximum number of addresses that can be appended by a single
+/// [`ExtendLookupTable`] instruction.
+pub const MAX_EXTEND_ADDRESSES: usize = 30;
+
+/// Append addresses to an address lookup table.
+///
+/// ### Accounts:
+///   0. `[WRITE]` Address lookup table account to extend
+///   1. `[SIGNER]` Current authority
+///   2. `[WRITE, SIGNER]` (Optional) Account that will fund the table
+///      reallocation
+///   3. `[]` (Optional) System program, required with the payer account
+pub struct ExtendLookupTable<'a, 'b> {
+    /// Address lookup table account to extend.
+    pub lookup_table: &'a AccountView,
+
+    /// Current authority.
+    pub authority: &'a AccountView,
+
+    /// Account that will fund the table reallocation, if the table does not
+    /// hold enough lamports to remain rent-exempt.
+    pub payer: Option<&'a AccountView>,
+
+    /// System program, required with the payer account.
+    pub system_program: Option<&'a AccountView>,
+
+    /// Addresses to append, no more than [`MAX_EXTEND_ADDRESSES`].
+    pub new_addresses: &'b [Address],
+}
+
+impl ExtendLookupTable<'_, '_> {
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    #[inline(always)]
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        self.with_instruction(|instruction, account_views| {
+            invoke_signed_with_bounds::<4>(instruction, account_views, signers)
+        })?
+    }
+
+    #[inline(always)]
+    pub fn with_instruction<R>(
+        &self,
+        f: impl FnOnce(&InstructionView, &[&AccountView]) -> R,
+    ) -> Result<R, ProgramError> {
+        if self.new_addresses.is_empty() || self.new_addresses.len() > MAX_EXTEND_ADDRESSES {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        // instruction data
+        // - [0..4  ]: instruction discriminator
+        // - [4..12 ]: number of addresses
+        // - [12..  ]: addresses (max 30)
+        let mut instruction_data = [0; 12 + MAX_EXTEND_ADDRESSES * 32];
+        instruction_data[0] = 2;
+        instruction_data[4..12].copy_from_slice(&(self.new_addresses.len() as u64).to_le_bytes());
+
+        for (chunk, address) in instruction_data[12..]
+            .chunks_exact_mut(32)
+            .zip(self.new_addresses)
+        {
+            chunk.copy_from_slice(address.as_ref());
+        }
+
+        let data = &instruction_data[..12 + self.new_addresses.len() * 32];
+
+        let lookup_table = InstructionAccount::writable(self.lookup_table.address());
+        let authority = InstructionAccount::readonly_signer(self.authority.address());
+
+        match (self.payer, self.system_program) {
+            (Some(payer), Some(system_program)) => {
+                let instruction = InstructionView {
+                    program_id: &crate::ID,
+                    accounts: &[
+                        lookup_table,
+                        authority,
+                        InstructionAccount::writable_signer(payer.address()),
+                        InstructionAccount::readonly(system_program.address()),
+                    ],
+                    data,
+                };
+
+                Ok(f(
+                    &instruction,
+                    &[self.lookup_table, self.authority, payer, system_program],
+                ))
+            }
+            (None, None) => {
+                let instruction = InstructionView {
+                    program_id: &crate::ID,
+                    accounts: &[lookup_table, authority],
+                    data,
+                };
+
+                Ok(f(&instruction, &[self.lookup_table, self.authority]))
+            }
+            _ => Err(ProgramError::InvalidArgument),
+        }
+    }
+}
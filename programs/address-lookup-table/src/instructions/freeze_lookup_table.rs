@@ -0,0 +1,56 @@
+use pinocchio::{
+    cpi::{invoke_signed, Signer},
+    instruction::{InstructionAccount, InstructionView},
+    AccountView, ProgramResult,
+};
+
+/// Permanently freeze an address lookup table, making it immutable.
+///
+/// The table must hold at least one address.
+///
+/// ### Accounts:
+///   0. `[WRITE]` Address lookup table account
+///   1. `[SIGNER]` Current authority
+pub struct FreezeLookupTable<'a> {
+    /// Address lookup table account.
+    pub lookup_table: &'a AccountView,
+
+    /// Current authority.
+    pub authority: &'a AccountView,
+}
+
+impl FreezeLookupTable<'_> {
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    #[inline(always)]
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        self.with_instruction(|instruction, account_views| {
+            invoke_signed(instruction, account_views, signers)
+        })
+    }
+
+    #[inline(always)]
+    pub fn with_instruction<R>(
+        &self,
+        f: impl FnOnce(&InstructionView, &[&AccountView; 2]) -> R,
+    ) -> R {
+        // Instruction accounts
+        let instruction_accounts: [InstructionAccount; 2] = [
+            InstructionAccount::writable(self.lookup_table.address()),
+            InstructionAccount::readonly_signer(self.authority.address()),
+        ];
+
+        // instruction data
+        // - [0..4]: instruction discriminator
+        let instruction = InstructionView {
+            program_id: &crate::ID,
+            accounts: &instruction_accounts,
+            data: &[1, 0, 0, 0],
+        };
+
+        f(&instruction, &[self.lookup_table, self.authority])
+    }
+}
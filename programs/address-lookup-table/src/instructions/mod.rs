@@ -0,0 +1,10 @@
+mod close_lookup_table;
+mod create_lookup_table;
+mod deactivate_lookup_table;
+mod extend_lookup_table;
+mod freeze_lookup_table;
+
+pub use {
+    close_lookup_table::*, create_lookup_table::*, deactivate_lookup_table::*,
+    extend_lookup_table::*, freeze_lookup_table::*,
+};
@@ -0,0 +1,20 @@
+#![no_std]
+
+pub mod instructions;
+pub mod state;
+
+pinocchio::address::declare_id!("AddressLookupTab1e1111111111111111111111111");
+
+/// Find the address and bump seed of the lookup table created by `authority`
+/// with `recent_slot`.
+///
+/// Deriving program addresses is only available on-chain or with the
+/// `curve25519` feature enabled.
+#[cfg(any(target_os = "solana", target_arch = "bpf", feature = "curve25519"))]
+#[inline(always)]
+pub fn find_lookup_table_address(
+    authority: &pinocchio::Address,
+    recent_slot: u64,
+) -> (pinocchio::Address, u8) {
+    pinocchio::Address::find_program_address(&[authority.as_ref(), &recent_slot.to_le_bytes()], &ID)
+}
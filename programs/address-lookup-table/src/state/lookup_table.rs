@@ -0,0 +1,266 @@
+use {
+    crate::ID,
+    core::{ops::Deref, slice::from_raw_parts},
+    pinocchio::{
+        account::{AccountView, Ref},
+        error::ProgramError,
+        Address,
+    },
+};
+
+/// The length of the lookup table metadata, preceding the addresses.
+pub const LOOKUP_TABLE_META_SIZE: usize = 56;
+
+/// The maximum number of addresses that a lookup table can hold.
+pub const LOOKUP_TABLE_MAX_ADDRESSES: usize = 256;
+
+/// Metadata of a lookup table.
+#[repr(C)]
+pub struct LookupTableMeta {
+    /// Type of the account, `1` for lookup tables.
+    account_type: [u8; 4],
+
+    /// Slot at which the table was deactivated, `u64::MAX` if the table is
+    /// active.
+    deactivation_slot: [u8; 8],
+
+    /// Slot at which the table was last extended.
+    last_extended_slot: [u8; 8],
+
+    /// Index of the first address added in the last extended slot.
+    last_extended_slot_start_index: u8,
+
+    /// Indicates whether the authority is present or not.
+    authority_flag: u8,
+
+    /// Authority allowed to extend, freeze, deactivate and close the table.
+    authority: Address,
+
+    /// Unused bytes.
+    _padding: [u8; 2],
+}
+
+impl LookupTableMeta {
+    /// Return the slot at which the table was deactivated.
+    ///
+    /// The value is `u64::MAX` if the table is active.
+    #[inline(always)]
+    pub fn deactivation_slot(&self) -> u64 {
+        u64::from_le_bytes(self.deactivation_slot)
+    }
+
+    /// Check whether the table is active.
+    #[inline(always)]
+    pub fn is_active(&self) -> bool {
+        self.deactivation_slot() == u64::MAX
+    }
+
+    /// Return the slot at which the table was last extended.
+    #[inline(always)]
+    pub fn last_extended_slot(&self) -> u64 {
+        u64::from_le_bytes(self.last_extended_slot)
+    }
+
+    /// Return the index of the first address added in the last extended slot.
+    #[inline(always)]
+    pub fn last_extended_slot_start_index(&self) -> u8 {
+        self.last_extended_slot_start_index
+    }
+
+    /// Check whether the table has an authority.
+    ///
+    /// Frozen tables have no authority.
+    #[inline(always)]
+    pub fn has_authority(&self) -> bool {
+        self.authority_flag != 0
+    }
+
+    /// Return the authority of the table, if any.
+    #[inline]
+    pub fn authority(&self) -> Option<&Address> {
+        if self.has_authority() {
+            Some(&self.authority)
+        } else {
+            None
+        }
+    }
+}
+
+/// Lookup table account data.
+///
+/// The data of a lookup table account is composed of:
+/// - `[0..56]`: metadata
+/// - `[56.. ]`: addresses
+pub struct AddressLookupTable<T: Deref<Target = [u8]>> {
+    data: T,
+}
+
+impl<'a> AddressLookupTable<Ref<'a, [u8]>> {
+    /// Return an `AddressLookupTable` from the given account view.
+    ///
+    /// This method performs owner and data validation on `AccountView`, safe
+    /// borrowing the account data.
+    #[inline]
+    pub fn from_account_view(account_view: &'a AccountView) -> Result<Self, ProgramError> {
+        if !account_view.owned_by(&ID) {
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+        Self::new(account_view.try_borrow()?)
+    }
+}
+
+impl<'a> AddressLookupTable<&'a [u8]> {
+    /// Return an `AddressLookupTable` from the given account view.
+    ///
+    /// This method performs owner and data validation on `AccountView`, but
+    /// does not perform the borrow check.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that it is safe to borrow the account data (e.g.,
+    /// there are no mutable borrows of the account data).
+    #[inline]
+    pub unsafe fn from_account_view_unchecked(
+        account_view: &'a AccountView,
+    ) -> Result<Self, ProgramError> {
+        if account_view.owner() != &ID {
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+        Self::from_bytes(account_view.borrow_unchecked())
+    }
+
+    /// Return an `AddressLookupTable` from the given account data.
+    ///
+    /// The account type and length of the data are validated, but the
+    /// account owner is not checked.
+    #[inline]
+    pub fn from_bytes(data: &'a [u8]) -> Result<Self, ProgramError> {
+        Self::new(data)
+    }
+}
+
+impl<T: Deref<Target = [u8]>> AddressLookupTable<T> {
+    /// Return an `AddressLookupTable` from the given data, validating its
+    /// account type and length.
+    #[inline]
+    fn new(data: T) -> Result<Self, ProgramError> {
+        if data.len() < LOOKUP_TABLE_META_SIZE
+            || (data.len() - LOOKUP_TABLE_META_SIZE) % size_of::<Address>() != 0
+            || data[0..4] != 1u32.to_le_bytes()
+        {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        Ok(Self { data })
+    }
+
+    /// Return the metadata of the table.
+    #[inline(always)]
+    pub fn meta(&self) -> &LookupTableMeta {
+        // SAFETY: `data` has at least `LOOKUP_TABLE_META_SIZE` bytes and
+        // `LookupTableMeta` has an alignment of 1 byte.
+        unsafe { &*(self.data.as_ptr() as *const LookupTableMeta) }
+    }
+
+    /// Return the addresses stored in the table.
+    #[inline(always)]
+    pub fn addresses(&self) -> &[Address] {
+        // SAFETY: `data` has `LOOKUP_TABLE_META_SIZE` bytes followed by a
+        // whole number of addresses, and `Address` has an alignment of 1 byte.
+        unsafe {
+            from_raw_parts(
+                self.data.as_ptr().add(LOOKUP_TABLE_META_SIZE) as *const Address,
+                (self.data.len() - LOOKUP_TABLE_META_SIZE) / size_of::<Address>(),
+            )
+        }
+    }
+
+    /// Return the address at `index`, if any.
+    #[inline(always)]
+    pub fn get(&self, index: u8) -> Option<&Address> {
+        self.addresses().get(index as usize)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        pinocchio::entrypoint::input::{AccountViews, InputAccount},
+    };
+
+    #[test]
+    fn lookup_table_reads_meta_and_addresses() {
+        let mut data = [0u8; LOOKUP_TABLE_META_SIZE + 2 * 32];
+        data[0..4].copy_from_slice(&1u32.to_le_bytes());
+        data[4..12].copy_from_slice(&u64::MAX.to_le_bytes());
+        data[12..20].copy_from_slice(&42u64.to_le_bytes());
+        data[20] = 1;
+        data[21] = 1;
+        data[22..54].copy_from_slice(&[7; 32]);
+        data[56..88].copy_from_slice(&[1; 32]);
+        data[88..120].copy_from_slice(&[2; 32]);
+
+        let table = AddressLookupTable::from_bytes(&data).unwrap();
+        let meta = table.meta();
+
+        assert!(meta.is_active());
+        assert_eq!(meta.last_extended_slot(), 42);
+        assert_eq!(meta.last_extended_slot_start_index(), 1);
+        assert_eq!(meta.authority().unwrap().as_array(), &[7; 32]);
+        assert_eq!(table.addresses().len(), 2);
+        assert_eq!(table.get(1).unwrap().as_array(), &[2; 32]);
+        assert!(table.get(2).is_none());
+
+        data[21] = 0;
+        let table = AddressLookupTable::from_bytes(&data).unwrap();
+        assert!(table.meta().authority().is_none());
+    }
+
+    #[test]
+    fn lookup_table_rejects_invalid_data() {
+        let mut data = [0u8; LOOKUP_TABLE_META_SIZE + 32];
+        assert!(AddressLookupTable::from_bytes(&data).is_err());
+
+        data[0] = 1;
+        assert!(AddressLookupTable::from_bytes(&data[..LOOKUP_TABLE_META_SIZE + 31]).is_err());
+        assert!(AddressLookupTable::from_bytes(&data[..LOOKUP_TABLE_META_SIZE - 1]).is_err());
+    }
+
+    #[test]
+    fn lookup_table_from_account_view() {
+        let mut data = [0u8; LOOKUP_TABLE_META_SIZE + 32];
+        data[0..4].copy_from_slice(&1u32.to_le_bytes());
+        data[56..88].copy_from_slice(&[1; 32]);
+
+        let address = Address::new_from_array([3; 32]);
+        let accounts = AccountViews::new(&[
+            InputAccount::new(address.clone(), ID, 1_000_000, &data),
+            InputAccount::new(address, Address::default(), 1_000_000, &data),
+            InputAccount::new(Address::new_from_array([4; 32]), ID, 1_000_000, &data[..87]),
+        ]);
+
+        {
+            let table = AddressLookupTable::from_account_view(&accounts[0]).unwrap();
+            assert_eq!(table.get(0).unwrap().as_array(), &[1; 32]);
+
+            // The account data is borrowed while the table is alive.
+            assert!(accounts[0].try_borrow_mut().is_err());
+        }
+
+        let _data = accounts[0].try_borrow_mut().unwrap();
+        assert!(matches!(
+            AddressLookupTable::from_account_view(&accounts[0]),
+            Err(ProgramError::AccountBorrowFailed)
+        ));
+
+        assert!(matches!(
+            AddressLookupTable::from_account_view(&accounts[1]),
+            Err(ProgramError::InvalidAccountOwner)
+        ));
+        assert!(matches!(
+            AddressLookupTable::from_account_view(&accounts[2]),
+            Err(ProgramError::InvalidAccountData)
+        ));
+    }
+}
@@ -0,0 +1,3 @@
+mod lookup_table;
+
+pub use lookup_table::*;
@@ -0,0 +1,66 @@
+use solana_address::Address;
+
+/// Address of the SPL Token program.
+pub const TOKEN_PROGRAM_ID: Address =
+    solana_address::address!("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA");
+
+/// Address of the Token-2022 program.
+pub const TOKEN_2022_PROGRAM_ID: Address =
+    solana_address::address!("TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb");
+
+/// Find the associated token account address and bump seed of `wallet` for
+/// `mint`.
+///
+/// The address depends on the token program owning `mint`, so
+/// `token_program` must be either [`TOKEN_PROGRAM_ID`] or
+/// [`TOKEN_2022_PROGRAM_ID`].
+#[inline(always)]
+pub fn find_associated_token_address(
+    wallet: &Address,
+    mint: &Address,
+    token_program: &Address,
+) -> (Address, u8) {
+    Address::find_program_address(
+        &[wallet.as_ref(), token_program.as_ref(), mint.as_ref()],
+        &crate::ID,
+    )
+}
+
+/// Return the associated token account address of `wallet` for `mint`.
+///
+/// See [`find_associated_token_address`] for the bump seed.
+#[inline(always)]
+pub fn get_associated_token_address(
+    wallet: &Address,
+    mint: &Address,
+    token_program: &Address,
+) -> Address {
+    find_associated_token_address(wallet, mint, token_program).0
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, solana_address::address};
+
+    #[test]
+    fn associated_token_address_matches_known_addresses() {
+        let wallet = address!("9WzDXwBbmkg8ZTbNMqUxvQRAyrZzDsGYdLVL9zYtAWWM");
+
+        // USDC mint, owned by the SPL Token program.
+        let mint = address!("EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v");
+        assert_eq!(
+            find_associated_token_address(&wallet, &mint, &TOKEN_PROGRAM_ID),
+            (
+                address!("FGETo8T8wMcN2wCjav8VK6eh3dLk63evNDPxzLSJra8B"),
+                254
+            )
+        );
+
+        // PYUSD mint, owned by the Token-2022 program.
+        let mint = address!("2b1kV6DkPAnxd5ixfnxCpjxmKwqjjaYmCZfHsFu24GXo");
+        assert_eq!(
+            get_associated_token_address(&wallet, &mint, &TOKEN_2022_PROGRAM_ID),
+            address!("897krAvWH3RbymaCYE3o9emopUwocieHuKTUk9nySpq6")
+        );
+    }
+}
@@ -40,6 +40,16 @@ impl Create<'_> {
 
     #[inline(always)]
     pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        self.with_instruction(|instruction, account_views| {
+            invoke_signed(instruction, account_views, signers)
+        })
+    }
+
+    #[inline(always)]
+    pub fn with_instruction<R>(
+        &self,
+        f: impl FnOnce(&InstructionView, &[&AccountView; 6]) -> R,
+    ) -> R {
         // Instruction accounts
         let instruction_accounts: [InstructionAccount; 6] = [
             InstructionAccount::writable_signer(self.funding_account.address()),
@@ -61,7 +71,7 @@ impl Create<'_> {
             data: &instruction_data,
         };
 
-        invoke_signed(
+        f(
             &instruction,
             &[
                 self.funding_account,
@@ -71,7 +81,6 @@ impl Create<'_> {
                 self.system_program,
                 self.token_program,
             ],
-            signers,
         )
     }
 }
@@ -41,6 +41,16 @@ impl CreateIdempotent<'_> {
 
     #[inline(always)]
     pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        self.with_instruction(|instruction, account_views| {
+            invoke_signed(instruction, account_views, signers)
+        })
+    }
+
+    #[inline(always)]
+    pub fn with_instruction<R>(
+        &self,
+        f: impl FnOnce(&InstructionView, &[&AccountView; 6]) -> R,
+    ) -> R {
         // Instruction accounts
         let instruction_accounts: [InstructionAccount; 6] = [
             InstructionAccount::writable_signer(self.funding_account.address()),
@@ -62,7 +72,7 @@ impl CreateIdempotent<'_> {
             data: &instruction_data,
         };
 
-        invoke_signed(
+        f(
             &instruction,
             &[
                 self.funding_account,
@@ -72,7 +82,6 @@ impl CreateIdempotent<'_> {
                 self.system_program,
                 self.token_program,
             ],
-            signers,
         )
     }
 }
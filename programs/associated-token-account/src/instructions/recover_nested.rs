@@ -53,6 +53,16 @@ impl RecoverNested<'_> {
 
     #[inline(always)]
     pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        self.with_instruction(|instruction, account_views| {
+            invoke_signed(instruction, account_views, signers)
+        })
+    }
+
+    #[inline(always)]
+    pub fn with_instruction<R>(
+        &self,
+        f: impl FnOnce(&InstructionView, &[&AccountView; 7]) -> R,
+    ) -> R {
         // Instruction accounts
         let instruction_accounts: [InstructionAccount; 7] = [
             InstructionAccount::writable(self.account.address()),
@@ -75,7 +85,7 @@ impl RecoverNested<'_> {
             data: &instruction_data,
         };
 
-        invoke_signed(
+        f(
             &instruction,
             &[
                 self.account,
@@ -86,7 +96,6 @@ impl RecoverNested<'_> {
                 self.wallet,
                 self.token_program,
             ],
-            signers,
         )
     }
 }
@@ -1,5 +1,10 @@
 #![no_std]
 
+#[cfg(any(target_os = "solana", target_arch = "bpf", feature = "curve25519"))]
+mod address;
 pub mod instructions;
 
+#[cfg(any(target_os = "solana", target_arch = "bpf", feature = "curve25519"))]
+pub use address::*;
+
 solana_address::declare_id!("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL");
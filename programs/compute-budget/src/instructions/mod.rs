@@ -0,0 +1,16 @@
+//! Encoders of the Compute Budget program instructions.
+//!
+//! Compute budget instructions are processed by the runtime before the
+//! transaction is executed, so they cannot be invoked through a CPI. The
+//! encoders can be used to build transactions or to compare against the
+//! instructions of the current transaction.
+
+mod request_heap_frame;
+mod set_compute_unit_limit;
+mod set_compute_unit_price;
+mod set_loaded_accounts_data_size_limit;
+
+pub use {
+    request_heap_frame::*, set_compute_unit_limit::*, set_compute_unit_price::*,
+    set_loaded_accounts_data_size_limit::*,
+};
@@ -0,0 +1,24 @@
+/// Request a specific transaction-wide program heap region size in bytes.
+///
+/// The value must be a multiple of 1024 and no larger than 256 KiB. This
+/// applies to each program executed in the transaction, including CPIs.
+pub struct RequestHeapFrame {
+    /// Heap region size in bytes.
+    pub bytes: u32,
+}
+
+impl RequestHeapFrame {
+    pub const DISCRIMINATOR: u8 = 1;
+
+    /// Return the instruction data.
+    #[inline(always)]
+    pub fn data(&self) -> [u8; 5] {
+        // instruction data
+        // - [0   ]: instruction discriminator
+        // - [1..5]: bytes
+        let mut instruction_data = [0; 5];
+        instruction_data[0] = Self::DISCRIMINATOR;
+        instruction_data[1..5].copy_from_slice(&self.bytes.to_le_bytes());
+        instruction_data
+    }
+}
@@ -0,0 +1,22 @@
+/// Set a specific compute unit limit that the transaction is allowed to
+/// consume.
+pub struct SetComputeUnitLimit {
+    /// Compute unit limit of the transaction.
+    pub units: u32,
+}
+
+impl SetComputeUnitLimit {
+    pub const DISCRIMINATOR: u8 = 2;
+
+    /// Return the instruction data.
+    #[inline(always)]
+    pub fn data(&self) -> [u8; 5] {
+        // instruction data
+        // - [0   ]: instruction discriminator
+        // - [1..5]: units
+        let mut instruction_data = [0; 5];
+        instruction_data[0] = Self::DISCRIMINATOR;
+        instruction_data[1..5].copy_from_slice(&self.units.to_le_bytes());
+        instruction_data
+    }
+}
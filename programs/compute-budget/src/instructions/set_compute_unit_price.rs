@@ -0,0 +1,22 @@
+/// Set a compute unit price in micro-lamports to pay a higher transaction fee
+/// for higher transaction prioritization.
+pub struct SetComputeUnitPrice {
+    /// Price of a compute unit in micro-lamports.
+    pub micro_lamports: u64,
+}
+
+impl SetComputeUnitPrice {
+    pub const DISCRIMINATOR: u8 = 3;
+
+    /// Return the instruction data.
+    #[inline(always)]
+    pub fn data(&self) -> [u8; 9] {
+        // instruction data
+        // - [0   ]: instruction discriminator
+        // - [1..9]: micro_lamports
+        let mut instruction_data = [0; 9];
+        instruction_data[0] = Self::DISCRIMINATOR;
+        instruction_data[1..9].copy_from_slice(&self.micro_lamports.to_le_bytes());
+        instruction_data
+    }
+}
@@ -0,0 +1,22 @@
+/// Set a specific transaction-wide account data size limit, in bytes, that
+/// the transaction is allowed to load.
+pub struct SetLoadedAccountsDataSizeLimit {
+    /// Loaded accounts data size limit in bytes.
+    pub bytes: u32,
+}
+
+impl SetLoadedAccountsDataSizeLimit {
+    pub const DISCRIMINATOR: u8 = 4;
+
+    /// Return the instruction data.
+    #[inline(always)]
+    pub fn data(&self) -> [u8; 5] {
+        // instruction data
+        // - [0   ]: instruction discriminator
+        // - [1..5]: bytes
+        let mut instruction_data = [0; 5];
+        instruction_data[0] = Self::DISCRIMINATOR;
+        instruction_data[1..5].copy_from_slice(&self.bytes.to_le_bytes());
+        instruction_data
+    }
+}
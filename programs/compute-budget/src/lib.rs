@@ -0,0 +1,8 @@
+#![no_std]
+
+pub mod instructions;
+mod request;
+
+pub use request::*;
+
+pinocchio::address::declare_id!("ComputeBudget111111111111111111111111111111");
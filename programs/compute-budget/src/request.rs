@@ -0,0 +1,151 @@
+use {
+    crate::instructions::{
+        RequestHeapFrame, SetComputeUnitLimit, SetComputeUnitPrice, SetLoadedAccountsDataSizeLimit,
+    },
+    core::ops::Deref,
+    pinocchio::{error::ProgramError, sysvars::instructions::Instructions},
+};
+
+/// Compute budget requested by the instructions of a transaction.
+///
+/// Fields are `None` when the transaction does not include the
+/// corresponding instruction, in which case the runtime defaults apply.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct ComputeBudgetRequest {
+    /// Requested compute unit limit.
+    pub compute_unit_limit: Option<u32>,
+
+    /// Requested compute unit price in micro-lamports.
+    pub compute_unit_price: Option<u64>,
+
+    /// Requested heap region size in bytes.
+    pub heap_frame: Option<u32>,
+
+    /// Requested loaded accounts data size limit in bytes.
+    pub loaded_accounts_data_size_limit: Option<u32>,
+}
+
+/// Return the compute budget requested by the current transaction.
+///
+/// `instructions` is the instructions sysvar of the current transaction. All
+/// of its instructions are scanned, including the ones following the current
+/// instruction.
+///
+/// Returns [`ProgramError::InvalidInstructionData`] if a compute budget
+/// instruction cannot be decoded. The runtime rejects such transactions, so
+/// this is only expected with invalid sysvar data.
+pub fn get_compute_budget_request<T>(
+    instructions: &Instructions<T>,
+) -> Result<ComputeBudgetRequest, ProgramError>
+where
+    T: Deref<Target = [u8]>,
+{
+    let mut request = ComputeBudgetRequest::default();
+
+    for index in 0..instructions.num_instructions() {
+        let instruction = instructions.load_instruction_at(index)?;
+
+        if instruction.get_program_id() != &crate::ID {
+            continue;
+        }
+
+        match instruction.get_instruction_data() {
+            [RequestHeapFrame::DISCRIMINATOR, bytes @ ..] => {
+                request.heap_frame = Some(u32::from_le_bytes(to_array(bytes)?));
+            }
+            [SetComputeUnitLimit::DISCRIMINATOR, units @ ..] => {
+                request.compute_unit_limit = Some(u32::from_le_bytes(to_array(units)?));
+            }
+            [SetComputeUnitPrice::DISCRIMINATOR, micro_lamports @ ..] => {
+                request.compute_unit_price = Some(u64::from_le_bytes(to_array(micro_lamports)?));
+            }
+            [SetLoadedAccountsDataSizeLimit::DISCRIMINATOR, bytes @ ..] => {
+                request.loaded_accounts_data_size_limit =
+                    Some(u32::from_le_bytes(to_array(bytes)?));
+            }
+            _ => return Err(ProgramError::InvalidInstructionData),
+        }
+    }
+
+    Ok(request)
+}
+
+#[inline(always)]
+fn to_array<const N: usize>(bytes: &[u8]) -> Result<[u8; N], ProgramError> {
+    bytes
+        .try_into()
+        .map_err(|_| ProgramError::InvalidInstructionData)
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, pinocchio::Address};
+
+    #[repr(C, align(8))]
+    struct SysvarData([u8; 256]);
+
+    /// Build instructions sysvar data with the given program instructions,
+    /// without accounts.
+    fn build_sysvar_data(instructions: &[(&Address, &[u8])]) -> (SysvarData, usize) {
+        let mut data = SysvarData([0; 256]);
+        let bytes = &mut data.0;
+        bytes[0..2].copy_from_slice(&(instructions.len() as u16).to_le_bytes());
+
+        let mut offset = 2 + instructions.len() * 2;
+        for (index, (program_id, instruction_data)) in instructions.iter().enumerate() {
+            bytes[2 + index * 2..4 + index * 2].copy_from_slice(&(offset as u16).to_le_bytes());
+            // no accounts
+            offset += 2;
+            bytes[offset..offset + 32].copy_from_slice(program_id.as_ref());
+            offset += 32;
+            bytes[offset..offset + 2]
+                .copy_from_slice(&(instruction_data.len() as u16).to_le_bytes());
+            offset += 2;
+            bytes[offset..offset + instruction_data.len()].copy_from_slice(instruction_data);
+            offset += instruction_data.len();
+        }
+
+        // current instruction index
+        offset += 2;
+
+        (data, offset)
+    }
+
+    #[test]
+    fn compute_budget_request_reads_instructions() {
+        let other_program = Address::new_from_array([1; 32]);
+        let limit = SetComputeUnitLimit { units: 200_000 }.data();
+        let price = SetComputeUnitPrice {
+            micro_lamports: 5_000,
+        }
+        .data();
+
+        let (data, len) = build_sysvar_data(&[
+            (&crate::ID, &limit),
+            (&other_program, &[2, 0, 0, 0, 0]),
+            (&crate::ID, &price),
+        ]);
+        let instructions = unsafe { Instructions::new_unchecked(&data.0[..len]) };
+
+        assert_eq!(
+            get_compute_budget_request(&instructions).unwrap(),
+            ComputeBudgetRequest {
+                compute_unit_limit: Some(200_000),
+                compute_unit_price: Some(5_000),
+                heap_frame: None,
+                loaded_accounts_data_size_limit: None,
+            }
+        );
+    }
+
+    #[test]
+    fn compute_budget_request_rejects_invalid_data() {
+        let (data, len) = build_sysvar_data(&[(&crate::ID, &[3, 0, 0])]);
+        let instructions = unsafe { Instructions::new_unchecked(&data.0[..len]) };
+
+        assert_eq!(
+            get_compute_budget_request(&instructions),
+            Err(ProgramError::InvalidInstructionData)
+        );
+    }
+}
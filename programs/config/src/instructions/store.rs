@@ -0,0 +1,150 @@
+use {
+    crate::short_vec::{self, MAX_ENCODING_LENGTH},
+    core::mem::MaybeUninit,
+    pinocchio::{
+        cpi::{invoke_signed_with_bounds, Signer, MAX_STATIC_CPI_ACCOUNTS},
+        error::ProgramError,
+        instruction::{InstructionAccount, InstructionView},
+        AccountView, Address, ProgramResult,
+    },
+};
+
+/// Maximum length of the instruction data of a [`Store`] instruction.
+pub const MAX_STORE_DATA_LEN: usize = 1024;
+
+/// Key to store in a config account.
+pub struct StoreKey<'a> {
+    /// Address of the key.
+    pub address: &'a Address,
+
+    /// Indicates whether the key must sign to update the config account.
+    pub is_signer: bool,
+}
+
+/// Store new data in a config account.
+///
+/// The config account must sign the first store. Afterwards, the keys marked
+/// as signers in the stored keys list must sign.
+///
+/// ### Accounts:
+///   0. `[WRITE, SIGNER]` Config account, signer if storing for the first time
+///   1. `..1+N` `[SIGNER]` N signer keys
+pub struct Store<'a, 'b> {
+    /// Config account.
+    pub config: &'a AccountView,
+
+    /// Indicates whether the config account signs.
+    pub is_config_signer: bool,
+
+    /// Signer keys of the config account.
+    pub signers: &'b [&'a AccountView],
+
+    /// Keys to store.
+    pub keys: &'b [StoreKey<'b>],
+
+    /// Config data to store after the keys.
+    pub data: &'b [u8],
+}
+
+impl Store<'_, '_> {
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    #[inline(always)]
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        self.with_instruction(|instruction, account_views| {
+            invoke_signed_with_bounds::<MAX_STATIC_CPI_ACCOUNTS>(
+                instruction,
+                account_views,
+                signers,
+            )
+        })?
+    }
+
+    #[inline(always)]
+    pub fn with_instruction<R>(
+        &self,
+        f: impl FnOnce(&InstructionView, &[&AccountView]) -> R,
+    ) -> Result<R, ProgramError> {
+        let num_accounts = 1 + self.signers.len();
+        let data_len = self
+            .keys
+            .len()
+            .checked_mul(33)
+            .and_then(|keys_len| keys_len.checked_add(MAX_ENCODING_LENGTH + self.data.len()));
+
+        if num_accounts > MAX_STATIC_CPI_ACCOUNTS
+            || !matches!(data_len, Some(len) if len <= MAX_STORE_DATA_LEN)
+        {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        // Instruction accounts
+
+        const UNINIT_INSTRUCTION_ACCOUNT: MaybeUninit<InstructionAccount> =
+            MaybeUninit::<InstructionAccount>::uninit();
+        let mut instruction_accounts = [UNINIT_INSTRUCTION_ACCOUNT; MAX_STATIC_CPI_ACCOUNTS];
+
+        instruction_accounts[0].write(InstructionAccount::new(
+            self.config.address(),
+            true,
+            self.is_config_signer,
+        ));
+
+        for (account, signer) in instruction_accounts[1..num_accounts]
+            .iter_mut()
+            .zip(self.signers.iter())
+        {
+            account.write(InstructionAccount::readonly_signer(signer.address()));
+        }
+
+        // Accounts
+
+        const UNINIT_ACCOUNT: MaybeUninit<&AccountView> = MaybeUninit::<&AccountView>::uninit();
+        let mut accounts = [UNINIT_ACCOUNT; MAX_STATIC_CPI_ACCOUNTS];
+
+        accounts[0].write(self.config);
+
+        for (account, signer) in accounts[1..num_accounts]
+            .iter_mut()
+            .zip(self.signers.iter())
+        {
+            account.write(signer);
+        }
+
+        // instruction data
+        // - [0..  ]: number of keys (compact-encoded, max 3 bytes)
+        // - [..  ]: keys (address and signer flag, 33 bytes each)
+        // - [..  ]: config data
+        let mut instruction_data = [0; MAX_STORE_DATA_LEN];
+
+        let mut keys_len = [0; MAX_ENCODING_LENGTH];
+        let mut offset = short_vec::encode_len(self.keys.len() as u16, &mut keys_len);
+        instruction_data[..offset].copy_from_slice(&keys_len[..offset]);
+
+        for key in self.keys {
+            instruction_data[offset..offset + 32].copy_from_slice(key.address.as_ref());
+            instruction_data[offset + 32] = key.is_signer as u8;
+            offset += 33;
+        }
+
+        instruction_data[offset..offset + self.data.len()].copy_from_slice(self.data);
+        offset += self.data.len();
+
+        let instruction = InstructionView {
+            program_id: &crate::ID,
+            // SAFETY: `num_accounts` instruction accounts are initialized.
+            accounts: unsafe {
+                core::slice::from_raw_parts(instruction_accounts.as_ptr() as _, num_accounts)
+            },
+            data: &instruction_data[..offset],
+        };
+
+        Ok(f(
+            &instruction, // SAFETY: `num_accounts` accounts are initialized.
+            unsafe { core::slice::from_raw_parts(accounts.as_ptr() as _, num_accounts) },
+        ))
+    }
+}
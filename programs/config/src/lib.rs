@@ -0,0 +1,7 @@
+#![no_std]
+
+pub mod instructions;
+mod short_vec;
+pub mod state;
+
+pinocchio::address::declare_id!("Config1111111111111111111111111111111111111");
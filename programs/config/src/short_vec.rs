@@ -0,0 +1,76 @@
+//! Compact encoding of the length of the config keys list.
+//!
+//! Lengths are encoded on 1 to 3 bytes, with 7 bits per byte and the high bit
+//! set when more bytes follow.
+
+/// Maximum number of bytes of an encoded length.
+pub(crate) const MAX_ENCODING_LENGTH: usize = 3;
+
+/// Encode `len` into `bytes`, returning the number of bytes written.
+#[inline(always)]
+pub(crate) fn encode_len(mut len: u16, bytes: &mut [u8; MAX_ENCODING_LENGTH]) -> usize {
+    let mut size = 0;
+
+    loop {
+        let byte = (len & 0x7f) as u8;
+        len >>= 7;
+
+        if len == 0 {
+            bytes[size] = byte;
+            return size + 1;
+        }
+
+        bytes[size] = byte | 0x80;
+        size += 1;
+    }
+}
+
+/// Decode a length from the start of `bytes`, returning the length and the
+/// number of bytes read.
+///
+/// Returns `None` if the encoding is truncated, not minimal or exceeds
+/// `u16::MAX`.
+#[inline(always)]
+pub(crate) fn decode_len(bytes: &[u8]) -> Option<(usize, usize)> {
+    let mut len = 0usize;
+
+    for (size, byte) in bytes.iter().take(MAX_ENCODING_LENGTH).enumerate() {
+        len |= ((byte & 0x7f) as usize) << (size * 7);
+
+        if byte & 0x80 == 0 {
+            // Reject non-minimal encodings and values larger than `u16::MAX`.
+            if (size > 0 && *byte == 0) || len > u16::MAX as usize {
+                return None;
+            }
+            return Some((len, size + 1));
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn short_vec_round_trip() {
+        for (len, expected) in [
+            (0u16, &[0x00][..]),
+            (0x7f, &[0x7f]),
+            (0x80, &[0x80, 0x01]),
+            (0x3fff, &[0xff, 0x7f]),
+            (0x4000, &[0x80, 0x80, 0x01]),
+            (u16::MAX, &[0xff, 0xff, 0x03]),
+        ] {
+            let mut bytes = [0; MAX_ENCODING_LENGTH];
+            let size = encode_len(len, &mut bytes);
+            assert_eq!(&bytes[..size], expected);
+            assert_eq!(decode_len(expected), Some((len as usize, size)));
+        }
+
+        assert_eq!(decode_len(&[0x80]), None);
+        assert_eq!(decode_len(&[0x80, 0x00]), None);
+        assert_eq!(decode_len(&[0xff, 0xff, 0x04]), None);
+    }
+}
@@ -0,0 +1,199 @@
+use {
+    crate::{short_vec, ID},
+    core::{ops::Deref, slice::from_raw_parts},
+    pinocchio::{
+        account::{AccountView, Ref},
+        error::ProgramError,
+        Address,
+    },
+};
+
+/// Key of a config account.
+#[repr(C)]
+pub struct ConfigKey {
+    /// Address of the key.
+    address: Address,
+
+    /// Indicates whether the key must sign to update the config account.
+    is_signer: u8,
+}
+
+impl ConfigKey {
+    /// The length of the serialized `ConfigKey`.
+    pub const LEN: usize = core::mem::size_of::<ConfigKey>();
+
+    /// Return the address of the key.
+    #[inline(always)]
+    pub fn address(&self) -> &Address {
+        &self.address
+    }
+
+    /// Check whether the key must sign to update the config account.
+    #[inline(always)]
+    pub fn is_signer(&self) -> bool {
+        self.is_signer != 0
+    }
+}
+
+/// Config account data.
+///
+/// The data of a config account is composed of the list of config keys,
+/// prefixed by its compact-encoded length, followed by the config data.
+pub struct ConfigState<T: Deref<Target = [u8]>> {
+    data: T,
+    keys_offset: usize,
+    keys_len: usize,
+}
+
+impl<'a> ConfigState<Ref<'a, [u8]>> {
+    /// Return a `ConfigState` from the given account view.
+    ///
+    /// This method performs owner and data validation on `AccountView`, safe
+    /// borrowing the account data.
+    #[inline]
+    pub fn from_account_view(account_view: &'a AccountView) -> Result<Self, ProgramError> {
+        if !account_view.owned_by(&ID) {
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+        Self::new(account_view.try_borrow()?)
+    }
+}
+
+impl<'a> ConfigState<&'a [u8]> {
+    /// Return a `ConfigState` from the given account view.
+    ///
+    /// This method performs owner and data validation on `AccountView`, but
+    /// does not perform the borrow check.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that it is safe to borrow the account data (e.g.,
+    /// there are no mutable borrows of the account data).
+    #[inline]
+    pub unsafe fn from_account_view_unchecked(
+        account_view: &'a AccountView,
+    ) -> Result<Self, ProgramError> {
+        if account_view.owner() != &ID {
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+        Self::from_bytes(account_view.borrow_unchecked())
+    }
+
+    /// Return a `ConfigState` from the given account data.
+    ///
+    /// The keys list is validated, but the account owner is not checked.
+    #[inline]
+    pub fn from_bytes(data: &'a [u8]) -> Result<Self, ProgramError> {
+        Self::new(data)
+    }
+}
+
+impl<T: Deref<Target = [u8]>> ConfigState<T> {
+    /// Return a `ConfigState` from the given data, validating its keys list.
+    #[inline]
+    fn new(data: T) -> Result<Self, ProgramError> {
+        let (keys_len, keys_offset) =
+            short_vec::decode_len(&data).ok_or(ProgramError::InvalidAccountData)?;
+
+        if data.len() < keys_offset + keys_len * ConfigKey::LEN {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        Ok(Self {
+            data,
+            keys_offset,
+            keys_len,
+        })
+    }
+
+    /// Return the keys of the config account.
+    #[inline(always)]
+    pub fn keys(&self) -> &[ConfigKey] {
+        // SAFETY: `data` holds `keys_len` keys after the length, and
+        // `ConfigKey` has an alignment of 1 byte.
+        unsafe {
+            from_raw_parts(
+                self.data.as_ptr().add(self.keys_offset) as *const ConfigKey,
+                self.keys_len,
+            )
+        }
+    }
+
+    /// Return the config data following the keys.
+    ///
+    /// The data includes any unused space at the end of the account.
+    #[inline(always)]
+    pub fn data(&self) -> &[u8] {
+        &self.data[self.keys_offset + self.keys_len * ConfigKey::LEN..]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        pinocchio::entrypoint::input::{AccountViews, InputAccount},
+    };
+
+    #[test]
+    fn config_state_reads_keys_and_data() {
+        let mut data = [0u8; 1 + 2 * ConfigKey::LEN + 4];
+        data[0] = 2;
+        data[1..33].copy_from_slice(&[1; 32]);
+        data[33] = 1;
+        data[34..66].copy_from_slice(&[2; 32]);
+        data[67..71].copy_from_slice(b"data");
+
+        let state = ConfigState::from_bytes(&data).unwrap();
+        let keys = state.keys();
+
+        assert_eq!(keys.len(), 2);
+        assert_eq!(keys[0].address().as_array(), &[1; 32]);
+        assert!(keys[0].is_signer());
+        assert_eq!(keys[1].address().as_array(), &[2; 32]);
+        assert!(!keys[1].is_signer());
+        assert_eq!(state.data(), b"data");
+
+        assert!(ConfigState::from_bytes(&data[..60]).is_err());
+        assert!(ConfigState::from_bytes(&[]).is_err());
+    }
+
+    #[test]
+    fn config_state_from_account_view() {
+        let mut data = [0u8; 1 + ConfigKey::LEN + 4];
+        data[0] = 1;
+        data[1..33].copy_from_slice(&[1; 32]);
+        data[34..38].copy_from_slice(b"data");
+
+        let address = Address::new_from_array([3; 32]);
+        let accounts = AccountViews::new(&[
+            InputAccount::new(address.clone(), ID, 1_000_000, &data),
+            InputAccount::new(address, Address::default(), 1_000_000, &data),
+            InputAccount::new(Address::new_from_array([4; 32]), ID, 1_000_000, &data[..33]),
+        ]);
+
+        {
+            let state = ConfigState::from_account_view(&accounts[0]).unwrap();
+            assert_eq!(state.keys()[0].address().as_array(), &[1; 32]);
+            assert_eq!(state.data(), b"data");
+
+            // The account data is borrowed while the state is alive.
+            assert!(accounts[0].try_borrow_mut().is_err());
+        }
+
+        let _data = accounts[0].try_borrow_mut().unwrap();
+        assert!(matches!(
+            ConfigState::from_account_view(&accounts[0]),
+            Err(ProgramError::AccountBorrowFailed)
+        ));
+
+        assert!(matches!(
+            ConfigState::from_account_view(&accounts[1]),
+            Err(ProgramError::InvalidAccountOwner)
+        ));
+        assert!(matches!(
+            ConfigState::from_account_view(&accounts[2]),
+            Err(ProgramError::InvalidAccountData)
+        ));
+    }
+}
@@ -0,0 +1,3 @@
+mod config_state;
+
+pub use config_state::*;
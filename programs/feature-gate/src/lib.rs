@@ -0,0 +1,5 @@
+#![no_std]
+
+pub mod state;
+
+pinocchio::address::declare_id!("Feature111111111111111111111111111111111111");
@@ -0,0 +1,128 @@
+use {
+    crate::ID,
+    pinocchio::{
+        account::{AccountView, Ref},
+        error::ProgramError,
+    },
+};
+
+/// Feature account data.
+///
+/// The data of a feature account is composed of:
+/// - `[0   ]`: activation flag
+/// - `[1..9]`: slot at which the feature was activated
+///
+/// Feature accounts are created with an empty activation slot when the
+/// feature is proposed, and the runtime sets it when activating the feature
+/// at an epoch boundary.
+#[repr(C)]
+pub struct Feature {
+    /// Indicates whether the feature is activated or not.
+    activated_flag: u8,
+
+    /// Slot at which the feature was activated.
+    activated_at: [u8; 8],
+}
+
+impl Feature {
+    /// The length of the `Feature` account data.
+    pub const LEN: usize = core::mem::size_of::<Feature>();
+
+    /// Return a `Feature` from the given account view.
+    ///
+    /// This method performs owner and length validation on `AccountView`, safe
+    /// borrowing the account data.
+    #[inline]
+    pub fn from_account_view(account_view: &AccountView) -> Result<Ref<Feature>, ProgramError> {
+        if !account_view.owned_by(&ID) {
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+        Ref::try_map(account_view.try_borrow()?, Self::from_bytes).map_err(|(_, error)| error)
+    }
+
+    /// Return a `Feature` from the given account view.
+    ///
+    /// This method performs owner and length validation on `AccountView`, but
+    /// does not perform the borrow check.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that it is safe to borrow the account data (e.g.,
+    /// there are no mutable borrows of the account data).
+    #[inline]
+    pub unsafe fn from_account_view_unchecked(
+        account_view: &AccountView,
+    ) -> Result<&Self, ProgramError> {
+        if account_view.owner() != &ID {
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+        Self::from_bytes(account_view.borrow_unchecked())
+    }
+
+    /// Return a `Feature` from the given bytes.
+    ///
+    /// This method performs a length validation, requiring `bytes` to hold at
+    /// least `Feature::LEN` bytes.
+    #[inline]
+    pub fn from_bytes(bytes: &[u8]) -> Result<&Self, ProgramError> {
+        if bytes.len() < Self::LEN {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        // SAFETY: `bytes` holds at least `LEN` bytes and `Feature` has an
+        // alignment of 1 byte.
+        Ok(unsafe { &*(bytes.as_ptr() as *const Feature) })
+    }
+
+    /// Check whether the feature is activated.
+    #[inline(always)]
+    pub fn is_active(&self) -> bool {
+        self.activated_flag != 0
+    }
+
+    /// Return the slot at which the feature was activated, if any.
+    #[inline]
+    pub fn activated_at(&self) -> Option<u64> {
+        if self.is_active() {
+            Some(u64::from_le_bytes(self.activated_at))
+        } else {
+            None
+        }
+    }
+}
+
+/// Check whether the feature of the given feature account is active.
+///
+/// Returns `false` if the account is not owned by the feature gate program,
+/// which is the case for features that have not been proposed yet.
+/// Returns [`ProgramError::InvalidAccountData`] if the account data is too
+/// short to be a feature account.
+#[inline]
+pub fn is_feature_active(account_view: &AccountView) -> Result<bool, ProgramError> {
+    if !account_view.owned_by(&ID) {
+        return Ok(false);
+    }
+    Ok(Feature::from_account_view(account_view)?.is_active())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn feature_reads_activation_slot() {
+        let mut data = [0u8; Feature::LEN];
+        assert_eq!(Feature::LEN, 9);
+
+        let feature = Feature::from_bytes(&data).unwrap();
+        assert!(!feature.is_active());
+        assert_eq!(feature.activated_at(), None);
+
+        data[0] = 1;
+        data[1..9].copy_from_slice(&1_234u64.to_le_bytes());
+        let feature = Feature::from_bytes(&data).unwrap();
+        assert!(feature.is_active());
+        assert_eq!(feature.activated_at(), Some(1_234));
+
+        assert!(Feature::from_bytes(&data[..8]).is_err());
+    }
+}
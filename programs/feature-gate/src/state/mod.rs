@@ -0,0 +1,3 @@
+mod feature;
+
+pub use feature::*;
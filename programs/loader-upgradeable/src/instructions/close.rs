@@ -0,0 +1,84 @@
+use pinocchio::{
+    cpi::{invoke_signed_with_bounds, Signer},
+    error::ProgramError,
+    instruction::{InstructionAccount, InstructionView},
+    AccountView, ProgramResult,
+};
+
+/// Close an account owned by the upgradeable loader, transferring its
+/// lamports to the recipient.
+///
+/// ### Accounts:
+///   0. `[WRITE]` Buffer, program data or uninitialized account to close
+///   1. `[WRITE]` Recipient account
+///   2. `[SIGNER]` (Optional) Authority, required unless closing an
+///      uninitialized account
+///   3. `[WRITE]` (Optional) Program account, required when closing a
+///      program data account
+pub struct Close<'a> {
+    /// Account to close.
+    pub account: &'a AccountView,
+
+    /// Recipient account.
+    pub recipient: &'a AccountView,
+
+    /// Authority of the account, required unless closing an uninitialized
+    /// account.
+    pub authority: Option<&'a AccountView>,
+
+    /// Program account, required when closing a program data account.
+    pub program: Option<&'a AccountView>,
+}
+
+impl Close<'_> {
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    #[inline(always)]
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        self.with_instruction(|instruction, account_views| {
+            invoke_signed_with_bounds::<4>(instruction, account_views, signers)
+        })?
+    }
+
+    #[inline(always)]
+    pub fn with_instruction<R>(
+        &self,
+        f: impl FnOnce(&InstructionView, &[&AccountView]) -> R,
+    ) -> Result<R, ProgramError> {
+        let num_accounts = match (self.authority, self.program) {
+            (None, None) => 2,
+            (Some(_), None) => 3,
+            (Some(_), Some(_)) => 4,
+            (None, Some(_)) => return Err(ProgramError::InvalidArgument),
+        };
+
+        // Optional accounts are only included when present; unused slots
+        // hold a placeholder that is sliced off.
+        let authority = self.authority.unwrap_or(self.account);
+        let program = self.program.unwrap_or(self.account);
+
+        // Instruction accounts
+        let instruction_accounts: [InstructionAccount; 4] = [
+            InstructionAccount::writable(self.account.address()),
+            InstructionAccount::writable(self.recipient.address()),
+            InstructionAccount::readonly_signer(authority.address()),
+            InstructionAccount::writable(program.address()),
+        ];
+
+        // instruction data
+        // - [0..4]: instruction discriminator
+        let instruction = InstructionView {
+            program_id: &crate::ID,
+            accounts: &instruction_accounts[..num_accounts],
+            data: &[5, 0, 0, 0],
+        };
+
+        Ok(f(
+            &instruction,
+            &[self.account, self.recipient, authority, program][..num_accounts],
+        ))
+    }
+}
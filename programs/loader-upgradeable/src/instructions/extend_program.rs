@@ -0,0 +1,87 @@
+use pinocchio::{
+    cpi::{invoke_signed_with_bounds, Signer},
+    error::ProgramError,
+    instruction::{InstructionAccount, InstructionView},
+    AccountView, ProgramResult,
+};
+
+/// Extend the data of a program data account.
+///
+/// The payer and system program accounts are required if the program data
+/// account does not hold enough lamports to remain rent-exempt after the
+/// extension.
+///
+/// ### Accounts:
+///   0. `[WRITE]` Program data account
+///   1. `[WRITE]` Program account
+///   2. `[]` (Optional) System program
+///   3. `[WRITE, SIGNER]` (Optional) Payer account
+pub struct ExtendProgram<'a> {
+    /// Program data account.
+    pub programdata: &'a AccountView,
+
+    /// Program account.
+    pub program: &'a AccountView,
+
+    /// System program, required with the payer account.
+    pub system_program: Option<&'a AccountView>,
+
+    /// Payer account funding the extension.
+    pub payer: Option<&'a AccountView>,
+
+    /// Number of bytes to extend the program data by.
+    pub additional_bytes: u32,
+}
+
+impl ExtendProgram<'_> {
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    #[inline(always)]
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        self.with_instruction(|instruction, account_views| {
+            invoke_signed_with_bounds::<4>(instruction, account_views, signers)
+        })?
+    }
+
+    #[inline(always)]
+    pub fn with_instruction<R>(
+        &self,
+        f: impl FnOnce(&InstructionView, &[&AccountView]) -> R,
+    ) -> Result<R, ProgramError> {
+        let (system_program, payer, num_accounts) = match (self.system_program, self.payer) {
+            (Some(system_program), Some(payer)) => (system_program, payer, 4),
+            // The unused slots hold a placeholder that is sliced off.
+            (None, None) => (self.programdata, self.programdata, 2),
+            _ => return Err(ProgramError::InvalidArgument),
+        };
+
+        // Instruction accounts
+        let instruction_accounts: [InstructionAccount; 4] = [
+            InstructionAccount::writable(self.programdata.address()),
+            InstructionAccount::writable(self.program.address()),
+            InstructionAccount::readonly(system_program.address()),
+            InstructionAccount::writable_signer(payer.address()),
+        ];
+
+        // instruction data
+        // - [0..4]: instruction discriminator
+        // - [4..8]: additional bytes
+        let mut instruction_data = [0; 8];
+        instruction_data[0] = 6;
+        instruction_data[4..8].copy_from_slice(&self.additional_bytes.to_le_bytes());
+
+        let instruction = InstructionView {
+            program_id: &crate::ID,
+            accounts: &instruction_accounts[..num_accounts],
+            data: &instruction_data,
+        };
+
+        Ok(f(
+            &instruction,
+            &[self.programdata, self.program, system_program, payer][..num_accounts],
+        ))
+    }
+}
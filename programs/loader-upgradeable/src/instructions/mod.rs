@@ -0,0 +1,6 @@
+mod close;
+mod extend_program;
+mod set_authority;
+mod upgrade;
+
+pub use {close::*, extend_program::*, set_authority::*, upgrade::*};
@@ -0,0 +1,67 @@
+use pinocchio::{
+    cpi::{invoke_signed_with_bounds, Signer},
+    instruction::{InstructionAccount, InstructionView},
+    AccountView, ProgramResult,
+};
+
+/// Set a new authority of a buffer or program data account.
+///
+/// Omitting the new authority makes a program immutable. Buffer accounts
+/// require a new authority.
+///
+/// ### Accounts:
+///   0. `[WRITE]` Buffer or program data account
+///   1. `[SIGNER]` Current authority
+///   2. `[]` (Optional) New authority
+pub struct SetAuthority<'a> {
+    /// Buffer or program data account.
+    pub account: &'a AccountView,
+
+    /// Current authority.
+    pub authority: &'a AccountView,
+
+    /// New authority, `None` to make a program immutable.
+    pub new_authority: Option<&'a AccountView>,
+}
+
+impl SetAuthority<'_> {
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    #[inline(always)]
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        self.with_instruction(|instruction, account_views| {
+            invoke_signed_with_bounds::<3>(instruction, account_views, signers)
+        })
+    }
+
+    #[inline(always)]
+    pub fn with_instruction<R>(&self, f: impl FnOnce(&InstructionView, &[&AccountView]) -> R) -> R {
+        // The new authority is only included when present; the unused slot
+        // holds a placeholder that is sliced off.
+        let new_authority = self.new_authority.unwrap_or(self.account);
+        let num_accounts = 2 + self.new_authority.is_some() as usize;
+
+        // Instruction accounts
+        let instruction_accounts: [InstructionAccount; 3] = [
+            InstructionAccount::writable(self.account.address()),
+            InstructionAccount::readonly_signer(self.authority.address()),
+            InstructionAccount::readonly(new_authority.address()),
+        ];
+
+        // instruction data
+        // - [0..4]: instruction discriminator
+        let instruction = InstructionView {
+            program_id: &crate::ID,
+            accounts: &instruction_accounts[..num_accounts],
+            data: &[4, 0, 0, 0],
+        };
+
+        f(
+            &instruction,
+            &[self.account, self.authority, new_authority][..num_accounts],
+        )
+    }
+}
@@ -0,0 +1,99 @@
+use pinocchio::{
+    cpi::{invoke_signed, Signer},
+    instruction::{InstructionAccount, InstructionView},
+    AccountView, ProgramResult,
+};
+
+/// Upgrade a program.
+///
+/// The program data is replaced by the content of the buffer account, which
+/// is then closed and its lamports transferred to the spill account.
+///
+/// ### Accounts:
+///   0. `[WRITE]` Program data account
+///   1. `[WRITE]` Program account
+///   2. `[WRITE]` Buffer account with the new program data
+///   3. `[WRITE]` Spill account
+///   4. `[]` Rent sysvar
+///   5. `[]` Clock sysvar
+///   6. `[SIGNER]` Upgrade authority
+pub struct Upgrade<'a> {
+    /// Program data account.
+    pub programdata: &'a AccountView,
+
+    /// Program account.
+    pub program: &'a AccountView,
+
+    /// Buffer account with the new program data.
+    pub buffer: &'a AccountView,
+
+    /// Spill account receiving the lamports of the buffer account.
+    pub spill: &'a AccountView,
+
+    /// Rent sysvar.
+    ///
+    /// The address of the account must be
+    /// [`RENT_ID`](pinocchio::sysvars::rent::RENT_ID).
+    pub rent_sysvar: &'a AccountView,
+
+    /// Clock sysvar.
+    ///
+    /// The address of the account must be
+    /// [`CLOCK_ID`](pinocchio::sysvars::clock::CLOCK_ID).
+    pub clock_sysvar: &'a AccountView,
+
+    /// Upgrade authority.
+    pub authority: &'a AccountView,
+}
+
+impl Upgrade<'_> {
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    #[inline(always)]
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        self.with_instruction(|instruction, account_views| {
+            invoke_signed(instruction, account_views, signers)
+        })
+    }
+
+    #[inline(always)]
+    pub fn with_instruction<R>(
+        &self,
+        f: impl FnOnce(&InstructionView, &[&AccountView; 7]) -> R,
+    ) -> R {
+        // Instruction accounts
+        let instruction_accounts: [InstructionAccount; 7] = [
+            InstructionAccount::writable(self.programdata.address()),
+            InstructionAccount::writable(self.program.address()),
+            InstructionAccount::writable(self.buffer.address()),
+            InstructionAccount::writable(self.spill.address()),
+            InstructionAccount::readonly(self.rent_sysvar.address()),
+            InstructionAccount::readonly(self.clock_sysvar.address()),
+            InstructionAccount::readonly_signer(self.authority.address()),
+        ];
+
+        // instruction data
+        // - [0..4]: instruction discriminator
+        let instruction = InstructionView {
+            program_id: &crate::ID,
+            accounts: &instruction_accounts,
+            data: &[3, 0, 0, 0],
+        };
+
+        f(
+            &instruction,
+            &[
+                self.programdata,
+                self.program,
+                self.buffer,
+                self.spill,
+                self.rent_sysvar,
+                self.clock_sysvar,
+                self.authority,
+            ],
+        )
+    }
+}
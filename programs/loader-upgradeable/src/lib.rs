@@ -0,0 +1,6 @@
+#![no_std]
+
+pub mod instructions;
+pub mod state;
+
+pinocchio::address::declare_id!("BPFLoaderUpgradeab1e11111111111111111111111");
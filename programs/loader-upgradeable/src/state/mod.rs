@@ -0,0 +1,21 @@
+mod program;
+mod program_data;
+
+pub use {program::*, program_data::*};
+
+/// Type of an account owned by the upgradeable loader.
+#[repr(u32)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum UpgradeableLoaderStateTag {
+    /// Account is not initialized.
+    Uninitialized,
+
+    /// Buffer account holding program data to be deployed.
+    Buffer,
+
+    /// Program account, pointing to its program data account.
+    Program,
+
+    /// Program data account, holding the program executable.
+    ProgramData,
+}
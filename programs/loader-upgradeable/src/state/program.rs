@@ -0,0 +1,77 @@
+use {
+    crate::{state::UpgradeableLoaderStateTag, ID},
+    pinocchio::{
+        account::{AccountView, Ref},
+        error::ProgramError,
+        Address,
+    },
+};
+
+/// Program account data.
+#[repr(C)]
+pub struct ProgramAccount {
+    /// Type of the account.
+    state: [u8; 4],
+
+    /// Address of the program data account.
+    programdata_address: Address,
+}
+
+impl ProgramAccount {
+    /// The length of the `ProgramAccount` account data.
+    pub const LEN: usize = core::mem::size_of::<ProgramAccount>();
+
+    /// Return a `ProgramAccount` from the given account view.
+    ///
+    /// This method performs owner, length and type validation on
+    /// `AccountView`, safe borrowing the account data.
+    #[inline]
+    pub fn from_account_view(
+        account_view: &AccountView,
+    ) -> Result<Ref<ProgramAccount>, ProgramError> {
+        if !account_view.owned_by(&ID) {
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+        Ref::try_map(account_view.try_borrow()?, Self::from_bytes).map_err(|(_, error)| error)
+    }
+
+    /// Return a `ProgramAccount` from the given account view.
+    ///
+    /// This method performs owner, length and type validation on
+    /// `AccountView`, but does not perform the borrow check.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that it is safe to borrow the account data (e.g.,
+    /// there are no mutable borrows of the account data).
+    #[inline]
+    pub unsafe fn from_account_view_unchecked(
+        account_view: &AccountView,
+    ) -> Result<&Self, ProgramError> {
+        if account_view.owner() != &ID {
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+        Self::from_bytes(account_view.borrow_unchecked())
+    }
+
+    /// Return a `ProgramAccount` from the given bytes.
+    ///
+    /// This method performs length and type validation.
+    #[inline]
+    pub fn from_bytes(bytes: &[u8]) -> Result<&Self, ProgramError> {
+        if bytes.len() != Self::LEN
+            || bytes[0..4] != (UpgradeableLoaderStateTag::Program as u32).to_le_bytes()
+        {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        // SAFETY: `bytes` has `LEN` bytes and `ProgramAccount` has an
+        // alignment of 1 byte.
+        Ok(unsafe { &*(bytes.as_ptr() as *const ProgramAccount) })
+    }
+
+    /// Return the address of the program data account.
+    #[inline(always)]
+    pub fn programdata_address(&self) -> &Address {
+        &self.programdata_address
+    }
+}
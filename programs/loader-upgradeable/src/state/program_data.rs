@@ -0,0 +1,149 @@
+use {
+    crate::{state::UpgradeableLoaderStateTag, ID},
+    pinocchio::{
+        account::{AccountView, Ref},
+        error::ProgramError,
+        Address,
+    },
+};
+
+/// Program data account metadata.
+///
+/// The metadata is followed by the program executable in the account data.
+#[repr(C)]
+pub struct ProgramDataAccount {
+    /// Type of the account.
+    state: [u8; 4],
+
+    /// Slot at which the program was last deployed.
+    slot: [u8; 8],
+
+    /// Indicates whether the upgrade authority is present or not.
+    upgrade_authority_flag: u8,
+
+    /// Authority allowed to upgrade the program.
+    upgrade_authority_address: Address,
+}
+
+impl ProgramDataAccount {
+    /// The length of the `ProgramDataAccount` metadata, preceding the program
+    /// executable.
+    pub const LEN: usize = core::mem::size_of::<ProgramDataAccount>();
+
+    /// Return a `ProgramDataAccount` from the given account view.
+    ///
+    /// This method performs owner, length and type validation on
+    /// `AccountView`, safe borrowing the account data.
+    #[inline]
+    pub fn from_account_view(
+        account_view: &AccountView,
+    ) -> Result<Ref<ProgramDataAccount>, ProgramError> {
+        if !account_view.owned_by(&ID) {
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+        Ref::try_map(account_view.try_borrow()?, Self::from_bytes).map_err(|(_, error)| error)
+    }
+
+    /// Return a `ProgramDataAccount` from the given account view.
+    ///
+    /// This method performs owner, length and type validation on
+    /// `AccountView`, but does not perform the borrow check.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that it is safe to borrow the account data (e.g.,
+    /// there are no mutable borrows of the account data).
+    #[inline]
+    pub unsafe fn from_account_view_unchecked(
+        account_view: &AccountView,
+    ) -> Result<&Self, ProgramError> {
+        if account_view.owner() != &ID {
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+        Self::from_bytes(account_view.borrow_unchecked())
+    }
+
+    /// Return a `ProgramDataAccount` from the given bytes.
+    ///
+    /// This method performs length and type validation. The bytes may
+    /// include the program executable following the metadata.
+    #[inline]
+    pub fn from_bytes(bytes: &[u8]) -> Result<&Self, ProgramError> {
+        if bytes.len() < Self::LEN
+            || bytes[0..4] != (UpgradeableLoaderStateTag::ProgramData as u32).to_le_bytes()
+        {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        // SAFETY: `bytes` has at least `LEN` bytes and `ProgramDataAccount`
+        // has an alignment of 1 byte.
+        Ok(unsafe { &*(bytes.as_ptr() as *const ProgramDataAccount) })
+    }
+
+    /// Return the slot at which the program was last deployed.
+    #[inline(always)]
+    pub fn slot(&self) -> u64 {
+        u64::from_le_bytes(self.slot)
+    }
+
+    /// Check whether the program has an upgrade authority.
+    ///
+    /// Immutable programs have no upgrade authority.
+    #[inline(always)]
+    pub fn has_upgrade_authority(&self) -> bool {
+        self.upgrade_authority_flag != 0
+    }
+
+    /// Return the upgrade authority of the program, if any.
+    #[inline]
+    pub fn upgrade_authority(&self) -> Option<&Address> {
+        if self.has_upgrade_authority() {
+            Some(&self.upgrade_authority_address)
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, crate::state::ProgramAccount};
+
+    #[test]
+    fn program_data_reads_metadata() {
+        let mut data = [0u8; ProgramDataAccount::LEN + 8];
+        data[0..4].copy_from_slice(&3u32.to_le_bytes());
+        data[4..12].copy_from_slice(&42u64.to_le_bytes());
+        data[12] = 1;
+        data[13..45].copy_from_slice(&[7; 32]);
+
+        assert_eq!(ProgramDataAccount::LEN, 45);
+
+        let program_data = ProgramDataAccount::from_bytes(&data).unwrap();
+        assert_eq!(program_data.slot(), 42);
+        assert_eq!(
+            program_data.upgrade_authority().unwrap().as_array(),
+            &[7; 32]
+        );
+
+        data[12] = 0;
+        let program_data = ProgramDataAccount::from_bytes(&data).unwrap();
+        assert!(program_data.upgrade_authority().is_none());
+
+        assert!(ProgramDataAccount::from_bytes(&data[..44]).is_err());
+        data[0] = 2;
+        assert!(ProgramDataAccount::from_bytes(&data).is_err());
+    }
+
+    #[test]
+    fn program_reads_programdata_address() {
+        let mut data = [0u8; ProgramAccount::LEN];
+        data[0..4].copy_from_slice(&2u32.to_le_bytes());
+        data[4..36].copy_from_slice(&[9; 32]);
+
+        let program = ProgramAccount::from_bytes(&data).unwrap();
+        assert_eq!(program.programdata_address().as_array(), &[9; 32]);
+
+        data[0] = 3;
+        assert!(ProgramAccount::from_bytes(&data).is_err());
+    }
+}
@@ -10,6 +10,11 @@ use {
 
 /// Memo instruction.
 ///
+/// Token-2022 accounts with the `MemoTransfer` extension enabled require
+/// incoming transfers to be immediately preceded by a memo. When
+/// transferring through a CPI, invoke this instruction right before the
+/// transfer instruction.
+///
 /// ### Accounts:
 ///   0. `..+N` `[SIGNER]` N signing accounts
 pub struct Memo<'a, 'b, 'c> {
@@ -27,6 +32,20 @@ impl Memo<'_, '_, '_> {
 
     #[inline(always)]
     pub fn invoke_signed(&self, signers_seeds: &[Signer]) -> ProgramResult {
+        self.with_instruction(|instruction, account_views| {
+            invoke_signed_with_bounds::<MAX_STATIC_CPI_ACCOUNTS>(
+                instruction,
+                account_views,
+                signers_seeds,
+            )
+        })?
+    }
+
+    #[inline(always)]
+    pub fn with_instruction<R>(
+        &self,
+        f: impl FnOnce(&InstructionView, &[&AccountView]) -> R,
+    ) -> Result<R, ProgramError> {
         const UNINIT_INSTRUCTION_ACCOUNT: MaybeUninit<InstructionAccount> =
             MaybeUninit::<InstructionAccount>::uninit();
 
@@ -57,10 +76,6 @@ impl Memo<'_, '_, '_> {
             data: self.memo.as_bytes(),
         };
 
-        invoke_signed_with_bounds::<MAX_STATIC_CPI_ACCOUNTS>(
-            &instruction,
-            self.signers,
-            signers_seeds,
-        )
+        Ok(f(&instruction, self.signers))
     }
 }
@@ -0,0 +1,104 @@
+use {
+    crate::state::StakeAuthorize,
+    pinocchio::{
+        cpi::{invoke_signed_with_bounds, Signer},
+        instruction::{InstructionAccount, InstructionView},
+        AccountView, Address, ProgramResult,
+    },
+};
+
+/// Authorize a key to manage stake or withdrawal.
+///
+/// ### Accounts:
+///   0. `[WRITE]` Stake account to be updated
+///   1. `[]` Clock sysvar
+///   2. `[SIGNER]` The stake or withdraw authority
+///   3. `[SIGNER]` (Optional) Lockup custodian, if updating the withdraw
+///      authority while the lockup is in force
+pub struct Authorize<'a, 'b> {
+    /// Stake account to be updated.
+    pub stake: &'a AccountView,
+
+    /// Clock sysvar.
+    ///
+    /// The address of the account must be
+    /// [`CLOCK_ID`](pinocchio::sysvars::clock::CLOCK_ID).
+    pub clock_sysvar: &'a AccountView,
+
+    /// Current stake or withdraw authority.
+    pub authority: &'a AccountView,
+
+    /// Lockup custodian, required to update the withdraw authority while the
+    /// lockup is in force.
+    pub lockup_custodian: Option<&'a AccountView>,
+
+    /// New authority.
+    pub new_authority: &'b Address,
+
+    /// Type of authority to update.
+    pub stake_authorize: StakeAuthorize,
+}
+
+impl Authorize<'_, '_> {
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    #[inline(always)]
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        self.with_instruction(|instruction, account_views| {
+            invoke_signed_with_bounds::<4>(instruction, account_views, signers)
+        })
+    }
+
+    #[inline(always)]
+    pub fn with_instruction<R>(&self, f: impl FnOnce(&InstructionView, &[&AccountView]) -> R) -> R {
+        // instruction data
+        // - [0..4  ]: instruction discriminator
+        // - [4..36 ]: new authority address
+        // - [36..40]: stake authorize
+        let mut instruction_data = [0; 40];
+        instruction_data[0] = 1;
+        instruction_data[4..36].copy_from_slice(self.new_authority.as_array());
+        instruction_data[36..40].copy_from_slice(&(self.stake_authorize as u32).to_le_bytes());
+
+        let stake = InstructionAccount::writable(self.stake.address());
+        let clock_sysvar = InstructionAccount::readonly(self.clock_sysvar.address());
+        let authority = InstructionAccount::readonly_signer(self.authority.address());
+
+        if let Some(lockup_custodian) = self.lockup_custodian {
+            let instruction = InstructionView {
+                program_id: &crate::ID,
+                accounts: &[
+                    stake,
+                    clock_sysvar,
+                    authority,
+                    InstructionAccount::readonly_signer(lockup_custodian.address()),
+                ],
+                data: &instruction_data,
+            };
+
+            f(
+                &instruction,
+                &[
+                    self.stake,
+                    self.clock_sysvar,
+                    self.authority,
+                    lockup_custodian,
+                ],
+            )
+        } else {
+            let instruction = InstructionView {
+                program_id: &crate::ID,
+                accounts: &[stake, clock_sysvar, authority],
+                data: &instruction_data,
+            };
+
+            f(
+                &instruction,
+                &[self.stake, self.clock_sysvar, self.authority],
+            )
+        }
+    }
+}
@@ -0,0 +1,114 @@
+use {
+    crate::state::StakeAuthorize,
+    pinocchio::{
+        cpi::{invoke_signed_with_bounds, Signer},
+        instruction::{InstructionAccount, InstructionView},
+        AccountView, ProgramResult,
+    },
+};
+
+/// Authorize a key to manage stake or withdrawal.
+///
+/// This instruction behaves like `Authorize` with the additional requirement
+/// that the new stake or withdraw authority must also be a signer.
+///
+/// ### Accounts:
+///   0. `[WRITE]` Stake account to be updated
+///   1. `[]` Clock sysvar
+///   2. `[SIGNER]` The stake or withdraw authority
+///   3. `[SIGNER]` The new stake or withdraw authority
+///   4. `[SIGNER]` (Optional) Lockup custodian, if updating the withdraw
+///      authority while the lockup is in force
+pub struct AuthorizeChecked<'a> {
+    /// Stake account to be updated.
+    pub stake: &'a AccountView,
+
+    /// Clock sysvar.
+    ///
+    /// The address of the account must be
+    /// [`CLOCK_ID`](pinocchio::sysvars::clock::CLOCK_ID).
+    pub clock_sysvar: &'a AccountView,
+
+    /// Current stake or withdraw authority.
+    pub authority: &'a AccountView,
+
+    /// New stake or withdraw authority.
+    pub new_authority: &'a AccountView,
+
+    /// Lockup custodian, required to update the withdraw authority while the
+    /// lockup is in force.
+    pub lockup_custodian: Option<&'a AccountView>,
+
+    /// Type of authority to update.
+    pub stake_authorize: StakeAuthorize,
+}
+
+impl AuthorizeChecked<'_> {
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    #[inline(always)]
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        self.with_instruction(|instruction, account_views| {
+            invoke_signed_with_bounds::<5>(instruction, account_views, signers)
+        })
+    }
+
+    #[inline(always)]
+    pub fn with_instruction<R>(&self, f: impl FnOnce(&InstructionView, &[&AccountView]) -> R) -> R {
+        // instruction data
+        // - [0..4]: instruction discriminator
+        // - [4..8]: stake authorize
+        let mut instruction_data = [0; 8];
+        instruction_data[0] = 10;
+        instruction_data[4..8].copy_from_slice(&(self.stake_authorize as u32).to_le_bytes());
+
+        let stake = InstructionAccount::writable(self.stake.address());
+        let clock_sysvar = InstructionAccount::readonly(self.clock_sysvar.address());
+        let authority = InstructionAccount::readonly_signer(self.authority.address());
+        let new_authority = InstructionAccount::readonly_signer(self.new_authority.address());
+
+        if let Some(lockup_custodian) = self.lockup_custodian {
+            let instruction = InstructionView {
+                program_id: &crate::ID,
+                accounts: &[
+                    stake,
+                    clock_sysvar,
+                    authority,
+                    new_authority,
+                    InstructionAccount::readonly_signer(lockup_custodian.address()),
+                ],
+                data: &instruction_data,
+            };
+
+            f(
+                &instruction,
+                &[
+                    self.stake,
+                    self.clock_sysvar,
+                    self.authority,
+                    self.new_authority,
+                    lockup_custodian,
+                ],
+            )
+        } else {
+            let instruction = InstructionView {
+                program_id: &crate::ID,
+                accounts: &[stake, clock_sysvar, authority, new_authority],
+                data: &instruction_data,
+            };
+
+            f(
+                &instruction,
+                &[
+                    self.stake,
+                    self.clock_sysvar,
+                    self.authority,
+                    self.new_authority,
+                ],
+            )
+        }
+    }
+}
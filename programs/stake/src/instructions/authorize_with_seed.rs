@@ -0,0 +1,125 @@
+use {
+    crate::state::StakeAuthorize,
+    pinocchio::{
+        address::MAX_SEED_LEN,
+        cpi::{invoke_signed_with_bounds, Signer},
+        error::ProgramError,
+        instruction::{InstructionAccount, InstructionView},
+        AccountView, Address, ProgramResult,
+    },
+};
+
+/// Authorize a key to manage stake or withdrawal with a derived key.
+///
+/// The current authority must be an address derived from the base account,
+/// the seed and the owner.
+///
+/// ### Accounts:
+///   0. `[WRITE]` Stake account to be updated
+///   1. `[SIGNER]` Base key of the stake or withdraw authority
+///   2. `[]` Clock sysvar
+///   3. `[SIGNER]` (Optional) Lockup custodian, if updating the withdraw
+///      authority while the lockup is in force
+pub struct AuthorizeWithSeed<'a, 'b, 'c> {
+    /// Stake account to be updated.
+    pub stake: &'a AccountView,
+
+    /// Base key of the current stake or withdraw authority.
+    pub base: &'a AccountView,
+
+    /// Clock sysvar.
+    ///
+    /// The address of the account must be
+    /// [`CLOCK_ID`](pinocchio::sysvars::clock::CLOCK_ID).
+    pub clock_sysvar: &'a AccountView,
+
+    /// Lockup custodian, required to update the withdraw authority while the
+    /// lockup is in force.
+    pub lockup_custodian: Option<&'a AccountView>,
+
+    /// New authority.
+    pub new_authority: &'c Address,
+
+    /// Type of authority to update.
+    pub stake_authorize: StakeAuthorize,
+
+    /// Seed used to derive the current authority, no longer than
+    /// [`MAX_SEED_LEN`].
+    pub seed: &'b str,
+
+    /// Owner used to derive the current authority.
+    pub owner: &'c Address,
+}
+
+impl AuthorizeWithSeed<'_, '_, '_> {
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    #[inline(always)]
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        self.with_instruction(|instruction, account_views| {
+            invoke_signed_with_bounds::<4>(instruction, account_views, signers)
+        })?
+    }
+
+    #[inline(always)]
+    pub fn with_instruction<R>(
+        &self,
+        f: impl FnOnce(&InstructionView, &[&AccountView]) -> R,
+    ) -> Result<R, ProgramError> {
+        if self.seed.len() > MAX_SEED_LEN {
+            return Err(ProgramError::MaxSeedLengthExceeded);
+        }
+
+        // instruction data
+        // - [0..4  ]: instruction discriminator
+        // - [4..36 ]: new authority address
+        // - [36..40]: stake authorize
+        // - [40..48]: seed length
+        // - [48..  ]: seed (max 32)
+        // - [.. +32]: owner address
+        let mut instruction_data = [0; 48 + MAX_SEED_LEN + 32];
+        instruction_data[0] = 8;
+        instruction_data[4..36].copy_from_slice(self.new_authority.as_array());
+        instruction_data[36..40].copy_from_slice(&(self.stake_authorize as u32).to_le_bytes());
+        instruction_data[40..48].copy_from_slice(&u64::to_le_bytes(self.seed.len() as u64));
+
+        let offset = 48 + self.seed.len();
+        instruction_data[48..offset].copy_from_slice(self.seed.as_bytes());
+        instruction_data[offset..offset + 32].copy_from_slice(self.owner.as_ref());
+
+        let data = &instruction_data[..offset + 32];
+
+        let stake = InstructionAccount::writable(self.stake.address());
+        let base = InstructionAccount::readonly_signer(self.base.address());
+        let clock_sysvar = InstructionAccount::readonly(self.clock_sysvar.address());
+
+        if let Some(lockup_custodian) = self.lockup_custodian {
+            let instruction = InstructionView {
+                program_id: &crate::ID,
+                accounts: &[
+                    stake,
+                    base,
+                    clock_sysvar,
+                    InstructionAccount::readonly_signer(lockup_custodian.address()),
+                ],
+                data,
+            };
+
+            Ok(f(
+                &instruction,
+                &[self.stake, self.base, self.clock_sysvar, lockup_custodian],
+            ))
+        } else {
+            let instruction = InstructionView {
+                program_id: &crate::ID,
+                accounts: &[stake, base, clock_sysvar],
+                data,
+            };
+
+            Ok(f(&instruction, &[self.stake, self.base, self.clock_sysvar]))
+        }
+    }
+}
@@ -0,0 +1,65 @@
+use pinocchio::{
+    cpi::{invoke_signed, Signer},
+    instruction::{InstructionAccount, InstructionView},
+    AccountView, ProgramResult,
+};
+
+/// Deactivate the stake in the account.
+///
+/// ### Accounts:
+///   0. `[WRITE]` Delegated stake account
+///   1. `[]` Clock sysvar
+///   2. `[SIGNER]` Stake authority
+pub struct Deactivate<'a> {
+    /// Delegated stake account.
+    pub stake: &'a AccountView,
+
+    /// Clock sysvar.
+    ///
+    /// The address of the account must be
+    /// [`CLOCK_ID`](pinocchio::sysvars::clock::CLOCK_ID).
+    pub clock_sysvar: &'a AccountView,
+
+    /// Stake authority.
+    pub stake_authority: &'a AccountView,
+}
+
+impl Deactivate<'_> {
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    #[inline(always)]
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        self.with_instruction(|instruction, account_views| {
+            invoke_signed(instruction, account_views, signers)
+        })
+    }
+
+    #[inline(always)]
+    pub fn with_instruction<R>(
+        &self,
+        f: impl FnOnce(&InstructionView, &[&AccountView; 3]) -> R,
+    ) -> R {
+        // Instruction accounts
+        let instruction_accounts: [InstructionAccount; 3] = [
+            InstructionAccount::writable(self.stake.address()),
+            InstructionAccount::readonly(self.clock_sysvar.address()),
+            InstructionAccount::readonly_signer(self.stake_authority.address()),
+        ];
+
+        // instruction data
+        // - [0..4]: instruction discriminator
+        let instruction = InstructionView {
+            program_id: &crate::ID,
+            accounts: &instruction_accounts,
+            data: &[5, 0, 0, 0],
+        };
+
+        f(
+            &instruction,
+            &[self.stake, self.clock_sysvar, self.stake_authority],
+        )
+    }
+}
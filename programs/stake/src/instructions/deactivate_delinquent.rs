@@ -0,0 +1,62 @@
+use pinocchio::{
+    cpi::invoke,
+    instruction::{InstructionAccount, InstructionView},
+    AccountView, ProgramResult,
+};
+
+/// Deactivate a stake delegated to a delinquent vote account.
+///
+/// The delinquent vote account must not have voted in the last
+/// `MINIMUM_DELINQUENT_EPOCHS_FOR_DEACTIVATION` (5) epochs, while the
+/// reference vote account must have voted in each of them.
+///
+/// No signatures are required to execute this instruction.
+///
+/// ### Accounts:
+///   0. `[WRITE]` Delegated stake account
+///   1. `[]` Delinquent vote account for the delegated stake account
+///   2. `[]` Reference vote account that has voted at least once in the last
+///      `MINIMUM_DELINQUENT_EPOCHS_FOR_DEACTIVATION` epochs
+pub struct DeactivateDelinquent<'a> {
+    /// Delegated stake account.
+    pub stake: &'a AccountView,
+
+    /// Delinquent vote account for the delegated stake account.
+    pub delinquent_vote: &'a AccountView,
+
+    /// Reference vote account.
+    pub reference_vote: &'a AccountView,
+}
+
+impl DeactivateDelinquent<'_> {
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.with_instruction(invoke)
+    }
+
+    #[inline(always)]
+    pub fn with_instruction<R>(
+        &self,
+        f: impl FnOnce(&InstructionView, &[&AccountView; 3]) -> R,
+    ) -> R {
+        // Instruction accounts
+        let instruction_accounts: [InstructionAccount; 3] = [
+            InstructionAccount::writable(self.stake.address()),
+            InstructionAccount::readonly(self.delinquent_vote.address()),
+            InstructionAccount::readonly(self.reference_vote.address()),
+        ];
+
+        // instruction data
+        // - [0..4]: instruction discriminator
+        let instruction = InstructionView {
+            program_id: &crate::ID,
+            accounts: &instruction_accounts,
+            data: &[14, 0, 0, 0],
+        };
+
+        f(
+            &instruction,
+            &[self.stake, self.delinquent_vote, self.reference_vote],
+        )
+    }
+}
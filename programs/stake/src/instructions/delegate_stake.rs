@@ -0,0 +1,89 @@
+use pinocchio::{
+    cpi::{invoke_signed, Signer},
+    instruction::{InstructionAccount, InstructionView},
+    AccountView, ProgramResult,
+};
+
+/// Delegate a stake to a particular vote account.
+///
+/// ### Accounts:
+///   0. `[WRITE]` Initialized stake account to be delegated
+///   1. `[]` Vote account to which this stake will be delegated
+///   2. `[]` Clock sysvar
+///   3. `[]` Stake history sysvar
+///   4. `[]` Stake config account
+///   5. `[SIGNER]` Stake authority
+pub struct DelegateStake<'a> {
+    /// Initialized stake account to be delegated.
+    pub stake: &'a AccountView,
+
+    /// Vote account to which the stake will be delegated.
+    pub vote: &'a AccountView,
+
+    /// Clock sysvar.
+    ///
+    /// The address of the account must be
+    /// [`CLOCK_ID`](pinocchio::sysvars::clock::CLOCK_ID).
+    pub clock_sysvar: &'a AccountView,
+
+    /// Stake history sysvar.
+    pub stake_history_sysvar: &'a AccountView,
+
+    /// Stake config account.
+    ///
+    /// The address of the account must be [`config::ID`](crate::config::ID).
+    pub config: &'a AccountView,
+
+    /// Stake authority.
+    pub stake_authority: &'a AccountView,
+}
+
+impl DelegateStake<'_> {
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    #[inline(always)]
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        self.with_instruction(|instruction, account_views| {
+            invoke_signed(instruction, account_views, signers)
+        })
+    }
+
+    #[inline(always)]
+    pub fn with_instruction<R>(
+        &self,
+        f: impl FnOnce(&InstructionView, &[&AccountView; 6]) -> R,
+    ) -> R {
+        // Instruction accounts
+        let instruction_accounts: [InstructionAccount; 6] = [
+            InstructionAccount::writable(self.stake.address()),
+            InstructionAccount::readonly(self.vote.address()),
+            InstructionAccount::readonly(self.clock_sysvar.address()),
+            InstructionAccount::readonly(self.stake_history_sysvar.address()),
+            InstructionAccount::readonly(self.config.address()),
+            InstructionAccount::readonly_signer(self.stake_authority.address()),
+        ];
+
+        // instruction data
+        // - [0..4]: instruction discriminator
+        let instruction = InstructionView {
+            program_id: &crate::ID,
+            accounts: &instruction_accounts,
+            data: &[2, 0, 0, 0],
+        };
+
+        f(
+            &instruction,
+            &[
+                self.stake,
+                self.vote,
+                self.clock_sysvar,
+                self.stake_history_sysvar,
+                self.config,
+                self.stake_authority,
+            ],
+        )
+    }
+}
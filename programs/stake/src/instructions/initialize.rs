@@ -0,0 +1,75 @@
+use {
+    crate::state::{Authorized, Lockup},
+    pinocchio::{
+        cpi::invoke,
+        instruction::{InstructionAccount, InstructionView},
+        AccountView, ProgramResult,
+    },
+};
+
+/// Initialize a stake with lockup and authorization information.
+///
+/// The stake account must be owned by the stake program, be rent-exempt and
+/// have [`STAKE_STATE_SIZE`](crate::state::STAKE_STATE_SIZE) bytes of
+/// data.
+///
+/// ### Accounts:
+///   0. `[WRITE]` Uninitialized stake account
+///   1. `[]` Rent sysvar
+pub struct Initialize<'a, 'b> {
+    /// Uninitialized stake account.
+    pub stake: &'a AccountView,
+
+    /// Rent sysvar.
+    ///
+    /// The address of the account must be
+    /// [`RENT_ID`](pinocchio::sysvars::rent::RENT_ID).
+    pub rent_sysvar: &'a AccountView,
+
+    /// Stake and withdraw authorities.
+    pub authorized: &'b Authorized,
+
+    /// Lockup of the stake account.
+    pub lockup: &'b Lockup,
+}
+
+impl Initialize<'_, '_> {
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.with_instruction(invoke)
+    }
+
+    #[inline(always)]
+    pub fn with_instruction<R>(
+        &self,
+        f: impl FnOnce(&InstructionView, &[&AccountView; 2]) -> R,
+    ) -> R {
+        // Instruction accounts
+        let instruction_accounts: [InstructionAccount; 2] = [
+            InstructionAccount::writable(self.stake.address()),
+            InstructionAccount::readonly(self.rent_sysvar.address()),
+        ];
+
+        // instruction data
+        // - [0..4    ]: instruction discriminator
+        // - [4..36   ]: staker address
+        // - [36..68  ]: withdrawer address
+        // - [68..76  ]: lockup unix timestamp
+        // - [76..84  ]: lockup epoch
+        // - [84..116 ]: lockup custodian address
+        let mut instruction_data = [0; 116];
+        instruction_data[4..36].copy_from_slice(self.authorized.staker().as_array());
+        instruction_data[36..68].copy_from_slice(self.authorized.withdrawer().as_array());
+        instruction_data[68..76].copy_from_slice(&self.lockup.unix_timestamp().to_le_bytes());
+        instruction_data[76..84].copy_from_slice(&self.lockup.epoch().to_le_bytes());
+        instruction_data[84..116].copy_from_slice(self.lockup.custodian().as_array());
+
+        let instruction = InstructionView {
+            program_id: &crate::ID,
+            accounts: &instruction_accounts,
+            data: &instruction_data,
+        };
+
+        f(&instruction, &[self.stake, self.rent_sysvar])
+    }
+}
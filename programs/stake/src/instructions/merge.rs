@@ -0,0 +1,84 @@
+use pinocchio::{
+    cpi::{invoke_signed, Signer},
+    instruction::{InstructionAccount, InstructionView},
+    AccountView, ProgramResult,
+};
+
+/// Merge two stake accounts.
+///
+/// Both accounts must have identical lockup and authority keys. The source
+/// stake account is drained and its data is reset.
+///
+/// ### Accounts:
+///   0. `[WRITE]` Destination stake account for the merge
+///   1. `[WRITE]` Source stake account to merge into the destination
+///   2. `[]` Clock sysvar
+///   3. `[]` Stake history sysvar
+///   4. `[SIGNER]` Stake authority
+pub struct Merge<'a> {
+    /// Destination stake account for the merge.
+    pub destination: &'a AccountView,
+
+    /// Source stake account to merge into the destination.
+    pub source: &'a AccountView,
+
+    /// Clock sysvar.
+    ///
+    /// The address of the account must be
+    /// [`CLOCK_ID`](pinocchio::sysvars::clock::CLOCK_ID).
+    pub clock_sysvar: &'a AccountView,
+
+    /// Stake history sysvar.
+    pub stake_history_sysvar: &'a AccountView,
+
+    /// Stake authority.
+    pub stake_authority: &'a AccountView,
+}
+
+impl Merge<'_> {
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    #[inline(always)]
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        self.with_instruction(|instruction, account_views| {
+            invoke_signed(instruction, account_views, signers)
+        })
+    }
+
+    #[inline(always)]
+    pub fn with_instruction<R>(
+        &self,
+        f: impl FnOnce(&InstructionView, &[&AccountView; 5]) -> R,
+    ) -> R {
+        // Instruction accounts
+        let instruction_accounts: [InstructionAccount; 5] = [
+            InstructionAccount::writable(self.destination.address()),
+            InstructionAccount::writable(self.source.address()),
+            InstructionAccount::readonly(self.clock_sysvar.address()),
+            InstructionAccount::readonly(self.stake_history_sysvar.address()),
+            InstructionAccount::readonly_signer(self.stake_authority.address()),
+        ];
+
+        // instruction data
+        // - [0..4]: instruction discriminator
+        let instruction = InstructionView {
+            program_id: &crate::ID,
+            accounts: &instruction_accounts,
+            data: &[7, 0, 0, 0],
+        };
+
+        f(
+            &instruction,
+            &[
+                self.destination,
+                self.source,
+                self.clock_sysvar,
+                self.stake_history_sysvar,
+                self.stake_authority,
+            ],
+        )
+    }
+}
@@ -0,0 +1,20 @@
+mod authorize;
+mod authorize_checked;
+mod authorize_with_seed;
+mod deactivate;
+mod deactivate_delinquent;
+mod delegate_stake;
+mod initialize;
+mod merge;
+mod move_lamports;
+mod move_stake;
+mod set_lockup;
+mod set_lockup_checked;
+mod split;
+mod withdraw;
+
+pub use {
+    authorize::*, authorize_checked::*, authorize_with_seed::*, deactivate::*,
+    deactivate_delinquent::*, delegate_stake::*, initialize::*, merge::*, move_lamports::*,
+    move_stake::*, set_lockup::*, set_lockup_checked::*, split::*, withdraw::*,
+};
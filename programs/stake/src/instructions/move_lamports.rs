@@ -0,0 +1,74 @@
+use pinocchio::{
+    cpi::{invoke_signed, Signer},
+    instruction::{InstructionAccount, InstructionView},
+    AccountView, ProgramResult,
+};
+
+/// Move unstaked lamports between accounts with the same authorities and
+/// lockups.
+///
+/// Both accounts must be fully active or inactive; only lamports in excess
+/// of the delegated stake and rent-exempt reserve can be moved.
+///
+/// ### Accounts:
+///   0. `[WRITE]` Source stake account
+///   1. `[WRITE]` Destination stake account
+///   2. `[SIGNER]` Stake authority
+pub struct MoveLamports<'a> {
+    /// Source stake account.
+    pub source: &'a AccountView,
+
+    /// Destination stake account.
+    pub destination: &'a AccountView,
+
+    /// Stake authority.
+    pub stake_authority: &'a AccountView,
+
+    /// Amount of lamports to move.
+    pub lamports: u64,
+}
+
+impl MoveLamports<'_> {
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    #[inline(always)]
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        self.with_instruction(|instruction, account_views| {
+            invoke_signed(instruction, account_views, signers)
+        })
+    }
+
+    #[inline(always)]
+    pub fn with_instruction<R>(
+        &self,
+        f: impl FnOnce(&InstructionView, &[&AccountView; 3]) -> R,
+    ) -> R {
+        // Instruction accounts
+        let instruction_accounts: [InstructionAccount; 3] = [
+            InstructionAccount::writable(self.source.address()),
+            InstructionAccount::writable(self.destination.address()),
+            InstructionAccount::readonly_signer(self.stake_authority.address()),
+        ];
+
+        // instruction data
+        // - [0..4 ]: instruction discriminator
+        // - [4..12]: lamports amount
+        let mut instruction_data = [0; 12];
+        instruction_data[0] = 17;
+        instruction_data[4..12].copy_from_slice(&self.lamports.to_le_bytes());
+
+        let instruction = InstructionView {
+            program_id: &crate::ID,
+            accounts: &instruction_accounts,
+            data: &instruction_data,
+        };
+
+        f(
+            &instruction,
+            &[self.source, self.destination, self.stake_authority],
+        )
+    }
+}
@@ -0,0 +1,97 @@
+use pinocchio::{
+    cpi::{invoke_signed, Signer},
+    instruction::{InstructionAccount, InstructionView},
+    AccountView, Address, ProgramResult,
+};
+
+/// Set the lockup of a stake account.
+///
+/// If the lockup is in force, the lockup custodian must sign. Otherwise the
+/// withdraw authority must sign. Only the fields set to `Some` are updated.
+///
+/// ### Accounts:
+///   0. `[WRITE]` Initialized stake account
+///   1. `[SIGNER]` Lockup custodian or withdraw authority
+pub struct SetLockup<'a, 'b> {
+    /// Initialized stake account.
+    pub stake: &'a AccountView,
+
+    /// Lockup custodian or withdraw authority.
+    pub authority: &'a AccountView,
+
+    /// New Unix timestamp at which the lockup expires.
+    pub unix_timestamp: Option<i64>,
+
+    /// New epoch at which the lockup expires.
+    pub epoch: Option<u64>,
+
+    /// New lockup custodian.
+    pub custodian: Option<&'b Address>,
+}
+
+impl SetLockup<'_, '_> {
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    #[inline(always)]
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        self.with_instruction(|instruction, account_views| {
+            invoke_signed(instruction, account_views, signers)
+        })
+    }
+
+    #[inline(always)]
+    pub fn with_instruction<R>(
+        &self,
+        f: impl FnOnce(&InstructionView, &[&AccountView; 2]) -> R,
+    ) -> R {
+        // Instruction accounts
+        let instruction_accounts: [InstructionAccount; 2] = [
+            InstructionAccount::writable(self.stake.address()),
+            InstructionAccount::readonly_signer(self.authority.address()),
+        ];
+
+        // instruction data
+        // - [0..4]: instruction discriminator
+        // - [4.. ]: optional unix timestamp (1 + 8 bytes)
+        // - [..  ]: optional epoch (1 + 8 bytes)
+        // - [..  ]: optional custodian address (1 + 32 bytes)
+        let mut instruction_data = [0; 4 + 9 + 9 + 33];
+        instruction_data[0] = 6;
+        let mut offset = 4;
+
+        if let Some(unix_timestamp) = self.unix_timestamp {
+            instruction_data[offset] = 1;
+            instruction_data[offset + 1..offset + 9].copy_from_slice(&unix_timestamp.to_le_bytes());
+            offset += 9;
+        } else {
+            offset += 1;
+        }
+
+        if let Some(epoch) = self.epoch {
+            instruction_data[offset] = 1;
+            instruction_data[offset + 1..offset + 9].copy_from_slice(&epoch.to_le_bytes());
+            offset += 9;
+        } else {
+            offset += 1;
+        }
+
+        if let Some(custodian) = self.custodian {
+            instruction_data[offset] = 1;
+            instruction_data[offset + 1..offset + 33].copy_from_slice(custodian.as_array());
+            offset += 33;
+        } else {
+            offset += 1;
+        }
+
+        let instruction = InstructionView {
+            program_id: &crate::ID,
+            accounts: &instruction_accounts,
+            data: &instruction_data[..offset],
+        };
+
+        f(&instruction, &[self.stake, self.authority])
+    }
+}
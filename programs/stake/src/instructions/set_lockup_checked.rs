@@ -0,0 +1,99 @@
+use pinocchio::{
+    cpi::{invoke_signed_with_bounds, Signer},
+    instruction::{InstructionAccount, InstructionView},
+    AccountView, ProgramResult,
+};
+
+/// Set the lockup of a stake account.
+///
+/// This instruction behaves like `SetLockup` with the additional requirement
+/// that the new lockup custodian, if set, must also be a signer.
+///
+/// ### Accounts:
+///   0. `[WRITE]` Initialized stake account
+///   1. `[SIGNER]` Lockup custodian or withdraw authority
+///   2. `[SIGNER]` (Optional) New lockup custodian
+pub struct SetLockupChecked<'a> {
+    /// Initialized stake account.
+    pub stake: &'a AccountView,
+
+    /// Lockup custodian or withdraw authority.
+    pub authority: &'a AccountView,
+
+    /// New lockup custodian.
+    pub new_custodian: Option<&'a AccountView>,
+
+    /// New Unix timestamp at which the lockup expires.
+    pub unix_timestamp: Option<i64>,
+
+    /// New epoch at which the lockup expires.
+    pub epoch: Option<u64>,
+}
+
+impl SetLockupChecked<'_> {
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    #[inline(always)]
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        self.with_instruction(|instruction, account_views| {
+            invoke_signed_with_bounds::<3>(instruction, account_views, signers)
+        })
+    }
+
+    #[inline(always)]
+    pub fn with_instruction<R>(&self, f: impl FnOnce(&InstructionView, &[&AccountView]) -> R) -> R {
+        // instruction data
+        // - [0..4]: instruction discriminator
+        // - [4.. ]: optional unix timestamp (1 + 8 bytes)
+        // - [..  ]: optional epoch (1 + 8 bytes)
+        let mut instruction_data = [0; 4 + 9 + 9];
+        instruction_data[0] = 12;
+        let mut offset = 4;
+
+        if let Some(unix_timestamp) = self.unix_timestamp {
+            instruction_data[offset] = 1;
+            instruction_data[offset + 1..offset + 9].copy_from_slice(&unix_timestamp.to_le_bytes());
+            offset += 9;
+        } else {
+            offset += 1;
+        }
+
+        if let Some(epoch) = self.epoch {
+            instruction_data[offset] = 1;
+            instruction_data[offset + 1..offset + 9].copy_from_slice(&epoch.to_le_bytes());
+            offset += 9;
+        } else {
+            offset += 1;
+        }
+
+        let data = &instruction_data[..offset];
+
+        let stake = InstructionAccount::writable(self.stake.address());
+        let authority = InstructionAccount::readonly_signer(self.authority.address());
+
+        if let Some(new_custodian) = self.new_custodian {
+            let instruction = InstructionView {
+                program_id: &crate::ID,
+                accounts: &[
+                    stake,
+                    authority,
+                    InstructionAccount::readonly_signer(new_custodian.address()),
+                ],
+                data,
+            };
+
+            f(&instruction, &[self.stake, self.authority, new_custodian])
+        } else {
+            let instruction = InstructionView {
+                program_id: &crate::ID,
+                accounts: &[stake, authority],
+                data,
+            };
+
+            f(&instruction, &[self.stake, self.authority])
+        }
+    }
+}
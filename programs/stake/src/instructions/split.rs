@@ -0,0 +1,74 @@
+use pinocchio::{
+    cpi::{invoke_signed, Signer},
+    instruction::{InstructionAccount, InstructionView},
+    AccountView, ProgramResult,
+};
+
+/// Split `lamports` from the stake account into another stake account.
+///
+/// The split stake account must be owned by the stake program, be
+/// uninitialized and have
+/// [`STAKE_STATE_SIZE`](crate::state::STAKE_STATE_SIZE) bytes of data.
+///
+/// ### Accounts:
+///   0. `[WRITE]` Stake account to be split
+///   1. `[WRITE]` Uninitialized stake account that will receive the split
+///   2. `[SIGNER]` Stake authority
+pub struct Split<'a> {
+    /// Stake account to be split.
+    pub stake: &'a AccountView,
+
+    /// Uninitialized stake account that will receive the split.
+    pub split_stake: &'a AccountView,
+
+    /// Stake authority.
+    pub stake_authority: &'a AccountView,
+
+    /// Amount of lamports to split.
+    pub lamports: u64,
+}
+
+impl Split<'_> {
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    #[inline(always)]
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        self.with_instruction(|instruction, account_views| {
+            invoke_signed(instruction, account_views, signers)
+        })
+    }
+
+    #[inline(always)]
+    pub fn with_instruction<R>(
+        &self,
+        f: impl FnOnce(&InstructionView, &[&AccountView; 3]) -> R,
+    ) -> R {
+        // Instruction accounts
+        let instruction_accounts: [InstructionAccount; 3] = [
+            InstructionAccount::writable(self.stake.address()),
+            InstructionAccount::writable(self.split_stake.address()),
+            InstructionAccount::readonly_signer(self.stake_authority.address()),
+        ];
+
+        // instruction data
+        // - [0..4 ]: instruction discriminator
+        // - [4..12]: lamports amount
+        let mut instruction_data = [0; 12];
+        instruction_data[0] = 3;
+        instruction_data[4..12].copy_from_slice(&self.lamports.to_le_bytes());
+
+        let instruction = InstructionView {
+            program_id: &crate::ID,
+            accounts: &instruction_accounts,
+            data: &instruction_data,
+        };
+
+        f(
+            &instruction,
+            &[self.stake, self.split_stake, self.stake_authority],
+        )
+    }
+}
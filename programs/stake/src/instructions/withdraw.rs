@@ -0,0 +1,122 @@
+use pinocchio::{
+    cpi::{invoke_signed_with_bounds, Signer},
+    instruction::{InstructionAccount, InstructionView},
+    AccountView, ProgramResult,
+};
+
+/// Withdraw unstaked lamports from the stake account.
+///
+/// ### Accounts:
+///   0. `[WRITE]` Stake account from which to withdraw
+///   1. `[WRITE]` Recipient account
+///   2. `[]` Clock sysvar
+///   3. `[]` Stake history sysvar
+///   4. `[SIGNER]` Withdraw authority
+///   5. `[SIGNER]` (Optional) Lockup custodian, if the lockup is in force
+pub struct Withdraw<'a> {
+    /// Stake account from which to withdraw.
+    pub stake: &'a AccountView,
+
+    /// Recipient account.
+    pub recipient: &'a AccountView,
+
+    /// Clock sysvar.
+    ///
+    /// The address of the account must be
+    /// [`CLOCK_ID`](pinocchio::sysvars::clock::CLOCK_ID).
+    pub clock_sysvar: &'a AccountView,
+
+    /// Stake history sysvar.
+    pub stake_history_sysvar: &'a AccountView,
+
+    /// Withdraw authority.
+    pub withdraw_authority: &'a AccountView,
+
+    /// Lockup custodian, required if the lockup is in force.
+    pub lockup_custodian: Option<&'a AccountView>,
+
+    /// Amount of lamports to withdraw.
+    pub lamports: u64,
+}
+
+impl Withdraw<'_> {
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    #[inline(always)]
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        self.with_instruction(|instruction, account_views| {
+            invoke_signed_with_bounds::<6>(instruction, account_views, signers)
+        })
+    }
+
+    #[inline(always)]
+    pub fn with_instruction<R>(&self, f: impl FnOnce(&InstructionView, &[&AccountView]) -> R) -> R {
+        // instruction data
+        // - [0..4 ]: instruction discriminator
+        // - [4..12]: lamports amount
+        let mut instruction_data = [0; 12];
+        instruction_data[0] = 4;
+        instruction_data[4..12].copy_from_slice(&self.lamports.to_le_bytes());
+
+        let stake = InstructionAccount::writable(self.stake.address());
+        let recipient = InstructionAccount::writable(self.recipient.address());
+        let clock_sysvar = InstructionAccount::readonly(self.clock_sysvar.address());
+        let stake_history_sysvar =
+            InstructionAccount::readonly(self.stake_history_sysvar.address());
+        let withdraw_authority =
+            InstructionAccount::readonly_signer(self.withdraw_authority.address());
+
+        if let Some(lockup_custodian) = self.lockup_custodian {
+            let instruction = InstructionView {
+                program_id: &crate::ID,
+                accounts: &[
+                    stake,
+                    recipient,
+                    clock_sysvar,
+                    stake_history_sysvar,
+                    withdraw_authority,
+                    InstructionAccount::readonly_signer(lockup_custodian.address()),
+                ],
+                data: &instruction_data,
+            };
+
+            f(
+                &instruction,
+                &[
+                    self.stake,
+                    self.recipient,
+                    self.clock_sysvar,
+                    self.stake_history_sysvar,
+                    self.withdraw_authority,
+                    lockup_custodian,
+                ],
+            )
+        } else {
+            let instruction = InstructionView {
+                program_id: &crate::ID,
+                accounts: &[
+                    stake,
+                    recipient,
+                    clock_sysvar,
+                    stake_history_sysvar,
+                    withdraw_authority,
+                ],
+                data: &instruction_data,
+            };
+
+            f(
+                &instruction,
+                &[
+                    self.stake,
+                    self.recipient,
+                    self.clock_sysvar,
+                    self.stake_history_sysvar,
+                    self.withdraw_authority,
+                ],
+            )
+        }
+    }
+}
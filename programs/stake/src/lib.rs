@@ -0,0 +1,14 @@
+#![no_std]
+
+pub mod instructions;
+pub mod state;
+
+pinocchio::address::declare_id!("Stake11111111111111111111111111111111111111");
+
+/// Address of the stake config account.
+///
+/// The account is no longer used by the stake program, but `DelegateStake`
+/// still expects it in its accounts.
+pub mod config {
+    pinocchio::address::declare_id!("StakeConfig11111111111111111111111111111111");
+}
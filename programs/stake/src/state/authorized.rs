@@ -0,0 +1,48 @@
+use pinocchio::Address;
+
+/// Authorities of a stake account.
+///
+/// The layout matches the serialized representation used by the stake
+/// program, both in instruction data and in account data.
+#[repr(C)]
+pub struct Authorized {
+    /// Authority allowed to delegate, deactivate and split the stake.
+    staker: Address,
+
+    /// Authority allowed to withdraw from the stake account.
+    withdrawer: Address,
+}
+
+impl Authorized {
+    /// The length of the serialized `Authorized`.
+    pub const LEN: usize = core::mem::size_of::<Authorized>();
+
+    /// Create a new `Authorized` from the given authorities.
+    #[inline(always)]
+    pub const fn new(staker: Address, withdrawer: Address) -> Self {
+        Self { staker, withdrawer }
+    }
+
+    /// Return the stake authority.
+    #[inline(always)]
+    pub fn staker(&self) -> &Address {
+        &self.staker
+    }
+
+    /// Return the withdraw authority.
+    #[inline(always)]
+    pub fn withdrawer(&self) -> &Address {
+        &self.withdrawer
+    }
+}
+
+/// Type of authority of a stake account.
+#[repr(u32)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum StakeAuthorize {
+    /// Authority allowed to delegate, deactivate and split the stake.
+    Staker,
+
+    /// Authority allowed to withdraw from the stake account.
+    Withdrawer,
+}
@@ -0,0 +1,91 @@
+use pinocchio::Address;
+
+/// Delegation of a stake account to a vote account.
+#[repr(C)]
+pub struct Delegation {
+    /// Vote account to which the stake is delegated.
+    voter: Address,
+
+    /// Activated stake amount.
+    stake: [u8; 8],
+
+    /// Epoch at which the stake was activated.
+    activation_epoch: [u8; 8],
+
+    /// Epoch at which the stake was deactivated, `u64::MAX` if the stake is
+    /// not deactivated.
+    deactivation_epoch: [u8; 8],
+
+    /// Deprecated warmup and cooldown rate.
+    _warmup_cooldown_rate: [u8; 8],
+}
+
+impl Delegation {
+    /// The length of the serialized `Delegation`.
+    pub const LEN: usize = core::mem::size_of::<Delegation>();
+
+    /// Return the vote account to which the stake is delegated.
+    #[inline(always)]
+    pub fn voter(&self) -> &Address {
+        &self.voter
+    }
+
+    /// Return the activated stake amount.
+    #[inline(always)]
+    pub fn stake(&self) -> u64 {
+        u64::from_le_bytes(self.stake)
+    }
+
+    /// Return the epoch at which the stake was activated.
+    #[inline(always)]
+    pub fn activation_epoch(&self) -> u64 {
+        u64::from_le_bytes(self.activation_epoch)
+    }
+
+    /// Return the epoch at which the stake was deactivated.
+    ///
+    /// The value is `u64::MAX` if the stake is not deactivated.
+    #[inline(always)]
+    pub fn deactivation_epoch(&self) -> u64 {
+        u64::from_le_bytes(self.deactivation_epoch)
+    }
+
+    /// Check whether the stake has been deactivated.
+    #[inline(always)]
+    pub fn is_deactivated(&self) -> bool {
+        self.deactivation_epoch() != u64::MAX
+    }
+
+    /// Check whether the stake is a bootstrap stake, active since genesis.
+    #[inline(always)]
+    pub fn is_bootstrap(&self) -> bool {
+        self.activation_epoch() == u64::MAX
+    }
+}
+
+/// Stake of a delegated stake account.
+#[repr(C)]
+pub struct Stake {
+    /// Delegation of the stake.
+    delegation: Delegation,
+
+    /// Credits observed at the last reward distribution.
+    credits_observed: [u8; 8],
+}
+
+impl Stake {
+    /// The length of the serialized `Stake`.
+    pub const LEN: usize = core::mem::size_of::<Stake>();
+
+    /// Return the delegation of the stake.
+    #[inline(always)]
+    pub fn delegation(&self) -> &Delegation {
+        &self.delegation
+    }
+
+    /// Return the credits observed at the last reward distribution.
+    #[inline(always)]
+    pub fn credits_observed(&self) -> u64 {
+        u64::from_le_bytes(self.credits_observed)
+    }
+}
@@ -0,0 +1,70 @@
+use pinocchio::Address;
+
+/// Lockup of a stake account.
+///
+/// While the lockup is in force, withdrawals and changes of the withdraw
+/// authority require the signature of the custodian.
+///
+/// The layout matches the serialized representation used by the stake
+/// program, both in instruction data and in account data.
+#[repr(C)]
+pub struct Lockup {
+    /// Unix timestamp at which the lockup expires.
+    unix_timestamp: [u8; 8],
+
+    /// Epoch at which the lockup expires.
+    epoch: [u8; 8],
+
+    /// Custodian allowed to bypass the lockup.
+    custodian: Address,
+}
+
+impl Lockup {
+    /// The length of the serialized `Lockup`.
+    pub const LEN: usize = core::mem::size_of::<Lockup>();
+
+    /// Create a new `Lockup` from the given values.
+    #[inline(always)]
+    pub const fn new(unix_timestamp: i64, epoch: u64, custodian: Address) -> Self {
+        Self {
+            unix_timestamp: unix_timestamp.to_le_bytes(),
+            epoch: epoch.to_le_bytes(),
+            custodian,
+        }
+    }
+
+    /// Return the Unix timestamp at which the lockup expires.
+    #[inline(always)]
+    pub fn unix_timestamp(&self) -> i64 {
+        i64::from_le_bytes(self.unix_timestamp)
+    }
+
+    /// Return the epoch at which the lockup expires.
+    #[inline(always)]
+    pub fn epoch(&self) -> u64 {
+        u64::from_le_bytes(self.epoch)
+    }
+
+    /// Return the custodian of the lockup.
+    #[inline(always)]
+    pub fn custodian(&self) -> &Address {
+        &self.custodian
+    }
+
+    /// Check whether the lockup is in force at the given Unix timestamp and
+    /// epoch.
+    ///
+    /// The lockup is not in force if `custodian` is the lockup custodian.
+    #[inline(always)]
+    pub fn is_in_force(
+        &self,
+        unix_timestamp: i64,
+        epoch: u64,
+        custodian: Option<&Address>,
+    ) -> bool {
+        if custodian == Some(&self.custodian) {
+            return false;
+        }
+        self.unix_timestamp() > unix_timestamp || self.epoch() > epoch
+    }
+}
@@ -0,0 +1,37 @@
+use crate::state::{Authorized, Lockup};
+
+/// Metadata of an initialized stake account.
+#[repr(C)]
+pub struct Meta {
+    /// Lamports reserved to keep the stake account rent-exempt.
+    rent_exempt_reserve: [u8; 8],
+
+    /// Authorities of the stake account.
+    authorized: Authorized,
+
+    /// Lockup of the stake account.
+    lockup: Lockup,
+}
+
+impl Meta {
+    /// The length of the serialized `Meta`.
+    pub const LEN: usize = core::mem::size_of::<Meta>();
+
+    /// Return the lamports reserved to keep the stake account rent-exempt.
+    #[inline(always)]
+    pub fn rent_exempt_reserve(&self) -> u64 {
+        u64::from_le_bytes(self.rent_exempt_reserve)
+    }
+
+    /// Return the authorities of the stake account.
+    #[inline(always)]
+    pub fn authorized(&self) -> &Authorized {
+        &self.authorized
+    }
+
+    /// Return the lockup of the stake account.
+    #[inline(always)]
+    pub fn lockup(&self) -> &Lockup {
+        &self.lockup
+    }
+}
@@ -0,0 +1,10 @@
+mod authorized;
+mod delegation;
+mod lockup;
+mod meta;
+mod stake_state;
+
+pub use {authorized::*, delegation::*, lockup::*, meta::*, stake_state::*};
+
+/// The length of the stake account data.
+pub const STAKE_STATE_SIZE: usize = 200;
@@ -0,0 +1,251 @@
+use {
+    crate::{
+        state::{Meta, Stake},
+        ID,
+    },
+    pinocchio::{
+        account::{AccountView, Ref},
+        error::ProgramError,
+    },
+};
+
+/// State of a stake account.
+#[repr(u32)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum StakeStateTag {
+    /// Stake account is not yet initialized.
+    Uninitialized,
+
+    /// Stake account is initialized, but not delegated.
+    Initialized,
+
+    /// Stake account is delegated.
+    Stake,
+
+    /// Deprecated rewards pool account.
+    RewardsPool,
+}
+
+/// Stake account data.
+///
+/// The data of a stake account is composed of:
+/// - `[0..4    ]`: state tag
+/// - `[4..124  ]`: meta
+/// - `[124..196]`: stake
+/// - `[196     ]`: stake flags
+/// - `[197..200]`: padding
+#[repr(C)]
+pub struct StakeStateV2 {
+    /// State of the stake account.
+    state: [u8; 4],
+
+    /// Metadata of the stake account.
+    meta: Meta,
+
+    /// Stake of the stake account.
+    stake: Stake,
+
+    /// Stake flags.
+    stake_flags: u8,
+
+    /// Unused bytes.
+    _padding: [u8; 3],
+}
+
+impl StakeStateV2 {
+    /// The length of the `StakeStateV2` account data.
+    pub const LEN: usize = core::mem::size_of::<StakeStateV2>();
+
+    /// Return a `StakeStateV2` from the given account view.
+    ///
+    /// This method performs owner, length and state validation on
+    /// `AccountView`, safe borrowing the account data.
+    #[inline]
+    pub fn from_account_view(
+        account_view: &AccountView,
+    ) -> Result<Ref<StakeStateV2>, ProgramError> {
+        if account_view.data_len() != Self::LEN {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        if !account_view.owned_by(&ID) {
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+        let data = account_view.try_borrow()?;
+        Self::validate(&data)?;
+        Ok(Ref::map(data, |data| unsafe {
+            Self::from_bytes_unchecked(data)
+        }))
+    }
+
+    /// Return a `StakeStateV2` from the given account view.
+    ///
+    /// This method performs owner, length and state validation on
+    /// `AccountView`, but does not perform the borrow check.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that it is safe to borrow the account data (e.g.,
+    /// there are no mutable borrows of the account data).
+    #[inline]
+    pub unsafe fn from_account_view_unchecked(
+        account_view: &AccountView,
+    ) -> Result<&Self, ProgramError> {
+        if account_view.data_len() != Self::LEN {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        if account_view.owner() != &ID {
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+        let data = account_view.borrow_unchecked();
+        Self::validate(data)?;
+        Ok(Self::from_bytes_unchecked(data))
+    }
+
+    /// Return a `StakeStateV2` from the given bytes.
+    ///
+    /// This method performs length and state validation.
+    #[inline]
+    pub fn from_bytes(bytes: &[u8]) -> Result<&Self, ProgramError> {
+        if bytes.len() != Self::LEN {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        Self::validate(bytes)?;
+        // SAFETY: `bytes` has `LEN` bytes and a valid state, and
+        // `StakeStateV2` has an alignment of 1 byte.
+        Ok(unsafe { Self::from_bytes_unchecked(bytes) })
+    }
+
+    /// Return a `StakeStateV2` from the given bytes.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that `bytes` contains a valid representation of
+    /// `StakeStateV2`, and it has the correct length to be interpreted as an
+    /// instance of `StakeStateV2`.
+    #[inline(always)]
+    pub unsafe fn from_bytes_unchecked(bytes: &[u8]) -> &Self {
+        &*(bytes.as_ptr() as *const StakeStateV2)
+    }
+
+    /// Check that the state value is valid.
+    #[inline(always)]
+    fn validate(bytes: &[u8]) -> Result<(), ProgramError> {
+        // SAFETY: The caller ensures that `bytes` has `LEN` bytes.
+        let state = unsafe { u32::from_le_bytes(*(bytes.as_ptr() as *const [u8; 4])) };
+
+        if state > StakeStateTag::RewardsPool as u32 {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        Ok(())
+    }
+
+    /// Return the state of the stake account.
+    #[inline(always)]
+    pub fn state(&self) -> StakeStateTag {
+        match u32::from_le_bytes(self.state) {
+            0 => StakeStateTag::Uninitialized,
+            1 => StakeStateTag::Initialized,
+            2 => StakeStateTag::Stake,
+            _ => StakeStateTag::RewardsPool,
+        }
+    }
+
+    /// Return the metadata of the stake account.
+    ///
+    /// Returns `None` if the stake account is not initialized.
+    #[inline(always)]
+    pub fn meta(&self) -> Option<&Meta> {
+        matches!(
+            self.state(),
+            StakeStateTag::Initialized | StakeStateTag::Stake
+        )
+        .then_some(&self.meta)
+    }
+
+    /// Return the stake of the stake account.
+    ///
+    /// Returns `None` if the stake account is not delegated.
+    #[inline(always)]
+    pub fn stake(&self) -> Option<&Stake> {
+        (self.state() == StakeStateTag::Stake).then_some(&self.stake)
+    }
+
+    /// Return the stake flags.
+    ///
+    /// The value is only meaningful when the stake account is delegated.
+    #[inline(always)]
+    pub fn stake_flags(&self) -> u8 {
+        self.stake_flags
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, crate::state::STAKE_STATE_SIZE};
+
+    #[test]
+    fn stake_state_reads_delegated_stake() {
+        assert_eq!(StakeStateV2::LEN, STAKE_STATE_SIZE);
+
+        let mut data = [0u8; STAKE_STATE_SIZE];
+        data[0..4].copy_from_slice(&2u32.to_le_bytes());
+        // meta
+        data[4..12].copy_from_slice(&2_282_880u64.to_le_bytes());
+        data[12..44].copy_from_slice(&[1; 32]);
+        data[44..76].copy_from_slice(&[2; 32]);
+        data[76..84].copy_from_slice(&(-1i64).to_le_bytes());
+        data[84..92].copy_from_slice(&7u64.to_le_bytes());
+        data[92..124].copy_from_slice(&[3; 32]);
+        // stake
+        data[124..156].copy_from_slice(&[4; 32]);
+        data[156..164].copy_from_slice(&1_000_000_000u64.to_le_bytes());
+        data[164..172].copy_from_slice(&10u64.to_le_bytes());
+        data[172..180].copy_from_slice(&u64::MAX.to_le_bytes());
+        data[188..196].copy_from_slice(&42u64.to_le_bytes());
+        data[196] = 1;
+
+        let state = StakeStateV2::from_bytes(&data).unwrap();
+        assert_eq!(state.state(), StakeStateTag::Stake);
+        assert_eq!(state.stake_flags(), 1);
+
+        let meta = state.meta().unwrap();
+        assert_eq!(meta.rent_exempt_reserve(), 2_282_880);
+        assert_eq!(meta.authorized().staker().as_array(), &[1; 32]);
+        assert_eq!(meta.authorized().withdrawer().as_array(), &[2; 32]);
+        assert_eq!(meta.lockup().unix_timestamp(), -1);
+        assert_eq!(meta.lockup().epoch(), 7);
+        assert_eq!(meta.lockup().custodian().as_array(), &[3; 32]);
+
+        let stake = state.stake().unwrap();
+        assert_eq!(stake.delegation().voter().as_array(), &[4; 32]);
+        assert_eq!(stake.delegation().stake(), 1_000_000_000);
+        assert_eq!(stake.delegation().activation_epoch(), 10);
+        assert!(!stake.delegation().is_deactivated());
+        assert_eq!(stake.credits_observed(), 42);
+    }
+
+    #[test]
+    fn stake_state_hides_fields_of_other_states() {
+        let mut data = [0u8; STAKE_STATE_SIZE];
+        let state = StakeStateV2::from_bytes(&data).unwrap();
+        assert_eq!(state.state(), StakeStateTag::Uninitialized);
+        assert!(state.meta().is_none());
+        assert!(state.stake().is_none());
+
+        data[0] = 1;
+        let state = StakeStateV2::from_bytes(&data).unwrap();
+        assert!(state.meta().is_some());
+        assert!(state.stake().is_none());
+
+        data[0] = 4;
+        assert_eq!(
+            StakeStateV2::from_bytes(&data).err(),
+            Some(ProgramError::InvalidAccountData)
+        );
+        assert_eq!(
+            StakeStateV2::from_bytes(&data[..199]).err(),
+            Some(ProgramError::InvalidAccountData)
+        );
+    }
+}
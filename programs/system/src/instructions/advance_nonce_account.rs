@@ -6,6 +6,9 @@ use pinocchio::{
 
 /// Consumes a stored nonce, replacing it with a successor.
 ///
+/// The new nonce value is derived from the most recent blockhash, so the
+/// instruction fails if the stored nonce is already up to date.
+///
 /// ### Accounts:
 ///   0. `[WRITE]` Nonce account
 ///   1. `[]` Recent blockhashes sysvar
@@ -15,6 +18,9 @@ pub struct AdvanceNonceAccount<'a> {
     pub account: &'a AccountView,
 
     /// Recent blockhashes sysvar.
+    ///
+    /// The address of the account must be
+    /// [`RECENT_BLOCKHASHES_ID`](pinocchio::sysvars::recent_blockhashes::RECENT_BLOCKHASHES_ID).
     pub recent_blockhashes_sysvar: &'a AccountView,
 
     /// Nonce authority.
@@ -29,6 +35,16 @@ impl AdvanceNonceAccount<'_> {
 
     #[inline(always)]
     pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        self.with_instruction(|instruction, account_views| {
+            invoke_signed(instruction, account_views, signers)
+        })
+    }
+
+    #[inline(always)]
+    pub fn with_instruction<R>(
+        &self,
+        f: impl FnOnce(&InstructionView, &[&AccountView; 3]) -> R,
+    ) -> R {
         // Instruction accounts
         let instruction_accounts: [InstructionAccount; 3] = [
             InstructionAccount::writable(self.account.address()),
@@ -43,10 +59,9 @@ impl AdvanceNonceAccount<'_> {
             data: &[4, 0, 0, 0],
         };
 
-        invoke_signed(
+        f(
             &instruction,
             &[self.account, self.recent_blockhashes_sysvar, self.authority],
-            signers,
         )
     }
 }
@@ -9,7 +9,7 @@ use pinocchio::{
 /// ### Accounts:
 ///   0. `[WRITE, SIGNER]` New account
 pub struct Allocate<'a> {
-    /// Account to be assigned.
+    /// Account to be allocated.
     pub account: &'a AccountView,
 
     /// Number of bytes of memory to allocate.
@@ -24,6 +24,16 @@ impl Allocate<'_> {
 
     #[inline(always)]
     pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        self.with_instruction(|instruction, account_views| {
+            invoke_signed(instruction, account_views, signers)
+        })
+    }
+
+    #[inline(always)]
+    pub fn with_instruction<R>(
+        &self,
+        f: impl FnOnce(&InstructionView, &[&AccountView; 1]) -> R,
+    ) -> R {
         // Instruction accounts
         let instruction_accounts: [InstructionAccount; 1] =
             [InstructionAccount::writable_signer(self.account.address())];
@@ -41,6 +51,6 @@ impl Allocate<'_> {
             data: &instruction_data,
         };
 
-        invoke_signed(&instruction, &[self.account], signers)
+        f(&instruction, &[self.account])
     }
 }
@@ -1,5 +1,7 @@
 use pinocchio::{
+    address::MAX_SEED_LEN,
     cpi::{invoke_signed, Signer},
+    error::ProgramError,
     instruction::{InstructionAccount, InstructionView},
     AccountView, Address, ProgramResult,
 };
@@ -21,7 +23,7 @@ pub struct AllocateWithSeed<'a, 'b, 'c> {
     /// as account 0.
     pub base: &'a AccountView,
 
-    /// String of ASCII chars, no longer than [`MAX_SEED_LEN`](https://docs.rs/solana-address/latest/solana_address/constant.MAX_SEED_LEN.html).
+    /// String of ASCII chars, no longer than [`MAX_SEED_LEN`].
     pub seed: &'b str,
 
     /// Number of bytes of memory to allocate.
@@ -39,6 +41,20 @@ impl AllocateWithSeed<'_, '_, '_> {
 
     #[inline(always)]
     pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        self.with_instruction(|instruction, account_views| {
+            invoke_signed(instruction, account_views, signers)
+        })?
+    }
+
+    #[inline(always)]
+    pub fn with_instruction<R>(
+        &self,
+        f: impl FnOnce(&InstructionView, &[&AccountView; 2]) -> R,
+    ) -> Result<R, ProgramError> {
+        if self.seed.len() > MAX_SEED_LEN {
+            return Err(ProgramError::MaxSeedLengthExceeded);
+        }
+
         // Instruction accounts
         let instruction_accounts: [InstructionAccount; 2] = [
             InstructionAccount::writable(self.account.address()),
@@ -52,7 +68,7 @@ impl AllocateWithSeed<'_, '_, '_> {
         // - [44..  ]: seed (max 32)
         // - [..  +8]: account space
         // - [.. +32]: owner address
-        let mut instruction_data = [0; 112];
+        let mut instruction_data = [0; 44 + MAX_SEED_LEN + 40];
         instruction_data[0] = 9;
         instruction_data[4..36].copy_from_slice(self.base.address().as_array());
         instruction_data[36..44].copy_from_slice(&u64::to_le_bytes(self.seed.len() as u64));
@@ -68,6 +84,6 @@ impl AllocateWithSeed<'_, '_, '_> {
             data: &instruction_data[..offset + 40],
         };
 
-        invoke_signed(&instruction, &[self.account, self.base], signers)
+        Ok(f(&instruction, &[self.account, self.base]))
     }
 }
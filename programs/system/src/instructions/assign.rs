@@ -24,6 +24,16 @@ impl Assign<'_, '_> {
 
     #[inline(always)]
     pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        self.with_instruction(|instruction, account_views| {
+            invoke_signed(instruction, account_views, signers)
+        })
+    }
+
+    #[inline(always)]
+    pub fn with_instruction<R>(
+        &self,
+        f: impl FnOnce(&InstructionView, &[&AccountView; 1]) -> R,
+    ) -> R {
         // Instruction accounts
         let instruction_accounts: [InstructionAccount; 1] =
             [InstructionAccount::writable_signer(self.account.address())];
@@ -41,6 +51,6 @@ impl Assign<'_, '_> {
             data: &instruction_data,
         };
 
-        invoke_signed(&instruction, &[self.account], signers)
+        f(&instruction, &[self.account])
     }
 }
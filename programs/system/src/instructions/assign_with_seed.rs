@@ -1,5 +1,7 @@
 use pinocchio::{
+    address::MAX_SEED_LEN,
     cpi::{invoke_signed, Signer},
+    error::ProgramError,
     instruction::{InstructionAccount, InstructionView},
     AccountView, Address, ProgramResult,
 };
@@ -10,7 +12,7 @@ use pinocchio::{
 ///   0. `[WRITE]` Assigned account
 ///   1. `[SIGNER]` Base account
 pub struct AssignWithSeed<'a, 'b, 'c> {
-    /// Allocated account.
+    /// Assigned account.
     pub account: &'a AccountView,
 
     /// Base account.
@@ -20,7 +22,7 @@ pub struct AssignWithSeed<'a, 'b, 'c> {
     /// as account 0.
     pub base: &'a AccountView,
 
-    /// String of ASCII chars, no longer than [`MAX_SEED_LEN`](https://docs.rs/solana-address/latest/solana_address/constant.MAX_SEED_LEN.html).
+    /// String of ASCII chars, no longer than [`MAX_SEED_LEN`].
     pub seed: &'b str,
 
     /// Address of program that will own the new account.
@@ -35,6 +37,20 @@ impl AssignWithSeed<'_, '_, '_> {
 
     #[inline(always)]
     pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        self.with_instruction(|instruction, account_views| {
+            invoke_signed(instruction, account_views, signers)
+        })?
+    }
+
+    #[inline(always)]
+    pub fn with_instruction<R>(
+        &self,
+        f: impl FnOnce(&InstructionView, &[&AccountView; 2]) -> R,
+    ) -> Result<R, ProgramError> {
+        if self.seed.len() > MAX_SEED_LEN {
+            return Err(ProgramError::MaxSeedLengthExceeded);
+        }
+
         // Instruction accounts
         let instruction_accounts: [InstructionAccount; 2] = [
             InstructionAccount::writable(self.account.address()),
@@ -47,7 +63,7 @@ impl AssignWithSeed<'_, '_, '_> {
         // - [36..44]: seed length
         // - [44..  ]: seed (max 32)
         // - [.. +32]: owner address
-        let mut instruction_data = [0; 104];
+        let mut instruction_data = [0; 44 + MAX_SEED_LEN + 32];
         instruction_data[0] = 10;
         instruction_data[4..36].copy_from_slice(self.base.address().as_array());
         instruction_data[36..44].copy_from_slice(&u64::to_le_bytes(self.seed.len() as u64));
@@ -62,6 +78,6 @@ impl AssignWithSeed<'_, '_, '_> {
             data: &instruction_data[..offset + 32],
         };
 
-        invoke_signed(&instruction, &[self.account, self.base], signers)
+        Ok(f(&instruction, &[self.account, self.base]))
     }
 }
@@ -30,6 +30,16 @@ impl AuthorizeNonceAccount<'_, '_> {
 
     #[inline(always)]
     pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        self.with_instruction(|instruction, account_views| {
+            invoke_signed(instruction, account_views, signers)
+        })
+    }
+
+    #[inline(always)]
+    pub fn with_instruction<R>(
+        &self,
+        f: impl FnOnce(&InstructionView, &[&AccountView; 2]) -> R,
+    ) -> R {
         // Instruction accounts
         let instruction_accounts: [InstructionAccount; 2] = [
             InstructionAccount::writable(self.account.address()),
@@ -38,7 +48,7 @@ impl AuthorizeNonceAccount<'_, '_> {
 
         // instruction data
         // - [0..4 ]: instruction discriminator
-        // - [4..12]: lamports
+        // - [4..36]: new authority address
         let mut instruction_data = [0; 36];
         instruction_data[0] = 7;
         instruction_data[4..36].copy_from_slice(self.new_authority.as_array());
@@ -49,6 +59,6 @@ impl AuthorizeNonceAccount<'_, '_> {
             data: &instruction_data,
         };
 
-        invoke_signed(&instruction, &[self.account, self.authority], signers)
+        f(&instruction, &[self.account, self.authority])
     }
 }
@@ -1,4 +1,5 @@
 use pinocchio::{
+    address::MAX_SEED_LEN,
     cpi::{invoke_signed, Signer},
     error::ProgramError,
     instruction::{InstructionAccount, InstructionView},
@@ -28,7 +29,7 @@ pub struct CreateAccountWithSeed<'a, 'b, 'c> {
     /// as account 0.
     pub base: Option<&'a AccountView>,
 
-    /// String of ASCII chars, no longer than [`MAX_SEED_LEN`](https://docs.rs/solana-address/latest/solana_address/constant.MAX_SEED_LEN.html).
+    /// String of ASCII chars, no longer than [`MAX_SEED_LEN`].
     pub seed: &'b str,
 
     /// Number of lamports to transfer to the new account.
@@ -91,6 +92,20 @@ impl<'a, 'b, 'c> CreateAccountWithSeed<'a, 'b, 'c> {
 
     #[inline(always)]
     pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        self.with_instruction(|instruction, account_views| {
+            invoke_signed(instruction, account_views, signers)
+        })?
+    }
+
+    #[inline(always)]
+    pub fn with_instruction<R>(
+        &self,
+        f: impl FnOnce(&InstructionView, &[&AccountView; 3]) -> R,
+    ) -> Result<R, ProgramError> {
+        if self.seed.len() > MAX_SEED_LEN {
+            return Err(ProgramError::MaxSeedLengthExceeded);
+        }
+
         // Instruction accounts
         let instruction_accounts: [InstructionAccount; 3] = [
             InstructionAccount::writable_signer(self.from.address()),
@@ -106,7 +121,7 @@ impl<'a, 'b, 'c> CreateAccountWithSeed<'a, 'b, 'c> {
         // - [..  +8]: lamports
         // - [..  +8]: account space
         // - [.. +32]: owner address
-        let mut instruction_data = [0; 120];
+        let mut instruction_data = [0; 44 + MAX_SEED_LEN + 48];
         instruction_data[0] = 3;
         instruction_data[4..36]
             .copy_from_slice(self.base.unwrap_or(self.from).address().as_array());
@@ -124,10 +139,9 @@ impl<'a, 'b, 'c> CreateAccountWithSeed<'a, 'b, 'c> {
             data: &instruction_data[..offset + 48],
         };
 
-        invoke_signed(
+        Ok(f(
             &instruction,
             &[self.from, self.to, self.base.unwrap_or(self.from)],
-            signers,
-        )
+        ))
     }
 }
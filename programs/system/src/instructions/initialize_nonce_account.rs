@@ -13,6 +13,9 @@ use pinocchio::{
 /// No signatures are required to execute this instruction, enabling derived
 /// nonce account addresses.
 ///
+/// The nonce account must be owned by the system program, be rent-exempt and
+/// have [`NONCE_STATE_SIZE`](crate::state::NONCE_STATE_SIZE) bytes of data.
+///
 /// ### Accounts:
 ///   0. `[WRITE]` Nonce account
 ///   1. `[]` Recent blockhashes sysvar
@@ -22,9 +25,15 @@ pub struct InitializeNonceAccount<'a, 'b> {
     pub account: &'a AccountView,
 
     /// Recent blockhashes sysvar.
+    ///
+    /// The address of the account must be
+    /// [`RECENT_BLOCKHASHES_ID`](pinocchio::sysvars::recent_blockhashes::RECENT_BLOCKHASHES_ID).
     pub recent_blockhashes_sysvar: &'a AccountView,
 
     /// Rent sysvar.
+    ///
+    /// The address of the account must be
+    /// [`RENT_ID`](pinocchio::sysvars::rent::RENT_ID).
     pub rent_sysvar: &'a AccountView,
 
     /// Indicates the entity authorized to execute nonce
@@ -35,6 +44,14 @@ pub struct InitializeNonceAccount<'a, 'b> {
 impl InitializeNonceAccount<'_, '_> {
     #[inline(always)]
     pub fn invoke(&self) -> ProgramResult {
+        self.with_instruction(invoke)
+    }
+
+    #[inline(always)]
+    pub fn with_instruction<R>(
+        &self,
+        f: impl FnOnce(&InstructionView, &[&AccountView; 3]) -> R,
+    ) -> R {
         // Instruction accounts
         let instruction_accounts: [InstructionAccount; 3] = [
             InstructionAccount::writable(self.account.address()),
@@ -55,7 +72,7 @@ impl InitializeNonceAccount<'_, '_> {
             data: &instruction_data,
         };
 
-        invoke(
+        f(
             &instruction,
             &[
                 self.account,
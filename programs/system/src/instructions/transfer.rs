@@ -28,6 +28,16 @@ impl Transfer<'_> {
 
     #[inline(always)]
     pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        self.with_instruction(|instruction, account_views| {
+            invoke_signed(instruction, account_views, signers)
+        })
+    }
+
+    #[inline(always)]
+    pub fn with_instruction<R>(
+        &self,
+        f: impl FnOnce(&InstructionView, &[&AccountView; 2]) -> R,
+    ) -> R {
         // Instruction accounts
         let instruction_accounts: [InstructionAccount; 2] = [
             InstructionAccount::writable_signer(self.from.address()),
@@ -47,6 +57,6 @@ impl Transfer<'_> {
             data: &instruction_data,
         };
 
-        invoke_signed(&instruction, &[self.from, self.to], signers)
+        f(&instruction, &[self.from, self.to])
     }
 }
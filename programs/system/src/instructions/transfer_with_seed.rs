@@ -1,5 +1,7 @@
 use pinocchio::{
+    address::MAX_SEED_LEN,
     cpi::{invoke_signed, Signer},
+    error::ProgramError,
     instruction::{InstructionAccount, InstructionView},
     AccountView, Address, ProgramResult,
 };
@@ -27,10 +29,10 @@ pub struct TransferWithSeed<'a, 'b, 'c> {
     /// Amount of lamports to transfer.
     pub lamports: u64,
 
-    /// String of ASCII chars, no longer than [`MAX_SEED_LEN`](https://docs.rs/solana-address/latest/solana_address/constant.MAX_SEED_LEN.html).
+    /// String of ASCII chars, no longer than [`MAX_SEED_LEN`].
     pub seed: &'b str,
 
-    /// Address of program that will own the new account.
+    /// Address of program that owns the funding account.
     pub owner: &'c Address,
 }
 
@@ -42,6 +44,20 @@ impl TransferWithSeed<'_, '_, '_> {
 
     #[inline(always)]
     pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        self.with_instruction(|instruction, account_views| {
+            invoke_signed(instruction, account_views, signers)
+        })?
+    }
+
+    #[inline(always)]
+    pub fn with_instruction<R>(
+        &self,
+        f: impl FnOnce(&InstructionView, &[&AccountView; 3]) -> R,
+    ) -> Result<R, ProgramError> {
+        if self.seed.len() > MAX_SEED_LEN {
+            return Err(ProgramError::MaxSeedLengthExceeded);
+        }
+
         // Instruction accounts
         let instruction_accounts: [InstructionAccount; 3] = [
             InstructionAccount::writable(self.from.address()),
@@ -55,7 +71,7 @@ impl TransferWithSeed<'_, '_, '_> {
         // - [12..20]: seed length
         // - [20..  ]: seed (max 32)
         // - [.. +32]: owner address
-        let mut instruction_data = [0; 80];
+        let mut instruction_data = [0; 20 + MAX_SEED_LEN + 32];
         instruction_data[0] = 11;
         instruction_data[4..12].copy_from_slice(&self.lamports.to_le_bytes());
         instruction_data[12..20].copy_from_slice(&u64::to_le_bytes(self.seed.len() as u64));
@@ -70,6 +86,6 @@ impl TransferWithSeed<'_, '_, '_> {
             data: &instruction_data[..offset + 32],
         };
 
-        invoke_signed(&instruction, &[self.from, self.base, self.to], signers)
+        Ok(f(&instruction, &[self.from, self.base, self.to]))
     }
 }
@@ -4,8 +4,12 @@ use pinocchio::{
     AccountView, ProgramResult,
 };
 
-/// One-time idempotent upgrade of legacy nonce versions in order to bump
-/// them out of chain blockhash domain.
+/// One-time upgrade of legacy nonce versions in order to bump them out of
+/// chain blockhash domain.
+///
+/// No signatures are required to execute this instruction, so any program
+/// can upgrade a legacy nonce account. Nonce accounts that are already
+/// using the current version are rejected by the system program.
 ///
 /// ### Accounts:
 ///   0. `[WRITE]` Nonce account
@@ -17,6 +21,14 @@ pub struct UpgradeNonceAccount<'a> {
 impl UpgradeNonceAccount<'_> {
     #[inline(always)]
     pub fn invoke(&self) -> ProgramResult {
+        self.with_instruction(invoke)
+    }
+
+    #[inline(always)]
+    pub fn with_instruction<R>(
+        &self,
+        f: impl FnOnce(&InstructionView, &[&AccountView; 1]) -> R,
+    ) -> R {
         // Instruction accounts
         let instruction_accounts: [InstructionAccount; 1] =
             [InstructionAccount::writable(self.account.address())];
@@ -28,6 +40,6 @@ impl UpgradeNonceAccount<'_> {
             data: &[12, 0, 0, 0],
         };
 
-        invoke(&instruction, &[self.account])
+        f(&instruction, &[self.account])
     }
 }
@@ -23,9 +23,15 @@ pub struct WithdrawNonceAccount<'a> {
     pub recipient: &'a AccountView,
 
     /// Recent blockhashes sysvar.
+    ///
+    /// The address of the account must be
+    /// [`RECENT_BLOCKHASHES_ID`](pinocchio::sysvars::recent_blockhashes::RECENT_BLOCKHASHES_ID).
     pub recent_blockhashes_sysvar: &'a AccountView,
 
     /// Rent sysvar.
+    ///
+    /// The address of the account must be
+    /// [`RENT_ID`](pinocchio::sysvars::rent::RENT_ID).
     pub rent_sysvar: &'a AccountView,
 
     /// Nonce authority.
@@ -34,7 +40,7 @@ pub struct WithdrawNonceAccount<'a> {
     /// Lamports to withdraw.
     ///
     /// The account balance must be left above the rent exempt reserve
-    /// or at zero.
+    /// or at zero, in which case the nonce account is closed.
     pub lamports: u64,
 }
 
@@ -46,6 +52,16 @@ impl WithdrawNonceAccount<'_> {
 
     #[inline(always)]
     pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        self.with_instruction(|instruction, account_views| {
+            invoke_signed(instruction, account_views, signers)
+        })
+    }
+
+    #[inline(always)]
+    pub fn with_instruction<R>(
+        &self,
+        f: impl FnOnce(&InstructionView, &[&AccountView; 5]) -> R,
+    ) -> R {
         // Instruction accounts
         let instruction_accounts: [InstructionAccount; 5] = [
             InstructionAccount::writable(self.account.address()),
@@ -68,7 +84,7 @@ impl WithdrawNonceAccount<'_> {
             data: &instruction_data,
         };
 
-        invoke_signed(
+        f(
             &instruction,
             &[
                 self.account,
@@ -77,7 +93,6 @@ impl WithdrawNonceAccount<'_> {
                 self.rent_sysvar,
                 self.authority,
             ],
-            signers,
         )
     }
 }
@@ -1,19 +1,27 @@
 #![no_std]
 
 use {
-    crate::instructions::{Assign, CreateAccount, Transfer},
+    crate::instructions::{Allocate, Assign, CreateAccount, Transfer},
     pinocchio::{
-        address::declare_id,
-        cpi::Signer,
+        address::{address, declare_id, MAX_SEEDS},
+        cpi::{Seed, Signer},
+        error::ProgramError,
         sysvars::{rent::Rent, Sysvar},
         AccountView, Address, ProgramResult,
     },
 };
 
 pub mod instructions;
+pub mod state;
 
 declare_id!("11111111111111111111111111111111");
 
+/// Address of the incinerator.
+///
+/// Lamports sent to this address are removed from the total supply at the end
+/// of the transaction.
+pub const INCINERATOR_ID: Address = address!("1nc1nerator11111111111111111111111111111111");
+
 /// Create an account with a minimum balance to be rent-exempt.
 #[inline(always)]
 pub fn create_account_with_minimum_balance(
@@ -74,13 +82,596 @@ pub fn create_account_with_minimum_balance_signed(
             .invoke_signed(signers)?;
         }
 
+        // Allocate the required space for the account.
+        Allocate {
+            account,
+            space: space as u64,
+        }
+        .invoke_signed(signers)?;
+
         // Assign the account to the specified owner.
-        Assign { account, owner }.invoke_signed(signers)?;
+        Assign { account, owner }.invoke_signed(signers)
+    }
+}
 
-        // Allocate the required space for the account.
-        //
-        // SAFETY: There are no active borrows of the `account`.
-        // This was checked by the `Assign` CPI above.
-        unsafe { account.resize_unchecked(space) }
+/// Create a program derived address (PDA) account with a minimum balance to be
+/// rent-exempt.
+///
+/// The `seeds` must include the bump seed and derive the address of the
+/// `account` from `program_id`, which must be the address of the executing
+/// program since `invoke_signed` signs on its behalf. The `payer` must be a
+/// signer of the transaction.
+///
+/// The helper is idempotent: when the `account` is already funded, owned by
+/// `owner` and has `space` bytes of data, no instruction is invoked. A funded
+/// account owned by `owner` with a different data length results in an
+/// [`ProgramError::AccountAlreadyInitialized`] error. In both cases, the
+/// address of the `account` is first checked against the `seeds`, returning
+/// a [`ProgramError::InvalidSeeds`] error if it is not the PDA.
+///
+/// Similarly to [`create_account_with_minimum_balance_signed`], accounts that
+/// already hold lamports are funded, allocated and assigned through CPIs
+/// instead of using `CreateAccount`, which would fail for such accounts.
+///
+/// Deriving program addresses is only available on-chain or with the
+/// `curve25519` feature enabled.
+#[cfg(any(target_os = "solana", target_arch = "bpf", feature = "curve25519"))]
+#[inline(always)]
+pub fn create_pda_account(
+    account: &AccountView,
+    space: usize,
+    owner: &Address,
+    payer: &AccountView,
+    seeds: &[Seed],
+    program_id: &Address,
+    rent_sysvar: Option<&AccountView>,
+) -> ProgramResult {
+    if account.owned_by(owner) && account.lamports() > 0 {
+        if seeds.len() > MAX_SEEDS {
+            return Err(ProgramError::MaxSeedLengthExceeded);
+        }
+
+        let mut seeds_bytes: [&[u8]; MAX_SEEDS] = [&[]; MAX_SEEDS];
+        seeds_bytes
+            .iter_mut()
+            .zip(seeds)
+            .for_each(|(bytes, seed)| *bytes = seed);
+
+        let address = Address::create_program_address(&seeds_bytes[..seeds.len()], program_id)
+            .map_err(|_| ProgramError::InvalidSeeds)?;
+
+        if account.address() != &address {
+            return Err(ProgramError::InvalidSeeds);
+        }
+
+        return if account.data_len() == space {
+            Ok(())
+        } else {
+            Err(ProgramError::AccountAlreadyInitialized)
+        };
+    }
+
+    create_account_with_minimum_balance_signed(
+        account,
+        space,
+        owner,
+        payer,
+        rent_sysvar,
+        &[Signer::from(seeds)],
+    )
+}
+
+/// Close an account, moving all of its lamports to the `destination`.
+///
+/// How the account is closed depends on its owner:
+///
+/// * Accounts owned by the system program are defunded through a `Transfer`
+///   CPI. These accounts must not hold any data, and the PDA signer seeds of
+///   the `account` must be provided via the `signers` when it is a PDA.
+/// * Accounts owned by the executing program are defunded directly and
+///   closed, which resizes their data to zero and assigns them back to the
+///   system program. The `signers` are not used in this case.
+///
+/// The `account` and `destination` must be writable.
+#[inline(always)]
+pub fn close_account(
+    account: &AccountView,
+    destination: &AccountView,
+    signers: &[Signer],
+) -> ProgramResult {
+    let lamports = account.lamports();
+
+    if account.owned_by(&ID) {
+        return Transfer {
+            from: account,
+            to: destination,
+            lamports,
+        }
+        .invoke_signed(signers);
+    }
+
+    destination.set_lamports(
+        destination
+            .lamports()
+            .checked_add(lamports)
+            .ok_or(ProgramError::ArithmeticOverflow)?,
+    );
+    account.set_lamports(0);
+
+    account.close()
+}
+
+/// Transfer lamports, checking that the recipient is rent-exempt after the
+/// transfer.
+///
+/// Returns a [`ProgramError::AccountNotRentExempt`] error without invoking
+/// the transfer if the balance of `to` after the transfer is below the
+/// minimum balance required for its current data length.
+#[inline(always)]
+pub fn transfer_checked_rent(from: &AccountView, to: &AccountView, lamports: u64) -> ProgramResult {
+    transfer_checked_rent_signed(from, to, lamports, &[])
+}
+
+/// Transfer lamports, checking that the recipient is rent-exempt after the
+/// transfer.
+///
+/// When `from` is a PDA, its signer seeds must be provided via the `signers`.
+///
+/// Returns a [`ProgramError::AccountNotRentExempt`] error without invoking
+/// the transfer if the balance of `to` after the transfer is below the
+/// minimum balance required for its current data length.
+#[inline(always)]
+pub fn transfer_checked_rent_signed(
+    from: &AccountView,
+    to: &AccountView,
+    lamports: u64,
+    signers: &[Signer],
+) -> ProgramResult {
+    let balance = to
+        .lamports()
+        .checked_add(lamports)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+
+    if balance < Rent::get()?.try_minimum_balance(to.data_len())? {
+        return Err(ProgramError::AccountNotRentExempt);
+    }
+
+    Transfer { from, to, lamports }.invoke_signed(signers)
+}
+
+/// Burn lamports by transferring them to the incinerator.
+///
+/// Returns a [`ProgramError::InvalidArgument`] error if the address of the
+/// `incinerator` account is not [`INCINERATOR_ID`].
+#[inline(always)]
+pub fn burn_lamports(
+    from: &AccountView,
+    incinerator: &AccountView,
+    lamports: u64,
+) -> ProgramResult {
+    burn_lamports_signed(from, incinerator, lamports, &[])
+}
+
+/// Burn lamports by transferring them to the incinerator.
+///
+/// When `from` is a PDA, its signer seeds must be provided via the `signers`.
+///
+/// Returns a [`ProgramError::InvalidArgument`] error if the address of the
+/// `incinerator` account is not [`INCINERATOR_ID`].
+#[inline(always)]
+pub fn burn_lamports_signed(
+    from: &AccountView,
+    incinerator: &AccountView,
+    lamports: u64,
+    signers: &[Signer],
+) -> ProgramResult {
+    if incinerator.address() != &INCINERATOR_ID {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    Transfer {
+        from,
+        to: incinerator,
+        lamports,
+    }
+    .invoke_signed(signers)
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use {
+        super::*,
+        pinocchio::{
+            entrypoint::input::{AccountViews, InputAccount},
+            instruction::{InstructionAccount, InstructionView},
+            test_syscalls::{set_syscalls, HostSyscalls, Invocation, InvokeRecorder},
+        },
+    };
+
+    const PAYER: Address = Address::new_from_array([1; 32]);
+
+    const ACCOUNT: Address = Address::new_from_array([2; 32]);
+
+    const OWNER: Address = Address::new_from_array([3; 32]);
+
+    const SPACE: usize = 10;
+
+    /// Rent-exempt minimum balance of an account with `SPACE` bytes of data.
+    const MINIMUM_BALANCE: u64 = (128 + SPACE as u64) * 3_480 * 2;
+
+    /// Register the `Rent` sysvar for the current thread, returning the
+    /// recorder of the cross-program invocations.
+    fn runtime() -> InvokeRecorder {
+        set_syscalls(HostSyscalls::default().with_rent(3_480, 2.0));
+        InvokeRecorder::new()
+    }
+
+    /// Create the payer and the account, owned by `owner` and holding
+    /// `lamports` and `data`.
+    fn accounts(owner: Address, lamports: u64, data: &[u8]) -> AccountViews {
+        AccountViews::new(&[
+            InputAccount::new(PAYER, ID, 1_000_000_000, &[])
+                .signer()
+                .writable(),
+            InputAccount::new(ACCOUNT, owner, lamports, data)
+                .signer()
+                .writable(),
+        ])
+    }
+
+    /// Create an invocation of the system program.
+    fn system_invocation(accounts: &[InstructionAccount], fields: &[&[u8]]) -> Invocation {
+        Invocation::new(&InstructionView {
+            program_id: &ID,
+            accounts,
+            data: &fields.concat(),
+        })
+    }
+
+    #[test]
+    fn test_create_pda_account() {
+        let invokes = runtime();
+        let accounts = accounts(ID, 0, &[]);
+
+        let seeds = [Seed::from(b"seed"), Seed::from(&[255])];
+        assert_eq!(
+            create_pda_account(
+                &accounts[1],
+                SPACE,
+                &OWNER,
+                &accounts[0],
+                &seeds,
+                &OWNER,
+                None
+            ),
+            Ok(())
+        );
+
+        invokes.assert_invoked(&[system_invocation(
+            &[
+                InstructionAccount::writable_signer(&PAYER),
+                InstructionAccount::writable_signer(&ACCOUNT),
+            ],
+            &[
+                &0u32.to_le_bytes(),
+                &MINIMUM_BALANCE.to_le_bytes(),
+                &(SPACE as u64).to_le_bytes(),
+                OWNER.as_ref(),
+            ],
+        )
+        .with_signer(&[b"seed", &[255]])]);
+    }
+
+    #[test]
+    fn test_create_pda_account_funded() {
+        let invokes = runtime();
+        let accounts = accounts(ID, 1_000, &[]);
+
+        let seeds = [Seed::from(b"seed"), Seed::from(&[255])];
+        assert_eq!(
+            create_pda_account(
+                &accounts[1],
+                SPACE,
+                &OWNER,
+                &accounts[0],
+                &seeds,
+                &OWNER,
+                None
+            ),
+            Ok(())
+        );
+
+        // The account already holds lamports, so it is funded, allocated and
+        // assigned instead of created.
+        let signer: &[&[u8]] = &[b"seed", &[255]];
+        invokes.assert_invoked(&[
+            system_invocation(
+                &[
+                    InstructionAccount::writable_signer(&PAYER),
+                    InstructionAccount::writable(&ACCOUNT),
+                ],
+                &[
+                    &2u32.to_le_bytes(),
+                    &(MINIMUM_BALANCE - 1_000).to_le_bytes(),
+                ],
+            )
+            .with_signer(signer),
+            system_invocation(
+                &[InstructionAccount::writable_signer(&ACCOUNT)],
+                &[&8u32.to_le_bytes(), &(SPACE as u64).to_le_bytes()],
+            )
+            .with_signer(signer),
+            system_invocation(
+                &[InstructionAccount::writable_signer(&ACCOUNT)],
+                &[&1u32.to_le_bytes(), OWNER.as_ref()],
+            )
+            .with_signer(signer),
+        ]);
+    }
+
+    /// Create the payer and the PDA of `[b"seed"]` for `OWNER`, owned by
+    /// `OWNER` and holding `data`, returning the bump seed of the PDA.
+    fn pda_accounts(data: &[u8]) -> (AccountViews, [u8; 1]) {
+        let (address, bump) = Address::find_program_address(&[b"seed"], &OWNER);
+        let accounts = AccountViews::new(&[
+            InputAccount::new(PAYER, ID, 1_000_000_000, &[])
+                .signer()
+                .writable(),
+            InputAccount::new(address, OWNER, MINIMUM_BALANCE, data).writable(),
+        ]);
+        (accounts, [bump])
+    }
+
+    #[test]
+    fn test_create_pda_account_idempotent() {
+        let invokes = runtime();
+        let (accounts, bump) = pda_accounts(&[0; SPACE]);
+
+        let seeds = [Seed::from(b"seed"), Seed::from(&bump)];
+        assert_eq!(
+            create_pda_account(
+                &accounts[1],
+                SPACE,
+                &OWNER,
+                &accounts[0],
+                &seeds,
+                &OWNER,
+                None
+            ),
+            Ok(())
+        );
+
+        invokes.assert_invoked(&[]);
+    }
+
+    #[test]
+    fn test_create_pda_account_initialized() {
+        let invokes = runtime();
+        let (accounts, bump) = pda_accounts(&[0; SPACE - 1]);
+
+        let seeds = [Seed::from(b"seed"), Seed::from(&bump)];
+        assert_eq!(
+            create_pda_account(
+                &accounts[1],
+                SPACE,
+                &OWNER,
+                &accounts[0],
+                &seeds,
+                &OWNER,
+                None
+            ),
+            Err(ProgramError::AccountAlreadyInitialized)
+        );
+
+        invokes.assert_invoked(&[]);
+    }
+
+    #[test]
+    fn test_create_pda_account_invalid_seeds() {
+        let invokes = runtime();
+        let (accounts, bump) = pda_accounts(&[0; SPACE]);
+
+        // The seeds derive a different address.
+        let seeds = [Seed::from(b"other"), Seed::from(&bump)];
+        assert_eq!(
+            create_pda_account(
+                &accounts[1],
+                SPACE,
+                &OWNER,
+                &accounts[0],
+                &seeds,
+                &OWNER,
+                None
+            ),
+            Err(ProgramError::InvalidSeeds)
+        );
+
+        // The seeds derive the address from a different program.
+        let seeds = [Seed::from(b"seed"), Seed::from(&bump)];
+        assert_eq!(
+            create_pda_account(&accounts[1], SPACE, &OWNER, &accounts[0], &seeds, &ID, None),
+            Err(ProgramError::InvalidSeeds)
+        );
+
+        invokes.assert_invoked(&[]);
+    }
+
+    /// Create the account, owned by `owner` and holding 1000 lamports and
+    /// `data`, and a destination holding `lamports`.
+    fn close_accounts(owner: Address, data: &[u8], lamports: u64) -> AccountViews {
+        AccountViews::new(&[
+            InputAccount::new(ACCOUNT, owner, 1_000, data)
+                .signer()
+                .writable(),
+            InputAccount::new(PAYER, ID, lamports, &[]).writable(),
+        ])
+    }
+
+    #[test]
+    fn test_close_system_account() {
+        let invokes = runtime();
+        let accounts = close_accounts(ID, &[], 5_000);
+
+        let seeds = [Seed::from(b"seed"), Seed::from(&[255])];
+        assert_eq!(
+            close_account(&accounts[0], &accounts[1], &[Signer::from(&seeds)]),
+            Ok(())
+        );
+
+        invokes.assert_invoked(&[system_invocation(
+            &[
+                InstructionAccount::writable_signer(&ACCOUNT),
+                InstructionAccount::writable(&PAYER),
+            ],
+            &[&2u32.to_le_bytes(), &1_000u64.to_le_bytes()],
+        )
+        .with_signer(&[b"seed", &[255]])]);
+    }
+
+    #[test]
+    fn test_close_program_account() {
+        let invokes = runtime();
+        let accounts = close_accounts(OWNER, &[1; SPACE], 5_000);
+
+        assert_eq!(close_account(&accounts[0], &accounts[1], &[]), Ok(()));
+
+        // The account is closed directly, without invoking the system program.
+        invokes.assert_invoked(&[]);
+        assert_eq!(accounts[0].lamports(), 0);
+        assert_eq!(accounts[0].data_len(), 0);
+        assert!(accounts[0].owned_by(&ID));
+        assert_eq!(accounts[1].lamports(), 6_000);
+    }
+
+    #[test]
+    fn test_close_program_account_overflow() {
+        let _invokes = runtime();
+        let accounts = close_accounts(OWNER, &[1; SPACE], u64::MAX);
+
+        assert_eq!(
+            close_account(&accounts[0], &accounts[1], &[]),
+            Err(ProgramError::ArithmeticOverflow)
+        );
+        assert_eq!(accounts[0].lamports(), 1_000);
+    }
+
+    #[test]
+    fn test_transfer_checked_rent() {
+        let invokes = runtime();
+        let accounts = accounts(OWNER, 1_000, &[0; SPACE]);
+
+        // The transfer leaves the recipient with exactly the minimum balance.
+        let lamports = MINIMUM_BALANCE - 1_000;
+        assert_eq!(
+            transfer_checked_rent(&accounts[0], &accounts[1], lamports),
+            Ok(())
+        );
+
+        invokes.assert_invoked(&[system_invocation(
+            &[
+                InstructionAccount::writable_signer(&PAYER),
+                InstructionAccount::writable(&ACCOUNT),
+            ],
+            &[&2u32.to_le_bytes(), &lamports.to_le_bytes()],
+        )]);
+    }
+
+    #[test]
+    fn test_transfer_checked_rent_not_rent_exempt() {
+        let invokes = runtime();
+        let accounts = accounts(OWNER, 1_000, &[0; SPACE]);
+
+        let seeds = [Seed::from(b"seed"), Seed::from(&[255])];
+        assert_eq!(
+            transfer_checked_rent_signed(
+                &accounts[0],
+                &accounts[1],
+                MINIMUM_BALANCE - 1_001,
+                &[Signer::from(&seeds)]
+            ),
+            Err(ProgramError::AccountNotRentExempt)
+        );
+
+        invokes.assert_invoked(&[]);
+    }
+
+    #[test]
+    fn test_transfer_checked_rent_overflow() {
+        let invokes = runtime();
+        let accounts = accounts(OWNER, 1_000, &[0; SPACE]);
+
+        assert_eq!(
+            transfer_checked_rent(&accounts[0], &accounts[1], u64::MAX),
+            Err(ProgramError::ArithmeticOverflow)
+        );
+
+        invokes.assert_invoked(&[]);
+    }
+
+    #[test]
+    fn test_burn_lamports() {
+        let invokes = runtime();
+        let accounts = AccountViews::new(&[
+            InputAccount::new(PAYER, ID, 1_000, &[]).signer().writable(),
+            InputAccount::new(INCINERATOR_ID, ID, 0, &[]).writable(),
+        ]);
+
+        assert_eq!(burn_lamports(&accounts[0], &accounts[1], 400), Ok(()));
+
+        let seeds = [Seed::from(b"seed"), Seed::from(&[255])];
+        assert_eq!(
+            burn_lamports_signed(&accounts[0], &accounts[1], 600, &[Signer::from(&seeds)]),
+            Ok(())
+        );
+
+        let transfer = |lamports: u64| {
+            system_invocation(
+                &[
+                    InstructionAccount::writable_signer(&PAYER),
+                    InstructionAccount::writable(&INCINERATOR_ID),
+                ],
+                &[&2u32.to_le_bytes(), &lamports.to_le_bytes()],
+            )
+        };
+        invokes.assert_invoked(&[transfer(400), transfer(600).with_signer(&[b"seed", &[255]])]);
+    }
+
+    #[test]
+    fn test_burn_lamports_invalid_incinerator() {
+        let invokes = runtime();
+        let accounts = AccountViews::new(&[
+            InputAccount::new(PAYER, ID, 1_000, &[]).signer().writable(),
+            InputAccount::new(ACCOUNT, ID, 0, &[]).writable(),
+        ]);
+
+        assert_eq!(
+            burn_lamports(&accounts[0], &accounts[1], 400),
+            Err(ProgramError::InvalidArgument)
+        );
+
+        invokes.assert_invoked(&[]);
+    }
+
+    #[test]
+    fn test_with_instruction() {
+        let accounts = accounts(ID, 0, &[]);
+
+        Transfer {
+            from: &accounts[0],
+            to: &accounts[1],
+            lamports: 1_000,
+        }
+        .with_instruction(|instruction, account_views| {
+            assert_eq!(instruction.program_id, &ID);
+            assert_eq!(instruction.accounts.len(), 2);
+            assert_eq!(instruction.accounts[0].address, &PAYER);
+            assert!(instruction.accounts[0].is_signer && instruction.accounts[0].is_writable);
+            assert_eq!(instruction.accounts[1].address, &ACCOUNT);
+            assert!(!instruction.accounts[1].is_signer && instruction.accounts[1].is_writable);
+            assert_eq!(instruction.data, &[2, 0, 0, 0, 232, 3, 0, 0, 0, 0, 0, 0]);
+            assert_eq!(account_views[1].address(), &ACCOUNT);
+        });
     }
 }
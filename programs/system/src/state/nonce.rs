@@ -0,0 +1,326 @@
+use {
+    crate::ID,
+    pinocchio::{
+        account::{AccountView, Ref},
+        error::ProgramError,
+        Address,
+    },
+};
+
+/// The length of the nonce account data.
+///
+/// The data of a nonce account is composed of:
+/// - `[0..4  ]`: version
+/// - `[4..8  ]`: state
+/// - `[8..40 ]`: authority address
+/// - `[40..72]`: durable nonce
+/// - `[72..80]`: lamports per signature
+pub const NONCE_STATE_SIZE: usize = 80;
+
+/// Version of a nonce account.
+#[repr(u32)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum NonceVersion {
+    /// Legacy nonce account, with the durable nonce in the chain blockhash
+    /// domain.
+    Legacy,
+
+    /// Current nonce account version.
+    Current,
+}
+
+/// State of a nonce account.
+#[repr(u32)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum NonceState {
+    /// Nonce account is not yet initialized.
+    Uninitialized,
+
+    /// Nonce account is initialized and holds a durable nonce.
+    Initialized,
+}
+
+/// Nonce account data.
+#[repr(C)]
+pub struct NonceData {
+    /// Version of the nonce account.
+    version: [u8; 4],
+
+    /// State of the nonce account.
+    state: [u8; 4],
+
+    /// Address of the entity authorized to execute nonce instructions.
+    authority: Address,
+
+    /// Durable nonce value.
+    durable_nonce: [u8; 32],
+
+    /// Lamports per signature at the time the nonce was stored.
+    lamports_per_signature: [u8; 8],
+}
+
+impl NonceData {
+    /// The length of the `NonceData` account data.
+    pub const LEN: usize = core::mem::size_of::<NonceData>();
+
+    /// Return a `NonceData` from the given account view.
+    ///
+    /// This method performs owner, length and version validation on
+    /// `AccountView`, safe borrowing the account data.
+    #[inline]
+    pub fn from_account_view(account_view: &AccountView) -> Result<Ref<NonceData>, ProgramError> {
+        if account_view.data_len() != Self::LEN {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        if !account_view.owned_by(&ID) {
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+        let data = account_view.try_borrow()?;
+        Self::validate(&data)?;
+        Ok(Ref::map(data, |data| unsafe {
+            Self::from_bytes_unchecked(data)
+        }))
+    }
+
+    /// Return a `NonceData` from the given account view.
+    ///
+    /// This method performs owner, length and version validation on
+    /// `AccountView`, but does not perform the borrow check.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that it is safe to borrow the account data (e.g.,
+    /// there are no mutable borrows of the account data).
+    #[inline]
+    pub unsafe fn from_account_view_unchecked(
+        account_view: &AccountView,
+    ) -> Result<&Self, ProgramError> {
+        if account_view.data_len() != Self::LEN {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        if account_view.owner() != &ID {
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+        let data = account_view.borrow_unchecked();
+        Self::validate(data)?;
+        Ok(Self::from_bytes_unchecked(data))
+    }
+
+    /// Return a `NonceData` from the given bytes.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that `bytes` contains a valid representation of
+    /// `NonceData`, and it has the correct length to be interpreted as an
+    /// instance of `NonceData`.
+    #[inline(always)]
+    pub unsafe fn from_bytes_unchecked(bytes: &[u8]) -> &Self {
+        &*(bytes.as_ptr() as *const NonceData)
+    }
+
+    /// Check that the version and state values are valid.
+    #[inline(always)]
+    fn validate(bytes: &[u8]) -> Result<(), ProgramError> {
+        // SAFETY: The caller ensures that `bytes` has `LEN` bytes.
+        let (version, state) = unsafe {
+            (
+                u32::from_le_bytes(*(bytes.as_ptr() as *const [u8; 4])),
+                u32::from_le_bytes(*(bytes.as_ptr().add(4) as *const [u8; 4])),
+            )
+        };
+
+        if version > NonceVersion::Current as u32 || state > NonceState::Initialized as u32 {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        Ok(())
+    }
+
+    /// Return the version of the nonce account.
+    #[inline(always)]
+    pub fn version(&self) -> NonceVersion {
+        if u32::from_le_bytes(self.version) == NonceVersion::Legacy as u32 {
+            NonceVersion::Legacy
+        } else {
+            NonceVersion::Current
+        }
+    }
+
+    /// Return the state of the nonce account.
+    #[inline(always)]
+    pub fn state(&self) -> NonceState {
+        if u32::from_le_bytes(self.state) == NonceState::Uninitialized as u32 {
+            NonceState::Uninitialized
+        } else {
+            NonceState::Initialized
+        }
+    }
+
+    /// Check whether the nonce account is initialized or not.
+    #[inline(always)]
+    pub fn is_initialized(&self) -> bool {
+        self.state() == NonceState::Initialized
+    }
+
+    /// Return the nonce authority.
+    ///
+    /// The value is only meaningful when the nonce account is initialized.
+    #[inline(always)]
+    pub fn authority(&self) -> &Address {
+        &self.authority
+    }
+
+    /// Return the durable nonce value.
+    ///
+    /// The value is only meaningful when the nonce account is initialized.
+    #[inline(always)]
+    pub fn durable_nonce(&self) -> &[u8; 32] {
+        &self.durable_nonce
+    }
+
+    /// Return the lamports per signature (fee calculator) stored with the
+    /// durable nonce.
+    ///
+    /// The value is only meaningful when the nonce account is initialized.
+    #[inline(always)]
+    pub fn lamports_per_signature(&self) -> u64 {
+        u64::from_le_bytes(self.lamports_per_signature)
+    }
+}
+
+/// Return the durable nonce and authority of a nonce account.
+///
+/// This function performs the same validation as
+/// [`NonceData::from_account_view`] and additionally checks that the nonce
+/// account is initialized, returning [`ProgramError::InvalidAccountData`]
+/// otherwise.
+///
+/// Programs co-signing durable transactions can compare the returned values
+/// with the expected nonce and authority to check their freshness.
+#[inline]
+pub fn durable_nonce_and_authority(
+    account_view: &AccountView,
+) -> Result<([u8; 32], Address), ProgramError> {
+    let nonce = NonceData::from_account_view(account_view)?;
+
+    if !nonce.is_initialized() {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    Ok((
+        *nonce.durable_nonce(),
+        Address::new_from_array(nonce.authority().to_bytes()),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use {
+        super::*,
+        core::mem::align_of,
+        pinocchio::entrypoint::input::{AccountViews, InputAccount},
+        std::vec::Vec,
+    };
+
+    const AUTHORITY: Address = Address::new_from_array([5; 32]);
+
+    const DURABLE_NONCE: [u8; 32] = [6; 32];
+
+    /// Serialize the data of a nonce account.
+    fn nonce_data(version: u32, state: u32) -> Vec<u8> {
+        let mut data = Vec::with_capacity(NONCE_STATE_SIZE);
+        data.extend_from_slice(&version.to_le_bytes());
+        data.extend_from_slice(&state.to_le_bytes());
+        data.extend_from_slice(AUTHORITY.as_ref());
+        data.extend_from_slice(&DURABLE_NONCE);
+        data.extend_from_slice(&5_000u64.to_le_bytes());
+        data
+    }
+
+    fn nonce_account(owner: Address, data: &[u8]) -> AccountViews {
+        AccountViews::new(&[InputAccount::new(
+            Address::new_from_array([1; 32]),
+            owner,
+            1_000_000,
+            data,
+        )])
+    }
+
+    #[test]
+    fn test_layout() {
+        assert_eq!(NonceData::LEN, NONCE_STATE_SIZE);
+        assert_eq!(align_of::<NonceData>(), 1);
+    }
+
+    #[test]
+    fn test_from_account_view() {
+        let accounts = nonce_account(ID, &nonce_data(1, 1));
+        let nonce = NonceData::from_account_view(&accounts[0]).unwrap();
+
+        assert_eq!(nonce.version(), NonceVersion::Current);
+        assert_eq!(nonce.state(), NonceState::Initialized);
+        assert!(nonce.is_initialized());
+        assert_eq!(nonce.authority(), &AUTHORITY);
+        assert_eq!(nonce.durable_nonce(), &DURABLE_NONCE);
+        assert_eq!(nonce.lamports_per_signature(), 5_000);
+    }
+
+    #[test]
+    fn test_version_and_state() {
+        let accounts = nonce_account(ID, &nonce_data(0, 0));
+        let nonce = NonceData::from_account_view(&accounts[0]).unwrap();
+
+        assert_eq!(nonce.version(), NonceVersion::Legacy);
+        assert_eq!(nonce.state(), NonceState::Uninitialized);
+        assert!(!nonce.is_initialized());
+    }
+
+    #[test]
+    fn test_from_account_view_invalid() {
+        for data in [
+            nonce_data(2, 1),
+            nonce_data(1, 2),
+            nonce_data(1, 1)[..79].to_vec(),
+        ] {
+            let accounts = nonce_account(ID, &data);
+            assert!(matches!(
+                NonceData::from_account_view(&accounts[0]),
+                Err(ProgramError::InvalidAccountData)
+            ));
+        }
+
+        let accounts = nonce_account(Address::new_from_array([2; 32]), &nonce_data(1, 1));
+        assert!(matches!(
+            NonceData::from_account_view(&accounts[0]),
+            Err(ProgramError::InvalidAccountOwner)
+        ));
+    }
+
+    #[test]
+    fn test_from_account_view_borrowed() {
+        let accounts = nonce_account(ID, &nonce_data(1, 1));
+        let _data = accounts[0].try_borrow_mut().unwrap();
+
+        assert!(matches!(
+            NonceData::from_account_view(&accounts[0]),
+            Err(ProgramError::AccountBorrowFailed)
+        ));
+    }
+
+    #[test]
+    fn test_durable_nonce_and_authority() {
+        let accounts = nonce_account(ID, &nonce_data(1, 1));
+        assert_eq!(
+            durable_nonce_and_authority(&accounts[0]),
+            Ok((DURABLE_NONCE, AUTHORITY))
+        );
+
+        let accounts = nonce_account(ID, &nonce_data(1, 0));
+        assert_eq!(
+            durable_nonce_and_authority(&accounts[0]),
+            Err(ProgramError::InvalidAccountData)
+        );
+    }
+}
@@ -0,0 +1,95 @@
+//! Cross-program invocation helpers for non-Solana targets.
+//!
+//! This module re-exports [`solana_instruction_view::cpi`], replacing the
+//! `invoke*` functions used by the instruction builders with stubs that
+//! return [`ProgramError::InvalidArgument`], since there is no runtime to
+//! invoke outside of Solana. The instructions can still be encoded with the
+//! `with_instruction` method of the builders.
+
+pub use solana_instruction_view::cpi::*;
+use {
+    core::hint::black_box,
+    solana_account_view::AccountView,
+    solana_instruction_view::InstructionView,
+    solana_program_error::{ProgramError, ProgramResult},
+};
+
+#[inline(always)]
+pub fn invoke<const ACCOUNTS: usize>(
+    instruction: &InstructionView,
+    account_views: &[&AccountView; ACCOUNTS],
+) -> ProgramResult {
+    unsupported(instruction, account_views, &[])
+}
+
+#[inline(always)]
+pub fn invoke_signed<const ACCOUNTS: usize>(
+    instruction: &InstructionView,
+    account_views: &[&AccountView; ACCOUNTS],
+    signers_seeds: &[Signer],
+) -> ProgramResult {
+    unsupported(instruction, account_views, signers_seeds)
+}
+
+#[inline(always)]
+pub fn invoke_with_bounds<const MAX_ACCOUNTS: usize>(
+    instruction: &InstructionView,
+    account_views: &[&AccountView],
+) -> ProgramResult {
+    unsupported(instruction, account_views, &[])
+}
+
+#[inline(always)]
+pub fn invoke_signed_with_bounds<const MAX_ACCOUNTS: usize>(
+    instruction: &InstructionView,
+    account_views: &[&AccountView],
+    signers_seeds: &[Signer],
+) -> ProgramResult {
+    unsupported(instruction, account_views, signers_seeds)
+}
+
+#[inline(always)]
+fn unsupported(
+    instruction: &InstructionView,
+    account_views: &[&AccountView],
+    signers_seeds: &[Signer],
+) -> ProgramResult {
+    black_box((instruction, account_views, signers_seeds));
+    Err(ProgramError::InvalidArgument)
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::{instructions::Transfer, ID},
+        pinocchio::{
+            entrypoint::input::{AccountViews, InputAccount},
+            Address,
+        },
+    };
+
+    #[test]
+    fn test_invoke_is_unsupported() {
+        let accounts = AccountViews::new(&[
+            InputAccount::new(Address::new_from_array([1; 32]), ID, 0, &[]).writable(),
+            InputAccount::new(Address::new_from_array([2; 32]), ID, 0, &[]).writable(),
+            InputAccount::new(Address::new_from_array([3; 32]), Address::default(), 0, &[])
+                .signer(),
+        ]);
+
+        let transfer = Transfer {
+            from: &accounts[0],
+            to: &accounts[1],
+            authority: &accounts[2],
+            amount: 10,
+            token_program: &ID,
+        };
+
+        assert_eq!(transfer.invoke(), Err(ProgramError::InvalidArgument));
+        assert_eq!(
+            transfer.with_instruction(|instruction, _| instruction.data.len()),
+            9
+        );
+    }
+}
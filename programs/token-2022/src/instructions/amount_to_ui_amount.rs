@@ -0,0 +1,102 @@
+use {
+    crate::{
+        cpi::{get_return_data, invoke, ReturnData},
+        write_bytes, UNINIT_BYTE,
+    },
+    core::{slice::from_raw_parts, str::from_utf8_unchecked},
+    solana_account_view::AccountView,
+    solana_address::Address,
+    solana_instruction_view::{InstructionAccount, InstructionView},
+    solana_program_error::{ProgramError, ProgramResult},
+};
+
+/// Convert an amount of tokens to a UI amount string, using the given mint.
+///
+/// For mints with the interest-bearing or scaled UI amount extensions, the
+/// UI amount includes the interest or multiplier. The UI amount is set as
+/// return data and can be retrieved with [`AmountToUiAmount::invoke_and_get`].
+///
+/// Accounts expected by this instruction:
+///
+///   0. `[]` The mint to calculate for.
+pub struct AmountToUiAmount<'a, 'b> {
+    /// The mint to calculate for.
+    pub mint: &'a AccountView,
+
+    /// The amount of tokens to convert.
+    pub amount: u64,
+
+    /// The token program.
+    pub token_program: &'b Address,
+}
+
+impl AmountToUiAmount<'_, '_> {
+    pub const DISCRIMINATOR: u8 = 23;
+
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.with_instruction(invoke)
+    }
+
+    #[inline(always)]
+    pub fn with_instruction<R>(
+        &self,
+        f: impl FnOnce(&InstructionView, &[&AccountView; 1]) -> R,
+    ) -> R {
+        // Instruction data.
+
+        let mut instruction_data = [UNINIT_BYTE; 9];
+
+        instruction_data[0].write(Self::DISCRIMINATOR);
+
+        write_bytes(&mut instruction_data[1..9], &self.amount.to_le_bytes());
+
+        f(
+            &InstructionView {
+                program_id: self.token_program,
+                accounts: &[InstructionAccount::readonly(self.mint.address())],
+                // SAFETY: `instruction_data` is initialized.
+                data: unsafe {
+                    from_raw_parts(instruction_data.as_ptr() as _, instruction_data.len())
+                },
+            },
+            &[self.mint],
+        )
+    }
+
+    /// Invoke the instruction and return the UI amount set as return data.
+    ///
+    /// Returns [`ProgramError::InvalidAccountData`] if the return data is
+    /// missing, was not set by the token program or is not a valid UTF-8
+    /// string.
+    #[inline(always)]
+    pub fn invoke_and_get(&self) -> Result<UiAmount, ProgramError> {
+        self.invoke()?;
+
+        let return_data = get_return_data()
+            .filter(|return_data| return_data.program_id() == self.token_program)
+            .ok_or(ProgramError::InvalidAccountData)?;
+
+        if core::str::from_utf8(return_data.as_slice()).is_err() {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        Ok(UiAmount { return_data })
+    }
+}
+
+/// UI amount string returned by the [`AmountToUiAmount`] instruction.
+pub struct UiAmount {
+    /// Return data holding a valid UTF-8 string.
+    return_data: ReturnData,
+}
+
+impl UiAmount {
+    /// Return the UI amount as a string.
+    #[inline(always)]
+    pub fn as_str(&self) -> &str {
+        // SAFETY: The return data was validated as UTF-8 when `UiAmount` was
+        // created.
+        unsafe { from_utf8_unchecked(self.return_data.as_slice()) }
+    }
+}
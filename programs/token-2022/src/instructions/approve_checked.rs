@@ -1,12 +1,12 @@
 use {
-    crate::{write_bytes, UNINIT_BYTE},
+    crate::{
+        cpi::{invoke_signed, Signer},
+        write_bytes, UNINIT_BYTE,
+    },
     core::slice::from_raw_parts,
     solana_account_view::AccountView,
     solana_address::Address,
-    solana_instruction_view::{
-        cpi::{invoke_signed, Signer},
-        InstructionAccount, InstructionView,
-    },
+    solana_instruction_view::{InstructionAccount, InstructionView},
     solana_program_error::ProgramResult,
 };
 
@@ -42,6 +42,16 @@ impl ApproveChecked<'_, '_> {
 
     #[inline(always)]
     pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        self.with_instruction(|instruction, account_views| {
+            invoke_signed(instruction, account_views, signers)
+        })
+    }
+
+    #[inline(always)]
+    pub fn with_instruction<R>(
+        &self,
+        f: impl FnOnce(&InstructionView, &[&AccountView; 4]) -> R,
+    ) -> R {
         // Instruction accounts
         let instruction_accounts: [InstructionAccount; 4] = [
             InstructionAccount::writable(self.source.address()),
@@ -69,10 +79,9 @@ impl ApproveChecked<'_, '_> {
             data: unsafe { from_raw_parts(instruction_data.as_ptr() as _, 10) },
         };
 
-        invoke_signed(
+        f(
             &instruction,
             &[self.source, self.mint, self.delegate, self.authority],
-            signers,
         )
     }
 }
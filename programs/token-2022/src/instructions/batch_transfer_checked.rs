@@ -0,0 +1,95 @@
+use {
+    crate::{
+        cpi::{invoke_signed, Signer},
+        write_bytes, UNINIT_BYTE,
+    },
+    core::slice::from_raw_parts,
+    solana_account_view::AccountView,
+    solana_address::Address,
+    solana_instruction_view::{InstructionAccount, InstructionView},
+    solana_program_error::ProgramResult,
+};
+
+/// Transfer tokens from one token account to many others.
+///
+/// This performs one [`TransferChecked`](super::TransferChecked) CPI per
+/// destination. The instruction accounts and data are built once and only
+/// the destination and amount are updated between CPIs.
+///
+/// Transfers are performed in order and stop at the first failure. Since it
+/// performs several CPIs, this builder has no `with_instruction`; use
+/// [`TransferChecked`](super::TransferChecked) to encode each transfer.
+///
+/// ### Accounts (per CPI):
+///   0. `[WRITE]` The source account.
+///   1. `[]` The token mint.
+///   2. `[WRITE]` The destination account.
+///   3. `[SIGNER]` The source account's owner/delegate.
+pub struct BatchTransferChecked<'a, 'b, 'c> {
+    /// Sender account.
+    pub from: &'a AccountView,
+    /// Mint Account
+    pub mint: &'a AccountView,
+    /// Recipient accounts and the amount of micro-tokens each receives.
+    pub transfers: &'c [(&'a AccountView, u64)],
+    /// Authority account.
+    pub authority: &'a AccountView,
+    /// Decimal for the Token
+    pub decimals: u8,
+    /// Token Program
+    pub token_program: &'b Address,
+}
+
+impl BatchTransferChecked<'_, '_, '_> {
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    #[inline]
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        let Some((first, _)) = self.transfers.first() else {
+            return Ok(());
+        };
+
+        // Instruction accounts
+        let mut instruction_accounts: [InstructionAccount; 4] = [
+            InstructionAccount::writable(self.from.address()),
+            InstructionAccount::readonly(self.mint.address()),
+            InstructionAccount::writable(first.address()),
+            InstructionAccount::readonly_signer(self.authority.address()),
+        ];
+
+        // Accounts
+        let mut accounts = [self.from, self.mint, first, self.authority];
+
+        // Instruction data layout:
+        // - [0]: instruction discriminator (1 byte, u8)
+        // - [1..9]: amount (8 bytes, u64)
+        // - [9]: decimals (1 byte, u8)
+        let mut instruction_data = [UNINIT_BYTE; 10];
+
+        // Set discriminator as u8 at offset [0]
+        write_bytes(&mut instruction_data, &[12]);
+        // Set decimals as u8 at offset [9]
+        write_bytes(&mut instruction_data[9..], &[self.decimals]);
+
+        for (to, amount) in self.transfers {
+            instruction_accounts[2].address = to.address();
+            accounts[2] = to;
+            // Set amount as u64 at offset [1..9]
+            write_bytes(&mut instruction_data[1..9], &amount.to_le_bytes());
+
+            let instruction = InstructionView {
+                program_id: self.token_program,
+                accounts: &instruction_accounts,
+                // SAFETY: instruction data is fully initialized.
+                data: unsafe { from_raw_parts(instruction_data.as_ptr() as _, 10) },
+            };
+
+            invoke_signed(&instruction, &accounts, signers)?;
+        }
+
+        Ok(())
+    }
+}
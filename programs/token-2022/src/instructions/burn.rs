@@ -1,12 +1,12 @@
 use {
-    crate::{write_bytes, UNINIT_BYTE},
+    crate::{
+        cpi::{invoke_signed, Signer},
+        write_bytes, UNINIT_BYTE,
+    },
     core::slice::from_raw_parts,
     solana_account_view::AccountView,
     solana_address::Address,
-    solana_instruction_view::{
-        cpi::{invoke_signed, Signer},
-        InstructionAccount, InstructionView,
-    },
+    solana_instruction_view::{InstructionAccount, InstructionView},
     solana_program_error::ProgramResult,
 };
 
@@ -37,6 +37,16 @@ impl Burn<'_, '_> {
 
     #[inline(always)]
     pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        self.with_instruction(|instruction, account_views| {
+            invoke_signed(instruction, account_views, signers)
+        })
+    }
+
+    #[inline(always)]
+    pub fn with_instruction<R>(
+        &self,
+        f: impl FnOnce(&InstructionView, &[&AccountView; 3]) -> R,
+    ) -> R {
         // Instruction accounts
         let instruction_accounts: [InstructionAccount; 3] = [
             InstructionAccount::writable(self.account.address()),
@@ -60,10 +70,6 @@ impl Burn<'_, '_> {
             data: unsafe { from_raw_parts(instruction_data.as_ptr() as _, 9) },
         };
 
-        invoke_signed(
-            &instruction,
-            &[self.account, self.mint, self.authority],
-            signers,
-        )
+        f(&instruction, &[self.account, self.mint, self.authority])
     }
 }
@@ -1,10 +1,8 @@
 use {
+    crate::cpi::{invoke_signed, Signer},
     solana_account_view::AccountView,
     solana_address::Address,
-    solana_instruction_view::{
-        cpi::{invoke_signed, Signer},
-        InstructionAccount, InstructionView,
-    },
+    solana_instruction_view::{InstructionAccount, InstructionView},
     solana_program_error::ProgramResult,
 };
 
@@ -33,6 +31,16 @@ impl CloseAccount<'_, '_> {
 
     #[inline(always)]
     pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        self.with_instruction(|instruction, account_views| {
+            invoke_signed(instruction, account_views, signers)
+        })
+    }
+
+    #[inline(always)]
+    pub fn with_instruction<R>(
+        &self,
+        f: impl FnOnce(&InstructionView, &[&AccountView; 3]) -> R,
+    ) -> R {
         // Instruction accounts
         let instruction_accounts: [InstructionAccount; 3] = [
             InstructionAccount::writable(self.account.address()),
@@ -46,10 +54,9 @@ impl CloseAccount<'_, '_> {
             data: &[9],
         };
 
-        invoke_signed(
+        f(
             &instruction,
             &[self.account, self.destination, self.authority],
-            signers,
         )
     }
 }
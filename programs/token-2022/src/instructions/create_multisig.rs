@@ -0,0 +1,104 @@
+use {
+    super::InitializeMultisig2,
+    crate::{
+        cpi::{invoke_signed, Signer},
+        state::Multisig,
+        write_bytes, UNINIT_BYTE,
+    },
+    core::slice::from_raw_parts,
+    pinocchio::sysvars::{rent::Rent, Sysvar},
+    solana_account_view::AccountView,
+    solana_address::Address,
+    solana_instruction_view::{InstructionAccount, InstructionView},
+    solana_program_error::ProgramResult,
+};
+
+/// Address of the System program.
+const SYSTEM_PROGRAM_ID: Address = Address::new_from_array([0; 32]);
+
+/// Create and initialize a new Multisig.
+///
+/// This creates the multisig account through the system program, funded
+/// with the rent-exempt minimum for [`Multisig::LEN`] bytes, and then
+/// invokes [`InitializeMultisig2`].
+///
+/// The signer seeds passed to `invoke_signed` are used for the account
+/// creation, so the payer and the multisig account can be PDAs.
+///
+/// Since it performs two CPIs, this builder has no `with_instruction`; use
+/// the system program `CreateAccount` and [`InitializeMultisig2`] builders
+/// to encode the instructions separately.
+///
+/// ### Accounts:
+///   0. `[WRITE, SIGNER]` The funding account.
+///   1. `[WRITE, SIGNER]` The multisig account to create.
+///   2. `..+N` `[]` The `N` signer accounts, where `N` is `1 <= N <= 11`.
+pub struct CreateMultisig<'a, 'b, 'c>
+where
+    'a: 'b,
+{
+    /// Funding Account.
+    pub payer: &'a AccountView,
+    /// Multisig Account.
+    pub multisig: &'a AccountView,
+    /// Signer Accounts
+    pub multisig_signers: &'b [&'a AccountView],
+    /// The number of signers (M) required to validate this multisignature
+    /// account.
+    pub m: u8,
+    /// Token Program.
+    pub token_program: &'c Address,
+}
+
+impl CreateMultisig<'_, '_, '_> {
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    #[inline(always)]
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        let lamports = Rent::get()?.try_minimum_balance(Multisig::LEN)?;
+
+        // System program `CreateAccount` instruction accounts
+        let instruction_accounts: [InstructionAccount; 2] = [
+            InstructionAccount::writable_signer(self.payer.address()),
+            InstructionAccount::writable_signer(self.multisig.address()),
+        ];
+
+        // System program `CreateAccount` instruction data
+        // - [0..4  ]: instruction discriminator (4 bytes, u32)
+        // - [4..12 ]: lamports (8 bytes, u64)
+        // - [12..20]: account space (8 bytes, u64)
+        // - [20..52]: owner address (32 bytes, Address)
+        let mut instruction_data = [UNINIT_BYTE; 52];
+
+        // Set discriminator as u32 at offset [0..4]
+        write_bytes(&mut instruction_data, &0u32.to_le_bytes());
+        // Set lamports as u64 at offset [4..12]
+        write_bytes(&mut instruction_data[4..12], &lamports.to_le_bytes());
+        // Set space as u64 at offset [12..20]
+        write_bytes(
+            &mut instruction_data[12..20],
+            &(Multisig::LEN as u64).to_le_bytes(),
+        );
+        // Set owner as Address at offset [20..52]
+        write_bytes(&mut instruction_data[20..52], self.token_program.as_ref());
+
+        let instruction = InstructionView {
+            program_id: &SYSTEM_PROGRAM_ID,
+            accounts: &instruction_accounts,
+            data: unsafe { from_raw_parts(instruction_data.as_ptr() as _, 52) },
+        };
+
+        invoke_signed(&instruction, &[self.payer, self.multisig], signers)?;
+
+        InitializeMultisig2 {
+            multisig: self.multisig,
+            signers: self.multisig_signers,
+            m: self.m,
+            token_program: self.token_program,
+        }
+        .invoke()
+    }
+}
@@ -1,10 +1,8 @@
 use {
+    crate::cpi::{invoke_signed, Signer},
     solana_account_view::AccountView,
     solana_address::Address,
-    solana_instruction_view::{
-        cpi::{invoke_signed, Signer},
-        InstructionAccount, InstructionView,
-    },
+    solana_instruction_view::{InstructionAccount, InstructionView},
     solana_program_error::ProgramResult,
 };
 
@@ -19,6 +17,30 @@ use {
 ///   0. `[writable,signer]` Funding account (must be a system account).
 ///   1. `[writable]` The native mint account to create.
 ///   2. `[]` System program for mint account funding.
+///
+/// The native mint account must be the [`crate::native_mint::ID`] address,
+/// which is a PDA of the token program and therefore does not need to sign.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use {solana_account_view::AccountView, solana_program_error::ProgramResult};
+/// use pinocchio_token_2022::{instructions::CreateNativeMint, ID};
+///
+/// fn create_native_mint(
+///     payer: &AccountView,
+///     native_mint: &AccountView,
+///     system_program: &AccountView,
+/// ) -> ProgramResult {
+///     CreateNativeMint {
+///         payer,
+///         native_mint,
+///         system_program,
+///         token_program: &ID,
+///     }
+///     .invoke()
+/// }
+/// ```
 pub struct CreateNativeMint<'a, 'b> {
     /// Funding account (must be a system account).
     pub payer: &'a AccountView,
@@ -43,7 +65,17 @@ impl CreateNativeMint<'_, '_> {
 
     #[inline(always)]
     pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
-        invoke_signed(
+        self.with_instruction(|instruction, account_views| {
+            invoke_signed(instruction, account_views, signers)
+        })
+    }
+
+    #[inline(always)]
+    pub fn with_instruction<R>(
+        &self,
+        f: impl FnOnce(&InstructionView, &[&AccountView; 3]) -> R,
+    ) -> R {
+        f(
             &InstructionView {
                 program_id: self.token_program,
                 accounts: &[
@@ -54,7 +86,6 @@ impl CreateNativeMint<'_, '_> {
                 data: &[Self::DISCRIMINATOR],
             },
             &[self.payer, self.native_mint, self.system_program],
-            signers,
         )
     }
 }
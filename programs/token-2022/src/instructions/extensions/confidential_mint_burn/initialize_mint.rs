@@ -0,0 +1,73 @@
+use {
+    crate::{cpi::invoke, instructions::ExtensionDiscriminator, write_bytes, UNINIT_BYTE},
+    core::slice::from_raw_parts,
+    solana_account_view::AccountView,
+    solana_address::Address,
+    solana_instruction_view::{InstructionAccount, InstructionView},
+    solana_program_error::ProgramResult,
+};
+
+/// Initialize confidential mint and burn for a mint.
+///
+/// The `ConfidentialMintBurnInstruction::InitializeMint` instruction
+/// requires no signers and MUST be included within the same Transaction as
+/// `TokenInstruction::InitializeMint`. Otherwise another party can
+/// initialize the configuration.
+///
+/// The mint must also have the `ConfidentialTransferMint` extension.
+///
+/// Accounts expected by this instruction:
+///
+///   0. `[writable]` The mint to initialize.
+pub struct InitializeMint<'a, 'b> {
+    /// The mint to initialize.
+    pub mint: &'a AccountView,
+
+    /// The ElGamal public key used to encrypt the confidential supply.
+    pub supply_elgamal_pubkey: &'b [u8; 32],
+
+    /// The initial confidential supply, encrypted with the supply AES key.
+    pub decryptable_supply: &'b [u8; 36],
+
+    /// The token program.
+    pub token_program: &'b Address,
+}
+
+impl InitializeMint<'_, '_> {
+    pub const DISCRIMINATOR: u8 = 0;
+
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.with_instruction(invoke)
+    }
+
+    #[inline(always)]
+    pub fn with_instruction<R>(
+        &self,
+        f: impl FnOnce(&InstructionView, &[&AccountView; 1]) -> R,
+    ) -> R {
+        // Instruction data.
+
+        let mut instruction_data = [UNINIT_BYTE; 70];
+
+        instruction_data[0].write(ExtensionDiscriminator::ConfidentialMintBurn as u8);
+
+        instruction_data[1].write(Self::DISCRIMINATOR);
+
+        write_bytes(&mut instruction_data[2..34], self.supply_elgamal_pubkey);
+
+        write_bytes(&mut instruction_data[34..70], self.decryptable_supply);
+
+        f(
+            &InstructionView {
+                program_id: self.token_program,
+                accounts: &[InstructionAccount::writable(self.mint.address())],
+                // SAFETY: `instruction_data` is initialized.
+                data: unsafe {
+                    from_raw_parts(instruction_data.as_ptr() as _, instruction_data.len())
+                },
+            },
+            &[self.mint],
+        )
+    }
+}
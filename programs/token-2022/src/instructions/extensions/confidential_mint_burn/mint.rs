@@ -0,0 +1,210 @@
+use {
+    crate::{
+        cpi::{invoke_signed_with_bounds, Signer},
+        instructions::{extensions::ExtensionDiscriminator, MAX_MULTISIG_SIGNERS},
+        write_bytes, UNINIT_BYTE,
+    },
+    core::{mem::MaybeUninit, slice::from_raw_parts},
+    solana_account_view::AccountView,
+    solana_address::Address,
+    solana_instruction_view::{InstructionAccount, InstructionView},
+    solana_program_error::{ProgramError, ProgramResult},
+};
+
+/// Mint tokens confidentially to a token account.
+///
+/// The mint amount is proven with `VerifyCiphertextCommitmentEquality`,
+/// `VerifyBatchedGroupedCiphertext3HandlesValidity` and
+/// `VerifyBatchedRangeProofU128` proofs, each either included in the same
+/// transaction or pre-verified into a context state account.
+///
+/// Accounts expected by this instruction:
+///
+///   * Single authority
+///   0. `[writable]` The token account.
+///   1. `[writable]` The mint.
+///   2. `[]` (Optional) Instructions sysvar if at least one of the proofs is
+///      included in the same transaction.
+///   3. `[]` (Optional) Equality proof context state account.
+///   4. `[]` (Optional) Ciphertext validity proof context state account.
+///   5. `[]` (Optional) Range proof context state account.
+///   6. `[signer]` The mint authority.
+///
+///   * Multisignature authority
+///   0. `[writable]` The token account.
+///   1. `[writable]` The mint.
+///   2. `[]` (Optional) Instructions sysvar.
+///   3. `[]` (Optional) Equality proof context state account.
+///   4. `[]` (Optional) Ciphertext validity proof context state account.
+///   5. `[]` (Optional) Range proof context state account.
+///   6. `[]` The multisig mint authority.
+///   7. `..7+M` `[signer]` M signer accounts.
+pub struct Mint<'a, 'b, 'c> {
+    /// The token account.
+    pub account: &'a AccountView,
+
+    /// The mint.
+    pub mint: &'a AccountView,
+
+    /// Instructions sysvar, required if any proof offset is non-zero.
+    pub instructions_sysvar: Option<&'a AccountView>,
+
+    /// Equality proof context state account, if the proof offset is `0`.
+    pub equality_proof_context: Option<&'a AccountView>,
+
+    /// Ciphertext validity proof context state account, if the proof offset
+    /// is `0`.
+    pub ciphertext_validity_proof_context: Option<&'a AccountView>,
+
+    /// Range proof context state account, if the proof offset is `0`.
+    pub range_proof_context: Option<&'a AccountView>,
+
+    /// The mint authority.
+    pub authority: &'a AccountView,
+
+    /// The signer accounts if `authority` is a multisig.
+    pub multisig_signers: &'c [&'a AccountView],
+
+    /// The new decryptable supply if the mint succeeds.
+    pub new_decryptable_supply: &'b [u8; 36],
+
+    /// The low 16 bits of the mint amount encrypted under the auditor
+    /// ElGamal public key.
+    pub mint_amount_auditor_ciphertext_lo: &'b [u8; 64],
+
+    /// The high 48 bits of the mint amount encrypted under the auditor
+    /// ElGamal public key.
+    pub mint_amount_auditor_ciphertext_hi: &'b [u8; 64],
+
+    /// Relative location of the equality proof instruction, or `0` to use a
+    /// context state account.
+    pub equality_proof_instruction_offset: i8,
+
+    /// Relative location of the ciphertext validity proof instruction, or
+    /// `0` to use a context state account.
+    pub ciphertext_validity_proof_instruction_offset: i8,
+
+    /// Relative location of the range proof instruction, or `0` to use a
+    /// context state account.
+    pub range_proof_instruction_offset: i8,
+
+    /// The token program.
+    pub token_program: &'b Address,
+}
+
+impl Mint<'_, '_, '_> {
+    pub const DISCRIMINATOR: u8 = 3;
+
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    #[inline(always)]
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        self.with_instruction(|instruction, account_views| {
+            invoke_signed_with_bounds::<{ 7 + MAX_MULTISIG_SIGNERS }>(
+                instruction,
+                account_views,
+                signers,
+            )
+        })?
+    }
+
+    #[inline(always)]
+    pub fn with_instruction<R>(
+        &self,
+        f: impl FnOnce(&InstructionView, &[&AccountView]) -> R,
+    ) -> Result<R, ProgramError> {
+        if self.multisig_signers.len() > MAX_MULTISIG_SIGNERS {
+            Err(ProgramError::InvalidArgument)?;
+        }
+
+        // Instruction accounts and accounts.
+
+        let mut instruction_accounts =
+            [const { MaybeUninit::<InstructionAccount>::uninit() }; 7 + MAX_MULTISIG_SIGNERS];
+
+        let mut accounts =
+            [const { MaybeUninit::<&AccountView>::uninit() }; 7 + MAX_MULTISIG_SIGNERS];
+
+        instruction_accounts[0].write(InstructionAccount::writable(self.account.address()));
+        accounts[0].write(self.account);
+
+        instruction_accounts[1].write(InstructionAccount::writable(self.mint.address()));
+        accounts[1].write(self.mint);
+
+        let mut expected_accounts = 2;
+
+        for proof_account in [
+            self.instructions_sysvar,
+            self.equality_proof_context,
+            self.ciphertext_validity_proof_context,
+            self.range_proof_context,
+        ]
+        .into_iter()
+        .flatten()
+        {
+            instruction_accounts[expected_accounts]
+                .write(InstructionAccount::readonly(proof_account.address()));
+            accounts[expected_accounts].write(proof_account);
+            expected_accounts += 1;
+        }
+
+        instruction_accounts[expected_accounts].write(InstructionAccount::new(
+            self.authority.address(),
+            false,
+            self.multisig_signers.is_empty(),
+        ));
+        accounts[expected_accounts].write(self.authority);
+        expected_accounts += 1;
+
+        for signer in self.multisig_signers.iter() {
+            instruction_accounts[expected_accounts]
+                .write(InstructionAccount::readonly_signer(signer.address()));
+            accounts[expected_accounts].write(signer);
+            expected_accounts += 1;
+        }
+
+        // Instruction data.
+
+        let mut instruction_data = [UNINIT_BYTE; 169];
+
+        instruction_data[0].write(ExtensionDiscriminator::ConfidentialMintBurn as u8);
+
+        instruction_data[1].write(Self::DISCRIMINATOR);
+
+        write_bytes(&mut instruction_data[2..38], self.new_decryptable_supply);
+
+        write_bytes(
+            &mut instruction_data[38..102],
+            self.mint_amount_auditor_ciphertext_lo,
+        );
+
+        write_bytes(
+            &mut instruction_data[102..166],
+            self.mint_amount_auditor_ciphertext_hi,
+        );
+
+        instruction_data[166].write(self.equality_proof_instruction_offset as u8);
+
+        instruction_data[167].write(self.ciphertext_validity_proof_instruction_offset as u8);
+
+        instruction_data[168].write(self.range_proof_instruction_offset as u8);
+
+        Ok(f(
+            &InstructionView {
+                program_id: self.token_program,
+                // SAFETY: instruction accounts has `expected_accounts` initialized.
+                accounts: unsafe {
+                    from_raw_parts(instruction_accounts.as_ptr() as _, expected_accounts)
+                },
+                // SAFETY: `instruction_data` is initialized.
+                data: unsafe {
+                    from_raw_parts(instruction_data.as_ptr() as _, instruction_data.len())
+                },
+            }, // SAFETY: accounts has `expected_accounts` initialized.
+            unsafe { from_raw_parts(accounts.as_ptr() as _, expected_accounts) },
+        ))
+    }
+}
@@ -0,0 +1,10 @@
+mod burn;
+mod initialize_mint;
+mod mint;
+mod rotate_supply_elgamal_pubkey;
+mod update_decryptable_supply;
+
+pub use {
+    burn::*, initialize_mint::*, mint::*, rotate_supply_elgamal_pubkey::*,
+    update_decryptable_supply::*,
+};
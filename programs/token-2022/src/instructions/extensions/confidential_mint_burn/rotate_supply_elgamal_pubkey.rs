@@ -0,0 +1,153 @@
+use {
+    crate::{
+        cpi::{invoke_signed_with_bounds, Signer},
+        instructions::{extensions::ExtensionDiscriminator, MAX_MULTISIG_SIGNERS},
+        write_bytes, UNINIT_BYTE,
+    },
+    core::{mem::MaybeUninit, slice::from_raw_parts},
+    solana_account_view::AccountView,
+    solana_address::Address,
+    solana_instruction_view::{InstructionAccount, InstructionView},
+    solana_program_error::{ProgramError, ProgramResult},
+};
+
+/// Rotate the ElGamal public key used to encrypt the confidential supply.
+///
+/// The new supply must be proven equal to the current one with a
+/// `VerifyCiphertextCiphertextEquality` proof.
+///
+/// Accounts expected by this instruction:
+///
+///   * Single authority
+///   0. `[writable]` The mint.
+///   1. `[]` Instructions sysvar if `VerifyCiphertextCiphertextEquality` is
+///      included in the same transaction or context state account if
+///      `VerifyCiphertextCiphertextEquality` is pre-verified into a context
+///      state account.
+///   2. `[signer]` The mint authority.
+///
+///   * Multisignature authority
+///   0. `[writable]` The mint.
+///   1. `[]` Instructions sysvar or context state account.
+///   2. `[]` The multisig mint authority.
+///   3. `..3+M` `[signer]` M signer accounts.
+pub struct RotateSupplyElGamalPubkey<'a, 'b, 'c> {
+    /// The mint.
+    pub mint: &'a AccountView,
+
+    /// Instructions sysvar or context state account.
+    pub proof_account: &'a AccountView,
+
+    /// The mint authority.
+    pub authority: &'a AccountView,
+
+    /// The signer accounts if `authority` is a multisig.
+    pub multisig_signers: &'c [&'a AccountView],
+
+    /// The new ElGamal public key for supply encryption.
+    pub new_supply_elgamal_pubkey: &'b [u8; 32],
+
+    /// Relative location of the `VerifyCiphertextCiphertextEquality`
+    /// instruction to the current instruction in the transaction. If the
+    /// offset is `0`, then use a context state account for the proof.
+    pub proof_instruction_offset: i8,
+
+    /// The token program.
+    pub token_program: &'b Address,
+}
+
+impl RotateSupplyElGamalPubkey<'_, '_, '_> {
+    pub const DISCRIMINATOR: u8 = 1;
+
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    #[inline(always)]
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        self.with_instruction(|instruction, account_views| {
+            invoke_signed_with_bounds::<{ 3 + MAX_MULTISIG_SIGNERS }>(
+                instruction,
+                account_views,
+                signers,
+            )
+        })?
+    }
+
+    #[inline(always)]
+    pub fn with_instruction<R>(
+        &self,
+        f: impl FnOnce(&InstructionView, &[&AccountView]) -> R,
+    ) -> Result<R, ProgramError> {
+        if self.multisig_signers.len() > MAX_MULTISIG_SIGNERS {
+            Err(ProgramError::InvalidArgument)?;
+        }
+
+        let expected_accounts = 3 + self.multisig_signers.len();
+
+        // Instruction accounts.
+
+        let mut instruction_accounts =
+            [const { MaybeUninit::<InstructionAccount>::uninit() }; 3 + MAX_MULTISIG_SIGNERS];
+
+        instruction_accounts[0].write(InstructionAccount::writable(self.mint.address()));
+
+        instruction_accounts[1].write(InstructionAccount::readonly(self.proof_account.address()));
+
+        instruction_accounts[2].write(InstructionAccount::new(
+            self.authority.address(),
+            false,
+            self.multisig_signers.is_empty(),
+        ));
+
+        for (account, signer) in instruction_accounts[3..]
+            .iter_mut()
+            .zip(self.multisig_signers.iter())
+        {
+            account.write(InstructionAccount::readonly_signer(signer.address()));
+        }
+
+        // Accounts.
+
+        let mut accounts =
+            [const { MaybeUninit::<&AccountView>::uninit() }; 3 + MAX_MULTISIG_SIGNERS];
+
+        accounts[0].write(self.mint);
+
+        accounts[1].write(self.proof_account);
+
+        accounts[2].write(self.authority);
+
+        for (account, signer) in accounts[3..].iter_mut().zip(self.multisig_signers.iter()) {
+            account.write(signer);
+        }
+
+        // Instruction data.
+
+        let mut instruction_data = [UNINIT_BYTE; 35];
+
+        instruction_data[0].write(ExtensionDiscriminator::ConfidentialMintBurn as u8);
+
+        instruction_data[1].write(Self::DISCRIMINATOR);
+
+        write_bytes(&mut instruction_data[2..34], self.new_supply_elgamal_pubkey);
+
+        instruction_data[34].write(self.proof_instruction_offset as u8);
+
+        Ok(f(
+            &InstructionView {
+                program_id: self.token_program,
+                // SAFETY: instruction accounts has `expected_accounts` initialized.
+                accounts: unsafe {
+                    from_raw_parts(instruction_accounts.as_ptr() as _, expected_accounts)
+                },
+                // SAFETY: `instruction_data` is initialized.
+                data: unsafe {
+                    from_raw_parts(instruction_data.as_ptr() as _, instruction_data.len())
+                },
+            }, // SAFETY: accounts has `expected_accounts` initialized.
+            unsafe { from_raw_parts(accounts.as_ptr() as _, expected_accounts) },
+        ))
+    }
+}
@@ -0,0 +1,131 @@
+use {
+    crate::{
+        cpi::{invoke_signed_with_bounds, Signer},
+        instructions::{extensions::ExtensionDiscriminator, MAX_MULTISIG_SIGNERS},
+        write_bytes, UNINIT_BYTE,
+    },
+    core::{mem::MaybeUninit, slice::from_raw_parts},
+    solana_account_view::AccountView,
+    solana_address::Address,
+    solana_instruction_view::{InstructionAccount, InstructionView},
+    solana_program_error::{ProgramError, ProgramResult},
+};
+
+/// Update the decryptable supply of a confidential mint.
+///
+/// Accounts expected by this instruction:
+///
+///   * Single authority
+///   0. `[writable]` The mint.
+///   1. `[signer]` The mint authority.
+///
+///   * Multisignature authority
+///   0. `[writable]` The mint.
+///   1. `[]` The multisig mint authority.
+///   2. `..2+M` `[signer]` M signer accounts.
+pub struct UpdateDecryptableSupply<'a, 'b, 'c> {
+    /// The mint.
+    pub mint: &'a AccountView,
+
+    /// The mint authority.
+    pub authority: &'a AccountView,
+
+    /// The signer accounts if `authority` is a multisig.
+    pub multisig_signers: &'c [&'a AccountView],
+
+    /// The new decryptable supply.
+    pub new_decryptable_supply: &'b [u8; 36],
+
+    /// The token program.
+    pub token_program: &'b Address,
+}
+
+impl UpdateDecryptableSupply<'_, '_, '_> {
+    pub const DISCRIMINATOR: u8 = 2;
+
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    #[inline(always)]
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        self.with_instruction(|instruction, account_views| {
+            invoke_signed_with_bounds::<{ 2 + MAX_MULTISIG_SIGNERS }>(
+                instruction,
+                account_views,
+                signers,
+            )
+        })?
+    }
+
+    #[inline(always)]
+    pub fn with_instruction<R>(
+        &self,
+        f: impl FnOnce(&InstructionView, &[&AccountView]) -> R,
+    ) -> Result<R, ProgramError> {
+        if self.multisig_signers.len() > MAX_MULTISIG_SIGNERS {
+            Err(ProgramError::InvalidArgument)?;
+        }
+
+        let expected_accounts = 2 + self.multisig_signers.len();
+
+        // Instruction accounts.
+
+        let mut instruction_accounts =
+            [const { MaybeUninit::<InstructionAccount>::uninit() }; 2 + MAX_MULTISIG_SIGNERS];
+
+        instruction_accounts[0].write(InstructionAccount::writable(self.mint.address()));
+
+        instruction_accounts[1].write(InstructionAccount::new(
+            self.authority.address(),
+            false,
+            self.multisig_signers.is_empty(),
+        ));
+
+        for (account, signer) in instruction_accounts[2..]
+            .iter_mut()
+            .zip(self.multisig_signers.iter())
+        {
+            account.write(InstructionAccount::readonly_signer(signer.address()));
+        }
+
+        // Accounts.
+
+        let mut accounts =
+            [const { MaybeUninit::<&AccountView>::uninit() }; 2 + MAX_MULTISIG_SIGNERS];
+
+        accounts[0].write(self.mint);
+
+        accounts[1].write(self.authority);
+
+        for (account, signer) in accounts[2..].iter_mut().zip(self.multisig_signers.iter()) {
+            account.write(signer);
+        }
+
+        // Instruction data.
+
+        let mut instruction_data = [UNINIT_BYTE; 38];
+
+        instruction_data[0].write(ExtensionDiscriminator::ConfidentialMintBurn as u8);
+
+        instruction_data[1].write(Self::DISCRIMINATOR);
+
+        write_bytes(&mut instruction_data[2..38], self.new_decryptable_supply);
+
+        Ok(f(
+            &InstructionView {
+                program_id: self.token_program,
+                // SAFETY: instruction accounts has `expected_accounts` initialized.
+                accounts: unsafe {
+                    from_raw_parts(instruction_accounts.as_ptr() as _, expected_accounts)
+                },
+                // SAFETY: `instruction_data` is initialized.
+                data: unsafe {
+                    from_raw_parts(instruction_data.as_ptr() as _, instruction_data.len())
+                },
+            }, // SAFETY: accounts has `expected_accounts` initialized.
+            unsafe { from_raw_parts(accounts.as_ptr() as _, expected_accounts) },
+        ))
+    }
+}
@@ -0,0 +1,96 @@
+use {
+    crate::{cpi::invoke, instructions::ExtensionDiscriminator, write_bytes, UNINIT_BYTE},
+    core::slice::from_raw_parts,
+    solana_account_view::AccountView,
+    solana_address::Address,
+    solana_instruction_view::{InstructionAccount, InstructionView},
+    solana_program_error::ProgramResult,
+};
+
+/// Initialize confidential transfers for a mint.
+///
+/// The `ConfidentialTransferInstruction::InitializeMint` instruction
+/// requires no signers and MUST be included within the same Transaction as
+/// `TokenInstruction::InitializeMint`. Otherwise another party can
+/// initialize the configuration.
+///
+/// The instruction fails if the `TokenInstruction::InitializeMint`
+/// instruction has already executed for the mint.
+///
+/// Accounts expected by this instruction:
+///
+///   0. `[writable]` The mint to initialize.
+pub struct InitializeMint<'a, 'b> {
+    /// The mint to initialize.
+    pub mint: &'a AccountView,
+
+    /// Authority to modify the `ConfidentialTransferMint` configuration and
+    /// to approve new accounts.
+    pub authority: Option<&'b Address>,
+
+    /// Determines if newly configured accounts must be approved by the
+    /// `authority` before they may be used by the user.
+    pub auto_approve_new_accounts: bool,
+
+    /// New authority to decode any transfer amount in a confidential
+    /// transfer.
+    pub auditor_elgamal_pubkey: Option<&'b [u8; 32]>,
+
+    /// The token program.
+    pub token_program: &'b Address,
+}
+
+impl InitializeMint<'_, '_> {
+    pub const DISCRIMINATOR: u8 = 0;
+
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.with_instruction(invoke)
+    }
+
+    #[inline(always)]
+    pub fn with_instruction<R>(
+        &self,
+        f: impl FnOnce(&InstructionView, &[&AccountView; 1]) -> R,
+    ) -> R {
+        // Instruction data.
+
+        let mut instruction_data = [UNINIT_BYTE; 67];
+
+        instruction_data[0].write(ExtensionDiscriminator::ConfidentialTransfer as u8);
+
+        instruction_data[1].write(Self::DISCRIMINATOR);
+
+        write_bytes(
+            &mut instruction_data[2..34],
+            if let Some(authority) = self.authority {
+                authority.as_ref()
+            } else {
+                &[0; 32]
+            },
+        );
+
+        instruction_data[34].write(self.auto_approve_new_accounts as u8);
+
+        write_bytes(
+            &mut instruction_data[35..67],
+            if let Some(auditor_elgamal_pubkey) = self.auditor_elgamal_pubkey {
+                auditor_elgamal_pubkey
+            } else {
+                &[0; 32]
+            },
+        );
+
+        f(
+            &InstructionView {
+                program_id: self.token_program,
+                accounts: &[InstructionAccount::writable(self.mint.address())],
+                // SAFETY: `instruction_data` is initialized.
+                data: unsafe {
+                    from_raw_parts(instruction_data.as_ptr() as _, instruction_data.len())
+                },
+            },
+            &[self.mint],
+        )
+    }
+}
@@ -0,0 +1,4 @@
+mod initialize_mint;
+mod update_mint;
+
+pub use {initialize_mint::*, update_mint::*};
@@ -0,0 +1,96 @@
+use {
+    crate::{
+        cpi::{invoke_signed, Signer},
+        instructions::ExtensionDiscriminator,
+        write_bytes, UNINIT_BYTE,
+    },
+    core::slice::from_raw_parts,
+    solana_account_view::AccountView,
+    solana_address::Address,
+    solana_instruction_view::{InstructionAccount, InstructionView},
+    solana_program_error::ProgramResult,
+};
+
+/// Update the confidential transfer mint configuration for a mint.
+///
+/// Use `TokenInstruction::SetAuthority` to update the confidential transfer
+/// mint authority.
+///
+/// Accounts expected by this instruction:
+///
+///   0. `[writable]` The mint.
+///   1. `[signer]` Confidential transfer mint authority.
+pub struct UpdateMint<'a, 'b> {
+    /// The mint.
+    pub mint: &'a AccountView,
+
+    /// Confidential transfer mint authority.
+    pub authority: &'a AccountView,
+
+    /// Determines if newly configured accounts must be approved by the
+    /// `authority` before they may be used by the user.
+    pub auto_approve_new_accounts: bool,
+
+    /// New authority to decode any transfer amount in a confidential
+    /// transfer.
+    pub auditor_elgamal_pubkey: Option<&'b [u8; 32]>,
+
+    /// The token program.
+    pub token_program: &'b Address,
+}
+
+impl UpdateMint<'_, '_> {
+    pub const DISCRIMINATOR: u8 = 1;
+
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    #[inline(always)]
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        self.with_instruction(|instruction, account_views| {
+            invoke_signed(instruction, account_views, signers)
+        })
+    }
+
+    #[inline(always)]
+    pub fn with_instruction<R>(
+        &self,
+        f: impl FnOnce(&InstructionView, &[&AccountView; 2]) -> R,
+    ) -> R {
+        // Instruction data.
+
+        let mut instruction_data = [UNINIT_BYTE; 35];
+
+        instruction_data[0].write(ExtensionDiscriminator::ConfidentialTransfer as u8);
+
+        instruction_data[1].write(Self::DISCRIMINATOR);
+
+        instruction_data[2].write(self.auto_approve_new_accounts as u8);
+
+        write_bytes(
+            &mut instruction_data[3..35],
+            if let Some(auditor_elgamal_pubkey) = self.auditor_elgamal_pubkey {
+                auditor_elgamal_pubkey
+            } else {
+                &[0; 32]
+            },
+        );
+
+        f(
+            &InstructionView {
+                program_id: self.token_program,
+                accounts: &[
+                    InstructionAccount::writable(self.mint.address()),
+                    InstructionAccount::readonly_signer(self.authority.address()),
+                ],
+                // SAFETY: `instruction_data` is initialized.
+                data: unsafe {
+                    from_raw_parts(instruction_data.as_ptr() as _, instruction_data.len())
+                },
+            },
+            &[self.mint, self.authority],
+        )
+    }
+}
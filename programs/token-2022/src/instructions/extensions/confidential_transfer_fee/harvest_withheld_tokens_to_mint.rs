@@ -0,0 +1,118 @@
+use {
+    crate::{
+        cpi::{invoke_with_bounds, MAX_STATIC_CPI_ACCOUNTS},
+        instructions::extensions::ExtensionDiscriminator,
+    },
+    core::{
+        mem::MaybeUninit,
+        slice::{self, from_raw_parts},
+    },
+    solana_account_view::AccountView,
+    solana_address::Address,
+    solana_instruction_view::{InstructionAccount, InstructionView},
+    solana_program_error::{ProgramError, ProgramResult},
+};
+
+/// Permissionless instruction to transfer all withheld confidential tokens
+/// to the mint.
+///
+/// Succeeds for frozen accounts.
+///
+/// Accounts provided should include both the `TransferFeeAmount` and
+/// `ConfidentialTransferAccount` extension. If not, the account is skipped.
+///
+/// Accounts expected by this instruction:
+///
+///   0. `[writable]` The mint.
+///   1. `..1+N` `[writable]` The source accounts to harvest from.
+pub struct HarvestWithheldTokensToMint<'a, 'b, S> {
+    /// The mint.
+    pub mint: &'a AccountView,
+
+    /// The source accounts to harvest from.
+    ///
+    /// Any cloneable iterator with an exact size can be used, such as
+    /// `remaining_accounts.iter()` or `sources.iter().copied()`.
+    pub sources: S,
+
+    /// The token program.
+    pub token_program: &'b Address,
+}
+
+impl<'a, S> HarvestWithheldTokensToMint<'a, '_, S>
+where
+    S: IntoIterator<Item = &'a AccountView> + Clone,
+    S::IntoIter: ExactSizeIterator,
+{
+    pub const DISCRIMINATOR: u8 = 3;
+
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.with_instruction(|instruction, account_views| {
+            invoke_with_bounds::<MAX_STATIC_CPI_ACCOUNTS>(instruction, account_views)
+        })?
+    }
+
+    #[inline(always)]
+    pub fn with_instruction<R>(
+        &self,
+        f: impl FnOnce(&InstructionView, &[&AccountView]) -> R,
+    ) -> Result<R, ProgramError> {
+        let sources = self.sources.clone().into_iter();
+        let sources_len = sources.len();
+
+        if sources_len >= MAX_STATIC_CPI_ACCOUNTS {
+            Err(ProgramError::InvalidArgument)?;
+        }
+
+        let expected_accounts = 1 + sources_len;
+
+        // Instruction accounts.
+
+        let mut instruction_accounts =
+            [const { MaybeUninit::<InstructionAccount>::uninit() }; MAX_STATIC_CPI_ACCOUNTS];
+
+        instruction_accounts[0].write(InstructionAccount::writable(self.mint.address()));
+
+        // Accounts.
+
+        let mut accounts =
+            [const { MaybeUninit::<&AccountView>::uninit() }; MAX_STATIC_CPI_ACCOUNTS];
+
+        accounts[0].write(self.mint);
+
+        // Sources.
+
+        let mut written_sources = 0;
+
+        for ((instruction_account, account), source) in instruction_accounts[1..expected_accounts]
+            .iter_mut()
+            .zip(accounts[1..expected_accounts].iter_mut())
+            .zip(sources)
+        {
+            instruction_account.write(InstructionAccount::writable(source.address()));
+            account.write(source);
+            written_sources += 1;
+        }
+
+        // The iterator may report a length larger than its item count.
+        if written_sources != sources_len {
+            Err(ProgramError::InvalidArgument)?;
+        }
+
+        Ok(f(
+            &InstructionView {
+                program_id: self.token_program,
+                // SAFETY: instruction accounts has `expected_accounts` initialized.
+                accounts: unsafe {
+                    from_raw_parts(instruction_accounts.as_ptr() as _, expected_accounts)
+                },
+                data: &[
+                    ExtensionDiscriminator::ConfidentialTransferFee as u8,
+                    Self::DISCRIMINATOR,
+                ],
+            }, // SAFETY: accounts has `expected_accounts` initialized.
+            unsafe { slice::from_raw_parts(accounts.as_ptr() as _, expected_accounts) },
+        ))
+    }
+}
@@ -0,0 +1,79 @@
+use {
+    crate::{cpi::invoke, instructions::ExtensionDiscriminator, write_bytes, UNINIT_BYTE},
+    core::slice::from_raw_parts,
+    solana_account_view::AccountView,
+    solana_address::Address,
+    solana_instruction_view::{InstructionAccount, InstructionView},
+    solana_program_error::ProgramResult,
+};
+
+/// Initialize the confidential transfer fee configuration for a mint.
+///
+/// The instruction fails if the `TokenInstruction::InitializeMint`
+/// instruction has already executed for the mint.
+///
+/// Accounts expected by this instruction:
+///
+///   0. `[writable]` The mint to initialize.
+pub struct InitializeConfidentialTransferFeeConfig<'a, 'b> {
+    /// The mint to initialize.
+    pub mint: &'a AccountView,
+
+    /// Confidential transfer fee authority.
+    pub authority: Option<&'b Address>,
+
+    /// ElGamal public key used to encrypt withheld fees.
+    pub withdraw_withheld_authority_elgamal_pubkey: &'b [u8; 32],
+
+    /// The token program.
+    pub token_program: &'b Address,
+}
+
+impl InitializeConfidentialTransferFeeConfig<'_, '_> {
+    pub const DISCRIMINATOR: u8 = 0;
+
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.with_instruction(invoke)
+    }
+
+    #[inline(always)]
+    pub fn with_instruction<R>(
+        &self,
+        f: impl FnOnce(&InstructionView, &[&AccountView; 1]) -> R,
+    ) -> R {
+        // Instruction data.
+
+        let mut instruction_data = [UNINIT_BYTE; 66];
+
+        instruction_data[0].write(ExtensionDiscriminator::ConfidentialTransferFee as u8);
+
+        instruction_data[1].write(Self::DISCRIMINATOR);
+
+        write_bytes(
+            &mut instruction_data[2..34],
+            if let Some(authority) = self.authority {
+                authority.as_ref()
+            } else {
+                &[0; 32]
+            },
+        );
+
+        write_bytes(
+            &mut instruction_data[34..66],
+            self.withdraw_withheld_authority_elgamal_pubkey,
+        );
+
+        f(
+            &InstructionView {
+                program_id: self.token_program,
+                accounts: &[InstructionAccount::writable(self.mint.address())],
+                // SAFETY: `instruction_data` is initialized.
+                data: unsafe {
+                    from_raw_parts(instruction_data.as_ptr() as _, instruction_data.len())
+                },
+            },
+            &[self.mint],
+        )
+    }
+}
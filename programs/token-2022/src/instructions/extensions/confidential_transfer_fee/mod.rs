@@ -0,0 +1,12 @@
+mod disable_harvest_to_mint;
+mod enable_harvest_to_mint;
+mod harvest_withheld_tokens_to_mint;
+mod initialize_config;
+mod withdraw_withheld_tokens_from_accounts;
+mod withdraw_withheld_tokens_from_mint;
+
+pub use {
+    disable_harvest_to_mint::*, enable_harvest_to_mint::*, harvest_withheld_tokens_to_mint::*,
+    initialize_config::*, withdraw_withheld_tokens_from_accounts::*,
+    withdraw_withheld_tokens_from_mint::*,
+};
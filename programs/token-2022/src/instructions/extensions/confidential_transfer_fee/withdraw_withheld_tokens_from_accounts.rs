@@ -0,0 +1,231 @@
+use {
+    crate::{
+        cpi::{invoke_signed_with_bounds, Signer, MAX_STATIC_CPI_ACCOUNTS},
+        instructions::{extensions::ExtensionDiscriminator, MAX_MULTISIG_SIGNERS},
+        write_bytes, UNINIT_BYTE,
+    },
+    core::{
+        mem::MaybeUninit,
+        slice::{self, from_raw_parts},
+    },
+    solana_account_view::AccountView,
+    solana_address::Address,
+    solana_instruction_view::{InstructionAccount, InstructionView},
+    solana_program_error::{ProgramError, ProgramResult},
+};
+
+/// Transfer all withheld tokens to an account. Signed by the mint's withdraw
+/// withheld tokens authority. This instruction is susceptible to
+/// front-running. Use `HarvestWithheldTokensToMint` and
+/// `WithdrawWithheldTokensFromMint` as an alternative.
+///
+/// The withheld confidential tokens are aggregated directly into the
+/// destination available balance.
+///
+/// Accounts expected by this instruction:
+///
+///   * Single authority
+///   0. `[]` The token mint.
+///   1. `[writable]` The fee receiver account.
+///   2. `[]` Instructions sysvar if `VerifyWithdrawWithheldTokens` is
+///      included in the same transaction or context state account if
+///      `VerifyWithdrawWithheldTokens` is pre-verified into a context state
+///      account.
+///   3. `[]` (Optional) Record account if the accompanying proof is to be
+///      read from a record account.
+///   4. `[signer]` The mint's `withdraw_withheld_authority`.
+///   5. `..5+N` `[writable]` The source accounts to withdraw from.
+///
+///   * Multisignature authority
+///   0. `[]` The token mint.
+///   1. `[writable]` The fee receiver account.
+///   2. `[]` Instructions sysvar or context state account.
+///   3. `[]` (Optional) Record account.
+///   4. `[]` The mint's multisig `withdraw_withheld_authority`.
+///   5. `..5+M` `[signer]` M signer accounts.
+///   6. `5+M+1..5+M+N` `[writable]` The source accounts to withdraw from.
+pub struct WithdrawWithheldTokensFromAccounts<'a, 'b, 'c, S> {
+    /// The token mint.
+    pub mint: &'a AccountView,
+
+    /// The fee receiver account.
+    pub destination: &'a AccountView,
+
+    /// Instructions sysvar or context state account.
+    pub proof_account: &'a AccountView,
+
+    /// Record account, if the accompanying proof is read from a record
+    /// account.
+    pub record_account: Option<&'a AccountView>,
+
+    /// The mint's `withdraw_withheld_authority`.
+    pub authority: &'a AccountView,
+
+    /// The signer accounts if `authority` is a multisig.
+    pub multisig_signers: &'c [&'a AccountView],
+
+    /// The source accounts to withdraw from.
+    ///
+    /// Any cloneable iterator with an exact size can be used, such as
+    /// `remaining_accounts.iter()` or `sources.iter().copied()`.
+    pub sources: S,
+
+    /// Relative location of the `VerifyWithdrawWithheldTokens` instruction to
+    /// the current instruction in the transaction. If the offset is `0`, then
+    /// use a context state account for the proof.
+    pub proof_instruction_offset: i8,
+
+    /// The new decryptable balance in the destination token account.
+    pub new_decryptable_available_balance: &'b [u8; 36],
+
+    /// The token program.
+    pub token_program: &'b Address,
+}
+
+impl<'a, S> WithdrawWithheldTokensFromAccounts<'a, '_, '_, S>
+where
+    S: IntoIterator<Item = &'a AccountView> + Clone,
+    S::IntoIter: ExactSizeIterator,
+{
+    pub const DISCRIMINATOR: u8 = 2;
+
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    #[inline(always)]
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        self.with_instruction(|instruction, account_views| {
+            invoke_signed_with_bounds::<MAX_STATIC_CPI_ACCOUNTS>(
+                instruction,
+                account_views,
+                signers,
+            )
+        })?
+    }
+
+    #[inline(always)]
+    pub fn with_instruction<R>(
+        &self,
+        f: impl FnOnce(&InstructionView, &[&AccountView]) -> R,
+    ) -> Result<R, ProgramError> {
+        if self.multisig_signers.len() > MAX_MULTISIG_SIGNERS {
+            Err(ProgramError::InvalidArgument)?;
+        }
+
+        let authority_index = 3 + self.record_account.is_some() as usize;
+        let sources_index = authority_index + 1 + self.multisig_signers.len();
+        let sources = self.sources.clone().into_iter();
+        let sources_len = sources.len();
+        let expected_accounts = sources_index + sources_len;
+
+        if expected_accounts > MAX_STATIC_CPI_ACCOUNTS || sources_len > u8::MAX as usize {
+            Err(ProgramError::InvalidArgument)?;
+        }
+
+        // Instruction accounts.
+
+        let mut instruction_accounts =
+            [const { MaybeUninit::<InstructionAccount>::uninit() }; MAX_STATIC_CPI_ACCOUNTS];
+
+        instruction_accounts[0].write(InstructionAccount::readonly(self.mint.address()));
+
+        instruction_accounts[1].write(InstructionAccount::writable(self.destination.address()));
+
+        instruction_accounts[2].write(InstructionAccount::readonly(self.proof_account.address()));
+
+        if let Some(record_account) = self.record_account {
+            instruction_accounts[3].write(InstructionAccount::readonly(record_account.address()));
+        }
+
+        instruction_accounts[authority_index].write(InstructionAccount::new(
+            self.authority.address(),
+            false,
+            self.multisig_signers.is_empty(),
+        ));
+
+        for (account, signer) in instruction_accounts[authority_index + 1..sources_index]
+            .iter_mut()
+            .zip(self.multisig_signers.iter())
+        {
+            account.write(InstructionAccount::readonly_signer(signer.address()));
+        }
+
+        // Accounts.
+
+        let mut accounts =
+            [const { MaybeUninit::<&AccountView>::uninit() }; MAX_STATIC_CPI_ACCOUNTS];
+
+        accounts[0].write(self.mint);
+
+        accounts[1].write(self.destination);
+
+        accounts[2].write(self.proof_account);
+
+        if let Some(record_account) = self.record_account {
+            accounts[3].write(record_account);
+        }
+
+        accounts[authority_index].write(self.authority);
+
+        for (account, signer) in accounts[authority_index + 1..sources_index]
+            .iter_mut()
+            .zip(self.multisig_signers.iter())
+        {
+            account.write(signer);
+        }
+
+        // Sources.
+
+        let mut written_sources = 0;
+
+        for ((instruction_account, account), source) in instruction_accounts
+            [sources_index..expected_accounts]
+            .iter_mut()
+            .zip(accounts[sources_index..expected_accounts].iter_mut())
+            .zip(sources)
+        {
+            instruction_account.write(InstructionAccount::writable(source.address()));
+            account.write(source);
+            written_sources += 1;
+        }
+
+        // The iterator may report a length larger than its item count.
+        if written_sources != sources_len {
+            Err(ProgramError::InvalidArgument)?;
+        }
+
+        // Instruction data.
+
+        let mut instruction_data = [UNINIT_BYTE; 40];
+
+        instruction_data[0].write(ExtensionDiscriminator::ConfidentialTransferFee as u8);
+
+        instruction_data[1].write(Self::DISCRIMINATOR);
+
+        instruction_data[2].write(sources_len as u8);
+
+        instruction_data[3].write(self.proof_instruction_offset as u8);
+
+        write_bytes(
+            &mut instruction_data[4..40],
+            self.new_decryptable_available_balance,
+        );
+
+        Ok(f(
+            &InstructionView {
+                program_id: self.token_program,
+                // SAFETY: instruction accounts has `expected_accounts` initialized.
+                accounts: unsafe {
+                    from_raw_parts(instruction_accounts.as_ptr() as _, expected_accounts)
+                },
+                // SAFETY: `instruction_data` is initialized.
+                data: unsafe {
+                    from_raw_parts(instruction_data.as_ptr() as _, instruction_data.len())
+                },
+            }, // SAFETY: accounts has `expected_accounts` initialized.
+            unsafe { slice::from_raw_parts(accounts.as_ptr() as _, expected_accounts) },
+        ))
+    }
+}
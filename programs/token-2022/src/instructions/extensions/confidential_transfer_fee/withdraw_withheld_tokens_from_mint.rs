@@ -0,0 +1,188 @@
+use {
+    crate::{
+        cpi::{invoke_signed_with_bounds, Signer},
+        instructions::{extensions::ExtensionDiscriminator, MAX_MULTISIG_SIGNERS},
+        write_bytes, UNINIT_BYTE,
+    },
+    core::{
+        mem::MaybeUninit,
+        slice::{self, from_raw_parts},
+    },
+    solana_account_view::AccountView,
+    solana_address::Address,
+    solana_instruction_view::{InstructionAccount, InstructionView},
+    solana_program_error::{ProgramError, ProgramResult},
+};
+
+/// Transfer all withheld confidential tokens in the mint to an account.
+/// Signed by the mint's withdraw withheld tokens authority.
+///
+/// The withheld confidential tokens are aggregated directly into the
+/// destination available balance.
+///
+/// Accounts expected by this instruction:
+///
+///   * Single authority
+///   0. `[writable]` The token mint.
+///   1. `[writable]` The fee receiver account.
+///   2. `[]` Instructions sysvar if `VerifyCiphertextCiphertextEquality` is
+///      included in the same transaction or context state account if
+///      `VerifyCiphertextCiphertextEquality` is pre-verified into a context
+///      state account.
+///   3. `[]` (Optional) Record account if the accompanying proof is to be
+///      read from a record account.
+///   4. `[signer]` The mint's `withdraw_withheld_authority`.
+///
+///   * Multisignature authority
+///   0. `[writable]` The token mint.
+///   1. `[writable]` The fee receiver account.
+///   2. `[]` Instructions sysvar or context state account.
+///   3. `[]` (Optional) Record account.
+///   4. `[]` The mint's multisig `withdraw_withheld_authority`.
+///   5. `..5+M` `[signer]` M signer accounts.
+pub struct WithdrawWithheldTokensFromMint<'a, 'b, 'c> {
+    /// The token mint.
+    pub mint: &'a AccountView,
+
+    /// The fee receiver account.
+    pub destination: &'a AccountView,
+
+    /// Instructions sysvar or context state account.
+    pub proof_account: &'a AccountView,
+
+    /// Record account, if the accompanying proof is read from a record
+    /// account.
+    pub record_account: Option<&'a AccountView>,
+
+    /// The mint's `withdraw_withheld_authority`.
+    pub authority: &'a AccountView,
+
+    /// The signer accounts if `authority` is a multisig.
+    pub multisig_signers: &'c [&'a AccountView],
+
+    /// Relative location of the `VerifyCiphertextCiphertextEquality`
+    /// instruction to the current instruction in the transaction. If the
+    /// offset is `0`, then use a context state account for the proof.
+    pub proof_instruction_offset: i8,
+
+    /// The new decryptable balance in the destination token account.
+    pub new_decryptable_available_balance: &'b [u8; 36],
+
+    /// The token program.
+    pub token_program: &'b Address,
+}
+
+impl WithdrawWithheldTokensFromMint<'_, '_, '_> {
+    pub const DISCRIMINATOR: u8 = 1;
+
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    #[inline(always)]
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        self.with_instruction(|instruction, account_views| {
+            invoke_signed_with_bounds::<{ 5 + MAX_MULTISIG_SIGNERS }>(
+                instruction,
+                account_views,
+                signers,
+            )
+        })?
+    }
+
+    #[inline(always)]
+    pub fn with_instruction<R>(
+        &self,
+        f: impl FnOnce(&InstructionView, &[&AccountView]) -> R,
+    ) -> Result<R, ProgramError> {
+        if self.multisig_signers.len() > MAX_MULTISIG_SIGNERS {
+            Err(ProgramError::InvalidArgument)?;
+        }
+
+        let authority_index = 3 + self.record_account.is_some() as usize;
+        let expected_accounts = authority_index + 1 + self.multisig_signers.len();
+
+        // Instruction accounts.
+
+        let mut instruction_accounts =
+            [const { MaybeUninit::<InstructionAccount>::uninit() }; 5 + MAX_MULTISIG_SIGNERS];
+
+        instruction_accounts[0].write(InstructionAccount::writable(self.mint.address()));
+
+        instruction_accounts[1].write(InstructionAccount::writable(self.destination.address()));
+
+        instruction_accounts[2].write(InstructionAccount::readonly(self.proof_account.address()));
+
+        if let Some(record_account) = self.record_account {
+            instruction_accounts[3].write(InstructionAccount::readonly(record_account.address()));
+        }
+
+        instruction_accounts[authority_index].write(InstructionAccount::new(
+            self.authority.address(),
+            false,
+            self.multisig_signers.is_empty(),
+        ));
+
+        for (account, signer) in instruction_accounts[authority_index + 1..]
+            .iter_mut()
+            .zip(self.multisig_signers.iter())
+        {
+            account.write(InstructionAccount::readonly_signer(signer.address()));
+        }
+
+        // Accounts.
+
+        let mut accounts =
+            [const { MaybeUninit::<&AccountView>::uninit() }; 5 + MAX_MULTISIG_SIGNERS];
+
+        accounts[0].write(self.mint);
+
+        accounts[1].write(self.destination);
+
+        accounts[2].write(self.proof_account);
+
+        if let Some(record_account) = self.record_account {
+            accounts[3].write(record_account);
+        }
+
+        accounts[authority_index].write(self.authority);
+
+        for (account, signer) in accounts[authority_index + 1..]
+            .iter_mut()
+            .zip(self.multisig_signers.iter())
+        {
+            account.write(signer);
+        }
+
+        // Instruction data.
+
+        let mut instruction_data = [UNINIT_BYTE; 39];
+
+        instruction_data[0].write(ExtensionDiscriminator::ConfidentialTransferFee as u8);
+
+        instruction_data[1].write(Self::DISCRIMINATOR);
+
+        instruction_data[2].write(self.proof_instruction_offset as u8);
+
+        write_bytes(
+            &mut instruction_data[3..39],
+            self.new_decryptable_available_balance,
+        );
+
+        Ok(f(
+            &InstructionView {
+                program_id: self.token_program,
+                // SAFETY: instruction accounts has `expected_accounts` initialized.
+                accounts: unsafe {
+                    from_raw_parts(instruction_accounts.as_ptr() as _, expected_accounts)
+                },
+                // SAFETY: `instruction_data` is initialized.
+                data: unsafe {
+                    from_raw_parts(instruction_data.as_ptr() as _, instruction_data.len())
+                },
+            }, // SAFETY: accounts has `expected_accounts` initialized.
+            unsafe { slice::from_raw_parts(accounts.as_ptr() as _, expected_accounts) },
+        ))
+    }
+}
@@ -0,0 +1,139 @@
+use {
+    crate::{
+        cpi::{invoke_signed_with_bounds, Signer},
+        instructions::{extensions::ExtensionDiscriminator, MAX_MULTISIG_SIGNERS},
+    },
+    core::{mem::MaybeUninit, slice},
+    solana_account_view::AccountView,
+    solana_address::Address,
+    solana_instruction_view::{InstructionAccount, InstructionView},
+    solana_program_error::{ProgramError, ProgramResult},
+};
+
+/// Disable the CPI Guard extension on a token account.
+///
+/// Note that the SPL Token-2022 program currently rejects CPI Guard toggles
+/// issued through CPI with a `CpiGuardSettingsLocked` error.
+///
+/// Expected accounts:
+///
+/// **Single authority**
+/// 0. `[writable]` The token account to disable CPI guard.
+/// 1. `[signer]` The owner of the token account.
+///
+/// **Multisignature authority**
+/// 0. `[writable]` The token account to disable CPI guard.
+/// 1. `[readonly]` The multisig account that owns the token account.
+/// 2. `[signer]` M signer accounts (as required by the multisig).
+pub struct Disable<'a, 'b, 'c> {
+    /// The token account to disable with the CPI Guard extension.
+    pub token_account: &'a AccountView,
+    /// The owner of the token account (single or multisig).
+    pub authority: &'a AccountView,
+    /// Signer accounts if the authority is a multisig.
+    pub signers: &'c [&'a AccountView],
+    /// Token program (Token-2022).
+    pub token_program: &'b Address,
+}
+
+impl Disable<'_, '_, '_> {
+    pub const DISCRIMINATOR: u8 = 1;
+
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    #[inline(always)]
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        self.with_instruction(|instruction, account_views| {
+            invoke_signed_with_bounds::<{ 2 + MAX_MULTISIG_SIGNERS }>(
+                instruction,
+                account_views,
+                signers,
+            )
+        })?
+    }
+
+    #[inline(always)]
+    pub fn with_instruction<R>(
+        &self,
+        f: impl FnOnce(&InstructionView, &[&AccountView]) -> R,
+    ) -> Result<R, ProgramError> {
+        let &Self {
+            token_account,
+            authority,
+            signers: multisig_accounts,
+            token_program,
+            ..
+        } = self;
+
+        if multisig_accounts.len() > MAX_MULTISIG_SIGNERS {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        const UNINIT_INSTRUCTION_ACCOUNTS: MaybeUninit<InstructionAccount> =
+            MaybeUninit::<InstructionAccount>::uninit();
+        let mut instruction_accounts = [UNINIT_INSTRUCTION_ACCOUNTS; 2 + MAX_MULTISIG_SIGNERS];
+
+        unsafe {
+            // SAFETY:
+            // - `instruction_accounts` is sized to 2 + MAX_MULTISIG_SIGNERS
+
+            // - Index 0 is always present (TokenAccount)
+            instruction_accounts
+                .get_unchecked_mut(0)
+                .write(InstructionAccount::writable(token_account.address()));
+
+            // - Index 1 is always present (Authority)
+            instruction_accounts
+                .get_unchecked_mut(1)
+                .write(InstructionAccount::new(
+                    authority.address(),
+                    false,
+                    multisig_accounts.is_empty(),
+                ));
+        }
+
+        for (instruction_account, signer) in instruction_accounts[2..]
+            .iter_mut()
+            .zip(multisig_accounts.iter())
+        {
+            instruction_account.write(InstructionAccount::readonly_signer(signer.address()));
+        }
+
+        let data = &[ExtensionDiscriminator::CpiGuard as u8, Self::DISCRIMINATOR];
+
+        let num_accounts = 2 + multisig_accounts.len();
+
+        let instruction = InstructionView {
+            program_id: token_program,
+            data,
+            accounts: unsafe {
+                slice::from_raw_parts(instruction_accounts.as_ptr() as _, num_accounts)
+            },
+        };
+
+        // Account view array
+        const UNINIT_ACCOUNT_VIEWS: MaybeUninit<&AccountView> = MaybeUninit::uninit();
+        let mut account_views = [UNINIT_ACCOUNT_VIEWS; 2 + MAX_MULTISIG_SIGNERS];
+
+        unsafe {
+            // SAFETY:
+            // - `account_views` is sized to 2 + MAX_MULTISIG_SIGNERS
+            // - Index 0 is always present
+            account_views.get_unchecked_mut(0).write(token_account);
+            // - Index 1 is always present
+            account_views.get_unchecked_mut(1).write(authority);
+        }
+
+        // Fill signer accounts
+        for (account_view, signer) in account_views[2..].iter_mut().zip(multisig_accounts.iter()) {
+            account_view.write(signer);
+        }
+
+        Ok(f(&instruction, unsafe {
+            slice::from_raw_parts(account_views.as_ptr() as *const &AccountView, num_accounts)
+        }))
+    }
+}
@@ -1,8 +1,8 @@
 use {
-    crate::instructions::extensions::ExtensionDiscriminator,
+    crate::{cpi::invoke, instructions::extensions::ExtensionDiscriminator},
     solana_account_view::AccountView,
     solana_address::Address,
-    solana_instruction_view::{cpi::invoke, InstructionAccount, InstructionView},
+    solana_instruction_view::{InstructionAccount, InstructionView},
     solana_program_error::ProgramResult,
 };
 
@@ -35,7 +35,15 @@ impl Initialize<'_, '_> {
 
     #[inline(always)]
     pub fn invoke(&self) -> ProgramResult {
-        invoke(
+        self.with_instruction(invoke)
+    }
+
+    #[inline(always)]
+    pub fn with_instruction<R>(
+        &self,
+        f: impl FnOnce(&InstructionView, &[&AccountView; 1]) -> R,
+    ) -> R {
+        f(
             &InstructionView {
                 program_id: self.token_program,
                 accounts: &[InstructionAccount::writable(self.mint.address())],
@@ -1,12 +1,12 @@
 use {
-    crate::instructions::{extensions::ExtensionDiscriminator, MAX_MULTISIG_SIGNERS},
+    crate::{
+        cpi::{invoke_signed_with_bounds, Signer},
+        instructions::{extensions::ExtensionDiscriminator, MAX_MULTISIG_SIGNERS},
+    },
     core::{mem::MaybeUninit, slice},
     solana_account_view::AccountView,
     solana_address::Address,
-    solana_instruction_view::{
-        cpi::{invoke_signed_with_bounds, Signer},
-        InstructionAccount, InstructionView,
-    },
+    solana_instruction_view::{InstructionAccount, InstructionView},
     solana_program_error::{ProgramError, ProgramResult},
 };
 
@@ -51,6 +51,20 @@ impl Update<'_, '_, '_> {
 
     #[inline(always)]
     pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        self.with_instruction(|instruction, account_views| {
+            invoke_signed_with_bounds::<{ 2 + MAX_MULTISIG_SIGNERS }>(
+                instruction,
+                account_views,
+                signers,
+            )
+        })?
+    }
+
+    #[inline(always)]
+    pub fn with_instruction<R>(
+        &self,
+        f: impl FnOnce(&InstructionView, &[&AccountView]) -> R,
+    ) -> Result<R, ProgramError> {
         if self.signers.len() > MAX_MULTISIG_SIGNERS {
             return Err(ProgramError::InvalidArgument);
         }
@@ -124,10 +138,8 @@ impl Update<'_, '_, '_> {
             }
         }
 
-        invoke_signed_with_bounds::<{ 2 + MAX_MULTISIG_SIGNERS }>(
-            &instruction,
-            unsafe { slice::from_raw_parts(accounts.as_ptr() as _, expected_accounts) },
-            signers,
-        )
+        Ok(f(&instruction, unsafe {
+            slice::from_raw_parts(accounts.as_ptr() as _, expected_accounts)
+        }))
     }
 }
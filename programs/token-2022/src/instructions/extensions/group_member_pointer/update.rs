@@ -1,5 +1,6 @@
 use {
     crate::{
+        cpi::{invoke_signed_with_bounds, Signer},
         instructions::{extensions::ExtensionDiscriminator, MAX_MULTISIG_SIGNERS},
         write_bytes, UNINIT_BYTE,
     },
@@ -9,10 +10,7 @@ use {
     },
     solana_account_view::AccountView,
     solana_address::Address,
-    solana_instruction_view::{
-        cpi::{invoke_signed_with_bounds, Signer},
-        InstructionAccount, InstructionView,
-    },
+    solana_instruction_view::{InstructionAccount, InstructionView},
     solana_program_error::{ProgramError, ProgramResult},
 };
 
@@ -93,6 +91,20 @@ impl<'a, 'b, 'c> Update<'a, 'b, 'c> {
 
     #[inline(always)]
     pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        self.with_instruction(|instruction, account_views| {
+            invoke_signed_with_bounds::<{ 2 + MAX_MULTISIG_SIGNERS }>(
+                instruction,
+                account_views,
+                signers,
+            )
+        })?
+    }
+
+    #[inline(always)]
+    pub fn with_instruction<R>(
+        &self,
+        f: impl FnOnce(&InstructionView, &[&AccountView]) -> R,
+    ) -> Result<R, ProgramError> {
         if self.signers.len() > MAX_MULTISIG_SIGNERS {
             Err(ProgramError::InvalidArgument)?;
         }
@@ -184,10 +196,8 @@ impl<'a, 'b, 'c> Update<'a, 'b, 'c> {
             }
         }
 
-        invoke_signed_with_bounds::<{ 2 + MAX_MULTISIG_SIGNERS }>(
-            &instruction,
-            unsafe { slice::from_raw_parts(accounts.as_ptr() as _, expected_accounts) },
-            signers,
-        )
+        Ok(f(&instruction, unsafe {
+            slice::from_raw_parts(accounts.as_ptr() as _, expected_accounts)
+        }))
     }
 }
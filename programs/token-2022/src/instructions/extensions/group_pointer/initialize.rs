@@ -1,13 +1,15 @@
 use {
-    crate::{instructions::extensions::ExtensionDiscriminator, write_bytes, UNINIT_BYTE},
+    crate::{
+        cpi::invoke, instructions::extensions::ExtensionDiscriminator, write_bytes, UNINIT_BYTE,
+    },
     core::slice::from_raw_parts,
     solana_account_view::AccountView,
     solana_address::Address,
-    solana_instruction_view::{cpi::invoke, InstructionAccount, InstructionView},
+    solana_instruction_view::{InstructionAccount, InstructionView},
     solana_program_error::ProgramResult,
 };
 
-/// Initialize a new mint with a group pointer
+/// Initialize a new mint with a group pointer.
 ///
 /// Fails if the mint has already been initialized, so must be called before
 /// `InitializeMint`.
@@ -38,6 +40,14 @@ impl Initialize<'_, '_> {
 
     #[inline(always)]
     pub fn invoke(&self) -> ProgramResult {
+        self.with_instruction(invoke)
+    }
+
+    #[inline(always)]
+    pub fn with_instruction<R>(
+        &self,
+        f: impl FnOnce(&InstructionView, &[&AccountView; 1]) -> R,
+    ) -> R {
         // Instruction data.
 
         let mut instruction_data = [UNINIT_BYTE; 66];
@@ -77,6 +87,6 @@ impl Initialize<'_, '_> {
             data: unsafe { from_raw_parts(instruction_data.as_ptr() as _, instruction_data.len()) },
         };
 
-        invoke(&instruction, &[self.mint])
+        f(&instruction, &[self.mint])
     }
 }
@@ -1,5 +1,6 @@
 use {
     crate::{
+        cpi::{invoke_signed_with_bounds, Signer},
         instructions::{extensions::ExtensionDiscriminator, MAX_MULTISIG_SIGNERS},
         write_bytes, UNINIT_BYTE,
     },
@@ -9,10 +10,7 @@ use {
     },
     solana_account_view::AccountView,
     solana_address::Address,
-    solana_instruction_view::{
-        cpi::{invoke_signed_with_bounds, Signer},
-        InstructionAccount, InstructionView,
-    },
+    solana_instruction_view::{InstructionAccount, InstructionView},
     solana_program_error::{ProgramError, ProgramResult},
 };
 
@@ -46,9 +44,46 @@ pub struct Update<'a, 'b, 'c> {
     pub token_program: &'b Address,
 }
 
-impl Update<'_, '_, '_> {
+impl<'a, 'b, 'c> Update<'a, 'b, 'c> {
     pub const DISCRIMINATOR: u8 = 1;
 
+    /// Creates a new `Update` instruction with a single owner/delegate
+    /// authority.
+    #[inline(always)]
+    pub fn new(
+        token_program: &'b Address,
+        mint: &'a AccountView,
+        authority: &'a AccountView,
+        group_address: Option<&'b Address>,
+    ) -> Self {
+        Self {
+            mint,
+            authority,
+            signers: &[],
+            group_address,
+            token_program,
+        }
+    }
+
+    /// Creates a new `Update` instruction with a multisignature owner/delegate
+    /// authority and signer accounts.
+    #[inline(always)]
+    pub fn with_multisig(
+        token_program: &'b Address,
+        mint: &'a AccountView,
+        authority: &'a AccountView,
+        group_address: Option<&'b Address>,
+        signers: &'c [&'a AccountView],
+    ) -> Self {
+        Self {
+            mint,
+            authority,
+            signers,
+            group_address,
+            token_program,
+        }
+    }
+
     #[inline(always)]
     pub fn invoke(&self) -> ProgramResult {
         self.invoke_signed(&[])
@@ -56,6 +91,20 @@ impl Update<'_, '_, '_> {
 
     #[inline(always)]
     pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        self.with_instruction(|instruction, account_views| {
+            invoke_signed_with_bounds::<{ 2 + MAX_MULTISIG_SIGNERS }>(
+                instruction,
+                account_views,
+                signers,
+            )
+        })?
+    }
+
+    #[inline(always)]
+    pub fn with_instruction<R>(
+        &self,
+        f: impl FnOnce(&InstructionView, &[&AccountView]) -> R,
+    ) -> Result<R, ProgramError> {
         if self.signers.len() > MAX_MULTISIG_SIGNERS {
             Err(ProgramError::InvalidArgument)?;
         }
@@ -76,7 +125,7 @@ impl Update<'_, '_, '_> {
                 .get_unchecked_mut(0)
                 .write(InstructionAccount::writable(self.mint.address()));
 
-            // athority
+            // authority
             instruction_accounts
                 .get_unchecked_mut(1)
                 .write(InstructionAccount::new(
@@ -151,10 +200,8 @@ impl Update<'_, '_, '_> {
             }
         }
 
-        invoke_signed_with_bounds::<{ 2 + MAX_MULTISIG_SIGNERS }>(
-            &instruction,
-            unsafe { slice::from_raw_parts(accounts.as_ptr() as _, expected_accounts) },
-            signers,
-        )
+        Ok(f(&instruction, unsafe {
+            slice::from_raw_parts(accounts.as_ptr() as _, expected_accounts)
+        }))
     }
 }
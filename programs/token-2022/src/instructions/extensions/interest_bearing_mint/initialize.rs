@@ -1,9 +1,9 @@
 use {
-    crate::{instructions::ExtensionDiscriminator, write_bytes, UNINIT_BYTE},
+    crate::{cpi::invoke, instructions::ExtensionDiscriminator, write_bytes, UNINIT_BYTE},
     core::slice::from_raw_parts,
     solana_account_view::AccountView,
     solana_address::Address,
-    solana_instruction_view::{cpi::invoke, InstructionAccount, InstructionView},
+    solana_instruction_view::{InstructionAccount, InstructionView},
     solana_program_error::ProgramResult,
 };
 
@@ -38,6 +38,14 @@ impl Initialize<'_, '_> {
 
     #[inline(always)]
     pub fn invoke(&self) -> ProgramResult {
+        self.with_instruction(invoke)
+    }
+
+    #[inline(always)]
+    pub fn with_instruction<R>(
+        &self,
+        f: impl FnOnce(&InstructionView, &[&AccountView; 1]) -> R,
+    ) -> R {
         // Instruction data.
 
         let mut instruction_data = [UNINIT_BYTE; 36];
@@ -57,7 +65,7 @@ impl Initialize<'_, '_> {
 
         write_bytes(&mut instruction_data[34..36], &self.rate.to_le_bytes());
 
-        invoke(
+        f(
             &InstructionView {
                 program_id: self.token_program,
                 accounts: &[InstructionAccount::writable(self.mint.address())],
@@ -1,12 +1,12 @@
 use {
-    crate::instructions::{extensions::ExtensionDiscriminator, MAX_MULTISIG_SIGNERS},
+    crate::{
+        cpi::{invoke_signed_with_bounds, Signer},
+        instructions::{extensions::ExtensionDiscriminator, MAX_MULTISIG_SIGNERS},
+    },
     core::{mem::MaybeUninit, slice},
     solana_account_view::AccountView,
     solana_address::Address,
-    solana_instruction_view::{
-        cpi::{invoke_signed_with_bounds, Signer},
-        InstructionAccount, InstructionView,
-    },
+    solana_instruction_view::{InstructionAccount, InstructionView},
     solana_program_error::{ProgramError, ProgramResult},
 };
 
@@ -43,6 +43,20 @@ impl Disable<'_, '_, '_> {
 
     #[inline(always)]
     pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        self.with_instruction(|instruction, account_views| {
+            invoke_signed_with_bounds::<{ 2 + MAX_MULTISIG_SIGNERS }>(
+                instruction,
+                account_views,
+                signers,
+            )
+        })?
+    }
+
+    #[inline(always)]
+    pub fn with_instruction<R>(
+        &self,
+        f: impl FnOnce(&InstructionView, &[&AccountView]) -> R,
+    ) -> Result<R, ProgramError> {
         let &Self {
             token_account,
             authority,
@@ -118,12 +132,8 @@ impl Disable<'_, '_, '_> {
             account_view.write(signer);
         }
 
-        invoke_signed_with_bounds::<{ 2 + MAX_MULTISIG_SIGNERS }>(
-            &instruction,
-            unsafe {
-                slice::from_raw_parts(account_views.as_ptr() as *const &AccountView, num_accounts)
-            },
-            signers,
-        )
+        Ok(f(&instruction, unsafe {
+            slice::from_raw_parts(account_views.as_ptr() as *const &AccountView, num_accounts)
+        }))
     }
 }
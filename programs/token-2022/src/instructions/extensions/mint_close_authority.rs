@@ -1,9 +1,9 @@
 use {
-    crate::{instructions::ExtensionDiscriminator, write_bytes, UNINIT_BYTE},
+    crate::{cpi::invoke, instructions::ExtensionDiscriminator, write_bytes, UNINIT_BYTE},
     core::slice::from_raw_parts,
     solana_account_view::AccountView,
     solana_address::Address,
-    solana_instruction_view::{cpi::invoke, InstructionAccount, InstructionView},
+    solana_instruction_view::{InstructionAccount, InstructionView},
     solana_program_error::ProgramResult,
 };
 
@@ -16,6 +16,8 @@ use {
 /// bytes), plus 83 bytes of padding, 1 byte reserved for the account type,
 /// then space required for this extension, plus any others.
 ///
+/// Without a close authority, the mint can never be closed.
+///
 /// Accounts expected by this instruction:
 ///
 ///   0. `[writable]` The mint to initialize.
@@ -26,20 +28,30 @@ pub struct InitializeMintCloseAuthority<'a, 'b> {
     /// Authority that must sign the `CloseAccount` instruction on a mint.
     pub close_authority: Option<&'b Address>,
 
-    // The token program.
+    /// The token program.
     pub token_program: &'b Address,
 }
 
 impl InitializeMintCloseAuthority<'_, '_> {
     #[inline(always)]
     pub fn invoke(&self) -> ProgramResult {
+        self.with_instruction(invoke)
+    }
+
+    #[inline(always)]
+    pub fn with_instruction<R>(
+        &self,
+        f: impl FnOnce(&InstructionView, &[&AccountView; 1]) -> R,
+    ) -> R {
         // Instruction data.
 
         let mut instruction_data = [UNINIT_BYTE; 34];
         let mut expected_data = 2;
 
+        // discriminator
         instruction_data[0].write(ExtensionDiscriminator::MintCloseAuthority as u8);
-
+        // close authority: `COption<Address>` encoded as a 1-byte tag followed
+        // by the address only when present
         if let Some(authority) = self.close_authority {
             instruction_data[1].write(1);
             write_bytes(&mut instruction_data[2..34], authority.as_ref());
@@ -48,7 +60,7 @@ impl InitializeMintCloseAuthority<'_, '_> {
             instruction_data[1].write(0);
         }
 
-        invoke(
+        f(
             &InstructionView {
                 program_id: self.token_program,
                 accounts: &[InstructionAccount::writable(self.mint.address())],
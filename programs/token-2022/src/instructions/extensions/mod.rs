@@ -1,3 +1,7 @@
+pub mod confidential_mint_burn;
+pub mod confidential_transfer;
+pub mod confidential_transfer_fee;
+pub mod cpi_guard;
 pub mod default_account_state;
 pub mod group_member_pointer;
 pub mod group_pointer;
@@ -5,23 +9,39 @@ pub mod interest_bearing_mint;
 pub mod memo_transfer;
 pub mod metadata_pointer;
 pub mod mint_close_authority;
+pub mod pausable;
 pub mod permanent_delegate;
 pub mod permissioned_burn;
 pub mod scaled_ui_amount;
+pub mod token_group;
+pub mod token_metadata;
 pub mod transfer_hook;
 
+/// Instruction prefixes of the Token-2022 extension instructions.
+///
+/// This is the first byte of the instruction data of an extension
+/// instruction, followed by the instruction discriminator within the
+/// extension. It is distinct from the `u16`
+/// [`ExtensionType`](crate::state::ExtensionType) that identifies extensions
+/// in account data and in `GetAccountDataSize` and `Reallocate`.
 #[repr(u8)]
 #[non_exhaustive]
 pub enum ExtensionDiscriminator {
     MintCloseAuthority = 25,
+    TransferFee = 26,
+    ConfidentialTransfer = 27,
     DefaultAccountState = 28,
     MemoTransfer = 30,
     InterestBearingMint = 33,
+    CpiGuard = 34,
     PermanentDelegate = 35,
     TransferHook = 36,
+    ConfidentialTransferFee = 37,
     MetadataPointer = 39,
     GroupPointer = 40,
     GroupMemberPointer = 41,
+    ConfidentialMintBurn = 42,
     ScaledUiAmount = 43,
+    Pausable = 44,
     PermissionedBurn = 46,
 }
@@ -0,0 +1,5 @@
+mod initialize;
+mod pause;
+mod resume;
+
+pub use {initialize::*, pause::*, resume::*};
@@ -0,0 +1,166 @@
+use {
+    crate::{
+        cpi::{invoke_signed_with_bounds, Signer},
+        instructions::{extensions::ExtensionDiscriminator, MAX_MULTISIG_SIGNERS},
+    },
+    core::{
+        mem::MaybeUninit,
+        slice::{self, from_raw_parts},
+    },
+    solana_account_view::AccountView,
+    solana_address::Address,
+    solana_instruction_view::{InstructionAccount, InstructionView},
+    solana_program_error::{ProgramError, ProgramResult},
+};
+
+/// Pause minting, burning, and transferring for the mint. Only supported
+/// for mints that include the `Pausable` extension.
+///
+/// Accounts expected by this instruction:
+///
+///   * Single authority
+///   0. `[writable]` The mint.
+///   1. `[signer]` The mint pause authority.
+///
+///   * Multisignature authority
+///   0. `[writable]` The mint.
+///   1. `[]` The mint's multisignature pause authority.
+///   2. `..2+M` `[signer]` M signer accounts.
+pub struct Pause<'a, 'b, 'c> {
+    /// The mint.
+    pub mint: &'a AccountView,
+
+    /// The mint pause authority.
+    pub authority: &'a AccountView,
+
+    /// The signer accounts if `authority` is a multisig.
+    pub multisig_signers: &'c [&'a AccountView],
+
+    /// The token program.
+    pub token_program: &'b Address,
+}
+
+impl<'a, 'b, 'c> Pause<'a, 'b, 'c> {
+    pub const DISCRIMINATOR: u8 = 1;
+
+    /// Creates a new `Pause` instruction with a single authority.
+    #[inline(always)]
+    pub fn new(
+        token_program: &'b Address,
+        mint: &'a AccountView,
+        authority: &'a AccountView,
+    ) -> Self {
+        Self::with_multisig_signers(token_program, mint, authority, &[])
+    }
+
+    /// Creates a new `Pause` instruction with a multisignature authority and
+    /// signer accounts.
+    #[inline(always)]
+    pub fn with_multisig_signers(
+        token_program: &'b Address,
+        mint: &'a AccountView,
+        authority: &'a AccountView,
+        multisig_signers: &'c [&'a AccountView],
+    ) -> Self {
+        Self {
+            mint,
+            authority,
+            multisig_signers,
+            token_program,
+        }
+    }
+
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    #[inline(always)]
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        self.with_instruction(|instruction, account_views| {
+            invoke_signed_with_bounds::<{ 2 + MAX_MULTISIG_SIGNERS }>(
+                instruction,
+                account_views,
+                signers,
+            )
+        })?
+    }
+
+    #[inline(always)]
+    pub fn with_instruction<R>(
+        &self,
+        f: impl FnOnce(&InstructionView, &[&AccountView]) -> R,
+    ) -> Result<R, ProgramError> {
+        toggle(
+            self.mint,
+            self.authority,
+            self.multisig_signers,
+            self.token_program,
+            Self::DISCRIMINATOR,
+            f,
+        )
+    }
+}
+
+/// Build a `Pause` or `Resume` instruction, which only differ by their
+/// discriminator.
+#[inline(always)]
+pub(super) fn toggle<R>(
+    mint: &AccountView,
+    authority: &AccountView,
+    multisig_signers: &[&AccountView],
+    token_program: &Address,
+    discriminator: u8,
+    f: impl FnOnce(&InstructionView, &[&AccountView]) -> R,
+) -> Result<R, ProgramError> {
+    if multisig_signers.len() > MAX_MULTISIG_SIGNERS {
+        Err(ProgramError::InvalidArgument)?;
+    }
+
+    let expected_accounts = 2 + multisig_signers.len();
+
+    // Instruction accounts.
+
+    let mut instruction_accounts =
+        [const { MaybeUninit::<InstructionAccount>::uninit() }; 2 + MAX_MULTISIG_SIGNERS];
+
+    instruction_accounts[0].write(InstructionAccount::writable(mint.address()));
+
+    instruction_accounts[1].write(InstructionAccount::new(
+        authority.address(),
+        false,
+        multisig_signers.is_empty(),
+    ));
+
+    for (account, signer) in instruction_accounts[2..]
+        .iter_mut()
+        .zip(multisig_signers.iter())
+    {
+        account.write(InstructionAccount::readonly_signer(signer.address()));
+    }
+
+    // Accounts.
+
+    let mut accounts = [const { MaybeUninit::<&AccountView>::uninit() }; 2 + MAX_MULTISIG_SIGNERS];
+
+    accounts[0].write(mint);
+
+    accounts[1].write(authority);
+
+    for (account, signer) in accounts[2..].iter_mut().zip(multisig_signers.iter()) {
+        account.write(signer);
+    }
+
+    Ok(f(
+        &InstructionView {
+            program_id: token_program,
+            // SAFETY: instruction accounts has `expected_accounts` initialized.
+            accounts: unsafe {
+                from_raw_parts(instruction_accounts.as_ptr() as _, expected_accounts)
+            },
+            data: &[ExtensionDiscriminator::Pausable as u8, discriminator],
+        },
+        // SAFETY: accounts has `expected_accounts` initialized.
+        unsafe { slice::from_raw_parts(accounts.as_ptr() as _, expected_accounts) },
+    ))
+}
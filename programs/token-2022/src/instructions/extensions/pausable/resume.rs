@@ -0,0 +1,100 @@
+use {
+    super::pause::toggle,
+    crate::{
+        cpi::{invoke_signed_with_bounds, Signer},
+        instructions::MAX_MULTISIG_SIGNERS,
+    },
+    solana_account_view::AccountView,
+    solana_address::Address,
+    solana_instruction_view::InstructionView,
+    solana_program_error::{ProgramError, ProgramResult},
+};
+
+/// Resume minting, burning, and transferring for the mint. Only supported
+/// for mints that include the `Pausable` extension.
+///
+/// Accounts expected by this instruction:
+///
+///   * Single authority
+///   0. `[writable]` The mint.
+///   1. `[signer]` The mint pause authority.
+///
+///   * Multisignature authority
+///   0. `[writable]` The mint.
+///   1. `[]` The mint's multisignature pause authority.
+///   2. `..2+M` `[signer]` M signer accounts.
+pub struct Resume<'a, 'b, 'c> {
+    /// The mint.
+    pub mint: &'a AccountView,
+
+    /// The mint pause authority.
+    pub authority: &'a AccountView,
+
+    /// The signer accounts if `authority` is a multisig.
+    pub multisig_signers: &'c [&'a AccountView],
+
+    /// The token program.
+    pub token_program: &'b Address,
+}
+
+impl<'a, 'b, 'c> Resume<'a, 'b, 'c> {
+    pub const DISCRIMINATOR: u8 = 2;
+
+    /// Creates a new `Resume` instruction with a single authority.
+    #[inline(always)]
+    pub fn new(
+        token_program: &'b Address,
+        mint: &'a AccountView,
+        authority: &'a AccountView,
+    ) -> Self {
+        Self::with_multisig_signers(token_program, mint, authority, &[])
+    }
+
+    /// Creates a new `Resume` instruction with a multisignature authority and
+    /// signer accounts.
+    #[inline(always)]
+    pub fn with_multisig_signers(
+        token_program: &'b Address,
+        mint: &'a AccountView,
+        authority: &'a AccountView,
+        multisig_signers: &'c [&'a AccountView],
+    ) -> Self {
+        Self {
+            mint,
+            authority,
+            multisig_signers,
+            token_program,
+        }
+    }
+
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    #[inline(always)]
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        self.with_instruction(|instruction, account_views| {
+            invoke_signed_with_bounds::<{ 2 + MAX_MULTISIG_SIGNERS }>(
+                instruction,
+                account_views,
+                signers,
+            )
+        })?
+    }
+
+    #[inline(always)]
+    pub fn with_instruction<R>(
+        &self,
+        f: impl FnOnce(&InstructionView, &[&AccountView]) -> R,
+    ) -> Result<R, ProgramError> {
+        toggle(
+            self.mint,
+            self.authority,
+            self.multisig_signers,
+            self.token_program,
+            Self::DISCRIMINATOR,
+            f,
+        )
+    }
+}
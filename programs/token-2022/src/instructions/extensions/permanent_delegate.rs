@@ -1,9 +1,11 @@
 use {
-    crate::{instructions::extensions::ExtensionDiscriminator, write_bytes, UNINIT_BYTE},
+    crate::{
+        cpi::invoke, instructions::extensions::ExtensionDiscriminator, write_bytes, UNINIT_BYTE,
+    },
     core::slice::from_raw_parts,
     solana_account_view::AccountView,
     solana_address::Address,
-    solana_instruction_view::{cpi::invoke, InstructionAccount, InstructionView},
+    solana_instruction_view::{InstructionAccount, InstructionView},
     solana_program_error::ProgramResult,
 };
 
@@ -33,6 +35,14 @@ pub struct InitializePermanentDelegate<'a, 'b> {
 impl InitializePermanentDelegate<'_, '_> {
     #[inline(always)]
     pub fn invoke(&self) -> ProgramResult {
+        self.with_instruction(invoke)
+    }
+
+    #[inline(always)]
+    pub fn with_instruction<R>(
+        &self,
+        f: impl FnOnce(&InstructionView, &[&AccountView; 1]) -> R,
+    ) -> R {
         // Instruction data.
 
         let mut instruction_data = [UNINIT_BYTE; 33];
@@ -42,7 +52,7 @@ impl InitializePermanentDelegate<'_, '_> {
         // delegate
         write_bytes(&mut instruction_data[1..33], self.delegate.as_ref());
 
-        invoke(
+        f(
             &InstructionView {
                 program_id: self.token_program,
                 accounts: &[InstructionAccount::writable(self.mint.address())],
@@ -1,5 +1,6 @@
 use {
     crate::{
+        cpi::{invoke_signed_with_bounds, Signer},
         instructions::{extensions::ExtensionDiscriminator, MAX_MULTISIG_SIGNERS},
         write_bytes, UNINIT_BYTE,
     },
@@ -9,10 +10,7 @@ use {
     },
     solana_account_view::AccountView,
     solana_address::Address,
-    solana_instruction_view::{
-        cpi::{invoke_signed_with_bounds, Signer},
-        InstructionAccount, InstructionView,
-    },
+    solana_instruction_view::{InstructionAccount, InstructionView},
     solana_program_error::{ProgramError, ProgramResult},
 };
 
@@ -112,6 +110,20 @@ impl<'a, 'b, 'c> Burn<'a, 'b, 'c> {
 
     #[inline(always)]
     pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        self.with_instruction(|instruction, account_views| {
+            invoke_signed_with_bounds::<{ 4 + MAX_MULTISIG_SIGNERS }>(
+                instruction,
+                account_views,
+                signers,
+            )
+        })?
+    }
+
+    #[inline(always)]
+    pub fn with_instruction<R>(
+        &self,
+        f: impl FnOnce(&InstructionView, &[&AccountView]) -> R,
+    ) -> Result<R, ProgramError> {
         if self.multisig_signers.len() > MAX_MULTISIG_SIGNERS {
             Err(ProgramError::InvalidArgument)?;
         }
@@ -171,7 +183,7 @@ impl<'a, 'b, 'c> Burn<'a, 'b, 'c> {
         // amount
         write_bytes(&mut instruction_data[2..10], &self.amount.to_le_bytes());
 
-        invoke_signed_with_bounds::<{ 4 + MAX_MULTISIG_SIGNERS }>(
+        Ok(f(
             &InstructionView {
                 program_id: self.token_program,
                 // SAFETY: instruction accounts has `expected_accounts` initialized.
@@ -182,10 +194,8 @@ impl<'a, 'b, 'c> Burn<'a, 'b, 'c> {
                 data: unsafe {
                     from_raw_parts(instruction_data.as_ptr() as _, instruction_data.len())
                 },
-            },
-            // SAFETY: accounts has `expected_accounts` initialized.
+            }, // SAFETY: accounts has `expected_accounts` initialized.
             unsafe { slice::from_raw_parts(accounts.as_ptr() as _, expected_accounts) },
-            signers,
-        )
+        ))
     }
 }
@@ -1,8 +1,10 @@
 use {
-    crate::{instructions::extensions::ExtensionDiscriminator, write_bytes, UNINIT_BYTE},
+    crate::{
+        cpi::invoke, instructions::extensions::ExtensionDiscriminator, write_bytes, UNINIT_BYTE,
+    },
     solana_account_view::AccountView,
     solana_address::Address,
-    solana_instruction_view::{cpi::invoke, InstructionAccount, InstructionView},
+    solana_instruction_view::{InstructionAccount, InstructionView},
     solana_program_error::ProgramResult,
 };
 
@@ -28,6 +30,14 @@ impl Initialize<'_, '_> {
 
     #[inline(always)]
     pub fn invoke(&self) -> ProgramResult {
+        self.with_instruction(invoke)
+    }
+
+    #[inline(always)]
+    pub fn with_instruction<R>(
+        &self,
+        f: impl FnOnce(&InstructionView, &[&AccountView; 1]) -> R,
+    ) -> R {
         let accounts = [InstructionAccount::writable(self.mint_account.address())];
 
         let authority = match self.authority {
@@ -51,6 +61,6 @@ impl Initialize<'_, '_> {
             data,
         };
 
-        invoke(&instruction, &[self.mint_account])
+        f(&instruction, &[self.mint_account])
     }
 }
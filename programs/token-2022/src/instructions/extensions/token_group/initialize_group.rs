@@ -0,0 +1,99 @@
+use {
+    crate::{
+        cpi::{invoke_signed, Signer},
+        write_bytes, UNINIT_BYTE,
+    },
+    core::slice::from_raw_parts,
+    solana_account_view::AccountView,
+    solana_address::Address,
+    solana_instruction_view::{InstructionAccount, InstructionView},
+    solana_program_error::ProgramResult,
+};
+
+/// Initialize a new token group.
+///
+/// When the group is stored in the mint itself (through the `GroupPointer`
+/// extension), the mint account must be reallocated and funded by the caller
+/// before invoking this instruction.
+///
+/// Accounts expected by this instruction:
+///
+///   0. `[writable]` Group.
+///   1. `[]` Mint.
+///   2. `[signer]` Mint authority.
+pub struct InitializeGroup<'a, 'b> {
+    /// The group account (the mint itself when storing the group in-mint).
+    pub group: &'a AccountView,
+
+    /// The mint.
+    pub mint: &'a AccountView,
+
+    /// The mint authority.
+    pub mint_authority: &'a AccountView,
+
+    /// Update authority for the group, or `None` to make the group
+    /// immutable.
+    pub update_authority: Option<&'b Address>,
+
+    /// The maximum number of group members.
+    pub max_size: u64,
+
+    /// The token program.
+    pub token_program: &'b Address,
+}
+
+impl InitializeGroup<'_, '_> {
+    /// First 8 bytes of `sha256("spl_token_group_interface:initialize_token_group")`.
+    pub const DISCRIMINATOR: [u8; 8] = [121, 113, 108, 39, 54, 51, 0, 4];
+
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    #[inline(always)]
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        self.with_instruction(|instruction, account_views| {
+            invoke_signed(instruction, account_views, signers)
+        })
+    }
+
+    #[inline(always)]
+    pub fn with_instruction<R>(
+        &self,
+        f: impl FnOnce(&InstructionView, &[&AccountView; 3]) -> R,
+    ) -> R {
+        // Instruction data layout:
+        // -  [0..8]: instruction discriminator
+        // -  [8..40]: update authority (zeroed for `None`)
+        // -  [40..48]: max size (u64)
+        let mut instruction_data = [UNINIT_BYTE; 48];
+
+        write_bytes(&mut instruction_data[..8], &Self::DISCRIMINATOR);
+        write_bytes(
+            &mut instruction_data[8..40],
+            if let Some(update_authority) = self.update_authority {
+                update_authority.as_ref()
+            } else {
+                &[0; 32]
+            },
+        );
+        write_bytes(&mut instruction_data[40..48], &self.max_size.to_le_bytes());
+
+        f(
+            &InstructionView {
+                program_id: self.token_program,
+                accounts: &[
+                    InstructionAccount::writable(self.group.address()),
+                    InstructionAccount::readonly(self.mint.address()),
+                    InstructionAccount::readonly_signer(self.mint_authority.address()),
+                ],
+                // SAFETY: `instruction_data` is initialized.
+                data: unsafe {
+                    from_raw_parts(instruction_data.as_ptr() as _, instruction_data.len())
+                },
+            },
+            &[self.group, self.mint, self.mint_authority],
+        )
+    }
+}
@@ -0,0 +1,85 @@
+use {
+    crate::cpi::{invoke_signed, Signer},
+    solana_account_view::AccountView,
+    solana_address::Address,
+    solana_instruction_view::{InstructionAccount, InstructionView},
+    solana_program_error::ProgramResult,
+};
+
+/// Initialize a new member of a token group.
+///
+/// When the member is stored in the member mint itself (through the
+/// `GroupMemberPointer` extension), the mint account must be reallocated and
+/// funded by the caller before invoking this instruction.
+///
+/// Accounts expected by this instruction:
+///
+///   0. `[writable]` Member.
+///   1. `[]` Member mint.
+///   2. `[signer]` Member mint authority.
+///   3. `[writable]` Group.
+///   4. `[signer]` Group update authority.
+pub struct InitializeMember<'a, 'b> {
+    /// The member account (the member mint itself when storing the member
+    /// in-mint).
+    pub member: &'a AccountView,
+
+    /// The member mint.
+    pub member_mint: &'a AccountView,
+
+    /// The member mint authority.
+    pub member_mint_authority: &'a AccountView,
+
+    /// The group account.
+    pub group: &'a AccountView,
+
+    /// The group update authority.
+    pub group_update_authority: &'a AccountView,
+
+    /// The token program.
+    pub token_program: &'b Address,
+}
+
+impl InitializeMember<'_, '_> {
+    /// First 8 bytes of `sha256("spl_token_group_interface:initialize_member")`.
+    pub const DISCRIMINATOR: [u8; 8] = [152, 32, 222, 176, 223, 237, 116, 134];
+
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    #[inline(always)]
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        self.with_instruction(|instruction, account_views| {
+            invoke_signed(instruction, account_views, signers)
+        })
+    }
+
+    #[inline(always)]
+    pub fn with_instruction<R>(
+        &self,
+        f: impl FnOnce(&InstructionView, &[&AccountView; 5]) -> R,
+    ) -> R {
+        f(
+            &InstructionView {
+                program_id: self.token_program,
+                accounts: &[
+                    InstructionAccount::writable(self.member.address()),
+                    InstructionAccount::readonly(self.member_mint.address()),
+                    InstructionAccount::readonly_signer(self.member_mint_authority.address()),
+                    InstructionAccount::writable(self.group.address()),
+                    InstructionAccount::readonly_signer(self.group_update_authority.address()),
+                ],
+                data: &Self::DISCRIMINATOR,
+            },
+            &[
+                self.member,
+                self.member_mint,
+                self.member_mint_authority,
+                self.group,
+                self.group_update_authority,
+            ],
+        )
+    }
+}
@@ -0,0 +1,8 @@
+mod initialize_group;
+mod initialize_member;
+mod update_group_authority;
+mod update_group_max_size;
+
+pub use {
+    initialize_group::*, initialize_member::*, update_group_authority::*, update_group_max_size::*,
+};
@@ -0,0 +1,84 @@
+use {
+    crate::{
+        cpi::{invoke_signed, Signer},
+        write_bytes, UNINIT_BYTE,
+    },
+    core::slice::from_raw_parts,
+    solana_account_view::AccountView,
+    solana_address::Address,
+    solana_instruction_view::{InstructionAccount, InstructionView},
+    solana_program_error::ProgramResult,
+};
+
+/// Update the authority of a token group.
+///
+/// Accounts expected by this instruction:
+///
+///   0. `[writable]` Group.
+///   1. `[signer]` Current update authority.
+pub struct UpdateGroupAuthority<'a, 'b> {
+    /// The group account.
+    pub group: &'a AccountView,
+
+    /// The current group update authority.
+    pub update_authority: &'a AccountView,
+
+    /// New authority for the group, or `None` to make the group immutable.
+    pub new_authority: Option<&'b Address>,
+
+    /// The token program.
+    pub token_program: &'b Address,
+}
+
+impl UpdateGroupAuthority<'_, '_> {
+    /// First 8 bytes of `sha256("spl_token_group_interface:update_authority")`.
+    pub const DISCRIMINATOR: [u8; 8] = [161, 105, 88, 1, 237, 221, 216, 203];
+
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    #[inline(always)]
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        self.with_instruction(|instruction, account_views| {
+            invoke_signed(instruction, account_views, signers)
+        })
+    }
+
+    #[inline(always)]
+    pub fn with_instruction<R>(
+        &self,
+        f: impl FnOnce(&InstructionView, &[&AccountView; 2]) -> R,
+    ) -> R {
+        // Instruction data layout:
+        // -  [0..8]: instruction discriminator
+        // -  [8..40]: new authority (zeroed for `None`)
+        let mut instruction_data = [UNINIT_BYTE; 40];
+
+        write_bytes(&mut instruction_data[..8], &Self::DISCRIMINATOR);
+        write_bytes(
+            &mut instruction_data[8..40],
+            if let Some(new_authority) = self.new_authority {
+                new_authority.as_ref()
+            } else {
+                &[0; 32]
+            },
+        );
+
+        f(
+            &InstructionView {
+                program_id: self.token_program,
+                accounts: &[
+                    InstructionAccount::writable(self.group.address()),
+                    InstructionAccount::readonly_signer(self.update_authority.address()),
+                ],
+                // SAFETY: `instruction_data` is initialized.
+                data: unsafe {
+                    from_raw_parts(instruction_data.as_ptr() as _, instruction_data.len())
+                },
+            },
+            &[self.group, self.update_authority],
+        )
+    }
+}
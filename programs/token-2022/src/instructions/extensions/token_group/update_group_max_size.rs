@@ -0,0 +1,77 @@
+use {
+    crate::{
+        cpi::{invoke_signed, Signer},
+        write_bytes, UNINIT_BYTE,
+    },
+    core::slice::from_raw_parts,
+    solana_account_view::AccountView,
+    solana_address::Address,
+    solana_instruction_view::{InstructionAccount, InstructionView},
+    solana_program_error::ProgramResult,
+};
+
+/// Update the max size of a token group.
+///
+/// Accounts expected by this instruction:
+///
+///   0. `[writable]` Group.
+///   1. `[signer]` Update authority.
+pub struct UpdateGroupMaxSize<'a, 'b> {
+    /// The group account.
+    pub group: &'a AccountView,
+
+    /// The group update authority.
+    pub update_authority: &'a AccountView,
+
+    /// New max size for the group.
+    pub max_size: u64,
+
+    /// The token program.
+    pub token_program: &'b Address,
+}
+
+impl UpdateGroupMaxSize<'_, '_> {
+    /// First 8 bytes of `sha256("spl_token_group_interface:update_group_max_size")`.
+    pub const DISCRIMINATOR: [u8; 8] = [108, 37, 171, 143, 248, 30, 18, 110];
+
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    #[inline(always)]
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        self.with_instruction(|instruction, account_views| {
+            invoke_signed(instruction, account_views, signers)
+        })
+    }
+
+    #[inline(always)]
+    pub fn with_instruction<R>(
+        &self,
+        f: impl FnOnce(&InstructionView, &[&AccountView; 2]) -> R,
+    ) -> R {
+        // Instruction data layout:
+        // -  [0..8]: instruction discriminator
+        // -  [8..16]: max size (u64)
+        let mut instruction_data = [UNINIT_BYTE; 16];
+
+        write_bytes(&mut instruction_data[..8], &Self::DISCRIMINATOR);
+        write_bytes(&mut instruction_data[8..16], &self.max_size.to_le_bytes());
+
+        f(
+            &InstructionView {
+                program_id: self.token_program,
+                accounts: &[
+                    InstructionAccount::writable(self.group.address()),
+                    InstructionAccount::readonly_signer(self.update_authority.address()),
+                ],
+                // SAFETY: `instruction_data` is initialized.
+                data: unsafe {
+                    from_raw_parts(instruction_data.as_ptr() as _, instruction_data.len())
+                },
+            },
+            &[self.group, self.update_authority],
+        )
+    }
+}
@@ -0,0 +1,287 @@
+use {
+    crate::{
+        cpi::{invoke, ReturnData},
+        write_bytes, UNINIT_BYTE,
+    },
+    core::{slice::from_raw_parts, str::from_utf8},
+    solana_account_view::AccountView,
+    solana_address::Address,
+    solana_instruction_view::{InstructionAccount, InstructionView},
+    solana_program_error::{ProgramError, ProgramResult},
+};
+
+/// Emit the token metadata as return data.
+///
+/// The format of the data emitted follows the borsh serialization of the SPL
+/// `TokenMetadata` struct. The optional `start` and `end` select a window of
+/// the serialized bytes, which is useful when the metadata exceeds the return
+/// data limit. The complete emitted metadata can be decoded with
+/// [`emitted_metadata`].
+///
+/// Accounts expected by this instruction:
+///
+///   0. `[]` Metadata account.
+pub struct Emit<'a, 'b> {
+    /// The metadata account.
+    pub metadata: &'a AccountView,
+
+    /// Start of the range of data to emit.
+    pub start: Option<u64>,
+
+    /// End of the range of data to emit.
+    pub end: Option<u64>,
+
+    /// The token program.
+    pub token_program: &'b Address,
+}
+
+impl Emit<'_, '_> {
+    /// First 8 bytes of `sha256("spl_token_metadata_interface:emitter")`.
+    pub const DISCRIMINATOR: [u8; 8] = [250, 166, 180, 250, 13, 12, 184, 70];
+
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.with_instruction(invoke)
+    }
+
+    #[inline(always)]
+    pub fn with_instruction<R>(
+        &self,
+        f: impl FnOnce(&InstructionView, &[&AccountView; 1]) -> R,
+    ) -> R {
+        // Instruction data layout:
+        // -  [0..8]: instruction discriminator
+        // -  [8..]: start and end as borsh `Option<u64>` (up to 9 bytes each)
+        let mut instruction_data = [UNINIT_BYTE; 26];
+
+        write_bytes(&mut instruction_data[..8], &Self::DISCRIMINATOR);
+
+        let mut offset = 8;
+
+        for value in [self.start, self.end] {
+            if let Some(value) = value {
+                instruction_data[offset].write(1);
+                write_bytes(
+                    &mut instruction_data[offset + 1..offset + 9],
+                    &value.to_le_bytes(),
+                );
+                offset += 9;
+            } else {
+                instruction_data[offset].write(0);
+                offset += 1;
+            }
+        }
+
+        f(
+            &InstructionView {
+                program_id: self.token_program,
+                accounts: &[InstructionAccount::readonly(self.metadata.address())],
+                // SAFETY: `instruction_data` was initialized for `offset` bytes.
+                data: unsafe { from_raw_parts(instruction_data.as_ptr() as _, offset) },
+            },
+            &[self.metadata],
+        )
+    }
+}
+
+/// Zero-copy view of borsh-serialized token metadata.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct TokenMetadata<'a> {
+    /// The authority that can sign to update the metadata.
+    pub update_authority: Option<&'a Address>,
+
+    /// The associated mint.
+    pub mint: &'a Address,
+
+    /// The longer name of the token.
+    pub name: &'a str,
+
+    /// The shortened symbol for the token.
+    pub symbol: &'a str,
+
+    /// The URI pointing to richer metadata.
+    pub uri: &'a str,
+
+    /// Additional key-value metadata.
+    pub additional_metadata: AdditionalMetadata<'a>,
+}
+
+impl<'a> TokenMetadata<'a> {
+    /// Decode token metadata from its borsh serialization.
+    ///
+    /// Returns [`ProgramError::InvalidAccountData`] if `bytes` does not
+    /// contain exactly one valid serialized `TokenMetadata`.
+    pub fn from_bytes(bytes: &'a [u8]) -> Result<Self, ProgramError> {
+        let (update_authority, bytes) = read_address(bytes)?;
+        let (mint, bytes) = read_address(bytes)?;
+        let (name, bytes) = read_str(bytes)?;
+        let (symbol, bytes) = read_str(bytes)?;
+        let (uri, bytes) = read_str(bytes)?;
+        let (count, data) = read_u32(bytes)?;
+
+        // Validate the additional metadata entries upfront.
+        let mut remaining = data;
+        for _ in 0..count {
+            let (_, rest) = read_str(remaining)?;
+            let (_, rest) = read_str(rest)?;
+            remaining = rest;
+        }
+
+        if !remaining.is_empty() {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        Ok(Self {
+            update_authority: if update_authority == &Address::default() {
+                None
+            } else {
+                Some(update_authority)
+            },
+            mint,
+            name,
+            symbol,
+            uri,
+            additional_metadata: AdditionalMetadata { count, data },
+        })
+    }
+}
+
+/// Iterator over the additional `(key, value)` metadata pairs.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct AdditionalMetadata<'a> {
+    count: u32,
+    data: &'a [u8],
+}
+
+impl<'a> Iterator for AdditionalMetadata<'a> {
+    type Item = (&'a str, &'a str);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.count == 0 {
+            return None;
+        }
+
+        // Entries were validated when the metadata was decoded.
+        let (key, rest) = read_str(self.data).ok()?;
+        let (value, rest) = read_str(rest).ok()?;
+
+        self.count -= 1;
+        self.data = rest;
+
+        Some((key, value))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.count as usize, Some(self.count as usize))
+    }
+}
+
+impl ExactSizeIterator for AdditionalMetadata<'_> {}
+
+/// Decode the token metadata emitted by an [`Emit`] instruction.
+///
+/// The return data must have been set by `token_program` and contain the
+/// complete metadata, i.e., the instruction was invoked without `start` and
+/// `end`.
+///
+/// Returns [`ProgramError::IncorrectProgramId`] if the return data was set
+/// by a different program.
+#[inline]
+pub fn emitted_metadata<'a>(
+    return_data: &'a ReturnData,
+    token_program: &Address,
+) -> Result<TokenMetadata<'a>, ProgramError> {
+    if return_data.program_id() != token_program {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    TokenMetadata::from_bytes(return_data.as_slice())
+}
+
+#[inline(always)]
+fn read_u32(bytes: &[u8]) -> Result<(u32, &[u8]), ProgramError> {
+    match bytes.split_first_chunk::<4>() {
+        Some((value, rest)) => Ok((u32::from_le_bytes(*value), rest)),
+        None => Err(ProgramError::InvalidAccountData),
+    }
+}
+
+#[inline(always)]
+fn read_address(bytes: &[u8]) -> Result<(&Address, &[u8]), ProgramError> {
+    match bytes.split_first_chunk::<32>() {
+        // SAFETY: `Address` is `#[repr(transparent)]` over `[u8; 32]` with
+        // alignment 1, so the pointer cast is valid.
+        Some((address, rest)) => Ok((unsafe { &*(address.as_ptr() as *const Address) }, rest)),
+        None => Err(ProgramError::InvalidAccountData),
+    }
+}
+
+#[inline(always)]
+fn read_str(bytes: &[u8]) -> Result<(&str, &[u8]), ProgramError> {
+    let (len, bytes) = read_u32(bytes)?;
+
+    if bytes.len() < len as usize {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    let (value, rest) = bytes.split_at(len as usize);
+    let value = from_utf8(value).map_err(|_| ProgramError::InvalidAccountData)?;
+
+    Ok((value, rest))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn push(buffer: &mut [u8], offset: &mut usize, bytes: &[u8]) {
+        buffer[*offset..*offset + bytes.len()].copy_from_slice(bytes);
+        *offset += bytes.len();
+    }
+
+    fn push_str(buffer: &mut [u8], offset: &mut usize, value: &str) {
+        push(buffer, offset, &(value.len() as u32).to_le_bytes());
+        push(buffer, offset, value.as_bytes());
+    }
+
+    #[test]
+    fn decode_token_metadata() {
+        let mut buffer = [0u8; 256];
+        let mut offset = 0;
+
+        push(&mut buffer, &mut offset, &[0; 32]);
+        push(&mut buffer, &mut offset, &[7; 32]);
+        push_str(&mut buffer, &mut offset, "Token");
+        push_str(&mut buffer, &mut offset, "TKN");
+        push_str(&mut buffer, &mut offset, "https://example.com");
+        push(&mut buffer, &mut offset, &2u32.to_le_bytes());
+        push_str(&mut buffer, &mut offset, "color");
+        push_str(&mut buffer, &mut offset, "blue");
+        push_str(&mut buffer, &mut offset, "size");
+        push_str(&mut buffer, &mut offset, "");
+
+        let metadata = TokenMetadata::from_bytes(&buffer[..offset]).unwrap();
+
+        assert_eq!(metadata.update_authority, None);
+        assert_eq!(metadata.mint, &Address::new_from_array([7; 32]));
+        assert_eq!(metadata.name, "Token");
+        assert_eq!(metadata.symbol, "TKN");
+        assert_eq!(metadata.uri, "https://example.com");
+
+        let mut additional_metadata = metadata.additional_metadata;
+        assert_eq!(additional_metadata.len(), 2);
+        assert_eq!(additional_metadata.next(), Some(("color", "blue")));
+        assert_eq!(additional_metadata.next(), Some(("size", "")));
+        assert_eq!(additional_metadata.next(), None);
+
+        // Truncated and trailing data are rejected.
+        assert_eq!(
+            TokenMetadata::from_bytes(&buffer[..offset - 1]),
+            Err(ProgramError::InvalidAccountData)
+        );
+        assert_eq!(
+            TokenMetadata::from_bytes(&buffer[..offset + 1]),
+            Err(ProgramError::InvalidAccountData)
+        );
+    }
+}
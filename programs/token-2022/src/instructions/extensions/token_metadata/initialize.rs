@@ -0,0 +1,135 @@
+use {
+    super::{write_str, DEFAULT_MAX_DATA_LEN},
+    crate::{
+        cpi::{invoke_signed, Signer},
+        write_bytes, UNINIT_BYTE,
+    },
+    core::slice::from_raw_parts,
+    solana_account_view::AccountView,
+    solana_address::Address,
+    solana_instruction_view::{InstructionAccount, InstructionView},
+    solana_program_error::{ProgramError, ProgramResult},
+};
+
+/// Initialize the basic token metadata fields of a metadata account.
+///
+/// By the end of the instruction, the metadata account must contain enough
+/// lamports to be rent-exempt. When metadata is stored in the mint itself
+/// (through the `MetadataPointer` extension), the mint account must be
+/// reallocated and funded by the caller before invoking this instruction.
+///
+/// Accounts expected by this instruction:
+///
+///   0. `[writable]` Metadata.
+///   1. `[]` Update authority.
+///   2. `[]` Mint.
+///   3. `[signer]` Mint authority.
+pub struct Initialize<'a, 'b> {
+    /// The metadata account (the mint itself when storing metadata in-mint).
+    pub metadata: &'a AccountView,
+
+    /// Authority that can update the metadata.
+    pub update_authority: &'a AccountView,
+
+    /// The mint.
+    pub mint: &'a AccountView,
+
+    /// The mint authority.
+    pub mint_authority: &'a AccountView,
+
+    /// Longer name of the token.
+    pub name: &'b str,
+
+    /// Shortened symbol of the token.
+    pub symbol: &'b str,
+
+    /// URI pointing to richer metadata.
+    pub uri: &'b str,
+
+    /// The token program.
+    pub token_program: &'b Address,
+}
+
+impl Initialize<'_, '_> {
+    /// First 8 bytes of `sha256("spl_token_metadata_interface:initialize_account")`.
+    pub const DISCRIMINATOR: [u8; 8] = [210, 225, 30, 162, 88, 184, 77, 141];
+
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    #[inline(always)]
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        self.invoke_signed_with_bounds::<DEFAULT_MAX_DATA_LEN>(signers)
+    }
+
+    /// Invoke the instruction serializing its data into a buffer of
+    /// `MAX_DATA_LEN` bytes.
+    ///
+    /// Returns [`ProgramError::InvalidArgument`] if the serialized data does
+    /// not fit in the buffer.
+    #[inline(always)]
+    pub fn invoke_signed_with_bounds<const MAX_DATA_LEN: usize>(
+        &self,
+        signers: &[Signer],
+    ) -> ProgramResult {
+        self.with_instruction_with_bounds::<MAX_DATA_LEN, _>(|instruction, account_views| {
+            invoke_signed(instruction, account_views, signers)
+        })?
+    }
+
+    #[inline(always)]
+    pub fn with_instruction<R>(
+        &self,
+        f: impl FnOnce(&InstructionView, &[&AccountView; 4]) -> R,
+    ) -> Result<R, ProgramError> {
+        self.with_instruction_with_bounds::<DEFAULT_MAX_DATA_LEN, R>(f)
+    }
+
+    /// Build the instruction serializing its data into a buffer of
+    /// `MAX_DATA_LEN` bytes.
+    ///
+    /// Returns [`ProgramError::InvalidArgument`] if the serialized data does
+    /// not fit in the buffer.
+    #[inline(always)]
+    pub fn with_instruction_with_bounds<const MAX_DATA_LEN: usize, R>(
+        &self,
+        f: impl FnOnce(&InstructionView, &[&AccountView; 4]) -> R,
+    ) -> Result<R, ProgramError> {
+        // Instruction data layout:
+        // -  [0..8]: instruction discriminator
+        // -  [8..]: name, symbol and uri as borsh strings
+        let mut instruction_data = [UNINIT_BYTE; MAX_DATA_LEN];
+
+        if MAX_DATA_LEN < Self::DISCRIMINATOR.len() {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        write_bytes(&mut instruction_data[..8], &Self::DISCRIMINATOR);
+
+        let mut offset = write_str(&mut instruction_data, 8, self.name)?;
+        offset = write_str(&mut instruction_data, offset, self.symbol)?;
+        offset = write_str(&mut instruction_data, offset, self.uri)?;
+
+        Ok(f(
+            &InstructionView {
+                program_id: self.token_program,
+                accounts: &[
+                    InstructionAccount::writable(self.metadata.address()),
+                    InstructionAccount::readonly(self.update_authority.address()),
+                    InstructionAccount::readonly(self.mint.address()),
+                    InstructionAccount::readonly_signer(self.mint_authority.address()),
+                ],
+                // SAFETY: `instruction_data` was initialized for `offset` bytes.
+                data: unsafe { from_raw_parts(instruction_data.as_ptr() as _, offset) },
+            },
+            &[
+                self.metadata,
+                self.update_authority,
+                self.mint,
+                self.mint_authority,
+            ],
+        ))
+    }
+}
@@ -0,0 +1,42 @@
+mod emit;
+mod initialize;
+mod remove_key;
+mod update_authority;
+mod update_field;
+
+pub use {emit::*, initialize::*, remove_key::*, update_authority::*, update_field::*};
+
+use {crate::write_bytes, core::mem::MaybeUninit, solana_program_error::ProgramError};
+
+/// Default maximum length of the instruction data of token metadata
+/// instructions with variable-length fields.
+///
+/// Instructions are serialized into a stack buffer; use the
+/// `invoke_signed_with_bounds` variants to specify a different bound.
+pub const DEFAULT_MAX_DATA_LEN: usize = 512;
+
+/// Write `value` as a borsh string (`u32` little-endian length followed by the
+/// UTF-8 bytes) into `buffer` at `offset`.
+///
+/// Returns the offset after the written bytes, or
+/// [`ProgramError::InvalidArgument`] if the string does not fit.
+#[inline(always)]
+fn write_str(
+    buffer: &mut [MaybeUninit<u8>],
+    offset: usize,
+    value: &str,
+) -> Result<usize, ProgramError> {
+    let end = offset + 4 + value.len();
+
+    if end > buffer.len() {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    write_bytes(
+        &mut buffer[offset..offset + 4],
+        &(value.len() as u32).to_le_bytes(),
+    );
+    write_bytes(&mut buffer[offset + 4..end], value.as_bytes());
+
+    Ok(end)
+}
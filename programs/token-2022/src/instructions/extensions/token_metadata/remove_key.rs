@@ -0,0 +1,119 @@
+use {
+    super::{write_str, DEFAULT_MAX_DATA_LEN},
+    crate::{
+        cpi::{invoke_signed, Signer},
+        write_bytes, UNINIT_BYTE,
+    },
+    core::slice::from_raw_parts,
+    solana_account_view::AccountView,
+    solana_address::Address,
+    solana_instruction_view::{InstructionAccount, InstructionView},
+    solana_program_error::{ProgramError, ProgramResult},
+};
+
+/// Remove a key-value pair in a token metadata account.
+///
+/// This only applies to additional fields, and not the base name / symbol /
+/// URI fields.
+///
+/// By the end of the instruction, the metadata account must contain enough
+/// lamports to be rent-exempt.
+///
+/// Accounts expected by this instruction:
+///
+///   0. `[writable]` Metadata account.
+///   1. `[signer]` Update authority.
+pub struct RemoveKey<'a, 'b> {
+    /// The metadata account.
+    pub metadata: &'a AccountView,
+
+    /// The update authority.
+    pub update_authority: &'a AccountView,
+
+    /// If the idempotent flag is set to `true`, then the instruction will not
+    /// error if the key does not exist.
+    pub idempotent: bool,
+
+    /// Key to remove in the additional metadata portion.
+    pub key: &'b str,
+
+    /// The token program.
+    pub token_program: &'b Address,
+}
+
+impl RemoveKey<'_, '_> {
+    /// First 8 bytes of `sha256("spl_token_metadata_interface:remove_key_ix")`.
+    pub const DISCRIMINATOR: [u8; 8] = [234, 18, 32, 56, 89, 141, 37, 181];
+
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    #[inline(always)]
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        self.invoke_signed_with_bounds::<DEFAULT_MAX_DATA_LEN>(signers)
+    }
+
+    /// Invoke the instruction serializing its data into a buffer of
+    /// `MAX_DATA_LEN` bytes.
+    ///
+    /// Returns [`ProgramError::InvalidArgument`] if the serialized data does
+    /// not fit in the buffer.
+    #[inline(always)]
+    pub fn invoke_signed_with_bounds<const MAX_DATA_LEN: usize>(
+        &self,
+        signers: &[Signer],
+    ) -> ProgramResult {
+        self.with_instruction_with_bounds::<MAX_DATA_LEN, _>(|instruction, account_views| {
+            invoke_signed(instruction, account_views, signers)
+        })?
+    }
+
+    #[inline(always)]
+    pub fn with_instruction<R>(
+        &self,
+        f: impl FnOnce(&InstructionView, &[&AccountView; 2]) -> R,
+    ) -> Result<R, ProgramError> {
+        self.with_instruction_with_bounds::<DEFAULT_MAX_DATA_LEN, R>(f)
+    }
+
+    /// Build the instruction serializing its data into a buffer of
+    /// `MAX_DATA_LEN` bytes.
+    ///
+    /// Returns [`ProgramError::InvalidArgument`] if the serialized data does
+    /// not fit in the buffer.
+    #[inline(always)]
+    pub fn with_instruction_with_bounds<const MAX_DATA_LEN: usize, R>(
+        &self,
+        f: impl FnOnce(&InstructionView, &[&AccountView; 2]) -> R,
+    ) -> Result<R, ProgramError> {
+        // Instruction data layout:
+        // -  [0..8]: instruction discriminator
+        // -  [8]: idempotent flag
+        // -  [9..]: key as a borsh string
+        let mut instruction_data = [UNINIT_BYTE; MAX_DATA_LEN];
+
+        if MAX_DATA_LEN < Self::DISCRIMINATOR.len() + 1 {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        write_bytes(&mut instruction_data[..8], &Self::DISCRIMINATOR);
+        instruction_data[8].write(self.idempotent as u8);
+
+        let offset = write_str(&mut instruction_data, 9, self.key)?;
+
+        Ok(f(
+            &InstructionView {
+                program_id: self.token_program,
+                accounts: &[
+                    InstructionAccount::writable(self.metadata.address()),
+                    InstructionAccount::readonly_signer(self.update_authority.address()),
+                ],
+                // SAFETY: `instruction_data` was initialized for `offset` bytes.
+                data: unsafe { from_raw_parts(instruction_data.as_ptr() as _, offset) },
+            },
+            &[self.metadata, self.update_authority],
+        ))
+    }
+}
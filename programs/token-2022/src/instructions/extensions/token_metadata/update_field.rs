@@ -0,0 +1,153 @@
+use {
+    super::{write_str, DEFAULT_MAX_DATA_LEN},
+    crate::{
+        cpi::{invoke_signed, Signer},
+        write_bytes, UNINIT_BYTE,
+    },
+    core::slice::from_raw_parts,
+    solana_account_view::AccountView,
+    solana_address::Address,
+    solana_instruction_view::{InstructionAccount, InstructionView},
+    solana_program_error::{ProgramError, ProgramResult},
+};
+
+/// Fields in the metadata account.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Field<'a> {
+    /// The name field, corresponding to `TokenMetadata.name`.
+    Name,
+
+    /// The symbol field, corresponding to `TokenMetadata.symbol`.
+    Symbol,
+
+    /// The uri field, corresponding to `TokenMetadata.uri`.
+    Uri,
+
+    /// A user field, whose key is given by the associated string.
+    Key(&'a str),
+}
+
+impl Field<'_> {
+    /// Return the borsh variant index of the field.
+    #[inline(always)]
+    const fn discriminator(&self) -> u8 {
+        match self {
+            Field::Name => 0,
+            Field::Symbol => 1,
+            Field::Uri => 2,
+            Field::Key(_) => 3,
+        }
+    }
+}
+
+/// Update a field in a token metadata account.
+///
+/// If the field does not exist on the account, it will be created. If the
+/// field does exist, it will be overwritten.
+///
+/// By the end of the instruction, the metadata account must contain enough
+/// lamports to be rent-exempt.
+///
+/// Accounts expected by this instruction:
+///
+///   0. `[writable]` Metadata account.
+///   1. `[signer]` Update authority.
+pub struct UpdateField<'a, 'b> {
+    /// The metadata account.
+    pub metadata: &'a AccountView,
+
+    /// The update authority.
+    pub update_authority: &'a AccountView,
+
+    /// Field to update in the metadata.
+    pub field: Field<'b>,
+
+    /// Value to write for the field.
+    pub value: &'b str,
+
+    /// The token program.
+    pub token_program: &'b Address,
+}
+
+impl UpdateField<'_, '_> {
+    /// First 8 bytes of `sha256("spl_token_metadata_interface:updating_field")`.
+    pub const DISCRIMINATOR: [u8; 8] = [221, 233, 49, 45, 181, 202, 220, 200];
+
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    #[inline(always)]
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        self.invoke_signed_with_bounds::<DEFAULT_MAX_DATA_LEN>(signers)
+    }
+
+    /// Invoke the instruction serializing its data into a buffer of
+    /// `MAX_DATA_LEN` bytes.
+    ///
+    /// Returns [`ProgramError::InvalidArgument`] if the serialized data does
+    /// not fit in the buffer.
+    #[inline(always)]
+    pub fn invoke_signed_with_bounds<const MAX_DATA_LEN: usize>(
+        &self,
+        signers: &[Signer],
+    ) -> ProgramResult {
+        self.with_instruction_with_bounds::<MAX_DATA_LEN, _>(|instruction, account_views| {
+            invoke_signed(instruction, account_views, signers)
+        })?
+    }
+
+    #[inline(always)]
+    pub fn with_instruction<R>(
+        &self,
+        f: impl FnOnce(&InstructionView, &[&AccountView; 2]) -> R,
+    ) -> Result<R, ProgramError> {
+        self.with_instruction_with_bounds::<DEFAULT_MAX_DATA_LEN, R>(f)
+    }
+
+    /// Build the instruction serializing its data into a buffer of
+    /// `MAX_DATA_LEN` bytes.
+    ///
+    /// Returns [`ProgramError::InvalidArgument`] if the serialized data does
+    /// not fit in the buffer.
+    #[inline(always)]
+    pub fn with_instruction_with_bounds<const MAX_DATA_LEN: usize, R>(
+        &self,
+        f: impl FnOnce(&InstructionView, &[&AccountView; 2]) -> R,
+    ) -> Result<R, ProgramError> {
+        // Instruction data layout:
+        // -  [0..8]: instruction discriminator
+        // -  [8]: field variant
+        // -  [9..]: key (for `Field::Key`) and value as borsh strings
+        let mut instruction_data = [UNINIT_BYTE; MAX_DATA_LEN];
+
+        if MAX_DATA_LEN < Self::DISCRIMINATOR.len() + 1 {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        write_bytes(&mut instruction_data[..8], &Self::DISCRIMINATOR);
+        instruction_data[8].write(self.field.discriminator());
+
+        let mut offset = 9;
+
+        if let Field::Key(key) = self.field {
+            offset = write_str(&mut instruction_data, offset, key)?;
+        }
+
+        offset = write_str(&mut instruction_data, offset, self.value)?;
+
+        Ok(f(
+            &InstructionView {
+                program_id: self.token_program,
+                accounts: &[
+                    InstructionAccount::writable(self.metadata.address()),
+                    InstructionAccount::readonly_signer(self.update_authority.address()),
+                ],
+                // SAFETY: `instruction_data` was initialized for `offset` bytes.
+                data: unsafe { from_raw_parts(instruction_data.as_ptr() as _, offset) },
+            },
+            &[self.metadata, self.update_authority],
+        ))
+    }
+}
@@ -0,0 +1,163 @@
+use {
+    crate::{
+        cpi::{invoke_signed_with_bounds, Signer, MAX_STATIC_CPI_ACCOUNTS},
+        transfer_hook::EXECUTE_DISCRIMINATOR,
+        write_bytes, UNINIT_BYTE,
+    },
+    core::{
+        mem::MaybeUninit,
+        slice::{self, from_raw_parts},
+    },
+    solana_account_view::AccountView,
+    solana_address::Address,
+    solana_instruction_view::{InstructionAccount, InstructionView},
+    solana_program_error::{ProgramError, ProgramResult},
+};
+
+/// Number of fixed accounts of the `Execute` instruction.
+const EXECUTE_ACCOUNTS_LEN: usize = 5;
+
+/// Invoke the `Execute` instruction of a transfer hook program.
+///
+/// This instruction is part of the transfer hook interface and is handled by
+/// the hook program configured on the mint, not by the token program. It is
+/// useful for programs that need to run the hook of a mint directly.
+///
+/// The extra accounts are passed with the same writable and signer flags as
+/// their account views.
+///
+/// Accounts expected by this instruction:
+///
+///   0. `[]` Source account.
+///   1. `[]` Token mint.
+///   2. `[]` Destination account.
+///   3. `[]` Source account's owner/delegate.
+///   4. `[]` Validation account (`ExtraAccountMetaList`).
+///   5. `..5+M` `[]` `M` extra accounts required by the hook.
+pub struct Execute<'a, 'b, 'c> {
+    /// The source token account.
+    pub source: &'a AccountView,
+
+    /// The token mint.
+    pub mint: &'a AccountView,
+
+    /// The destination token account.
+    pub destination: &'a AccountView,
+
+    /// The source account's owner or delegate.
+    pub authority: &'a AccountView,
+
+    /// The validation account holding the `ExtraAccountMetaList`.
+    pub extra_account_metas: &'a AccountView,
+
+    /// The extra accounts required by the hook program.
+    pub extra_accounts: &'c [&'a AccountView],
+
+    /// The amount of tokens being transferred.
+    pub amount: u64,
+
+    /// The transfer hook program.
+    pub program_id: &'b Address,
+}
+
+impl Execute<'_, '_, '_> {
+    /// First 8 bytes of `sha256("spl-transfer-hook-interface:execute")`.
+    pub const DISCRIMINATOR: [u8; 8] = EXECUTE_DISCRIMINATOR;
+
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    #[inline(always)]
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        self.with_instruction(|instruction, account_views| {
+            invoke_signed_with_bounds::<MAX_STATIC_CPI_ACCOUNTS>(
+                instruction,
+                account_views,
+                signers,
+            )
+        })?
+    }
+
+    #[inline(always)]
+    pub fn with_instruction<R>(
+        &self,
+        f: impl FnOnce(&InstructionView, &[&AccountView]) -> R,
+    ) -> Result<R, ProgramError> {
+        if self.extra_accounts.len() > MAX_STATIC_CPI_ACCOUNTS - EXECUTE_ACCOUNTS_LEN {
+            Err(ProgramError::InvalidArgument)?;
+        }
+
+        let expected_accounts = EXECUTE_ACCOUNTS_LEN + self.extra_accounts.len();
+
+        // Instruction accounts.
+
+        let mut instruction_accounts =
+            [const { MaybeUninit::<InstructionAccount>::uninit() }; MAX_STATIC_CPI_ACCOUNTS];
+
+        instruction_accounts[0].write(InstructionAccount::readonly(self.source.address()));
+
+        instruction_accounts[1].write(InstructionAccount::readonly(self.mint.address()));
+
+        instruction_accounts[2].write(InstructionAccount::readonly(self.destination.address()));
+
+        instruction_accounts[3].write(InstructionAccount::readonly(self.authority.address()));
+
+        instruction_accounts[4].write(InstructionAccount::readonly(
+            self.extra_account_metas.address(),
+        ));
+
+        for (account, extra_account) in instruction_accounts[EXECUTE_ACCOUNTS_LEN..]
+            .iter_mut()
+            .zip(self.extra_accounts.iter())
+        {
+            account.write(InstructionAccount::from(*extra_account));
+        }
+
+        // Accounts.
+
+        let mut accounts =
+            [const { MaybeUninit::<&AccountView>::uninit() }; MAX_STATIC_CPI_ACCOUNTS];
+
+        accounts[0].write(self.source);
+
+        accounts[1].write(self.mint);
+
+        accounts[2].write(self.destination);
+
+        accounts[3].write(self.authority);
+
+        accounts[4].write(self.extra_account_metas);
+
+        for (account, extra_account) in accounts[EXECUTE_ACCOUNTS_LEN..]
+            .iter_mut()
+            .zip(self.extra_accounts.iter())
+        {
+            account.write(extra_account);
+        }
+
+        // Instruction data.
+
+        let mut instruction_data = [UNINIT_BYTE; 16];
+
+        write_bytes(&mut instruction_data[..8], &Self::DISCRIMINATOR);
+
+        write_bytes(&mut instruction_data[8..16], &self.amount.to_le_bytes());
+
+        Ok(f(
+            &InstructionView {
+                program_id: self.program_id,
+                // SAFETY: instruction accounts has `expected_accounts` initialized.
+                accounts: unsafe {
+                    from_raw_parts(instruction_accounts.as_ptr() as _, expected_accounts)
+                },
+                // SAFETY: `instruction_data` is initialized.
+                data: unsafe {
+                    from_raw_parts(instruction_data.as_ptr() as _, instruction_data.len())
+                },
+            }, // SAFETY: accounts has `expected_accounts` initialized.
+            unsafe { slice::from_raw_parts(accounts.as_ptr() as _, expected_accounts) },
+        ))
+    }
+}
@@ -1,4 +1,5 @@
+pub mod execute;
 pub mod initialize;
 pub mod update;
 
-pub use {initialize::*, update::*};
+pub use {execute::*, initialize::*, update::*};
@@ -0,0 +1,104 @@
+use {
+    crate::{
+        cpi::{get_return_data, invoke},
+        state::{ExtensionType, MAX_EXTENSIONS},
+        write_bytes, UNINIT_BYTE,
+    },
+    core::slice::from_raw_parts,
+    solana_account_view::AccountView,
+    solana_address::Address,
+    solana_instruction_view::{InstructionAccount, InstructionView},
+    solana_program_error::{ProgramError, ProgramResult},
+};
+
+/// Maximum number of extension types of a [`GetAccountDataSize`] instruction.
+///
+/// This is now an alias of [`MAX_EXTENSIONS`], which caps the number of
+/// extension types at 28 (previously 32).
+#[deprecated(note = "use `MAX_EXTENSIONS` instead")]
+pub const MAX_EXTENSION_TYPES: usize = MAX_EXTENSIONS;
+
+/// Get the required size of a token account for the given mint, including
+/// the account extensions required by the mint and the additional
+/// `extension_types`.
+///
+/// The size is set as return data and can be retrieved with
+/// [`GetAccountDataSize::invoke_and_get`].
+///
+/// Accounts expected by this instruction:
+///
+///   0. `[]` The mint to calculate for.
+pub struct GetAccountDataSize<'a, 'b> {
+    /// The mint to calculate for.
+    pub mint: &'a AccountView,
+
+    /// Additional extension types to include in the returned account size,
+    /// at most [`MAX_EXTENSIONS`] (28, previously 32).
+    pub extension_types: &'b [ExtensionType],
+
+    /// The token program.
+    pub token_program: &'b Address,
+}
+
+impl GetAccountDataSize<'_, '_> {
+    pub const DISCRIMINATOR: u8 = 21;
+
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.with_instruction(invoke)?
+    }
+
+    #[inline(always)]
+    pub fn with_instruction<R>(
+        &self,
+        f: impl FnOnce(&InstructionView, &[&AccountView; 1]) -> R,
+    ) -> Result<R, ProgramError> {
+        if self.extension_types.len() > MAX_EXTENSIONS {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        // Instruction data.
+
+        let mut instruction_data = [UNINIT_BYTE; 1 + 2 * MAX_EXTENSIONS];
+        let expected_data = 1 + 2 * self.extension_types.len();
+
+        instruction_data[0].write(Self::DISCRIMINATOR);
+
+        for (chunk, extension_type) in instruction_data[1..expected_data]
+            .chunks_exact_mut(2)
+            .zip(self.extension_types.iter())
+        {
+            write_bytes(chunk, &(*extension_type as u16).to_le_bytes());
+        }
+
+        Ok(f(
+            &InstructionView {
+                program_id: self.token_program,
+                accounts: &[InstructionAccount::readonly(self.mint.address())],
+                // SAFETY: `instruction_data` was initialized for `expected_data` bytes.
+                data: unsafe { from_raw_parts(instruction_data.as_ptr() as _, expected_data) },
+            },
+            &[self.mint],
+        ))
+    }
+
+    /// Invoke the instruction and return the account size set as return data.
+    ///
+    /// Returns [`ProgramError::InvalidAccountData`] if the return data is
+    /// missing, was not set by the token program or is not a `u64`.
+    #[inline(always)]
+    pub fn invoke_and_get(&self) -> Result<u64, ProgramError> {
+        self.invoke()?;
+
+        get_return_data()
+            .filter(|return_data| return_data.program_id() == self.token_program)
+            .and_then(|return_data| {
+                return_data
+                    .as_slice()
+                    .try_into()
+                    .ok()
+                    .map(u64::from_le_bytes)
+            })
+            .ok_or(ProgramError::InvalidAccountData)
+    }
+}
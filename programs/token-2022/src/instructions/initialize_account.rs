@@ -1,7 +1,8 @@
 use {
+    crate::cpi::invoke,
     solana_account_view::AccountView,
     solana_address::Address,
-    solana_instruction_view::{cpi::invoke, InstructionAccount, InstructionView},
+    solana_instruction_view::{InstructionAccount, InstructionView},
     solana_program_error::ProgramResult,
 };
 
@@ -28,6 +29,14 @@ pub struct InitializeAccount<'a, 'b> {
 impl InitializeAccount<'_, '_> {
     #[inline(always)]
     pub fn invoke(&self) -> ProgramResult {
+        self.with_instruction(invoke)
+    }
+
+    #[inline(always)]
+    pub fn with_instruction<R>(
+        &self,
+        f: impl FnOnce(&InstructionView, &[&AccountView; 4]) -> R,
+    ) -> R {
         // Instruction accounts
         let instruction_accounts: [InstructionAccount; 4] = [
             InstructionAccount::writable(self.account.address()),
@@ -42,7 +51,7 @@ impl InitializeAccount<'_, '_> {
             data: &[1],
         };
 
-        invoke(
+        f(
             &instruction,
             &[self.account, self.mint, self.owner, self.rent_sysvar],
         )
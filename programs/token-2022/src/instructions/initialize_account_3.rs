@@ -1,9 +1,9 @@
 use {
-    crate::{write_bytes, UNINIT_BYTE},
+    crate::{cpi::invoke, write_bytes, UNINIT_BYTE},
     core::slice::from_raw_parts,
     solana_account_view::AccountView,
     solana_address::Address,
-    solana_instruction_view::{cpi::invoke, InstructionAccount, InstructionView},
+    solana_instruction_view::{InstructionAccount, InstructionView},
     solana_program_error::ProgramResult,
 };
 
@@ -26,6 +26,14 @@ pub struct InitializeAccount3<'a, 'b> {
 impl InitializeAccount3<'_, '_> {
     #[inline(always)]
     pub fn invoke(&self) -> ProgramResult {
+        self.with_instruction(invoke)
+    }
+
+    #[inline(always)]
+    pub fn with_instruction<R>(
+        &self,
+        f: impl FnOnce(&InstructionView, &[&AccountView; 2]) -> R,
+    ) -> R {
         // Instruction accounts
         let instruction_accounts: [InstructionAccount; 2] = [
             InstructionAccount::writable(self.account.address()),
@@ -48,6 +56,6 @@ impl InitializeAccount3<'_, '_> {
             data: unsafe { from_raw_parts(instruction_data.as_ptr() as _, 33) },
         };
 
-        invoke(&instruction, &[self.account, self.mint])
+        f(&instruction, &[self.account, self.mint])
     }
 }
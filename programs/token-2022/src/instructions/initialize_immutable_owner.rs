@@ -0,0 +1,52 @@
+use {
+    crate::cpi::invoke,
+    solana_account_view::AccountView,
+    solana_address::Address,
+    solana_instruction_view::{InstructionAccount, InstructionView},
+    solana_program_error::ProgramResult,
+};
+
+/// Initialize the immutable owner extension for the given token account.
+///
+/// Fails if the account has already been initialized, so must be called
+/// before `InitializeAccount`.
+///
+/// The account must have exactly enough space allocated for the base token
+/// account (165 bytes), 1 byte reserved for the account type, then space
+/// required for this extension, plus any others. The immutable owner
+/// extension has no data, so it only adds its 4-byte TLV header.
+///
+/// Accounts expected by this instruction:
+///
+///   0. `[writable]`  The account to initialize.
+pub struct InitializeImmutableOwner<'a, 'b> {
+    /// The account to initialize.
+    pub account: &'a AccountView,
+
+    /// The token program.
+    pub token_program: &'b Address,
+}
+
+impl InitializeImmutableOwner<'_, '_> {
+    pub const DISCRIMINATOR: u8 = 22;
+
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.with_instruction(invoke)
+    }
+
+    #[inline(always)]
+    pub fn with_instruction<R>(
+        &self,
+        f: impl FnOnce(&InstructionView, &[&AccountView; 1]) -> R,
+    ) -> R {
+        f(
+            &InstructionView {
+                program_id: self.token_program,
+                accounts: &[InstructionAccount::writable(self.account.address())],
+                data: &[Self::DISCRIMINATOR],
+            },
+            &[self.account],
+        )
+    }
+}
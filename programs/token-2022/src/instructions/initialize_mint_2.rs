@@ -1,9 +1,9 @@
 use {
-    crate::{write_bytes, UNINIT_BYTE},
+    crate::{cpi::invoke, write_bytes, UNINIT_BYTE},
     core::slice::from_raw_parts,
     solana_account_view::AccountView,
     solana_address::Address,
-    solana_instruction_view::{cpi::invoke, InstructionAccount, InstructionView},
+    solana_instruction_view::{InstructionAccount, InstructionView},
     solana_program_error::ProgramResult,
 };
 
@@ -27,6 +27,14 @@ pub struct InitializeMint2<'a, 'b> {
 impl InitializeMint2<'_, '_> {
     #[inline(always)]
     pub fn invoke(&self) -> ProgramResult {
+        self.with_instruction(invoke)
+    }
+
+    #[inline(always)]
+    pub fn with_instruction<R>(
+        &self,
+        f: impl FnOnce(&InstructionView, &[&AccountView; 1]) -> R,
+    ) -> R {
         // Instruction accounts
         let instruction_accounts: [InstructionAccount; 1] =
             [InstructionAccount::writable(self.mint.address())];
@@ -64,6 +72,6 @@ impl InitializeMint2<'_, '_> {
             data: unsafe { from_raw_parts(instruction_data.as_ptr() as _, length) },
         };
 
-        invoke(&instruction, &[self.mint])
+        f(&instruction, &[self.mint])
     }
 }
@@ -1,8 +1,9 @@
 use {
+    crate::cpi::invoke_with_bounds,
     core::{mem::MaybeUninit, slice},
     solana_account_view::AccountView,
     solana_address::Address,
-    solana_instruction_view::{cpi::invoke_with_bounds, InstructionAccount, InstructionView},
+    solana_instruction_view::{InstructionAccount, InstructionView},
     solana_program_error::{ProgramError, ProgramResult},
 };
 
@@ -35,6 +36,16 @@ where
 impl InitializeMultisig<'_, '_, '_> {
     #[inline(always)]
     pub fn invoke(&self) -> ProgramResult {
+        self.with_instruction(|instruction, account_views| {
+            invoke_with_bounds::<{ 2 + MAX_MULTISIG_SIGNERS }>(instruction, account_views)
+        })?
+    }
+
+    #[inline(always)]
+    pub fn with_instruction<R>(
+        &self,
+        f: impl FnOnce(&InstructionView, &[&AccountView]) -> R,
+    ) -> Result<R, ProgramError> {
         let &Self {
             multisig,
             rent_sysvar,
@@ -102,8 +113,8 @@ impl InitializeMultisig<'_, '_, '_> {
             account_view.write(signer);
         }
 
-        invoke_with_bounds::<{ 2 + MAX_MULTISIG_SIGNERS }>(&instruction, unsafe {
+        Ok(f(&instruction, unsafe {
             slice::from_raw_parts(acc_views.as_ptr() as _, num_accounts)
-        })
+        }))
     }
 }
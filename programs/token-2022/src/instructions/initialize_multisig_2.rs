@@ -1,9 +1,9 @@
 use {
-    crate::instructions::MAX_MULTISIG_SIGNERS,
+    crate::{cpi::invoke_with_bounds, instructions::MAX_MULTISIG_SIGNERS},
     core::{mem::MaybeUninit, slice},
     solana_account_view::AccountView,
     solana_address::Address,
-    solana_instruction_view::{cpi::invoke_with_bounds, InstructionAccount, InstructionView},
+    solana_instruction_view::{InstructionAccount, InstructionView},
     solana_program_error::{ProgramError, ProgramResult},
 };
 
@@ -30,6 +30,16 @@ where
 impl InitializeMultisig2<'_, '_, '_> {
     #[inline(always)]
     pub fn invoke(&self) -> ProgramResult {
+        self.with_instruction(|instruction, account_views| {
+            invoke_with_bounds::<{ 1 + MAX_MULTISIG_SIGNERS }>(instruction, account_views)
+        })?
+    }
+
+    #[inline(always)]
+    pub fn with_instruction<R>(
+        &self,
+        f: impl FnOnce(&InstructionView, &[&AccountView]) -> R,
+    ) -> Result<R, ProgramError> {
         let &Self {
             multisig,
             signers,
@@ -92,8 +102,8 @@ impl InitializeMultisig2<'_, '_, '_> {
             account_view.write(signer);
         }
 
-        invoke_with_bounds::<{ 1 + MAX_MULTISIG_SIGNERS }>(&instruction, unsafe {
+        Ok(f(&instruction, unsafe {
             slice::from_raw_parts(acc_views.as_ptr() as _, num_accounts)
-        })
+        }))
     }
 }
@@ -1,15 +1,26 @@
 use {
+    crate::cpi::invoke,
     solana_account_view::AccountView,
     solana_address::Address,
-    solana_instruction_view::{cpi::invoke, InstructionAccount, InstructionView},
+    solana_instruction_view::{InstructionAccount, InstructionView},
     solana_program_error::ProgramResult,
 };
 
-/// Initialize the non transferable extension for the given mint account.
+/// Initialize the non-transferable extension for the given mint account.
 ///
 /// Fails if the account has already been initialized, so must be called
 /// before `InitializeMint`.
 ///
+/// The mint must have exactly enough space allocated for the base mint (82
+/// bytes), plus 83 bytes of padding, 1 byte reserved for the account type,
+/// then space required for this extension, plus any others. The
+/// non-transferable extension has no data, so it only adds its 4-byte TLV
+/// header.
+///
+/// Tokens of a non-transferable mint can only be minted to, burned from or
+/// have their accounts closed by their owner, which makes them suitable for
+/// "soulbound" tokens.
+///
 /// Accounts expected by this instruction:
 ///
 ///   0. `[writable]`  The mint account to initialize.
@@ -26,7 +37,15 @@ impl InitializeNonTransferableMint<'_, '_> {
 
     #[inline(always)]
     pub fn invoke(&self) -> ProgramResult {
-        invoke(
+        self.with_instruction(invoke)
+    }
+
+    #[inline(always)]
+    pub fn with_instruction<R>(
+        &self,
+        f: impl FnOnce(&InstructionView, &[&AccountView; 1]) -> R,
+    ) -> R {
+        f(
             &InstructionView {
                 program_id: self.token_program,
                 accounts: &[InstructionAccount::writable(self.mint.address())],
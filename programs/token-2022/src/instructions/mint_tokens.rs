@@ -0,0 +1,91 @@
+use {
+    super::{MintTo, MintToChecked},
+    crate::token_program::TokenProgram,
+    solana_account_view::AccountView,
+    solana_address::Address,
+    solana_instruction_view::{cpi::Signer, InstructionView},
+    solana_program_error::ProgramResult,
+};
+
+/// Mint tokens using the instruction best suited to the token program.
+///
+/// Programs that do not require checked instructions, such as the legacy SPL
+/// Token program, are invoked with [`MintTo`]. Any other program, such as
+/// Token-2022, is invoked with [`MintToChecked`].
+///
+/// The token program can be given as an [`Address`] or as one of the
+/// [`TokenProgram`] markers.
+///
+/// ### Accounts:
+///   0. `[WRITE]` The mint.
+///   1. `[WRITE]` The account to mint tokens to.
+///   2. `[SIGNER]` The mint's minting authority.
+pub struct MintTokens<'a, 'b, P: TokenProgram + ?Sized = Address> {
+    /// Mint Account.
+    pub mint: &'a AccountView,
+    /// Token Account.
+    pub account: &'a AccountView,
+    /// Mint Authority
+    pub mint_authority: &'a AccountView,
+    /// Amount
+    pub amount: u64,
+    /// Decimals
+    pub decimals: u8,
+    /// Token Program
+    pub token_program: &'b P,
+}
+
+impl<P: TokenProgram + ?Sized> MintTokens<'_, '_, P> {
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    #[inline(always)]
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        if !self.token_program.requires_checked_instructions() {
+            MintTo {
+                mint: self.mint,
+                account: self.account,
+                mint_authority: self.mint_authority,
+                amount: self.amount,
+                token_program: self.token_program.id(),
+            }
+            .invoke_signed(signers)
+        } else {
+            MintToChecked {
+                mint: self.mint,
+                account: self.account,
+                mint_authority: self.mint_authority,
+                amount: self.amount,
+                decimals: self.decimals,
+                token_program: self.token_program.id(),
+            }
+            .invoke_signed(signers)
+        }
+    }
+
+    #[inline(always)]
+    pub fn with_instruction<R>(&self, f: impl FnOnce(&InstructionView, &[&AccountView]) -> R) -> R {
+        if !self.token_program.requires_checked_instructions() {
+            MintTo {
+                mint: self.mint,
+                account: self.account,
+                mint_authority: self.mint_authority,
+                amount: self.amount,
+                token_program: self.token_program.id(),
+            }
+            .with_instruction(|instruction, account_views| f(instruction, account_views))
+        } else {
+            MintToChecked {
+                mint: self.mint,
+                account: self.account,
+                mint_authority: self.mint_authority,
+                amount: self.amount,
+                decimals: self.decimals,
+                token_program: self.token_program.id(),
+            }
+            .with_instruction(|instruction, account_views| f(instruction, account_views))
+        }
+    }
+}
@@ -1,14 +1,20 @@
+mod amount_to_ui_amount;
 mod approve;
 mod approve_checked;
+mod batch_transfer_checked;
 mod burn;
 mod burn_checked;
+mod burn_tokens;
 mod close_account;
+mod create_multisig;
 mod create_native_mint;
 mod extensions;
 mod freeze_account;
+mod get_account_data_size;
 mod initialize_account;
 mod initialize_account_2;
 mod initialize_account_3;
+mod initialize_immutable_owner;
 mod initialize_mint;
 mod initialize_mint_2;
 mod initialize_multisig;
@@ -16,20 +22,27 @@ mod initialize_multisig_2;
 mod initialize_non_transferable_mint;
 mod mint_to;
 mod mint_to_checked;
+mod mint_tokens;
+mod reallocate;
 mod revoke;
 mod set_authority;
 mod sync_native;
 mod thaw_account;
 mod transfer;
 mod transfer_checked;
+mod transfer_tokens;
+mod ui_amount_to_amount;
 mod unwrap_lamports;
 mod withdraw_excess_lamports;
 
 pub use {
-    approve::*, approve_checked::*, burn::*, burn_checked::*, close_account::*,
-    create_native_mint::*, extensions::*, freeze_account::*, initialize_account::*,
-    initialize_account_2::*, initialize_account_3::*, initialize_mint::*, initialize_mint_2::*,
-    initialize_multisig::*, initialize_multisig_2::*, initialize_non_transferable_mint::*,
-    mint_to::*, mint_to_checked::*, revoke::*, set_authority::*, sync_native::*, thaw_account::*,
-    transfer::*, transfer_checked::*, unwrap_lamports::*, withdraw_excess_lamports::*,
+    amount_to_ui_amount::*, approve::*, approve_checked::*, batch_transfer_checked::*, burn::*,
+    burn_checked::*, burn_tokens::*, close_account::*, create_multisig::*, create_native_mint::*,
+    extensions::*, freeze_account::*, get_account_data_size::*, initialize_account::*,
+    initialize_account_2::*, initialize_account_3::*, initialize_immutable_owner::*,
+    initialize_mint::*, initialize_mint_2::*, initialize_multisig::*, initialize_multisig_2::*,
+    initialize_non_transferable_mint::*, mint_to::*, mint_to_checked::*, mint_tokens::*,
+    reallocate::*, revoke::*, set_authority::*, sync_native::*, thaw_account::*, transfer::*,
+    transfer_checked::*, transfer_tokens::*, ui_amount_to_amount::*, unwrap_lamports::*,
+    withdraw_excess_lamports::*,
 };
@@ -0,0 +1,203 @@
+use {
+    crate::{
+        cpi::{invoke_signed_with_bounds, Signer},
+        instructions::MAX_MULTISIG_SIGNERS,
+        state::{ExtensionType, MAX_EXTENSIONS},
+        write_bytes, UNINIT_BYTE,
+    },
+    core::{mem::MaybeUninit, slice::from_raw_parts},
+    solana_account_view::AccountView,
+    solana_address::Address,
+    solana_instruction_view::{InstructionAccount, InstructionView},
+    solana_program_error::{ProgramError, ProgramResult},
+};
+
+/// Check to see if a token account is large enough for a list of
+/// extension types, and if not, use reallocation to increase the data
+/// size.
+///
+/// Accounts expected by this instruction:
+///
+///   * Single owner
+///   0. `[writable]` The account to reallocate.
+///   1. `[signer, writable]` The payer account to fund reallocation.
+///   2. `[]` System program for reallocation funding.
+///   3. `[signer]` The account's owner.
+///
+///   * Multisignature owner
+///   0. `[writable]` The account to reallocate.
+///   1. `[signer, writable]` The payer account to fund reallocation.
+///   2. `[]` System program for reallocation funding.
+///   3. `[]` The account's multisignature owner/delegate.
+///   4. ..`4+M` `[signer]` M signer accounts.
+pub struct Reallocate<'a, 'b, 'c> {
+    /// The account to reallocate.
+    pub account: &'a AccountView,
+
+    /// The payer account to fund reallocation.
+    pub payer: &'a AccountView,
+
+    /// System program for reallocation funding.
+    pub system_program: &'a AccountView,
+
+    /// The account's owner.
+    pub owner: &'a AccountView,
+
+    /// The signer accounts if `owner` is a multisig.
+    pub multisig_signers: &'c [&'a AccountView],
+
+    /// New extension types to include in the reallocated account, at most
+    /// [`MAX_EXTENSIONS`].
+    pub extension_types: &'b [ExtensionType],
+
+    /// The token program.
+    pub token_program: &'b Address,
+}
+
+impl<'a, 'b, 'c> Reallocate<'a, 'b, 'c> {
+    pub const DISCRIMINATOR: u8 = 29;
+
+    /// Creates a new `Reallocate` instruction with a single owner.
+    #[inline(always)]
+    pub fn new(
+        token_program: &'b Address,
+        account: &'a AccountView,
+        payer: &'a AccountView,
+        system_program: &'a AccountView,
+        owner: &'a AccountView,
+        extension_types: &'b [ExtensionType],
+    ) -> Self {
+        Self::with_multisig_signers(
+            token_program,
+            account,
+            payer,
+            system_program,
+            owner,
+            extension_types,
+            &[],
+        )
+    }
+
+    /// Creates a new `Reallocate` instruction with a multisignature owner and
+    /// signer accounts.
+    #[inline(always)]
+    pub fn with_multisig_signers(
+        token_program: &'b Address,
+        account: &'a AccountView,
+        payer: &'a AccountView,
+        system_program: &'a AccountView,
+        owner: &'a AccountView,
+        extension_types: &'b [ExtensionType],
+        multisig_signers: &'c [&'a AccountView],
+    ) -> Self {
+        Self {
+            account,
+            payer,
+            system_program,
+            owner,
+            multisig_signers,
+            extension_types,
+            token_program,
+        }
+    }
+
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    #[inline(always)]
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        self.with_instruction(|instruction, account_views| {
+            invoke_signed_with_bounds::<{ 4 + MAX_MULTISIG_SIGNERS }>(
+                instruction,
+                account_views,
+                signers,
+            )
+        })?
+    }
+
+    #[inline(always)]
+    pub fn with_instruction<R>(
+        &self,
+        f: impl FnOnce(&InstructionView, &[&AccountView]) -> R,
+    ) -> Result<R, ProgramError> {
+        if self.multisig_signers.len() > MAX_MULTISIG_SIGNERS
+            || self.extension_types.len() > MAX_EXTENSIONS
+        {
+            Err(ProgramError::InvalidArgument)?;
+        }
+
+        let expected_accounts = 4 + self.multisig_signers.len();
+
+        // Instruction accounts.
+
+        let mut instruction_accounts =
+            [const { MaybeUninit::<InstructionAccount>::uninit() }; 4 + MAX_MULTISIG_SIGNERS];
+
+        instruction_accounts[0].write(InstructionAccount::writable(self.account.address()));
+
+        instruction_accounts[1].write(InstructionAccount::writable_signer(self.payer.address()));
+
+        instruction_accounts[2].write(InstructionAccount::readonly(self.system_program.address()));
+
+        instruction_accounts[3].write(InstructionAccount::new(
+            self.owner.address(),
+            false,
+            self.multisig_signers.is_empty(),
+        ));
+
+        for (account, signer) in instruction_accounts[4..]
+            .iter_mut()
+            .zip(self.multisig_signers.iter())
+        {
+            account.write(InstructionAccount::readonly_signer(signer.address()));
+        }
+
+        // Accounts.
+
+        let mut accounts =
+            [const { MaybeUninit::<&AccountView>::uninit() }; 4 + MAX_MULTISIG_SIGNERS];
+
+        accounts[0].write(self.account);
+
+        accounts[1].write(self.payer);
+
+        accounts[2].write(self.system_program);
+
+        accounts[3].write(self.owner);
+
+        for (account, signer) in accounts[4..].iter_mut().zip(self.multisig_signers.iter()) {
+            account.write(signer);
+        }
+
+        // Instruction data.
+        // - [0]: instruction discriminator (1 byte, u8)
+        // - [1..]: extension types (2 bytes each, u16)
+
+        let mut instruction_data = [UNINIT_BYTE; 1 + 2 * MAX_EXTENSIONS];
+        let expected_data = 1 + 2 * self.extension_types.len();
+
+        instruction_data[0].write(Self::DISCRIMINATOR);
+
+        for (chunk, extension_type) in instruction_data[1..expected_data]
+            .chunks_exact_mut(2)
+            .zip(self.extension_types.iter())
+        {
+            write_bytes(chunk, &(*extension_type as u16).to_le_bytes());
+        }
+
+        Ok(f(
+            &InstructionView {
+                program_id: self.token_program,
+                // SAFETY: instruction accounts has `expected_accounts` initialized.
+                accounts: unsafe {
+                    from_raw_parts(instruction_accounts.as_ptr() as _, expected_accounts)
+                },
+                // SAFETY: `instruction_data` was initialized for `expected_data` bytes.
+                data: unsafe { from_raw_parts(instruction_data.as_ptr() as _, expected_data) },
+            }, // SAFETY: accounts has `expected_accounts` initialized.
+            unsafe { from_raw_parts(accounts.as_ptr() as _, expected_accounts) },
+        ))
+    }
+}
@@ -1,10 +1,8 @@
 use {
+    crate::cpi::{invoke_signed, Signer},
     solana_account_view::AccountView,
     solana_address::Address,
-    solana_instruction_view::{
-        cpi::{invoke_signed, Signer},
-        InstructionAccount, InstructionView,
-    },
+    solana_instruction_view::{InstructionAccount, InstructionView},
     solana_program_error::ProgramResult,
 };
 
@@ -30,6 +28,16 @@ impl Revoke<'_, '_> {
 
     #[inline(always)]
     pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        self.with_instruction(|instruction, account_views| {
+            invoke_signed(instruction, account_views, signers)
+        })
+    }
+
+    #[inline(always)]
+    pub fn with_instruction<R>(
+        &self,
+        f: impl FnOnce(&InstructionView, &[&AccountView; 2]) -> R,
+    ) -> R {
         // Instruction accounts
         let instruction_accounts: [InstructionAccount; 2] = [
             InstructionAccount::writable(self.source.address()),
@@ -42,6 +50,6 @@ impl Revoke<'_, '_> {
             data: &[5],
         };
 
-        invoke_signed(&instruction, &[self.source, self.authority], signers)
+        f(&instruction, &[self.source, self.authority])
     }
 }
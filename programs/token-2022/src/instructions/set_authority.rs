@@ -1,22 +1,54 @@
 use {
-    crate::{write_bytes, UNINIT_BYTE},
+    crate::{
+        cpi::{invoke_signed, Signer},
+        write_bytes, UNINIT_BYTE,
+    },
     core::slice::from_raw_parts,
     solana_account_view::AccountView,
     solana_address::Address,
-    solana_instruction_view::{
-        cpi::{invoke_signed, Signer},
-        InstructionAccount, InstructionView,
-    },
+    solana_instruction_view::{InstructionAccount, InstructionView},
     solana_program_error::ProgramResult,
 };
 
+/// Specifies the authority type for `SetAuthority` instructions.
 #[repr(u8)]
 #[derive(Clone, Copy)]
 pub enum AuthorityType {
+    /// Authority to mint new tokens.
     MintTokens = 0,
+    /// Authority to freeze any account associated with the mint.
     FreezeAccount = 1,
+    /// Owner of a given token account.
     AccountOwner = 2,
+    /// Authority to close a token account.
     CloseAccount = 3,
+    /// Authority to set the transfer fee.
+    TransferFeeConfig = 4,
+    /// Authority to withdraw withheld tokens from a mint.
+    WithheldWithdraw = 5,
+    /// Authority to close a mint account.
+    CloseMint = 6,
+    /// Authority to set the interest rate.
+    InterestRate = 7,
+    /// Authority to transfer or burn any tokens for a mint.
+    PermanentDelegate = 8,
+    /// Authority to update confidential transfer mint and approve accounts
+    /// for confidential transfers.
+    ConfidentialTransferMint = 9,
+    /// Authority to set the transfer hook program id.
+    TransferHookProgramId = 10,
+    /// Authority to set the withdraw withheld authority encryption key.
+    ConfidentialTransferFeeConfig = 11,
+    /// Authority to set the metadata address.
+    MetadataPointer = 12,
+    /// Authority to set the group address.
+    GroupPointer = 13,
+    /// Authority to set the group member address.
+    GroupMemberPointer = 14,
+    /// Authority to set the UI amount scale.
+    ScaledUiAmount = 15,
+    /// Authority to pause or resume minting, burning and transferring.
+    Pause = 16,
 }
 
 /// Sets a new authority of a mint or account.
@@ -45,6 +77,16 @@ impl SetAuthority<'_, '_> {
 
     #[inline(always)]
     pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        self.with_instruction(|instruction, account_views| {
+            invoke_signed(instruction, account_views, signers)
+        })
+    }
+
+    #[inline(always)]
+    pub fn with_instruction<R>(
+        &self,
+        f: impl FnOnce(&InstructionView, &[&AccountView; 2]) -> R,
+    ) -> R {
         // Instruction accounts
         let instruction_accounts: [InstructionAccount; 2] = [
             InstructionAccount::writable(self.account.address()),
@@ -80,6 +122,6 @@ impl SetAuthority<'_, '_> {
             data: unsafe { from_raw_parts(instruction_data.as_ptr() as _, length) },
         };
 
-        invoke_signed(&instruction, &[self.account, self.authority], signers)
+        f(&instruction, &[self.account, self.authority])
     }
 }
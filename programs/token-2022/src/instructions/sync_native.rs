@@ -1,7 +1,8 @@
 use {
+    crate::cpi::invoke,
     solana_account_view::AccountView,
     solana_address::Address,
-    solana_instruction_view::{cpi::invoke, InstructionAccount, InstructionView},
+    solana_instruction_view::{InstructionAccount, InstructionView},
     solana_program_error::ProgramResult,
 };
 
@@ -21,6 +22,14 @@ pub struct SyncNative<'a, 'b> {
 impl SyncNative<'_, '_> {
     #[inline(always)]
     pub fn invoke(&self) -> ProgramResult {
+        self.with_instruction(invoke)
+    }
+
+    #[inline(always)]
+    pub fn with_instruction<R>(
+        &self,
+        f: impl FnOnce(&InstructionView, &[&AccountView; 1]) -> R,
+    ) -> R {
         // Instruction accounts
         let instruction_accounts: [InstructionAccount; 1] =
             [InstructionAccount::writable(self.native_token.address())];
@@ -31,6 +40,6 @@ impl SyncNative<'_, '_> {
             data: &[17],
         };
 
-        invoke(&instruction, &[self.native_token])
+        f(&instruction, &[self.native_token])
     }
 }
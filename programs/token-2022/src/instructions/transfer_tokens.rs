@@ -0,0 +1,102 @@
+use {
+    super::{Transfer, TransferChecked},
+    crate::token_program::TokenProgram,
+    solana_account_view::AccountView,
+    solana_address::Address,
+    solana_instruction_view::{cpi::Signer, InstructionView},
+    solana_program_error::ProgramResult,
+};
+
+/// Address of the legacy SPL Token program.
+pub const LEGACY_TOKEN_PROGRAM_ID: Address =
+    solana_address::address!("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA");
+
+/// Transfer tokens using the instruction best suited to the token program.
+///
+/// Programs that do not require checked instructions, such as the legacy SPL
+/// Token program, are invoked with [`Transfer`], which does not need the
+/// mint. Any other program, such as Token-2022, is invoked with
+/// [`TransferChecked`], since extensions like transfer fees and transfer
+/// hooks reject unchecked transfers.
+///
+/// The token program can be given as an [`Address`] or as one of the
+/// [`TokenProgram`] markers.
+///
+/// ### Accounts:
+///   0. `[WRITE]` The source account.
+///   1. `[]` The token mint (unused for the legacy program).
+///   2. `[WRITE]` The destination account.
+///   3. `[SIGNER]` The source account's owner/delegate.
+pub struct TransferTokens<'a, 'b, P: TokenProgram + ?Sized = Address> {
+    /// Sender account.
+    pub from: &'a AccountView,
+    /// Mint Account
+    pub mint: &'a AccountView,
+    /// Recipient account.
+    pub to: &'a AccountView,
+    /// Authority account.
+    pub authority: &'a AccountView,
+    /// Amount of micro-tokens to transfer.
+    pub amount: u64,
+    /// Decimal for the Token
+    pub decimals: u8,
+    /// Token Program
+    pub token_program: &'b P,
+}
+
+impl<P: TokenProgram + ?Sized> TransferTokens<'_, '_, P> {
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    #[inline(always)]
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        if !self.token_program.requires_checked_instructions() {
+            Transfer {
+                from: self.from,
+                to: self.to,
+                authority: self.authority,
+                amount: self.amount,
+                token_program: self.token_program.id(),
+            }
+            .invoke_signed(signers)
+        } else {
+            TransferChecked {
+                from: self.from,
+                mint: self.mint,
+                to: self.to,
+                authority: self.authority,
+                amount: self.amount,
+                decimals: self.decimals,
+                token_program: self.token_program.id(),
+            }
+            .invoke_signed(signers)
+        }
+    }
+
+    #[inline(always)]
+    pub fn with_instruction<R>(&self, f: impl FnOnce(&InstructionView, &[&AccountView]) -> R) -> R {
+        if !self.token_program.requires_checked_instructions() {
+            Transfer {
+                from: self.from,
+                to: self.to,
+                authority: self.authority,
+                amount: self.amount,
+                token_program: self.token_program.id(),
+            }
+            .with_instruction(|instruction, account_views| f(instruction, account_views))
+        } else {
+            TransferChecked {
+                from: self.from,
+                mint: self.mint,
+                to: self.to,
+                authority: self.authority,
+                amount: self.amount,
+                decimals: self.decimals,
+                token_program: self.token_program.id(),
+            }
+            .with_instruction(|instruction, account_views| f(instruction, account_views))
+        }
+    }
+}
@@ -0,0 +1,98 @@
+use {
+    crate::{
+        cpi::{get_return_data, invoke},
+        write_bytes, UNINIT_BYTE,
+    },
+    core::slice::from_raw_parts,
+    solana_account_view::AccountView,
+    solana_address::Address,
+    solana_instruction_view::{InstructionAccount, InstructionView},
+    solana_program_error::{ProgramError, ProgramResult},
+};
+
+/// Maximum length of the UI amount string of an [`UiAmountToAmount`]
+/// instruction.
+pub const MAX_UI_AMOUNT_LEN: usize = 64;
+
+/// Convert a UI amount string of tokens to a raw amount, using the given
+/// mint.
+///
+/// For mints with the interest-bearing or scaled UI amount extensions, the
+/// interest or multiplier is removed from the UI amount. The amount is set as
+/// return data and can be retrieved with [`UiAmountToAmount::invoke_and_get`].
+///
+/// Accounts expected by this instruction:
+///
+///   0. `[]` The mint to calculate for.
+pub struct UiAmountToAmount<'a, 'b> {
+    /// The mint to calculate for.
+    pub mint: &'a AccountView,
+
+    /// The UI amount of tokens to convert, at most [`MAX_UI_AMOUNT_LEN`]
+    /// bytes long.
+    pub ui_amount: &'b str,
+
+    /// The token program.
+    pub token_program: &'b Address,
+}
+
+impl UiAmountToAmount<'_, '_> {
+    pub const DISCRIMINATOR: u8 = 24;
+
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.with_instruction(invoke)?
+    }
+
+    #[inline(always)]
+    pub fn with_instruction<R>(
+        &self,
+        f: impl FnOnce(&InstructionView, &[&AccountView; 1]) -> R,
+    ) -> Result<R, ProgramError> {
+        if self.ui_amount.len() > MAX_UI_AMOUNT_LEN {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        // Instruction data.
+
+        let mut instruction_data = [UNINIT_BYTE; 1 + MAX_UI_AMOUNT_LEN];
+        let expected_data = 1 + self.ui_amount.len();
+
+        instruction_data[0].write(Self::DISCRIMINATOR);
+
+        write_bytes(
+            &mut instruction_data[1..expected_data],
+            self.ui_amount.as_bytes(),
+        );
+
+        Ok(f(
+            &InstructionView {
+                program_id: self.token_program,
+                accounts: &[InstructionAccount::readonly(self.mint.address())],
+                // SAFETY: `instruction_data` was initialized for `expected_data` bytes.
+                data: unsafe { from_raw_parts(instruction_data.as_ptr() as _, expected_data) },
+            },
+            &[self.mint],
+        ))
+    }
+
+    /// Invoke the instruction and return the amount set as return data.
+    ///
+    /// Returns [`ProgramError::InvalidAccountData`] if the return data is
+    /// missing, was not set by the token program or is not a `u64`.
+    #[inline(always)]
+    pub fn invoke_and_get(&self) -> Result<u64, ProgramError> {
+        self.invoke()?;
+
+        get_return_data()
+            .filter(|return_data| return_data.program_id() == self.token_program)
+            .and_then(|return_data| {
+                return_data
+                    .as_slice()
+                    .try_into()
+                    .ok()
+                    .map(u64::from_le_bytes)
+            })
+            .ok_or(ProgramError::InvalidAccountData)
+    }
+}
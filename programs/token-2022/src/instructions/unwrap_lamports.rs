@@ -1,12 +1,13 @@
 use {
-    crate::{instructions::MAX_MULTISIG_SIGNERS, write_bytes, UNINIT_BYTE},
+    crate::{
+        cpi::{invoke_signed_with_bounds, Signer},
+        instructions::MAX_MULTISIG_SIGNERS,
+        write_bytes, UNINIT_BYTE,
+    },
     core::{mem::MaybeUninit, slice::from_raw_parts},
     solana_account_view::AccountView,
     solana_address::Address,
-    solana_instruction_view::{
-        cpi::{invoke_signed_with_bounds, Signer},
-        InstructionAccount, InstructionView,
-    },
+    solana_instruction_view::{InstructionAccount, InstructionView},
     solana_program_error::{ProgramError, ProgramResult},
 };
 
@@ -90,6 +91,20 @@ impl<'a, 'b, 'c> UnwrapLamports<'a, 'b, 'c> {
 
     #[inline(always)]
     pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        self.with_instruction(|instruction, account_views| {
+            invoke_signed_with_bounds::<{ 3 + MAX_MULTISIG_SIGNERS }>(
+                instruction,
+                account_views,
+                signers,
+            )
+        })?
+    }
+
+    #[inline(always)]
+    pub fn with_instruction<R>(
+        &self,
+        f: impl FnOnce(&InstructionView, &[&AccountView]) -> R,
+    ) -> Result<R, ProgramError> {
         if self.multisig_signers.len() > MAX_MULTISIG_SIGNERS {
             Err(ProgramError::InvalidArgument)?;
         }
@@ -149,7 +164,7 @@ impl<'a, 'b, 'c> UnwrapLamports<'a, 'b, 'c> {
             instruction_data[1].write(0);
         }
 
-        invoke_signed_with_bounds::<{ 3 + MAX_MULTISIG_SIGNERS }>(
+        Ok(f(
             &InstructionView {
                 program_id: self.token_program,
                 // SAFETY: instruction accounts have `expected_accounts` initialized.
@@ -158,10 +173,8 @@ impl<'a, 'b, 'c> UnwrapLamports<'a, 'b, 'c> {
                 },
                 // SAFETY: instruction data has `expected_data` initialized.
                 data: unsafe { from_raw_parts(instruction_data.as_ptr() as _, expected_data) },
-            },
-            // SAFETY: accounts have `expected_accounts` initialized.
+            }, // SAFETY: accounts have `expected_accounts` initialized.
             unsafe { from_raw_parts(accounts.as_ptr() as _, expected_accounts) },
-            signers,
-        )
+        ))
     }
 }
@@ -1,12 +1,12 @@
 use {
-    crate::instructions::MAX_MULTISIG_SIGNERS,
+    crate::{
+        cpi::{invoke_signed_with_bounds, Signer},
+        instructions::MAX_MULTISIG_SIGNERS,
+    },
     core::{mem::MaybeUninit, slice::from_raw_parts},
     solana_account_view::AccountView,
     solana_address::Address,
-    solana_instruction_view::{
-        cpi::{invoke_signed_with_bounds, Signer},
-        InstructionAccount, InstructionView,
-    },
+    solana_instruction_view::{InstructionAccount, InstructionView},
     solana_program_error::{ProgramError, ProgramResult},
 };
 
@@ -14,11 +14,21 @@ use {
 /// owned account by sending them to any other account, leaving behind only
 /// lamports for rent exemption.
 ///
-/// 0. `[writable]` Source account owned by the token program.
-/// 1. `[writable]` Destination account.
-/// 2. `[signer]` Authority.
-/// 3. ..`3+M` `[signer]` M signer accounts.
-pub struct WidthdrawExcessLamports<'a, 'b, 'c> {
+/// The source can be a token account, a mint or a multisig account.
+///
+/// Accounts expected by this instruction:
+///
+///   * Single authority
+///   0. `[writable]` Source account owned by the token program.
+///   1. `[writable]` Destination account.
+///   2. `[signer]` Authority.
+///
+///   * Multisignature authority
+///   0. `[writable]` Source account owned by the token program.
+///   1. `[writable]` Destination account.
+///   2. `[]` Multisig authority.
+///   3. ..`3+M` `[signer]` M signer accounts.
+pub struct WithdrawExcessLamports<'a, 'b, 'c> {
     /// Source account owned by the token program.
     pub source: &'a AccountView,
 
@@ -35,10 +45,14 @@ pub struct WidthdrawExcessLamports<'a, 'b, 'c> {
     pub token_program: &'b Address,
 }
 
-impl<'a, 'b, 'c> WidthdrawExcessLamports<'a, 'b, 'c> {
+/// Misspelled alias of [`WithdrawExcessLamports`].
+#[deprecated(note = "Use `WithdrawExcessLamports` instead")]
+pub type WidthdrawExcessLamports<'a, 'b, 'c> = WithdrawExcessLamports<'a, 'b, 'c>;
+
+impl<'a, 'b, 'c> WithdrawExcessLamports<'a, 'b, 'c> {
     pub const DISCRIMINATOR: u8 = 38;
 
-    /// Creates a new `WidthdrawExcessLamports` instruction with a single
+    /// Creates a new `WithdrawExcessLamports` instruction with a single
     /// owner/delegate authority.
     #[inline(always)]
     pub fn new(
@@ -50,7 +64,7 @@ impl<'a, 'b, 'c> WidthdrawExcessLamports<'a, 'b, 'c> {
         Self::with_signers(token_program, source, destination, authority, &[])
     }
 
-    /// Creates a new `WidthdrawExcessLamports` instruction with a
+    /// Creates a new `WithdrawExcessLamports` instruction with a
     /// multisignature owner/delegate authority and signer accounts.
     #[inline(always)]
     pub fn with_signers(
@@ -76,6 +90,20 @@ impl<'a, 'b, 'c> WidthdrawExcessLamports<'a, 'b, 'c> {
 
     #[inline(always)]
     pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        self.with_instruction(|instruction, account_views| {
+            invoke_signed_with_bounds::<{ 3 + MAX_MULTISIG_SIGNERS }>(
+                instruction,
+                account_views,
+                signers,
+            )
+        })?
+    }
+
+    #[inline(always)]
+    pub fn with_instruction<R>(
+        &self,
+        f: impl FnOnce(&InstructionView, &[&AccountView]) -> R,
+    ) -> Result<R, ProgramError> {
         if self.multisig_signers.len() > MAX_MULTISIG_SIGNERS {
             Err(ProgramError::InvalidArgument)?;
         }
@@ -119,7 +147,7 @@ impl<'a, 'b, 'c> WidthdrawExcessLamports<'a, 'b, 'c> {
             account.write(signer);
         }
 
-        invoke_signed_with_bounds::<{ 3 + MAX_MULTISIG_SIGNERS }>(
+        Ok(f(
             &InstructionView {
                 program_id: self.token_program,
                 // SAFETY: instruction accounts has `expected_accounts` initialized.
@@ -127,10 +155,8 @@ impl<'a, 'b, 'c> WidthdrawExcessLamports<'a, 'b, 'c> {
                     from_raw_parts(instruction_accounts.as_ptr() as _, expected_accounts)
                 },
                 data: &[Self::DISCRIMINATOR],
-            },
-            // SAFETY: accounts has `expected_accounts` initialized.
+            }, // SAFETY: accounts has `expected_accounts` initialized.
             unsafe { from_raw_parts(accounts.as_ptr() as _, expected_accounts) },
-            signers,
-        )
+        ))
     }
 }
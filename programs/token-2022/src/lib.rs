@@ -1,9 +1,15 @@
 #![no_std]
 
+#[cfg(not(any(target_os = "solana", target_arch = "bpf")))]
+mod cpi;
 pub mod instructions;
 pub mod state;
+pub mod token_program;
+pub mod transfer_hook;
 
 use core::mem::MaybeUninit;
+#[cfg(any(target_os = "solana", target_arch = "bpf"))]
+use solana_instruction_view::cpi;
 
 solana_address::declare_id!("TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb");
 
@@ -32,6 +38,15 @@ pub mod native_mint {
     /// Seed for the native mint's program-derived address
     pub const PROGRAM_ADDRESS_SEEDS: &[&[u8]] = &["native-mint".as_bytes(), &[255]];
 
+    /// Return `true` if `mint` is the Token-2022 native mint.
+    ///
+    /// Token accounts of the native mint hold wrapped SOL, and report it
+    /// through [`TokenAccount::is_native`](crate::state::TokenAccount::is_native).
+    #[inline(always)]
+    pub fn is_native_mint(mint: &solana_address::Address) -> bool {
+        mint == &ID
+    }
+
     #[cfg(test)]
     mod tests {
         use {super::*, solana_address::Address};
@@ -42,5 +57,11 @@ pub mod native_mint {
                 Address::create_program_address(PROGRAM_ADDRESS_SEEDS, &crate::id()).unwrap();
             assert_eq!(id(), native_mint_id);
         }
+
+        #[test]
+        fn is_native_mint_matches_only_native_mint() {
+            assert!(is_native_mint(&ID));
+            assert!(!is_native_mint(&crate::ID));
+        }
     }
 }
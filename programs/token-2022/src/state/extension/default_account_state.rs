@@ -8,6 +8,19 @@ use {
 ///
 /// When set on a mint, all new token accounts are initialized
 /// with this state.
+///
+/// # Example
+///
+/// ```
+/// use pinocchio_token_2022::state::{get_extension, AccountState, DefaultAccountStateExtension};
+/// use solana_program_error::ProgramError;
+///
+/// /// Return whether new token accounts for the mint start frozen.
+/// fn starts_frozen(mint_data: &[u8]) -> Result<bool, ProgramError> {
+///     let extension = get_extension::<DefaultAccountStateExtension>(mint_data)?;
+///     Ok(extension.state()? == AccountState::Frozen)
+/// }
+/// ```
 #[repr(C)]
 #[derive(Clone, Copy, Debug, Default, PartialEq)]
 pub struct DefaultAccountStateExtension {
@@ -0,0 +1,58 @@
+use {
+    super::{sealed, ExtensionType, ExtensionValue, Pod},
+    solana_address::Address,
+};
+
+/// Group member pointer extension data for mints (64 bytes).
+///
+/// Points to the account holding the group membership, which may be the
+/// mint itself when it has the `TokenGroupMember` extension.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct GroupMemberPointerExtension {
+    authority: [u8; 32],
+    member_address: [u8; 32],
+}
+
+impl GroupMemberPointerExtension {
+    pub const LEN: usize = core::mem::size_of::<GroupMemberPointerExtension>();
+
+    /// Authority that can set the group member address.
+    ///
+    /// An all-zero address means that no authority is set.
+    #[inline(always)]
+    pub fn authority(&self) -> &Address {
+        // SAFETY: `Address` is `#[repr(transparent)]` over `[u8; 32]` with
+        // alignment 1, so the pointer cast is valid.
+        unsafe { &*(self.authority.as_ptr() as *const Address) }
+    }
+
+    /// Account address that holds the group member.
+    ///
+    /// An all-zero address means that no address is set.
+    #[inline(always)]
+    pub fn member_address(&self) -> &Address {
+        // SAFETY: `Address` is `#[repr(transparent)]` over `[u8; 32]` with
+        // alignment 1, so the pointer cast is valid.
+        unsafe { &*(self.member_address.as_ptr() as *const Address) }
+    }
+
+    #[inline(always)]
+    pub fn set_authority(&mut self, authority: &Address) {
+        self.authority.copy_from_slice(authority.as_ref());
+    }
+
+    #[inline(always)]
+    pub fn set_member_address(&mut self, member_address: &Address) {
+        self.member_address.copy_from_slice(member_address.as_ref());
+    }
+}
+
+// SAFETY: `GroupMemberPointerExtension` is repr(C), contains only `[u8; 32]` arrays,
+// has no padding, and all bit patterns are valid.
+impl sealed::SealedPod for GroupMemberPointerExtension {}
+unsafe impl Pod for GroupMemberPointerExtension {}
+
+impl ExtensionValue for GroupMemberPointerExtension {
+    const TYPE: ExtensionType = ExtensionType::GroupMemberPointer;
+}
@@ -0,0 +1,58 @@
+use {
+    super::{sealed, ExtensionType, ExtensionValue, Pod},
+    solana_address::Address,
+};
+
+/// Group pointer extension data for mints (64 bytes).
+///
+/// Points to the account holding the token group configuration, which may
+/// be the mint itself when it has the `TokenGroup` extension.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct GroupPointerExtension {
+    authority: [u8; 32],
+    group_address: [u8; 32],
+}
+
+impl GroupPointerExtension {
+    pub const LEN: usize = core::mem::size_of::<GroupPointerExtension>();
+
+    /// Authority that can set the group address.
+    ///
+    /// An all-zero address means that no authority is set.
+    #[inline(always)]
+    pub fn authority(&self) -> &Address {
+        // SAFETY: `Address` is `#[repr(transparent)]` over `[u8; 32]` with
+        // alignment 1, so the pointer cast is valid.
+        unsafe { &*(self.authority.as_ptr() as *const Address) }
+    }
+
+    /// Account address that holds the group.
+    ///
+    /// An all-zero address means that no address is set.
+    #[inline(always)]
+    pub fn group_address(&self) -> &Address {
+        // SAFETY: `Address` is `#[repr(transparent)]` over `[u8; 32]` with
+        // alignment 1, so the pointer cast is valid.
+        unsafe { &*(self.group_address.as_ptr() as *const Address) }
+    }
+
+    #[inline(always)]
+    pub fn set_authority(&mut self, authority: &Address) {
+        self.authority.copy_from_slice(authority.as_ref());
+    }
+
+    #[inline(always)]
+    pub fn set_group_address(&mut self, group_address: &Address) {
+        self.group_address.copy_from_slice(group_address.as_ref());
+    }
+}
+
+// SAFETY: `GroupPointerExtension` is repr(C), contains only `[u8; 32]` arrays,
+// has no padding, and all bit patterns are valid.
+impl sealed::SealedPod for GroupPointerExtension {}
+unsafe impl Pod for GroupPointerExtension {}
+
+impl ExtensionValue for GroupPointerExtension {
+    const TYPE: ExtensionType = ExtensionType::GroupPointer;
+}
@@ -0,0 +1,341 @@
+use {
+    super::{sealed, ExtensionType, ExtensionValue, Pod},
+    solana_address::Address,
+    solana_program_error::ProgramError,
+};
+
+/// Number of seconds in a year, as used by the interest-bearing extension.
+pub const SECONDS_PER_YEAR: f64 = 60.0 * 60.0 * 24.0 * 365.24;
+
+/// Number of basis points in `1`.
+pub const ONE_IN_BASIS_POINTS: f64 = 10_000.0;
+
+/// Interest-bearing config extension data for mints (52 bytes).
+///
+/// Interest is continuously compounded: the UI amount of a raw token amount
+/// is scaled by the average rate until the last rate update and by the
+/// current rate since then.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct InterestBearingConfigExtension {
+    rate_authority: [u8; 32],
+    initialization_timestamp: [u8; 8],
+    pre_update_average_rate: [u8; 2],
+    last_update_timestamp: [u8; 8],
+    current_rate: [u8; 2],
+}
+
+impl InterestBearingConfigExtension {
+    pub const LEN: usize = core::mem::size_of::<InterestBearingConfigExtension>();
+
+    /// Authority that can set the interest rate.
+    ///
+    /// An all-zero address means that no authority is set.
+    #[inline(always)]
+    pub fn rate_authority(&self) -> &Address {
+        // SAFETY: `Address` is `#[repr(transparent)]` over `[u8; 32]` with
+        // alignment 1, so the pointer cast is valid.
+        unsafe { &*(self.rate_authority.as_ptr() as *const Address) }
+    }
+
+    /// Timestamp of the mint initialization.
+    #[inline(always)]
+    pub const fn initialization_timestamp(&self) -> i64 {
+        i64::from_le_bytes(self.initialization_timestamp)
+    }
+
+    /// Average rate, in basis points, from initialization until the last
+    /// update.
+    #[inline(always)]
+    pub const fn pre_update_average_rate(&self) -> i16 {
+        i16::from_le_bytes(self.pre_update_average_rate)
+    }
+
+    /// Timestamp of the last rate update.
+    #[inline(always)]
+    pub const fn last_update_timestamp(&self) -> i64 {
+        i64::from_le_bytes(self.last_update_timestamp)
+    }
+
+    /// Current rate, in basis points, since the last update.
+    #[inline(always)]
+    pub const fn current_rate(&self) -> i16 {
+        i16::from_le_bytes(self.current_rate)
+    }
+
+    #[inline(always)]
+    pub fn set_rate_authority(&mut self, rate_authority: &Address) {
+        self.rate_authority.copy_from_slice(rate_authority.as_ref());
+    }
+
+    #[inline(always)]
+    pub fn set_initialization_timestamp(&mut self, initialization_timestamp: i64) {
+        self.initialization_timestamp = initialization_timestamp.to_le_bytes();
+    }
+
+    #[inline(always)]
+    pub fn set_pre_update_average_rate(&mut self, pre_update_average_rate: i16) {
+        self.pre_update_average_rate = pre_update_average_rate.to_le_bytes();
+    }
+
+    #[inline(always)]
+    pub fn set_last_update_timestamp(&mut self, last_update_timestamp: i64) {
+        self.last_update_timestamp = last_update_timestamp.to_le_bytes();
+    }
+
+    #[inline(always)]
+    pub fn set_current_rate(&mut self, current_rate: i16) {
+        self.current_rate = current_rate.to_le_bytes();
+    }
+
+    /// Return the scale accrued between initialization and the last rate
+    /// update.
+    #[inline]
+    pub const fn pre_update_exp(&self) -> Option<f64> {
+        match self
+            .last_update_timestamp()
+            .checked_sub(self.initialization_timestamp())
+        {
+            Some(timespan) => Some(rate_exp(self.pre_update_average_rate(), timespan)),
+            None => None,
+        }
+    }
+
+    /// Return the scale accrued between the last rate update and
+    /// `unix_timestamp`.
+    #[inline]
+    pub const fn post_update_exp(&self, unix_timestamp: i64) -> Option<f64> {
+        match unix_timestamp.checked_sub(self.last_update_timestamp()) {
+            Some(timespan) => Some(rate_exp(self.current_rate(), timespan)),
+            None => None,
+        }
+    }
+
+    /// Return the total scale applied to a raw amount at `unix_timestamp`,
+    /// including the mint decimals.
+    #[inline]
+    pub const fn total_scale(&self, decimals: u8, unix_timestamp: i64) -> Option<f64> {
+        match (self.pre_update_exp(), self.post_update_exp(unix_timestamp)) {
+            (Some(pre_update_exp), Some(post_update_exp)) => {
+                Some(pre_update_exp * post_update_exp / pow10(decimals))
+            }
+            _ => None,
+        }
+    }
+
+    /// Convert a raw amount to its interest-adjusted UI amount at
+    /// `unix_timestamp`.
+    ///
+    /// This approximates (within ~1e-12 relative) the value returned by the
+    /// `AmountToUiAmount` instruction before it is formatted as a string.
+    #[inline]
+    pub const fn amount_to_ui_amount(
+        &self,
+        amount: u64,
+        decimals: u8,
+        unix_timestamp: i64,
+    ) -> Option<f64> {
+        match self.total_scale(decimals, unix_timestamp) {
+            Some(total_scale) => Some(total_scale * amount as f64),
+            None => None,
+        }
+    }
+
+    /// Convert an interest-adjusted UI amount to its raw amount at
+    /// `unix_timestamp`.
+    ///
+    /// Returns [`ProgramError::InvalidArgument`] if the resulting amount does
+    /// not fit in a `u64`.
+    #[inline]
+    pub const fn try_ui_amount_into_amount(
+        &self,
+        ui_amount: f64,
+        decimals: u8,
+        unix_timestamp: i64,
+    ) -> Result<u64, ProgramError> {
+        let total_scale = match self.total_scale(decimals, unix_timestamp) {
+            Some(total_scale) => total_scale,
+            None => return Err(ProgramError::InvalidArgument),
+        };
+        // Divide before rounding, otherwise large amounts round to infinity.
+        let amount = ui_amount / total_scale;
+
+        if amount.is_nan() || amount < 0.0 || amount > u64::MAX as f64 {
+            Err(ProgramError::InvalidArgument)
+        } else {
+            // Round half away from zero; the cast saturates at `u64::MAX`.
+            Ok((amount + 0.5) as u64)
+        }
+    }
+}
+
+// SAFETY: `InterestBearingConfigExtension` is repr(C), contains only byte
+// arrays, has no padding, and all bit patterns are valid.
+impl sealed::SealedPod for InterestBearingConfigExtension {}
+unsafe impl Pod for InterestBearingConfigExtension {}
+
+impl ExtensionValue for InterestBearingConfigExtension {
+    const TYPE: ExtensionType = ExtensionType::InterestBearingConfig;
+}
+
+/// Return `e^(rate * timespan)`, with the rate expressed in basis points per
+/// year and the timespan in seconds.
+#[inline(always)]
+const fn rate_exp(rate: i16, timespan: i64) -> f64 {
+    exp(rate as f64 * timespan as f64 / SECONDS_PER_YEAR / ONE_IN_BASIS_POINTS)
+}
+
+/// Return `10^exponent`.
+#[inline(always)]
+pub(super) const fn pow10(exponent: u8) -> f64 {
+    let mut result = 1.0;
+    let mut i = 0;
+    while i < exponent {
+        result *= 10.0;
+        i += 1;
+    }
+    result
+}
+
+/// Return `2^exponent` for an exponent in the normal `f64` range.
+#[inline(always)]
+const fn pow2(exponent: i64) -> f64 {
+    f64::from_bits(((exponent + 1023) as u64) << 52)
+}
+
+/// Return `e^x`.
+///
+/// `core` does not provide `f64::exp`, so the value is computed by reducing
+/// `x` to `k * ln(2) + r`, with `|r| <= ln(2) / 2`, and evaluating the Taylor
+/// series of `e^r`.
+const fn exp(x: f64) -> f64 {
+    if x.is_nan() {
+        return x;
+    }
+    if x > 709.8 {
+        return f64::INFINITY;
+    }
+    if x < -745.2 {
+        return 0.0;
+    }
+
+    let k = (x / core::f64::consts::LN_2 + if x < 0.0 { -0.5 } else { 0.5 }) as i64;
+    let r = x - k as f64 * core::f64::consts::LN_2;
+
+    let mut sum = 1.0;
+    let mut term = 1.0;
+    let mut i = 1;
+    while i < 20 {
+        term = term * r / i as f64;
+        sum += term;
+        i += 1;
+    }
+
+    // Split the scaling so that both factors stay in the normal range.
+    let half = k / 2;
+    sum * pow2(half) * pow2(k - half)
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use super::*;
+
+    const TOLERANCE: f64 = 1e-12;
+
+    fn config(
+        initialization_timestamp: i64,
+        pre_update_average_rate: i16,
+        last_update_timestamp: i64,
+        current_rate: i16,
+    ) -> InterestBearingConfigExtension {
+        let mut extension = InterestBearingConfigExtension::default();
+        extension.set_initialization_timestamp(initialization_timestamp);
+        extension.set_pre_update_average_rate(pre_update_average_rate);
+        extension.set_last_update_timestamp(last_update_timestamp);
+        extension.set_current_rate(current_rate);
+        extension
+    }
+
+    fn assert_close(value: f64, expected: f64) {
+        assert!(
+            ((value - expected) / expected).abs() < TOLERANCE,
+            "{value} != {expected}"
+        );
+    }
+
+    #[test]
+    fn test_len() {
+        assert_eq!(InterestBearingConfigExtension::LEN, 52);
+    }
+
+    #[test]
+    fn test_exp() {
+        assert_eq!(exp(0.0), 1.0);
+
+        for x in [
+            -700.0, -20.0, -1.5, -0.05, 1e-9, 0.05, 0.5, 1.0, 3.25, 42.0, 700.0,
+        ] {
+            assert_close(exp(x), std::primitive::f64::exp(x));
+        }
+
+        assert_eq!(exp(1_000.0), f64::INFINITY);
+        assert_eq!(exp(-1_000.0), 0.0);
+        assert!(exp(f64::NAN).is_nan());
+    }
+
+    #[test]
+    fn test_amount_to_ui_amount() {
+        let one_year = SECONDS_PER_YEAR as i64;
+
+        // No time elapsed.
+        let extension = config(0, 0, 0, 500);
+        assert_eq!(extension.amount_to_ui_amount(1_000_000, 6, 0), Some(1.0));
+
+        // 5% continuously compounded over one year.
+        let ui_amount = extension.amount_to_ui_amount(1_000_000, 6, one_year);
+        assert_close(ui_amount.unwrap(), 0.05f64.exp());
+
+        // 10% until the update, then -5% for another year.
+        let extension = config(0, 1_000, one_year, -500);
+        let ui_amount = extension.amount_to_ui_amount(100, 2, 2 * one_year);
+        assert_close(ui_amount.unwrap(), (0.1f64 - 0.05).exp());
+
+        // Overflowing timespan.
+        let extension = config(0, 0, i64::MIN, 0);
+        assert_eq!(extension.amount_to_ui_amount(100, 2, 1), None);
+    }
+
+    #[test]
+    fn test_try_ui_amount_into_amount() {
+        let one_year = SECONDS_PER_YEAR as i64;
+        let extension = config(0, 0, 0, 500);
+
+        assert_eq!(
+            extension.try_ui_amount_into_amount(1.0, 6, 0),
+            Ok(1_000_000)
+        );
+
+        let ui_amount = extension
+            .amount_to_ui_amount(1_000_000, 6, one_year)
+            .unwrap();
+        assert_eq!(
+            extension.try_ui_amount_into_amount(ui_amount, 6, one_year),
+            Ok(1_000_000)
+        );
+
+        assert_eq!(
+            extension.try_ui_amount_into_amount(-1.0, 6, 0),
+            Err(ProgramError::InvalidArgument)
+        );
+        assert_eq!(
+            extension.try_ui_amount_into_amount(f64::MAX, 0, 0),
+            Err(ProgramError::InvalidArgument)
+        );
+        assert_eq!(
+            extension.try_ui_amount_into_amount(f64::NAN, 0, 0),
+            Err(ProgramError::InvalidArgument)
+        );
+    }
+}
@@ -0,0 +1,233 @@
+use {
+    super::{sealed, ExtensionType, ExtensionValue, Pod, RefStateWithExtensions, TokenError},
+    crate::state::TokenAccount,
+    core::ops::Deref,
+    pinocchio::sysvars::instructions::Instructions,
+    solana_account_view::AccountView,
+    solana_address::{address, Address},
+    solana_program_error::ProgramResult,
+};
+
+/// Address of the SPL Memo program (v3).
+pub const MEMO_PROGRAM_ID: Address = address!("MemoSq4gqABAXKb96qnH8TysNcWxMyWCqXgDLGmfcHr");
+
+/// Address of the legacy SPL Memo program (v1).
+pub const MEMO_V1_PROGRAM_ID: Address = address!("Memo1UhkJRfHyvLMcVucJwxXeuD728EqVDDwQDxFMNo");
+
+/// Memo transfer extension data for token accounts (1 byte).
+///
+/// When enabled, incoming transfers into the account must be preceded by a
+/// memo instruction in the same transaction.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct MemoTransferExtension {
+    require_incoming_transfer_memos: u8,
+}
+
+impl MemoTransferExtension {
+    pub const LEN: usize = core::mem::size_of::<MemoTransferExtension>();
+
+    #[inline(always)]
+    pub fn require_incoming_transfer_memos(&self) -> bool {
+        self.require_incoming_transfer_memos != 0
+    }
+
+    #[inline(always)]
+    pub fn set_require_incoming_transfer_memos(&mut self, require_incoming_transfer_memos: bool) {
+        self.require_incoming_transfer_memos = require_incoming_transfer_memos as u8;
+    }
+}
+
+// SAFETY: `MemoTransferExtension` is repr(C), contains only `u8`, has no
+// padding, and all bit patterns are valid.
+impl sealed::SealedPod for MemoTransferExtension {}
+unsafe impl Pod for MemoTransferExtension {}
+
+impl ExtensionValue for MemoTransferExtension {
+    const TYPE: ExtensionType = ExtensionType::MemoTransfer;
+}
+
+/// Check that an incoming transfer into `destination` satisfies its
+/// [`MemoTransferExtension`].
+///
+/// When the destination token account requires incoming transfer memos, the
+/// instruction preceding the current one in the transaction must be a memo
+/// instruction; otherwise [`TokenError::NoMemo`] is returned. Accounts without
+/// the extension, or with the requirement disabled, always pass.
+///
+/// This only inspects top-level instructions. A program that transfers via
+/// CPI must invoke the Memo program itself right before the transfer.
+pub fn check_incoming_transfer_memo(
+    destination: &AccountView,
+    instructions_sysvar: &AccountView,
+) -> ProgramResult {
+    let account = RefStateWithExtensions::<TokenAccount>::from_account_view(destination)?;
+
+    let require_memo = match account.get_extension::<MemoTransferExtension>() {
+        Ok(extension) => extension.require_incoming_transfer_memos(),
+        Err(error) if error == TokenError::ExtensionNotFound.into() => false,
+        Err(error) => return Err(error),
+    };
+
+    if !require_memo {
+        return Ok(());
+    }
+
+    let instructions = Instructions::try_from(instructions_sysvar)?;
+
+    if is_memo_preceding_current_instruction(&instructions) {
+        Ok(())
+    } else {
+        Err(TokenError::NoMemo.into())
+    }
+}
+
+/// Return whether the instruction preceding the current one is a memo
+/// instruction.
+pub fn is_memo_preceding_current_instruction<T>(instructions: &Instructions<T>) -> bool
+where
+    T: Deref<Target = [u8]>,
+{
+    instructions
+        .get_instruction_relative(-1)
+        .map(|instruction| {
+            let program_id = instruction.get_program_id();
+            program_id == &MEMO_PROGRAM_ID || program_id == &MEMO_V1_PROGRAM_ID
+        })
+        .unwrap_or(false)
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use {
+        super::{super::TLV_START_INDEX, *},
+        crate::{state::AccountType, ID},
+        pinocchio::{
+            entrypoint::input::{AccountViews, InputAccount},
+            error::ProgramError,
+            sysvars::instructions::INSTRUCTIONS_ID,
+        },
+        std::{vec, vec::Vec},
+    };
+
+    #[repr(C, align(8))]
+    struct SysvarData([u8; 256]);
+
+    /// Build instructions sysvar data with the given program ids, without
+    /// accounts or instruction data.
+    fn build_sysvar_data(program_ids: &[&Address], current_index: u16) -> (SysvarData, usize) {
+        let mut data = SysvarData([0; 256]);
+        let bytes = &mut data.0;
+        bytes[0..2].copy_from_slice(&(program_ids.len() as u16).to_le_bytes());
+
+        let mut offset = 2 + program_ids.len() * 2;
+        for (index, program_id) in program_ids.iter().enumerate() {
+            bytes[2 + index * 2..4 + index * 2].copy_from_slice(&(offset as u16).to_le_bytes());
+            // no accounts
+            offset += 2;
+            bytes[offset..offset + 32].copy_from_slice(program_id.as_ref());
+            // no instruction data
+            offset += 34;
+        }
+
+        bytes[offset..offset + 2].copy_from_slice(&current_index.to_le_bytes());
+        offset += 2;
+
+        (data, offset)
+    }
+
+    #[test]
+    fn memo_preceding_current_instruction() {
+        let program = Address::new_from_array([1; 32]);
+
+        for memo_program in [&MEMO_PROGRAM_ID, &MEMO_V1_PROGRAM_ID] {
+            let (data, len) = build_sysvar_data(&[memo_program, &program], 1);
+            let instructions = unsafe { Instructions::new_unchecked(&data.0[..len]) };
+            assert!(is_memo_preceding_current_instruction(&instructions));
+        }
+    }
+
+    #[test]
+    fn memo_not_preceding_current_instruction() {
+        let program = Address::new_from_array([1; 32]);
+        let other_program = Address::new_from_array([2; 32]);
+
+        // memo follows the current instruction
+        let (data, len) = build_sysvar_data(&[&program, &MEMO_PROGRAM_ID], 0);
+        let instructions = unsafe { Instructions::new_unchecked(&data.0[..len]) };
+        assert!(!is_memo_preceding_current_instruction(&instructions));
+
+        // memo is not immediately before the current instruction
+        let (data, len) = build_sysvar_data(&[&MEMO_PROGRAM_ID, &other_program, &program], 2);
+        let instructions = unsafe { Instructions::new_unchecked(&data.0[..len]) };
+        assert!(!is_memo_preceding_current_instruction(&instructions));
+    }
+
+    /// Build token account data, with a `MemoTransfer` extension holding
+    /// `require_memos` if it is set.
+    fn build_token_data(require_memos: Option<u8>) -> Vec<u8> {
+        let Some(require_memos) = require_memos else {
+            return vec![0; TokenAccount::BASE_LEN];
+        };
+
+        let mut data = vec![0; TLV_START_INDEX];
+        data[TLV_START_INDEX - 1] = AccountType::Account as u8;
+        data.extend_from_slice(&(ExtensionType::MemoTransfer as u16).to_le_bytes());
+        data.extend_from_slice(&(MemoTransferExtension::LEN as u16).to_le_bytes());
+        data.push(require_memos);
+        data
+    }
+
+    /// Check an incoming transfer into a destination holding `require_memos`,
+    /// preceded by an instruction of `previous_program`.
+    fn check(require_memos: Option<u8>, previous_program: &Address) -> ProgramResult {
+        let program = Address::new_from_array([1; 32]);
+        let (sysvar_data, len) = build_sysvar_data(&[previous_program, &program], 1);
+
+        let accounts = AccountViews::new(&[
+            InputAccount::new(
+                Address::new_from_array([3; 32]),
+                ID,
+                1_000_000,
+                &build_token_data(require_memos),
+            ),
+            InputAccount::new(
+                INSTRUCTIONS_ID,
+                Address::default(),
+                0,
+                &sysvar_data.0[..len],
+            ),
+        ]);
+
+        check_incoming_transfer_memo(&accounts[0], &accounts[1])
+    }
+
+    #[test]
+    fn check_incoming_transfer_memo_without_extension() {
+        let other_program = Address::new_from_array([2; 32]);
+        assert_eq!(check(None, &other_program), Ok(()));
+    }
+
+    #[test]
+    fn check_incoming_transfer_memo_not_required() {
+        let other_program = Address::new_from_array([2; 32]);
+        assert_eq!(check(Some(0), &other_program), Ok(()));
+    }
+
+    #[test]
+    fn check_incoming_transfer_memo_required() {
+        assert_eq!(check(Some(1), &MEMO_PROGRAM_ID), Ok(()));
+        assert_eq!(check(Some(1), &MEMO_V1_PROGRAM_ID), Ok(()));
+    }
+
+    #[test]
+    fn check_incoming_transfer_memo_missing() {
+        let other_program = Address::new_from_array([2; 32]);
+        assert_eq!(
+            check(Some(1), &other_program),
+            Err(ProgramError::from(TokenError::NoMemo))
+        );
+    }
+}
@@ -0,0 +1,59 @@
+use {
+    super::{sealed, ExtensionType, ExtensionValue, Pod},
+    solana_address::Address,
+};
+
+/// Metadata pointer extension data for mints (64 bytes).
+///
+/// Points to the account holding the token metadata, which may be the mint
+/// itself when it has the `TokenMetadata` extension.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct MetadataPointerExtension {
+    authority: [u8; 32],
+    metadata_address: [u8; 32],
+}
+
+impl MetadataPointerExtension {
+    pub const LEN: usize = core::mem::size_of::<MetadataPointerExtension>();
+
+    /// Authority that can set the metadata address.
+    ///
+    /// An all-zero address means that no authority is set.
+    #[inline(always)]
+    pub fn authority(&self) -> &Address {
+        // SAFETY: `Address` is `#[repr(transparent)]` over `[u8; 32]` with
+        // alignment 1, so the pointer cast is valid.
+        unsafe { &*(self.authority.as_ptr() as *const Address) }
+    }
+
+    /// Account address that holds the metadata.
+    ///
+    /// An all-zero address means that no address is set.
+    #[inline(always)]
+    pub fn metadata_address(&self) -> &Address {
+        // SAFETY: `Address` is `#[repr(transparent)]` over `[u8; 32]` with
+        // alignment 1, so the pointer cast is valid.
+        unsafe { &*(self.metadata_address.as_ptr() as *const Address) }
+    }
+
+    #[inline(always)]
+    pub fn set_authority(&mut self, authority: &Address) {
+        self.authority.copy_from_slice(authority.as_ref());
+    }
+
+    #[inline(always)]
+    pub fn set_metadata_address(&mut self, metadata_address: &Address) {
+        self.metadata_address
+            .copy_from_slice(metadata_address.as_ref());
+    }
+}
+
+// SAFETY: `MetadataPointerExtension` is repr(C), contains only `[u8; 32]` arrays,
+// has no padding, and all bit patterns are valid.
+impl sealed::SealedPod for MetadataPointerExtension {}
+unsafe impl Pod for MetadataPointerExtension {}
+
+impl ExtensionValue for MetadataPointerExtension {
+    const TYPE: ExtensionType = ExtensionType::MetadataPointer;
+}
@@ -0,0 +1,42 @@
+use {
+    super::{sealed, ExtensionType, ExtensionValue, Pod},
+    solana_address::Address,
+};
+
+/// Mint close authority extension data for mints (32 bytes).
+///
+/// The close authority can close the mint once its supply is zero.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct MintCloseAuthorityExtension {
+    close_authority: [u8; 32],
+}
+
+impl MintCloseAuthorityExtension {
+    pub const LEN: usize = core::mem::size_of::<MintCloseAuthorityExtension>();
+
+    /// Authority that can close the mint.
+    ///
+    /// An all-zero address means that no authority is set.
+    #[inline(always)]
+    pub fn close_authority(&self) -> &Address {
+        // SAFETY: `Address` is `#[repr(transparent)]` over `[u8; 32]` with
+        // alignment 1, so the pointer cast is valid.
+        unsafe { &*(self.close_authority.as_ptr() as *const Address) }
+    }
+
+    #[inline(always)]
+    pub fn set_close_authority(&mut self, close_authority: &Address) {
+        self.close_authority
+            .copy_from_slice(close_authority.as_ref());
+    }
+}
+
+// SAFETY: `MintCloseAuthorityExtension` is repr(C), contains only `[u8; 32]`,
+// has no padding, and all bit patterns are valid.
+impl sealed::SealedPod for MintCloseAuthorityExtension {}
+unsafe impl Pod for MintCloseAuthorityExtension {}
+
+impl ExtensionValue for MintCloseAuthorityExtension {
+    const TYPE: ExtensionType = ExtensionType::MintCloseAuthority;
+}
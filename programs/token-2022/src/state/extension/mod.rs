@@ -1,6 +1,17 @@
 pub mod default_account_state;
+pub mod group_member_pointer;
+pub mod group_pointer;
+pub mod interest_bearing_config;
+pub mod memo_transfer;
+pub mod metadata_pointer;
+pub mod mint_close_authority;
+pub mod non_transferable;
+pub mod pausable;
 pub mod permanent_delegate;
+pub mod scaled_ui_amount;
 mod state;
+pub mod token_group;
+pub mod transfer_fee;
 pub mod transfer_hook;
 pub mod transfer_hook_account;
 
@@ -10,11 +21,25 @@ use {
 };
 pub use {
     default_account_state::DefaultAccountStateExtension,
+    group_member_pointer::GroupMemberPointerExtension,
+    group_pointer::GroupPointerExtension,
+    interest_bearing_config::InterestBearingConfigExtension,
+    memo_transfer::{
+        check_incoming_transfer_memo, is_memo_preceding_current_instruction, MemoTransferExtension,
+        MEMO_PROGRAM_ID, MEMO_V1_PROGRAM_ID,
+    },
+    metadata_pointer::MetadataPointerExtension,
+    mint_close_authority::MintCloseAuthorityExtension,
+    non_transferable::{NonTransferableAccountExtension, NonTransferableExtension},
+    pausable::{PausableAccountExtension, PausableConfigExtension},
     permanent_delegate::PermanentDelegateExtension,
+    scaled_ui_amount::ScaledUiAmountConfigExtension,
     state::{
-        RefMutStateWithExtensions, RefStateWithExtensions, StateWithExtensions,
-        StateWithExtensionsMut,
+        get_extension, ExtensionIter, RefMutStateWithExtensions, RefStateWithExtensions,
+        StateWithExtensions, StateWithExtensionsMut,
     },
+    token_group::{TokenGroupExtension, TokenGroupMemberExtension},
+    transfer_fee::{TransferFee, TransferFeeAmountExtension, TransferFeeConfigExtension},
     transfer_hook::TransferHookExtension,
     transfer_hook_account::TransferHookAccountExtension,
 };
@@ -39,6 +64,9 @@ const TLV_HEADER_LEN: usize = 4;
 #[repr(u32)]
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub enum TokenError {
+    /// Incoming transfer is not preceded by a memo instruction.
+    NoMemo = 36,
+
     /// Extension not found in account data.
     ExtensionNotFound = 48,
 }
@@ -112,9 +140,26 @@ pub enum ExtensionType {
     PausableAccount = 27,
 }
 
+impl ExtensionType {
+    /// Calculate the exact length of an account of type `B` holding the
+    /// given extensions.
+    ///
+    /// See [`try_calculate_account_len`].
+    #[inline(always)]
+    pub fn try_calculate_account_len<B: ExtensionBaseState>(
+        extension_types: &[ExtensionType],
+    ) -> Result<usize, ProgramError> {
+        try_calculate_account_len::<B>(extension_types)
+    }
+}
+
 /// Marker for typed extension values that can be decoded from TLV entries.
 pub trait ExtensionValue: Pod {
+    /// The extension type of the TLV entry holding the value.
     const TYPE: ExtensionType;
+
+    /// The fixed length of the value in the TLV entry.
+    const LEN: usize = core::mem::size_of::<Self>();
 }
 
 /// Trait for supported token-2022 base account types that can host TLV
@@ -268,21 +313,54 @@ unsafe fn token_account_from_bytes_unchecked_mut(bytes: &mut [u8]) -> &mut Token
 }
 
 /// Returns the fixed byte length of the given extension's value payload,
-/// or `None` if the extension type is not yet supported.
+/// or `None` if the extension type does not have a fixed length.
 ///
-/// Only a subset of extension types have known sizes registered here.
-/// Unsupported types will cause [`try_calculate_account_len`] to fail.
+/// `TokenMetadata` is variable-length and `Uninitialized` is not an
+/// extension, so both cause [`try_calculate_account_len`] to fail. Lengths
+/// match the SPL Token-2022 `Pod` layouts of each extension.
 #[inline(always)]
 pub const fn extension_value_len(extension_type: ExtensionType) -> Option<usize> {
     match extension_type {
+        ExtensionType::Uninitialized | ExtensionType::TokenMetadata => None,
+        ExtensionType::TransferFeeConfig => Some(TransferFeeConfigExtension::LEN),
+        ExtensionType::TransferFeeAmount => Some(TransferFeeAmountExtension::LEN),
+        ExtensionType::MintCloseAuthority => Some(MintCloseAuthorityExtension::LEN),
+        ExtensionType::ConfidentialTransferMint => Some(65),
+        ExtensionType::ConfidentialTransferAccount => Some(295),
         ExtensionType::DefaultAccountState => Some(DefaultAccountStateExtension::LEN),
+        ExtensionType::ImmutableOwner => Some(0),
+        ExtensionType::NonTransferable => Some(NonTransferableExtension::LEN),
+        ExtensionType::NonTransferableAccount => Some(NonTransferableAccountExtension::LEN),
+        ExtensionType::PausableAccount => Some(PausableAccountExtension::LEN),
+        ExtensionType::MemoTransfer => Some(MemoTransferExtension::LEN),
+        ExtensionType::InterestBearingConfig => Some(InterestBearingConfigExtension::LEN),
+        ExtensionType::CpiGuard => Some(1),
         ExtensionType::PermanentDelegate => Some(PermanentDelegateExtension::LEN),
         ExtensionType::TransferHook => Some(TransferHookExtension::LEN),
         ExtensionType::TransferHookAccount => Some(TransferHookAccountExtension::LEN),
-        _ => None,
+        ExtensionType::ConfidentialTransferFeeConfig => Some(129),
+        ExtensionType::ConfidentialTransferFeeAmount => Some(64),
+        ExtensionType::MetadataPointer => Some(MetadataPointerExtension::LEN),
+        ExtensionType::GroupPointer => Some(GroupPointerExtension::LEN),
+        ExtensionType::TokenGroup => Some(TokenGroupExtension::LEN),
+        ExtensionType::GroupMemberPointer => Some(GroupMemberPointerExtension::LEN),
+        ExtensionType::TokenGroupMember => Some(TokenGroupMemberExtension::LEN),
+        ExtensionType::ConfidentialMintBurn => Some(196),
+        ExtensionType::ScaledUiAmount => Some(ScaledUiAmountConfigExtension::LEN),
+        ExtensionType::Pausable => Some(PausableConfigExtension::LEN),
     }
 }
 
+/// Calculate the exact length of an account of type `B` holding the given
+/// extensions.
+///
+/// The length includes the base state, the mint padding, the account type
+/// and one TLV entry per distinct extension type. Without extensions, the
+/// base length is returned.
+///
+/// Returns [`ProgramError::InvalidAccountData`] if an extension does not
+/// belong to `B`, and [`ProgramError::InvalidInstructionData`] if an
+/// extension is variable-length (`TokenMetadata`).
 #[inline]
 pub fn try_calculate_account_len<B: ExtensionBaseState>(
     extension_types: &[ExtensionType],
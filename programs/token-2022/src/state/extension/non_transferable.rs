@@ -0,0 +1,40 @@
+use super::{sealed, ExtensionType, ExtensionValue, Pod};
+
+/// Non-transferable extension marker for mints (0 bytes).
+///
+/// Tokens of a non-transferable mint cannot be moved to another owner; they
+/// can only be burned or held by the account they were minted to.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct NonTransferableExtension;
+
+impl NonTransferableExtension {
+    pub const LEN: usize = core::mem::size_of::<NonTransferableExtension>();
+}
+
+/// Non-transferable account extension marker for token accounts (0 bytes).
+///
+/// Token accounts of a non-transferable mint carry this extension.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct NonTransferableAccountExtension;
+
+impl NonTransferableAccountExtension {
+    pub const LEN: usize = core::mem::size_of::<NonTransferableAccountExtension>();
+}
+
+// SAFETY: `NonTransferableExtension` is a zero-sized repr(C) struct.
+impl sealed::SealedPod for NonTransferableExtension {}
+unsafe impl Pod for NonTransferableExtension {}
+
+impl ExtensionValue for NonTransferableExtension {
+    const TYPE: ExtensionType = ExtensionType::NonTransferable;
+}
+
+// SAFETY: `NonTransferableAccountExtension` is a zero-sized repr(C) struct.
+impl sealed::SealedPod for NonTransferableAccountExtension {}
+unsafe impl Pod for NonTransferableAccountExtension {}
+
+impl ExtensionValue for NonTransferableAccountExtension {
+    const TYPE: ExtensionType = ExtensionType::NonTransferableAccount;
+}
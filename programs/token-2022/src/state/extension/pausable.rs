@@ -0,0 +1,73 @@
+use {
+    super::{sealed, ExtensionType, ExtensionValue, Pod},
+    solana_address::Address,
+};
+
+/// Pausable config extension data for mints (33 bytes).
+///
+/// While the mint is paused, the token program rejects transfers, mints and
+/// burns of its tokens.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct PausableConfigExtension {
+    authority: [u8; 32],
+    paused: u8,
+}
+
+impl PausableConfigExtension {
+    pub const LEN: usize = core::mem::size_of::<PausableConfigExtension>();
+
+    /// Authority that can pause and resume the mint.
+    ///
+    /// An all-zero address means that no authority is set.
+    #[inline(always)]
+    pub fn authority(&self) -> &Address {
+        // SAFETY: `Address` is `#[repr(transparent)]` over `[u8; 32]` with
+        // alignment 1, so the pointer cast is valid.
+        unsafe { &*(self.authority.as_ptr() as *const Address) }
+    }
+
+    #[inline(always)]
+    pub fn is_paused(&self) -> bool {
+        self.paused != 0
+    }
+
+    #[inline(always)]
+    pub fn set_authority(&mut self, authority: &Address) {
+        self.authority.copy_from_slice(authority.as_ref());
+    }
+
+    #[inline(always)]
+    pub fn set_paused(&mut self, paused: bool) {
+        self.paused = paused as u8;
+    }
+}
+
+/// Pausable account extension marker for token accounts (0 bytes).
+///
+/// Token accounts of a pausable mint carry this extension; its presence is
+/// the only information it holds.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct PausableAccountExtension;
+
+impl PausableAccountExtension {
+    pub const LEN: usize = core::mem::size_of::<PausableAccountExtension>();
+}
+
+// SAFETY: `PausableConfigExtension` is repr(C), contains only `[u8; 32]` and
+// `u8`, has no padding, and all bit patterns are valid.
+impl sealed::SealedPod for PausableConfigExtension {}
+unsafe impl Pod for PausableConfigExtension {}
+
+impl ExtensionValue for PausableConfigExtension {
+    const TYPE: ExtensionType = ExtensionType::Pausable;
+}
+
+// SAFETY: `PausableAccountExtension` is a zero-sized repr(C) struct.
+impl sealed::SealedPod for PausableAccountExtension {}
+unsafe impl Pod for PausableAccountExtension {}
+
+impl ExtensionValue for PausableAccountExtension {
+    const TYPE: ExtensionType = ExtensionType::PausableAccount;
+}
@@ -0,0 +1,185 @@
+use {
+    super::{interest_bearing_config::pow10, sealed, ExtensionType, ExtensionValue, Pod},
+    solana_address::Address,
+    solana_program_error::ProgramError,
+};
+
+/// Scaled UI amount config extension data for mints (56 bytes).
+///
+/// The UI amount of a raw token amount is scaled by a multiplier. A new
+/// multiplier can be scheduled to take effect at a given timestamp.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct ScaledUiAmountConfigExtension {
+    authority: [u8; 32],
+    multiplier: [u8; 8],
+    new_multiplier_effective_timestamp: [u8; 8],
+    new_multiplier: [u8; 8],
+}
+
+impl ScaledUiAmountConfigExtension {
+    pub const LEN: usize = core::mem::size_of::<ScaledUiAmountConfigExtension>();
+
+    /// Authority that can set the multiplier.
+    ///
+    /// An all-zero address means that no authority is set.
+    #[inline(always)]
+    pub fn authority(&self) -> &Address {
+        // SAFETY: `Address` is `#[repr(transparent)]` over `[u8; 32]` with
+        // alignment 1, so the pointer cast is valid.
+        unsafe { &*(self.authority.as_ptr() as *const Address) }
+    }
+
+    #[inline(always)]
+    pub const fn multiplier(&self) -> f64 {
+        f64::from_le_bytes(self.multiplier)
+    }
+
+    #[inline(always)]
+    pub const fn new_multiplier_effective_timestamp(&self) -> i64 {
+        i64::from_le_bytes(self.new_multiplier_effective_timestamp)
+    }
+
+    #[inline(always)]
+    pub const fn new_multiplier(&self) -> f64 {
+        f64::from_le_bytes(self.new_multiplier)
+    }
+
+    /// Return the multiplier in effect at `unix_timestamp`.
+    #[inline(always)]
+    pub const fn current_multiplier(&self, unix_timestamp: i64) -> f64 {
+        if unix_timestamp >= self.new_multiplier_effective_timestamp() {
+            self.new_multiplier()
+        } else {
+            self.multiplier()
+        }
+    }
+
+    /// Return the total scale applied to a raw amount at `unix_timestamp`,
+    /// including the mint decimals.
+    #[inline(always)]
+    pub const fn total_multiplier(&self, decimals: u8, unix_timestamp: i64) -> f64 {
+        self.current_multiplier(unix_timestamp) / pow10(decimals)
+    }
+
+    /// Convert a raw amount to its scaled UI amount at `unix_timestamp`.
+    ///
+    /// The scaled amount is truncated to a whole number of raw units before
+    /// the decimals are applied, matching the value returned by the
+    /// `AmountToUiAmount` instruction before it is formatted as a string.
+    #[inline]
+    pub const fn amount_to_ui_amount(&self, amount: u64, decimals: u8, unix_timestamp: i64) -> f64 {
+        let scaled_amount = amount as f64 * self.current_multiplier(unix_timestamp);
+        // The cast truncates towards zero and saturates at the `u64` bounds.
+        (scaled_amount as u64) as f64 / pow10(decimals)
+    }
+
+    /// Convert a scaled UI amount to its raw amount at `unix_timestamp`.
+    ///
+    /// Returns [`ProgramError::InvalidArgument`] if the resulting amount does
+    /// not fit in a `u64`.
+    #[inline]
+    pub const fn try_ui_amount_into_amount(
+        &self,
+        ui_amount: f64,
+        decimals: u8,
+        unix_timestamp: i64,
+    ) -> Result<u64, ProgramError> {
+        // Divide before rounding, otherwise large amounts round to infinity.
+        let amount = ui_amount / self.total_multiplier(decimals, unix_timestamp);
+
+        if amount.is_nan() || amount < 0.0 || amount > u64::MAX as f64 {
+            Err(ProgramError::InvalidArgument)
+        } else {
+            // Round half away from zero; the cast saturates at `u64::MAX`.
+            Ok((amount + 0.5) as u64)
+        }
+    }
+
+    #[inline(always)]
+    pub fn set_authority(&mut self, authority: &Address) {
+        self.authority.copy_from_slice(authority.as_ref());
+    }
+
+    #[inline(always)]
+    pub fn set_multiplier(&mut self, multiplier: f64) {
+        self.multiplier = multiplier.to_le_bytes();
+    }
+
+    /// Schedule `new_multiplier` to take effect at `effective_timestamp`.
+    #[inline(always)]
+    pub fn set_new_multiplier(&mut self, new_multiplier: f64, effective_timestamp: i64) {
+        self.new_multiplier = new_multiplier.to_le_bytes();
+        self.new_multiplier_effective_timestamp = effective_timestamp.to_le_bytes();
+    }
+}
+
+// SAFETY: `ScaledUiAmountConfigExtension` is repr(C), contains only byte
+// arrays, has no padding, and all bit patterns are valid.
+impl sealed::SealedPod for ScaledUiAmountConfigExtension {}
+unsafe impl Pod for ScaledUiAmountConfigExtension {}
+
+impl ExtensionValue for ScaledUiAmountConfigExtension {
+    const TYPE: ExtensionType = ExtensionType::ScaledUiAmount;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config(
+        multiplier: f64,
+        new_multiplier: f64,
+        effective_timestamp: i64,
+    ) -> ScaledUiAmountConfigExtension {
+        let mut extension = ScaledUiAmountConfigExtension::default();
+        extension.set_multiplier(multiplier);
+        extension.set_new_multiplier(new_multiplier, effective_timestamp);
+        extension
+    }
+
+    #[test]
+    fn test_len() {
+        assert_eq!(ScaledUiAmountConfigExtension::LEN, 56);
+    }
+
+    #[test]
+    fn test_current_multiplier() {
+        let extension = config(2.0, 3.0, 100);
+
+        assert_eq!(extension.current_multiplier(99), 2.0);
+        assert_eq!(extension.current_multiplier(100), 3.0);
+        assert_eq!(extension.amount_to_ui_amount(1_000, 2, 99), 20.0);
+        assert_eq!(extension.amount_to_ui_amount(1_000, 2, 100), 30.0);
+    }
+
+    #[test]
+    fn test_amount_to_ui_amount_truncates() {
+        let extension = config(0.5, 0.5, 0);
+
+        assert_eq!(extension.amount_to_ui_amount(3, 0, 0), 1.0);
+        assert_eq!(
+            extension.amount_to_ui_amount(u64::MAX, 0, 0),
+            (u64::MAX / 2) as f64
+        );
+
+        let extension = config(f64::MAX, f64::MAX, 0);
+        assert_eq!(extension.amount_to_ui_amount(2, 0, 0), u64::MAX as f64);
+    }
+
+    #[test]
+    fn test_try_ui_amount_into_amount() {
+        let extension = config(2.0, 4.0, 100);
+
+        assert_eq!(extension.try_ui_amount_into_amount(20.0, 2, 0), Ok(1_000));
+        assert_eq!(extension.try_ui_amount_into_amount(20.0, 2, 100), Ok(500));
+        assert_eq!(
+            extension.try_ui_amount_into_amount(-1.0, 2, 0),
+            Err(ProgramError::InvalidArgument)
+        );
+        assert_eq!(
+            extension.try_ui_amount_into_amount(f64::MAX, 0, 0),
+            Err(ProgramError::InvalidArgument)
+        );
+    }
+}
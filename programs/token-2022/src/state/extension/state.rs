@@ -1,9 +1,10 @@
 use {
     super::{
-        extension_not_found_error, extension_type_from_u16, mint_from_bytes_unchecked_mut,
-        token_account_from_bytes_unchecked_mut, validate_extension_account_type,
-        validate_mint_extensions_data, validate_token_extensions_data, ExtensionBaseState,
-        ExtensionType, ExtensionValue, Pod, BASE_ACCOUNT_LEN, TLV_HEADER_LEN, TLV_START_INDEX,
+        extension_account_type, extension_not_found_error, extension_type_from_u16,
+        mint_from_bytes_unchecked_mut, token_account_from_bytes_unchecked_mut,
+        validate_extension_account_type, validate_mint_extensions_data,
+        validate_token_extensions_data, ExtensionBaseState, ExtensionType, ExtensionValue, Pod,
+        BASE_ACCOUNT_LEN, TLV_HEADER_LEN, TLV_START_INDEX,
     },
     crate::{
         state::{AccountType, Mint, Multisig, TokenAccount},
@@ -127,6 +128,73 @@ pub(super) fn collect_extension_types_from_tlv(
     Ok(count)
 }
 
+/// Iterator over the TLV entries of an account's extension data.
+///
+/// Each item is the extension type and its value bytes, in encounter order.
+/// Iteration stops at the first `Uninitialized` entry or at the end of the
+/// data. Malformed data yields a single [`ProgramError::InvalidAccountData`]
+/// error, after which the iterator is exhausted.
+#[derive(Clone, Debug)]
+pub struct ExtensionIter<'a> {
+    tlv_data: &'a [u8],
+    offset: usize,
+}
+
+impl<'a> ExtensionIter<'a> {
+    #[inline(always)]
+    pub(super) fn new(tlv_data: &'a [u8]) -> Self {
+        Self {
+            tlv_data,
+            offset: 0,
+        }
+    }
+
+    #[inline(always)]
+    fn fail(&mut self) -> Option<Result<(ExtensionType, &'a [u8]), ProgramError>> {
+        self.offset = self.tlv_data.len();
+        Some(Err(ProgramError::InvalidAccountData))
+    }
+}
+
+impl<'a> Iterator for ExtensionIter<'a> {
+    type Item = Result<(ExtensionType, &'a [u8]), ProgramError>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let tlv_data = self.tlv_data;
+        let offset = self.offset;
+
+        if offset == tlv_data.len() {
+            return None;
+        }
+        if offset + TLV_HEADER_LEN > tlv_data.len() {
+            return self.fail();
+        }
+
+        let ext_type_raw = u16::from_le_bytes([tlv_data[offset], tlv_data[offset + 1]]);
+        let length = u16::from_le_bytes([tlv_data[offset + 2], tlv_data[offset + 3]]) as usize;
+
+        if ext_type_raw == 0 {
+            self.offset = tlv_data.len();
+            return None;
+        }
+
+        let value_start = offset + TLV_HEADER_LEN;
+        let value_end = value_start + length;
+
+        if value_end > tlv_data.len() {
+            return self.fail();
+        }
+
+        let Ok(extension_type) = extension_type_from_u16(ext_type_raw) else {
+            return self.fail();
+        };
+
+        self.offset = value_end;
+        Some(Ok((extension_type, &tlv_data[value_start..value_end])))
+    }
+}
+
 #[inline(always)]
 pub(super) fn extension_from_bytes<T: Pod>(bytes: &[u8]) -> Result<&T, ProgramError> {
     if bytes.len() != core::mem::size_of::<T>() {
@@ -220,6 +288,21 @@ fn validate_state_with_extensions_data<B: ExtensionBaseState>(
     B::validate_extensions_data(data)
 }
 
+/// Return the extension value `V` from the given account data.
+///
+/// The base state (mint or token account) is determined by the account type
+/// of `V`, and the data is validated before walking the TLV entries.
+#[inline]
+pub fn get_extension<V: ExtensionValue>(data: &[u8]) -> Result<&V, ProgramError> {
+    match extension_account_type(V::TYPE) {
+        AccountType::Mint => StateWithExtensions::<Mint>::from_bytes(data)?.get_extension(),
+        AccountType::Account => {
+            StateWithExtensions::<TokenAccount>::from_bytes(data)?.get_extension()
+        }
+        AccountType::Uninitialized => Err(ProgramError::InvalidAccountData),
+    }
+}
+
 /// A base state with TLV extension data.
 pub struct StateWithExtensions<'a, B: ExtensionBaseState> {
     base: &'a B,
@@ -257,11 +340,33 @@ impl<'a, B: ExtensionBaseState> StateWithExtensions<'a, B> {
         Ok(Self { base, tlv_data })
     }
 
+    /// Return a `StateWithExtensions` from the given account data.
+    ///
+    /// Both base-size accounts and accounts with extensions are accepted. The
+    /// data is validated, but the account owner is not checked.
+    #[inline]
+    pub fn from_bytes(data: &'a [u8]) -> Result<Self, ProgramError> {
+        validate_state_with_extensions_data::<B>(data)?;
+
+        // SAFETY: `data` has been validated to hold at least `B::BASE_LEN`
+        // bytes.
+        let base = unsafe { B::from_bytes_unchecked(data) };
+        let tlv_data = &data[extension_data_start::<B>(data.len())..];
+
+        Ok(Self { base, tlv_data })
+    }
+
     #[inline(always)]
     pub fn base(&self) -> &B {
         self.base
     }
 
+    /// Return an iterator over the TLV extension entries.
+    #[inline(always)]
+    pub fn iter_extensions(&self) -> ExtensionIter<'a> {
+        ExtensionIter::new(self.tlv_data)
+    }
+
     /// Find the value bytes for a given extension type via
     /// linear TLV walk.
     #[inline]
@@ -324,6 +429,12 @@ impl<'a, B: ExtensionBaseState> RefStateWithExtensions<'a, B> {
         &self.data[extension_data_start::<B>(self.data.len())..]
     }
 
+    /// Return an iterator over the TLV extension entries.
+    #[inline(always)]
+    pub fn iter_extensions(&self) -> ExtensionIter<'_> {
+        ExtensionIter::new(self.tlv_data())
+    }
+
     /// Find the value bytes for a given extension type via
     /// linear TLV walk.
     #[inline]
@@ -388,6 +499,12 @@ impl<'a, B: ExtensionBaseState> RefMutStateWithExtensions<'a, B> {
         &self.data[extension_data_start::<B>(self.data.len())..]
     }
 
+    /// Return an iterator over the TLV extension entries.
+    #[inline(always)]
+    pub fn iter_extensions(&self) -> ExtensionIter<'_> {
+        ExtensionIter::new(self.tlv_data())
+    }
+
     #[inline]
     fn tlv_data_mut(&mut self) -> &mut [u8] {
         let data_len = self.data.len();
@@ -478,6 +595,12 @@ impl<'a, B: ExtensionBaseState> StateWithExtensionsMut<'a, B> {
         self.base
     }
 
+    /// Return an iterator over the TLV extension entries.
+    #[inline(always)]
+    pub fn iter_extensions(&self) -> ExtensionIter<'_> {
+        ExtensionIter::new(self.tlv_data)
+    }
+
     /// Find the value bytes for a given extension type via
     /// linear TLV walk.
     #[inline]
@@ -523,12 +646,26 @@ mod tests {
         super::*,
         crate::state::{
             extension::{
-                adjust_len_for_multisig, default_account_state::DefaultAccountStateExtension,
-                extension_account_type, is_extension_not_found_error,
+                adjust_len_for_multisig,
+                default_account_state::DefaultAccountStateExtension,
+                extension_account_type, extension_value_len,
+                group_member_pointer::GroupMemberPointerExtension,
+                group_pointer::GroupPointerExtension,
+                interest_bearing_config::InterestBearingConfigExtension,
+                is_extension_not_found_error,
+                memo_transfer::MemoTransferExtension,
+                metadata_pointer::MetadataPointerExtension,
+                mint_close_authority::MintCloseAuthorityExtension,
+                non_transferable::{NonTransferableAccountExtension, NonTransferableExtension},
+                pausable::{PausableAccountExtension, PausableConfigExtension},
                 permanent_delegate::PermanentDelegateExtension,
+                scaled_ui_amount::ScaledUiAmountConfigExtension,
+                token_group::{TokenGroupExtension, TokenGroupMemberExtension},
+                transfer_fee::{TransferFeeAmountExtension, TransferFeeConfigExtension},
                 transfer_hook::TransferHookExtension,
-                transfer_hook_account::TransferHookAccountExtension, try_calculate_account_len,
-                TokenError, ACCOUNT_TYPE_INDEX, EXTENSION_NOT_FOUND_ERROR_CODE,
+                transfer_hook_account::TransferHookAccountExtension,
+                try_calculate_account_len, TokenError, ACCOUNT_TYPE_INDEX,
+                EXTENSION_NOT_FOUND_ERROR_CODE,
             },
             AccountState,
         },
@@ -1068,6 +1205,102 @@ mod tests {
         assert_eq!(written, 0);
     }
 
+    #[test]
+    fn state_with_extensions_from_bytes_reads_mint_and_iterates_entries() {
+        let mut tlv_data = Vec::new();
+        push_tlv_entry(
+            &mut tlv_data,
+            ExtensionType::DefaultAccountState,
+            &[AccountState::Frozen as u8],
+        );
+        push_tlv_entry(&mut tlv_data, ExtensionType::NonTransferable, &[]);
+        push_tlv_entry(&mut tlv_data, ExtensionType::Uninitialized, &[]);
+        let mut data = build_mint_data(&tlv_data);
+        data[36..44].copy_from_slice(&42u64.to_le_bytes());
+        data[44] = 6;
+        data[45] = 1;
+
+        let mint = StateWithExtensions::<Mint>::from_bytes(&data).unwrap();
+        assert_eq!(mint.base().supply(), 42);
+        assert_eq!(mint.base().decimals(), 6);
+        assert!(mint.base().is_initialized());
+        assert!(mint.base().mint_authority().is_none());
+
+        let mut entries = mint.iter_extensions();
+        assert_eq!(
+            entries.next(),
+            Some(Ok((
+                ExtensionType::DefaultAccountState,
+                &[AccountState::Frozen as u8][..]
+            )))
+        );
+        assert_eq!(
+            entries.next(),
+            Some(Ok((ExtensionType::NonTransferable, &[][..])))
+        );
+        assert_eq!(entries.next(), None);
+
+        let base_only = vec![0u8; Mint::BASE_LEN];
+        let mint = StateWithExtensions::<Mint>::from_bytes(&base_only).unwrap();
+        assert_eq!(mint.iter_extensions().next(), None);
+    }
+
+    #[test]
+    fn token_account_from_bytes_reads_base_fields() {
+        let mut tlv_data = Vec::new();
+        push_tlv_entry(&mut tlv_data, ExtensionType::ImmutableOwner, &[]);
+        let mut data = build_token_data(&tlv_data);
+        data[..32].copy_from_slice(&[1; 32]);
+        data[32..64].copy_from_slice(&[2; 32]);
+        data[64..72].copy_from_slice(&500u64.to_le_bytes());
+        data[72] = 1;
+        data[76..108].copy_from_slice(&[3; 32]);
+        data[108] = AccountState::Frozen as u8;
+        data[121..129].copy_from_slice(&7u64.to_le_bytes());
+
+        let token = TokenAccount::from_bytes(&data).unwrap();
+        assert_eq!(token.mint(), &Address::new_from_array([1; 32]));
+        assert_eq!(token.owner(), &Address::new_from_array([2; 32]));
+        assert_eq!(token.amount(), 500);
+        assert_eq!(token.delegate(), Some(&Address::new_from_array([3; 32])));
+        assert_eq!(token.state(), Ok(AccountState::Frozen));
+        assert!(token.is_frozen());
+        assert_eq!(token.native_amount(), None);
+        assert_eq!(token.delegated_amount(), 7);
+        assert_eq!(token.close_authority(), None);
+
+        let token = StateWithExtensions::<TokenAccount>::from_bytes(&data).unwrap();
+        assert_eq!(token.base().amount(), 500);
+        assert!(token
+            .get_extension_bytes(ExtensionType::ImmutableOwner)
+            .is_ok());
+
+        assert_eq!(
+            TokenAccount::from_bytes(&data[..TokenAccount::BASE_LEN - 1]).err(),
+            Some(ProgramError::InvalidAccountData)
+        );
+    }
+
+    #[test]
+    fn iter_extensions_stops_after_corrupt_entry() {
+        let mut tlv_data = Vec::new();
+        push_tlv_entry(&mut tlv_data, ExtensionType::ImmutableOwner, &[]);
+        tlv_data.extend_from_slice(&(ExtensionType::MemoTransfer as u16).to_le_bytes());
+        tlv_data.extend_from_slice(&8u16.to_le_bytes());
+        let data = build_token_data(&tlv_data);
+
+        let token = StateWithExtensions::<TokenAccount>::from_bytes(&data).unwrap();
+        let mut entries = token.iter_extensions();
+        assert_eq!(
+            entries.next(),
+            Some(Ok((ExtensionType::ImmutableOwner, &[][..])))
+        );
+        assert_eq!(entries.next(), Some(Err(ProgramError::InvalidAccountData)));
+        assert_eq!(entries.next(), None);
+
+        assert!(StateWithExtensions::<Mint>::from_bytes(&data).is_err());
+    }
+
     #[test]
     fn get_extension_types_requires_sufficient_output_capacity() {
         let mut tlv_data = Vec::new();
@@ -1117,6 +1350,33 @@ mod tests {
         );
     }
 
+    #[test]
+    fn try_calculate_account_len_matches_spl_lengths() {
+        assert_eq!(
+            ExtensionType::try_calculate_account_len::<Mint>(&[ExtensionType::TransferFeeConfig]),
+            Ok(278)
+        );
+        assert_eq!(
+            ExtensionType::try_calculate_account_len::<Mint>(&[
+                ExtensionType::MintCloseAuthority,
+                ExtensionType::NonTransferable,
+                ExtensionType::InterestBearingConfig,
+            ]),
+            Ok(TLV_START_INDEX + 3 * TLV_HEADER_LEN + 32 + 52)
+        );
+        assert_eq!(
+            ExtensionType::try_calculate_account_len::<TokenAccount>(&[
+                ExtensionType::ImmutableOwner,
+                ExtensionType::TransferFeeAmount,
+            ]),
+            Ok(TLV_START_INDEX + 2 * TLV_HEADER_LEN + 8)
+        );
+        assert_eq!(
+            ExtensionType::try_calculate_account_len::<Mint>(&[ExtensionType::TokenMetadata]),
+            Err(ProgramError::InvalidInstructionData)
+        );
+    }
+
     #[test]
     fn try_calculate_account_len_rejects_wrong_or_unsupported_extensions() {
         assert_eq!(
@@ -1174,6 +1434,151 @@ mod tests {
         assert_eq!(TransferHookExtension::LEN, 64);
     }
 
+    #[test]
+    fn extension_value_len_matches_typed_extensions() {
+        fn assert_len<V: ExtensionValue>() {
+            assert_eq!(extension_value_len(V::TYPE), Some(V::LEN));
+        }
+
+        assert_len::<DefaultAccountStateExtension>();
+        assert_len::<TransferHookAccountExtension>();
+        assert_len::<PermanentDelegateExtension>();
+        assert_len::<TransferHookExtension>();
+        assert_len::<TransferFeeConfigExtension>();
+        assert_len::<TransferFeeAmountExtension>();
+        assert_len::<MemoTransferExtension>();
+        assert_len::<ScaledUiAmountConfigExtension>();
+        assert_len::<InterestBearingConfigExtension>();
+        assert_len::<PausableConfigExtension>();
+        assert_len::<PausableAccountExtension>();
+        assert_len::<NonTransferableExtension>();
+        assert_len::<NonTransferableAccountExtension>();
+        assert_len::<MintCloseAuthorityExtension>();
+        assert_len::<MetadataPointerExtension>();
+        assert_len::<GroupPointerExtension>();
+        assert_len::<GroupMemberPointerExtension>();
+        assert_len::<TokenGroupExtension>();
+        assert_len::<TokenGroupMemberExtension>();
+    }
+
+    #[test]
+    fn get_extension_reads_memo_transfer() {
+        let mut tlv_data = Vec::new();
+        push_tlv_entry(&mut tlv_data, ExtensionType::MemoTransfer, &[1]);
+        let data = build_token_data(&tlv_data);
+
+        let extension = get_extension::<MemoTransferExtension>(&data).unwrap();
+        assert!(extension.require_incoming_transfer_memos());
+    }
+
+    #[test]
+    fn get_extension_reads_pausable_state() {
+        let mut value = [7u8; 33];
+        value[32] = 1;
+        let mut tlv_data = Vec::new();
+        push_tlv_entry(&mut tlv_data, ExtensionType::Pausable, &value);
+        let data = build_mint_data(&tlv_data);
+
+        let config = get_extension::<PausableConfigExtension>(&data).unwrap();
+        assert_eq!(config.authority().as_ref(), &[7u8; 32]);
+        assert!(config.is_paused());
+
+        let mut tlv_data = Vec::new();
+        push_tlv_entry(&mut tlv_data, ExtensionType::PausableAccount, &[]);
+        let data = build_token_data(&tlv_data);
+
+        assert!(get_extension::<PausableAccountExtension>(&data).is_ok());
+    }
+
+    #[test]
+    fn get_extension_detects_non_transferable_markers() {
+        let mut tlv_data = Vec::new();
+        push_tlv_entry(&mut tlv_data, ExtensionType::NonTransferable, &[]);
+        let data = build_mint_data(&tlv_data);
+
+        assert!(get_extension::<NonTransferableExtension>(&data).is_ok());
+
+        let error = get_extension::<PermanentDelegateExtension>(&data).unwrap_err();
+        assert!(is_extension_not_found_error(&error));
+
+        let mut tlv_data = Vec::new();
+        push_tlv_entry(&mut tlv_data, ExtensionType::NonTransferableAccount, &[]);
+        let data = build_token_data(&tlv_data);
+
+        assert!(get_extension::<NonTransferableAccountExtension>(&data).is_ok());
+    }
+
+    #[test]
+    fn get_extension_reads_pointer_extensions() {
+        let mut pointer = [1u8; 64];
+        pointer[32..].copy_from_slice(&[2u8; 32]);
+        let mut tlv_data = Vec::new();
+        push_tlv_entry(&mut tlv_data, ExtensionType::MintCloseAuthority, &[3u8; 32]);
+        push_tlv_entry(&mut tlv_data, ExtensionType::MetadataPointer, &pointer);
+        push_tlv_entry(&mut tlv_data, ExtensionType::GroupPointer, &pointer);
+        push_tlv_entry(&mut tlv_data, ExtensionType::GroupMemberPointer, &pointer);
+        let data = build_mint_data(&tlv_data);
+
+        let close = get_extension::<MintCloseAuthorityExtension>(&data).unwrap();
+        assert_eq!(close.close_authority().as_ref(), &[3u8; 32]);
+
+        let metadata = get_extension::<MetadataPointerExtension>(&data).unwrap();
+        assert_eq!(metadata.authority().as_ref(), &[1u8; 32]);
+        assert_eq!(metadata.metadata_address().as_ref(), &[2u8; 32]);
+
+        let group = get_extension::<GroupPointerExtension>(&data).unwrap();
+        assert_eq!(group.group_address().as_ref(), &[2u8; 32]);
+
+        let member = get_extension::<GroupMemberPointerExtension>(&data).unwrap();
+        assert_eq!(member.member_address().as_ref(), &[2u8; 32]);
+    }
+
+    #[test]
+    fn get_extension_reads_token_group_state() {
+        let mut group = [0u8; 80];
+        group[..32].copy_from_slice(&[1u8; 32]);
+        group[32..64].copy_from_slice(&[2u8; 32]);
+        group[64..72].copy_from_slice(&3u64.to_le_bytes());
+        group[72..].copy_from_slice(&10u64.to_le_bytes());
+        let mut member = [2u8; 72];
+        member[32..64].copy_from_slice(&[4u8; 32]);
+        member[64..].copy_from_slice(&3u64.to_le_bytes());
+        let mut tlv_data = Vec::new();
+        push_tlv_entry(&mut tlv_data, ExtensionType::TokenGroup, &group);
+        push_tlv_entry(&mut tlv_data, ExtensionType::TokenGroupMember, &member);
+        let data = build_mint_data(&tlv_data);
+
+        let group = get_extension::<TokenGroupExtension>(&data).unwrap();
+        assert_eq!(group.update_authority().as_ref(), &[1u8; 32]);
+        assert_eq!(group.mint().as_ref(), &[2u8; 32]);
+        assert_eq!(group.size(), 3);
+        assert_eq!(group.max_size(), 10);
+
+        let member = get_extension::<TokenGroupMemberExtension>(&data).unwrap();
+        assert_eq!(member.mint().as_ref(), &[2u8; 32]);
+        assert_eq!(member.group().as_ref(), &[4u8; 32]);
+        assert_eq!(member.member_number(), 3);
+    }
+
+    #[test]
+    fn get_extension_reads_raw_account_data() {
+        let mut tlv_data = Vec::new();
+        push_tlv_entry(&mut tlv_data, ExtensionType::PermanentDelegate, &[5; 32]);
+        let data = build_mint_data(&tlv_data);
+
+        let extension = get_extension::<PermanentDelegateExtension>(&data).unwrap();
+        assert_eq!(extension.delegate(), &Address::new_from_array([5; 32]));
+
+        let error = get_extension::<TransferHookExtension>(&data).unwrap_err();
+        assert!(is_extension_not_found_error(&error));
+
+        // Token account extensions require token account data.
+        assert_eq!(
+            get_extension::<TransferHookAccountExtension>(&data).err(),
+            Some(ProgramError::InvalidAccountData)
+        );
+    }
+
     #[test]
     fn spl2022_supported_extension_alignments_match() {
         assert_eq!(core::mem::align_of::<DefaultAccountStateExtension>(), 1);
@@ -1209,6 +1614,8 @@ mod tests {
         let ext = mint.get_extension::<TransferHookExtension>().unwrap();
         assert_eq!(ext.authority().as_ref(), &[11u8; 32]);
         assert_eq!(ext.program_id().as_ref(), &[22u8; 32]);
+        assert!(ext.has_program_id());
+        assert!(!TransferHookExtension::default().has_program_id());
     }
 
     #[test]
@@ -0,0 +1,138 @@
+use {
+    super::{sealed, ExtensionType, ExtensionValue, Pod},
+    solana_address::Address,
+};
+
+/// Token group extension data for mints (80 bytes).
+///
+/// Holds the configuration of a collection of mints, such as an NFT
+/// collection.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct TokenGroupExtension {
+    update_authority: [u8; 32],
+    mint: [u8; 32],
+    size: [u8; 8],
+    max_size: [u8; 8],
+}
+
+impl TokenGroupExtension {
+    pub const LEN: usize = core::mem::size_of::<TokenGroupExtension>();
+
+    /// Authority that can update the group.
+    ///
+    /// An all-zero address means that the group is immutable.
+    #[inline(always)]
+    pub fn update_authority(&self) -> &Address {
+        // SAFETY: `Address` is `#[repr(transparent)]` over `[u8; 32]` with
+        // alignment 1, so the pointer cast is valid.
+        unsafe { &*(self.update_authority.as_ptr() as *const Address) }
+    }
+
+    /// Mint associated with the group.
+    #[inline(always)]
+    pub fn mint(&self) -> &Address {
+        // SAFETY: `Address` is `#[repr(transparent)]` over `[u8; 32]` with
+        // alignment 1, so the pointer cast is valid.
+        unsafe { &*(self.mint.as_ptr() as *const Address) }
+    }
+
+    /// Current number of group members.
+    #[inline(always)]
+    pub const fn size(&self) -> u64 {
+        u64::from_le_bytes(self.size)
+    }
+
+    /// Maximum number of group members.
+    #[inline(always)]
+    pub const fn max_size(&self) -> u64 {
+        u64::from_le_bytes(self.max_size)
+    }
+
+    #[inline(always)]
+    pub fn set_update_authority(&mut self, update_authority: &Address) {
+        self.update_authority
+            .copy_from_slice(update_authority.as_ref());
+    }
+
+    #[inline(always)]
+    pub fn set_mint(&mut self, mint: &Address) {
+        self.mint.copy_from_slice(mint.as_ref());
+    }
+
+    #[inline(always)]
+    pub fn set_size(&mut self, size: u64) {
+        self.size = size.to_le_bytes();
+    }
+
+    #[inline(always)]
+    pub fn set_max_size(&mut self, max_size: u64) {
+        self.max_size = max_size.to_le_bytes();
+    }
+}
+
+/// Token group member extension data for mints (72 bytes).
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct TokenGroupMemberExtension {
+    mint: [u8; 32],
+    group: [u8; 32],
+    member_number: [u8; 8],
+}
+
+impl TokenGroupMemberExtension {
+    pub const LEN: usize = core::mem::size_of::<TokenGroupMemberExtension>();
+
+    /// Mint associated with the member.
+    #[inline(always)]
+    pub fn mint(&self) -> &Address {
+        // SAFETY: `Address` is `#[repr(transparent)]` over `[u8; 32]` with
+        // alignment 1, so the pointer cast is valid.
+        unsafe { &*(self.mint.as_ptr() as *const Address) }
+    }
+
+    /// Address of the group the member belongs to.
+    #[inline(always)]
+    pub fn group(&self) -> &Address {
+        // SAFETY: `Address` is `#[repr(transparent)]` over `[u8; 32]` with
+        // alignment 1, so the pointer cast is valid.
+        unsafe { &*(self.group.as_ptr() as *const Address) }
+    }
+
+    /// Member number within the group, starting at `1`.
+    #[inline(always)]
+    pub const fn member_number(&self) -> u64 {
+        u64::from_le_bytes(self.member_number)
+    }
+
+    #[inline(always)]
+    pub fn set_mint(&mut self, mint: &Address) {
+        self.mint.copy_from_slice(mint.as_ref());
+    }
+
+    #[inline(always)]
+    pub fn set_group(&mut self, group: &Address) {
+        self.group.copy_from_slice(group.as_ref());
+    }
+
+    #[inline(always)]
+    pub fn set_member_number(&mut self, member_number: u64) {
+        self.member_number = member_number.to_le_bytes();
+    }
+}
+
+// SAFETY: `TokenGroupExtension` and `TokenGroupMemberExtension` are repr(C),
+// contain only byte arrays, have no padding, and all bit patterns are valid.
+impl sealed::SealedPod for TokenGroupExtension {}
+unsafe impl Pod for TokenGroupExtension {}
+
+impl ExtensionValue for TokenGroupExtension {
+    const TYPE: ExtensionType = ExtensionType::TokenGroup;
+}
+
+impl sealed::SealedPod for TokenGroupMemberExtension {}
+unsafe impl Pod for TokenGroupMemberExtension {}
+
+impl ExtensionValue for TokenGroupMemberExtension {
+    const TYPE: ExtensionType = ExtensionType::TokenGroupMember;
+}
@@ -0,0 +1,318 @@
+use {
+    super::{sealed, ExtensionType, ExtensionValue, Pod},
+    solana_address::Address,
+};
+
+/// Maximum possible fee in basis points (100%).
+pub const MAX_FEE_BASIS_POINTS: u16 = 10_000;
+
+/// Number of basis points in `1`.
+const ONE_IN_BASIS_POINTS: u128 = MAX_FEE_BASIS_POINTS as u128;
+
+/// Transfer fee information (18 bytes).
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct TransferFee {
+    epoch: [u8; 8],
+    maximum_fee: [u8; 8],
+    transfer_fee_basis_points: [u8; 2],
+}
+
+impl TransferFee {
+    pub const LEN: usize = core::mem::size_of::<TransferFee>();
+
+    /// First epoch where the transfer fee takes effect.
+    #[inline(always)]
+    pub const fn epoch(&self) -> u64 {
+        u64::from_le_bytes(self.epoch)
+    }
+
+    /// Maximum fee assessed on transfers, expressed as an amount of tokens.
+    #[inline(always)]
+    pub const fn maximum_fee(&self) -> u64 {
+        u64::from_le_bytes(self.maximum_fee)
+    }
+
+    /// Amount of transfer collected as fees, expressed as basis points of the
+    /// transfer amount.
+    #[inline(always)]
+    pub const fn transfer_fee_basis_points(&self) -> u16 {
+        u16::from_le_bytes(self.transfer_fee_basis_points)
+    }
+
+    #[inline(always)]
+    pub fn set_epoch(&mut self, epoch: u64) {
+        self.epoch = epoch.to_le_bytes();
+    }
+
+    #[inline(always)]
+    pub fn set_maximum_fee(&mut self, maximum_fee: u64) {
+        self.maximum_fee = maximum_fee.to_le_bytes();
+    }
+
+    #[inline(always)]
+    pub fn set_transfer_fee_basis_points(&mut self, transfer_fee_basis_points: u16) {
+        self.transfer_fee_basis_points = transfer_fee_basis_points.to_le_bytes();
+    }
+
+    /// Calculate the transfer fee for a pre-fee amount.
+    ///
+    /// The fee is rounded up and capped at the maximum fee.
+    #[inline]
+    pub fn calculate_fee(&self, pre_fee_amount: u64) -> Option<u64> {
+        let transfer_fee_basis_points = self.transfer_fee_basis_points() as u128;
+
+        if transfer_fee_basis_points == 0 || pre_fee_amount == 0 {
+            Some(0)
+        } else {
+            let numerator = (pre_fee_amount as u128).checked_mul(transfer_fee_basis_points)?;
+            let raw_fee = u64::try_from(ceil_div(numerator, ONE_IN_BASIS_POINTS)?).ok()?;
+
+            Some(core::cmp::min(raw_fee, self.maximum_fee()))
+        }
+    }
+
+    /// Calculate the amount received by the destination for a pre-fee
+    /// amount.
+    #[inline]
+    pub fn calculate_post_fee_amount(&self, pre_fee_amount: u64) -> Option<u64> {
+        pre_fee_amount.checked_sub(self.calculate_fee(pre_fee_amount)?)
+    }
+
+    /// Calculate the pre-fee amount required for the destination to receive
+    /// `post_fee_amount`.
+    #[inline]
+    pub fn calculate_pre_fee_amount(&self, post_fee_amount: u64) -> Option<u64> {
+        let maximum_fee = self.maximum_fee();
+        let transfer_fee_basis_points = self.transfer_fee_basis_points() as u128;
+
+        match (transfer_fee_basis_points, post_fee_amount) {
+            (0, _) => Some(post_fee_amount),
+            (_, 0) => Some(0),
+            (ONE_IN_BASIS_POINTS, _) => maximum_fee.checked_add(post_fee_amount),
+            _ => {
+                let numerator = (post_fee_amount as u128).checked_mul(ONE_IN_BASIS_POINTS)?;
+                let denominator = ONE_IN_BASIS_POINTS.checked_sub(transfer_fee_basis_points)?;
+                let raw_pre_fee_amount = ceil_div(numerator, denominator)?;
+
+                if raw_pre_fee_amount.checked_sub(post_fee_amount as u128)? >= maximum_fee as u128 {
+                    post_fee_amount.checked_add(maximum_fee)
+                } else {
+                    u64::try_from(raw_pre_fee_amount).ok()
+                }
+            }
+        }
+    }
+
+    /// Calculate the fee that would produce `post_fee_amount` after it is
+    /// deducted from the transfer amount.
+    #[inline]
+    pub fn calculate_inverse_fee(&self, post_fee_amount: u64) -> Option<u64> {
+        let pre_fee_amount = self.calculate_pre_fee_amount(post_fee_amount)?;
+        self.calculate_fee(pre_fee_amount)
+    }
+}
+
+/// Transfer fee config extension data for mints (108 bytes).
+///
+/// Holds two transfer fees so that fee updates only take effect two epochs
+/// later: the newer fee applies from its epoch onwards, the older one before.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct TransferFeeConfigExtension {
+    transfer_fee_config_authority: [u8; 32],
+    withdraw_withheld_authority: [u8; 32],
+    withheld_amount: [u8; 8],
+    older_transfer_fee: TransferFee,
+    newer_transfer_fee: TransferFee,
+}
+
+impl TransferFeeConfigExtension {
+    pub const LEN: usize = core::mem::size_of::<TransferFeeConfigExtension>();
+
+    /// Authority that can set the fee.
+    ///
+    /// An all-zero address means that no authority is set.
+    #[inline(always)]
+    pub fn transfer_fee_config_authority(&self) -> &Address {
+        // SAFETY: `Address` is `#[repr(transparent)]` over `[u8; 32]` with
+        // alignment 1, so the pointer cast is valid.
+        unsafe { &*(self.transfer_fee_config_authority.as_ptr() as *const Address) }
+    }
+
+    /// Authority that can withdraw withheld fees.
+    ///
+    /// An all-zero address means that no authority is set.
+    #[inline(always)]
+    pub fn withdraw_withheld_authority(&self) -> &Address {
+        // SAFETY: `Address` is `#[repr(transparent)]` over `[u8; 32]` with
+        // alignment 1, so the pointer cast is valid.
+        unsafe { &*(self.withdraw_withheld_authority.as_ptr() as *const Address) }
+    }
+
+    /// Withheld transfer fee tokens that have been moved to the mint.
+    #[inline(always)]
+    pub const fn withheld_amount(&self) -> u64 {
+        u64::from_le_bytes(self.withheld_amount)
+    }
+
+    #[inline(always)]
+    pub const fn older_transfer_fee(&self) -> &TransferFee {
+        &self.older_transfer_fee
+    }
+
+    #[inline(always)]
+    pub const fn newer_transfer_fee(&self) -> &TransferFee {
+        &self.newer_transfer_fee
+    }
+
+    #[inline(always)]
+    pub fn set_withheld_amount(&mut self, withheld_amount: u64) {
+        self.withheld_amount = withheld_amount.to_le_bytes();
+    }
+
+    /// Return the transfer fee in effect at `epoch`.
+    #[inline(always)]
+    pub const fn get_epoch_fee(&self, epoch: u64) -> &TransferFee {
+        if epoch >= self.newer_transfer_fee.epoch() {
+            &self.newer_transfer_fee
+        } else {
+            &self.older_transfer_fee
+        }
+    }
+
+    /// Calculate the fee for a pre-fee amount transferred at `epoch`.
+    ///
+    /// This is the fee expected by `TransferCheckedWithFee`.
+    #[inline(always)]
+    pub fn calculate_epoch_fee(&self, epoch: u64, pre_fee_amount: u64) -> Option<u64> {
+        self.get_epoch_fee(epoch).calculate_fee(pre_fee_amount)
+    }
+
+    /// Calculate the fee for a transfer at `epoch` that results in
+    /// `post_fee_amount` for the destination.
+    #[inline(always)]
+    pub fn calculate_inverse_epoch_fee(&self, epoch: u64, post_fee_amount: u64) -> Option<u64> {
+        self.get_epoch_fee(epoch)
+            .calculate_inverse_fee(post_fee_amount)
+    }
+}
+
+/// Transfer fee amount extension data for token accounts (8 bytes).
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct TransferFeeAmountExtension {
+    withheld_amount: [u8; 8],
+}
+
+impl TransferFeeAmountExtension {
+    pub const LEN: usize = core::mem::size_of::<TransferFeeAmountExtension>();
+
+    /// Amount withheld during transfers, to be harvested to the mint.
+    #[inline(always)]
+    pub const fn withheld_amount(&self) -> u64 {
+        u64::from_le_bytes(self.withheld_amount)
+    }
+
+    #[inline(always)]
+    pub fn set_withheld_amount(&mut self, withheld_amount: u64) {
+        self.withheld_amount = withheld_amount.to_le_bytes();
+    }
+}
+
+#[inline(always)]
+fn ceil_div(numerator: u128, denominator: u128) -> Option<u128> {
+    numerator
+        .checked_add(denominator)?
+        .checked_sub(1)?
+        .checked_div(denominator)
+}
+
+// SAFETY: `TransferFeeConfigExtension` and `TransferFeeAmountExtension` are
+// repr(C), contain only byte arrays, have no padding, and all bit patterns
+// are valid.
+impl sealed::SealedPod for TransferFeeConfigExtension {}
+unsafe impl Pod for TransferFeeConfigExtension {}
+
+impl ExtensionValue for TransferFeeConfigExtension {
+    const TYPE: ExtensionType = ExtensionType::TransferFeeConfig;
+}
+
+impl sealed::SealedPod for TransferFeeAmountExtension {}
+unsafe impl Pod for TransferFeeAmountExtension {}
+
+impl ExtensionValue for TransferFeeAmountExtension {
+    const TYPE: ExtensionType = ExtensionType::TransferFeeAmount;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn transfer_fee(epoch: u64, maximum_fee: u64, transfer_fee_basis_points: u16) -> TransferFee {
+        let mut fee = TransferFee::default();
+        fee.set_epoch(epoch);
+        fee.set_maximum_fee(maximum_fee);
+        fee.set_transfer_fee_basis_points(transfer_fee_basis_points);
+        fee
+    }
+
+    #[test]
+    fn test_len() {
+        assert_eq!(TransferFee::LEN, 18);
+        assert_eq!(TransferFeeConfigExtension::LEN, 108);
+        assert_eq!(TransferFeeAmountExtension::LEN, 8);
+    }
+
+    #[test]
+    fn test_calculate_fee() {
+        // 1% with a maximum fee of 5,000.
+        let fee = transfer_fee(0, 5_000, 100);
+
+        assert_eq!(fee.calculate_fee(0), Some(0));
+        assert_eq!(fee.calculate_fee(1), Some(1));
+        assert_eq!(fee.calculate_fee(100), Some(1));
+        assert_eq!(fee.calculate_fee(101), Some(2));
+        assert_eq!(fee.calculate_fee(10_000), Some(100));
+        assert_eq!(fee.calculate_fee(u64::MAX), Some(5_000));
+        assert_eq!(fee.calculate_post_fee_amount(10_000), Some(9_900));
+
+        assert_eq!(transfer_fee(0, 5_000, 0).calculate_fee(10_000), Some(0));
+    }
+
+    #[test]
+    fn test_calculate_inverse_fee() {
+        let fee = transfer_fee(0, 5_000, 100);
+
+        for post_fee_amount in [0, 1, 99, 100, 9_900, 1_000_000, u64::MAX - 5_000] {
+            let pre_fee_amount = fee.calculate_pre_fee_amount(post_fee_amount).unwrap();
+            let fee_amount = fee.calculate_inverse_fee(post_fee_amount).unwrap();
+
+            assert_eq!(fee.calculate_fee(pre_fee_amount), Some(fee_amount));
+            assert_eq!(pre_fee_amount - fee_amount, post_fee_amount);
+        }
+
+        // 100% fee always charges the maximum fee.
+        let fee = transfer_fee(0, 5_000, MAX_FEE_BASIS_POINTS);
+        assert_eq!(fee.calculate_pre_fee_amount(1), Some(5_001));
+        assert_eq!(fee.calculate_inverse_fee(1), Some(5_000));
+        assert_eq!(fee.calculate_pre_fee_amount(u64::MAX), None);
+    }
+
+    #[test]
+    fn test_get_epoch_fee() {
+        let config = TransferFeeConfigExtension {
+            older_transfer_fee: transfer_fee(0, u64::MAX, 10),
+            newer_transfer_fee: transfer_fee(10, u64::MAX, 100),
+            ..Default::default()
+        };
+
+        assert_eq!(config.transfer_fee_config_authority(), &Address::default());
+        assert_eq!(config.withdraw_withheld_authority(), &Address::default());
+        assert_eq!(config.get_epoch_fee(9), config.older_transfer_fee());
+        assert_eq!(config.get_epoch_fee(10), config.newer_transfer_fee());
+        assert_eq!(config.calculate_epoch_fee(9, 10_000), Some(10));
+        assert_eq!(config.calculate_epoch_fee(10, 10_000), Some(100));
+        assert_eq!(config.calculate_inverse_epoch_fee(10, 9_900), Some(100));
+    }
+}
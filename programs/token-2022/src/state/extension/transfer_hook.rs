@@ -17,6 +17,9 @@ pub struct TransferHookExtension {
 impl TransferHookExtension {
     pub const LEN: usize = core::mem::size_of::<TransferHookExtension>();
 
+    /// Authority that can set the transfer hook program id.
+    ///
+    /// An all-zero address means that no authority is set.
     #[inline(always)]
     pub fn authority(&self) -> &Address {
         // SAFETY: `Address` is `#[repr(transparent)]` over `[u8; 32]` with
@@ -24,6 +27,9 @@ impl TransferHookExtension {
         unsafe { &*(self.authority.as_ptr() as *const Address) }
     }
 
+    /// Program called during transfers.
+    ///
+    /// An all-zero address means that no transfer hook program is set.
     #[inline(always)]
     pub fn program_id(&self) -> &Address {
         // SAFETY: `Address` is `#[repr(transparent)]` over `[u8; 32]` with
@@ -31,6 +37,12 @@ impl TransferHookExtension {
         unsafe { &*(self.program_id.as_ptr() as *const Address) }
     }
 
+    /// Return whether a transfer hook program is set.
+    #[inline(always)]
+    pub fn has_program_id(&self) -> bool {
+        self.program_id != [0; 32]
+    }
+
     #[inline(always)]
     pub fn set_authority(&mut self, authority: &Address) {
         self.authority.copy_from_slice(authority.as_ref());
@@ -1,6 +1,10 @@
 use super::{sealed, ExtensionType, ExtensionValue, Pod};
 
 /// Transfer hook account extension data (1 byte).
+///
+/// The token program sets the `transferring` flag on the source and
+/// destination accounts while the transfer hook program executes, so the hook
+/// can check that it was invoked during a transfer.
 #[repr(C)]
 #[derive(Clone, Copy, Debug, Default, PartialEq)]
 pub struct TransferHookAccountExtension {
@@ -1,4 +1,5 @@
 use {
+    super::PodCOption,
     crate::ID,
     solana_account_view::{AccountView, Ref},
     solana_address::Address,
@@ -8,14 +9,11 @@ use {
 /// Mint data.
 #[repr(C)]
 pub struct Mint {
-    /// Indicates whether the mint authority is present or not.
-    mint_authority_flag: [u8; 4],
-
     /// Optional authority used to mint new tokens. The mint authority may only
     /// be provided during mint creation. If no mint authority is present
     /// then the mint has a fixed supply and no further tokens may be
     /// minted.
-    mint_authority: Address,
+    mint_authority: PodCOption<Address>,
 
     /// Total supply of tokens.
     supply: [u8; 8],
@@ -26,11 +24,8 @@ pub struct Mint {
     /// Is `true` if this structure has been initialized.
     is_initialized: u8,
 
-    /// Indicates whether the freeze authority is present or not.
-    freeze_authority_flag: [u8; 4],
-
     /// Optional authority to freeze token accounts.
-    freeze_authority: Address,
+    freeze_authority: PodCOption<Address>,
 }
 
 impl Mint {
@@ -91,15 +86,11 @@ impl Mint {
 
     #[inline(always)]
     pub fn has_mint_authority(&self) -> bool {
-        self.mint_authority_flag[0] == 1
+        self.mint_authority.is_some()
     }
 
     pub fn mint_authority(&self) -> Option<&Address> {
-        if self.has_mint_authority() {
-            Some(self.mint_authority_unchecked())
-        } else {
-            None
-        }
+        self.mint_authority.get()
     }
 
     /// Return the mint authority.
@@ -108,7 +99,7 @@ impl Mint {
     /// a mint authority set since it skips the `Option` check.
     #[inline(always)]
     pub fn mint_authority_unchecked(&self) -> &Address {
-        &self.mint_authority
+        self.mint_authority.get_unchecked()
     }
 
     pub fn supply(&self) -> u64 {
@@ -125,15 +116,11 @@ impl Mint {
 
     #[inline(always)]
     pub fn has_freeze_authority(&self) -> bool {
-        self.freeze_authority_flag[0] == 1
+        self.freeze_authority.is_some()
     }
 
     pub fn freeze_authority(&self) -> Option<&Address> {
-        if self.has_freeze_authority() {
-            Some(self.freeze_authority_unchecked())
-        } else {
-            None
-        }
+        self.freeze_authority.get()
     }
 
     /// Return the freeze authority.
@@ -142,6 +129,6 @@ impl Mint {
     /// a freeze authority set since it skips the `Option` check.
     #[inline(always)]
     pub fn freeze_authority_unchecked(&self) -> &Address {
-        &self.freeze_authority
+        self.freeze_authority.get_unchecked()
     }
 }
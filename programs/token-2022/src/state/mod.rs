@@ -3,6 +3,9 @@ mod account_type;
 mod extension;
 mod mint;
 mod multisig;
+mod pod_coption;
 mod token;
 
-pub use {account_state::*, account_type::*, extension::*, mint::*, multisig::*, token::*};
+pub use {
+    account_state::*, account_type::*, extension::*, mint::*, multisig::*, pod_coption::*, token::*,
+};
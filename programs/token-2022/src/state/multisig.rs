@@ -3,7 +3,7 @@ use {
     core::mem::size_of,
     solana_account_view::{AccountView, Ref},
     solana_address::Address,
-    solana_program_error::ProgramError,
+    solana_program_error::{ProgramError, ProgramResult},
 };
 
 /// Multisignature data.
@@ -104,4 +104,106 @@ impl Multisig {
     pub fn is_initialized(&self) -> bool {
         self.is_initialized != 0
     }
+
+    /// Check that `signers` satisfy the `Multisig` threshold.
+    ///
+    /// Each multisig signer address is matched at most once, so duplicated
+    /// accounts do not count twice. This mirrors the check performed by the
+    /// token program: a provided account matching a multisig signer must be
+    /// a signer, and at least [`required_signers`](Self::required_signers)
+    /// distinct multisig signers must be matched.
+    ///
+    /// Returns [`ProgramError::MissingRequiredSignature`] otherwise.
+    #[inline]
+    pub fn validate_signers(&self, signers: &[&AccountView]) -> ProgramResult {
+        let mut matched = [false; MAX_MULTISIG_SIGNERS];
+        let mut num_signers = 0;
+
+        for signer in signers {
+            for (position, address) in self.signers().iter().enumerate() {
+                if address == signer.address() && !matched[position] {
+                    if !signer.is_signer() {
+                        return Err(ProgramError::MissingRequiredSignature);
+                    }
+                    matched[position] = true;
+                    num_signers += 1;
+                }
+            }
+        }
+
+        if num_signers < self.required_signers() {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use {
+        super::*,
+        solana_account_view::{RuntimeAccount, NOT_BORROWED},
+        std::{vec, vec::Vec},
+    };
+
+    fn build_signer_view(address: [u8; 32], is_signer: bool) -> (Vec<u64>, AccountView) {
+        let mut backing = vec![0u64; size_of::<RuntimeAccount>().div_ceil(size_of::<u64>())];
+        let raw = backing.as_mut_ptr() as *mut RuntimeAccount;
+
+        unsafe {
+            (*raw).borrow_state = NOT_BORROWED;
+            (*raw).is_signer = is_signer as u8;
+            (*raw).address = Address::new_from_array(address);
+
+            (backing, AccountView::new_unchecked(raw))
+        }
+    }
+
+    fn build_multisig(m: u8, n: u8) -> Vec<u8> {
+        let mut data = vec![0u8; Multisig::LEN];
+        data[0] = m;
+        data[1] = n;
+        data[2] = 1;
+        for i in 0..n as usize {
+            let start = 3 + i * 32;
+            data[start..start + 32].copy_from_slice(&[i as u8 + 1; 32]);
+        }
+        data
+    }
+
+    #[test]
+    fn validate_signers_requires_threshold() {
+        let data = build_multisig(2, 3);
+        let multisig = unsafe { Multisig::from_bytes_unchecked(&data) };
+        assert!(multisig.is_initialized());
+        assert_eq!(multisig.signers().len(), 3);
+        assert_eq!(multisig.signers()[2], Address::new_from_array([3; 32]));
+
+        let (_a, first) = build_signer_view([1; 32], true);
+        let (_b, third) = build_signer_view([3; 32], true);
+        let (_c, unknown) = build_signer_view([9; 32], true);
+
+        assert_eq!(multisig.validate_signers(&[&first, &third]), Ok(()));
+        assert_eq!(
+            multisig.validate_signers(&[&first, &first, &unknown]),
+            Err(ProgramError::MissingRequiredSignature)
+        );
+    }
+
+    #[test]
+    fn validate_signers_rejects_non_signer_match() {
+        let data = build_multisig(1, 2);
+        let multisig = unsafe { Multisig::from_bytes_unchecked(&data) };
+
+        let (_a, first) = build_signer_view([1; 32], true);
+        let (_b, second) = build_signer_view([2; 32], false);
+
+        assert_eq!(
+            multisig.validate_signers(&[&first, &second]),
+            Err(ProgramError::MissingRequiredSignature)
+        );
+    }
 }
@@ -0,0 +1,104 @@
+use solana_address::Address;
+
+mod sealed {
+    pub trait SealedValue {}
+}
+
+/// Marker trait for the values of a [`PodCOption`].
+///
+/// Options are read in place from account data, so values must have
+/// alignment 1 and no invalid bit-patterns. The trait is implemented for
+/// [`Address`] and byte arrays.
+pub trait PodCOptionValue: sealed::SealedValue {}
+
+impl sealed::SealedValue for Address {}
+impl PodCOptionValue for Address {}
+
+impl<const N: usize> sealed::SealedValue for [u8; N] {}
+impl<const N: usize> PodCOptionValue for [u8; N] {}
+
+const _ASSERT_ADDRESS_ALIGN: () = assert!(core::mem::align_of::<Address>() == 1);
+
+/// An optional value encoded as an SPL `COption`.
+///
+/// The value is preceded by a 4-byte little-endian tag, which is `1` when
+/// the value is present and `0` otherwise. The value bytes are always
+/// present in the account data.
+#[repr(C)]
+pub struct PodCOption<T: PodCOptionValue> {
+    /// Indicates whether the value is present or not.
+    tag: [u8; 4],
+
+    /// The value, only meaningful when the tag is set.
+    value: T,
+}
+
+impl<T: PodCOptionValue> PodCOption<T> {
+    /// Return `true` if the value is present.
+    #[inline(always)]
+    pub fn is_some(&self) -> bool {
+        self.tag[0] == 1
+    }
+
+    /// Return `true` if the value is absent.
+    #[inline(always)]
+    pub fn is_none(&self) -> bool {
+        !self.is_some()
+    }
+
+    /// Return the value if it is present.
+    #[inline(always)]
+    pub fn get(&self) -> Option<&T> {
+        if self.is_some() {
+            Some(&self.value)
+        } else {
+            None
+        }
+    }
+
+    /// Return the value without checking the tag.
+    ///
+    /// This method should be used when the caller knows that the value is
+    /// present since it skips the `Option` check.
+    #[inline(always)]
+    pub fn get_unchecked(&self) -> &T {
+        &self.value
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::state::{Mint, TokenAccount},
+        core::mem::{align_of, size_of},
+    };
+
+    #[test]
+    fn pod_coption_reads_tag() {
+        let some = PodCOption {
+            tag: [1, 0, 0, 0],
+            value: [7u8; 8],
+        };
+        assert!(some.is_some());
+        assert_eq!(some.get(), Some(&[7u8; 8]));
+
+        let none = PodCOption {
+            tag: [0; 4],
+            value: [7u8; 8],
+        };
+        assert!(none.is_none());
+        assert_eq!(none.get(), None);
+        assert_eq!(none.get_unchecked(), &[7u8; 8]);
+    }
+
+    #[test]
+    fn pod_coption_keeps_spl_layout() {
+        assert_eq!(size_of::<PodCOption<Address>>(), 36);
+        assert_eq!(align_of::<PodCOption<Address>>(), 1);
+        assert_eq!(size_of::<PodCOption<[u8; 8]>>(), 12);
+        assert_eq!(align_of::<PodCOption<[u8; 8]>>(), 1);
+        assert_eq!(Mint::BASE_LEN, 82);
+        assert_eq!(TokenAccount::BASE_LEN, 165);
+    }
+}
@@ -1,5 +1,5 @@
 use {
-    super::AccountState,
+    super::{AccountState, PodCOption},
     crate::ID,
     solana_account_view::{AccountView, Ref},
     solana_address::Address,
@@ -7,6 +7,10 @@ use {
 };
 
 /// Token account data.
+///
+/// This is a zero-copy view over the base 165 bytes of a token account. Use
+/// [`RefStateWithExtensions`](super::RefStateWithExtensions) to also access
+/// the TLV extension entries of the account.
 #[repr(C)]
 pub struct TokenAccount {
     /// The mint associated with this account
@@ -18,33 +22,24 @@ pub struct TokenAccount {
     /// The amount of tokens this account holds.
     amount: [u8; 8],
 
-    /// Indicates whether the delegate is present or not.
-    delegate_flag: [u8; 4],
-
     /// If `delegate` is `Some` then `delegated_amount` represents
     /// the amount authorized by the delegate.
-    delegate: Address,
+    delegate: PodCOption<Address>,
 
     /// The account's state.
     state: u8,
 
-    /// Indicates whether this account represents a native token or not.
-    is_native: [u8; 4],
-
-    /// When `is_native.is_some()` is `true`, this is a native token, and the
+    /// When `native_amount.is_some()` is `true`, this is a native token, and the
     /// value logs the rent-exempt reserve. An Account is required to be
     /// rent-exempt, so the value is used by the Processor to ensure that
     /// wrapped SOL accounts do not drop below this threshold.
-    native_amount: [u8; 8],
+    native_amount: PodCOption<[u8; 8]>,
 
     /// The amount delegated.
     delegated_amount: [u8; 8],
 
-    /// Indicates whether the close authority is present or not.
-    close_authority_flag: [u8; 4],
-
     /// Optional authority to close the account.
-    close_authority: Address,
+    close_authority: PodCOption<Address>,
 }
 
 impl TokenAccount {
@@ -91,6 +86,20 @@ impl TokenAccount {
         Ok(Self::from_bytes_unchecked(account_view.borrow_unchecked()))
     }
 
+    /// Return a `TokenAccount` from the given bytes.
+    ///
+    /// This method performs a length validation, accepting both base-size
+    /// and extended token accounts.
+    #[inline]
+    pub fn from_bytes(bytes: &[u8]) -> Result<&Self, ProgramError> {
+        if bytes.len() < Self::BASE_LEN {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        // SAFETY: `bytes` holds at least `BASE_LEN` bytes and `TokenAccount`
+        // has an alignment of 1 byte.
+        Ok(unsafe { Self::from_bytes_unchecked(bytes) })
+    }
+
     /// Return a `TokenAccount` from the given bytes.
     ///
     /// # Safety
@@ -122,23 +131,19 @@ impl TokenAccount {
 
     #[inline(always)]
     pub fn has_delegate(&self) -> bool {
-        self.delegate_flag[0] == 1
+        self.delegate.is_some()
     }
 
     #[inline(always)]
     pub fn delegate(&self) -> Option<&Address> {
-        if self.has_delegate() {
-            Some(self.delegate_unchecked())
-        } else {
-            None
-        }
+        self.delegate.get()
     }
 
     /// Use this when you know the account will have a delegate and want to skip
     /// the `Option` check.
     #[inline(always)]
     pub fn delegate_unchecked(&self) -> &Address {
-        &self.delegate
+        self.delegate.get_unchecked()
     }
 
     #[inline(always)]
@@ -148,16 +153,12 @@ impl TokenAccount {
 
     #[inline(always)]
     pub fn is_native(&self) -> bool {
-        self.is_native[0] == 1
+        self.native_amount.is_some()
     }
 
     #[inline(always)]
     pub fn native_amount(&self) -> Option<u64> {
-        if self.is_native() {
-            Some(self.native_amount_unchecked())
-        } else {
-            None
-        }
+        self.native_amount.get().copied().map(u64::from_le_bytes)
     }
 
     /// Return the native amount.
@@ -166,7 +167,7 @@ impl TokenAccount {
     /// native since it skips the `Option` check.
     #[inline(always)]
     pub fn native_amount_unchecked(&self) -> u64 {
-        u64::from_le_bytes(self.native_amount)
+        u64::from_le_bytes(*self.native_amount.get_unchecked())
     }
 
     #[inline(always)]
@@ -176,16 +177,12 @@ impl TokenAccount {
 
     #[inline(always)]
     pub fn has_close_authority(&self) -> bool {
-        self.close_authority_flag[0] == 1
+        self.close_authority.is_some()
     }
 
     #[inline(always)]
     pub fn close_authority(&self) -> Option<&Address> {
-        if self.has_close_authority() {
-            Some(self.close_authority_unchecked())
-        } else {
-            None
-        }
+        self.close_authority.get()
     }
 
     /// Return the close authority.
@@ -194,7 +191,7 @@ impl TokenAccount {
     /// have a close authority set since it skips the `Option` check.
     #[inline(always)]
     pub fn close_authority_unchecked(&self) -> &Address {
-        &self.close_authority
+        self.close_authority.get_unchecked()
     }
 
     #[inline(always)]
@@ -0,0 +1,108 @@
+//! Abstraction over the SPL Token and Token-2022 programs.
+
+use {
+    crate::{
+        instructions::LEGACY_TOKEN_PROGRAM_ID,
+        state::{Mint, TokenAccount},
+    },
+    solana_address::Address,
+};
+
+/// A token program implementing the SPL Token interface.
+///
+/// Helpers such as [`TransferTokens`](crate::instructions::TransferTokens)
+/// use it to pick the instruction best suited to the program. It is
+/// implemented by the [`LegacyToken`] and [`Token2022`] markers, when the
+/// program is known at compile time, and by [`Address`], when the program is
+/// only known at runtime.
+pub trait TokenProgram {
+    /// Return the address of the token program.
+    fn id(&self) -> &Address;
+
+    /// Check whether the program requires checked instructions, such as
+    /// `TransferChecked`, for tokens of any mint.
+    ///
+    /// Token-2022 extensions like transfer fees and transfer hooks reject
+    /// unchecked transfers, so only the legacy program accepts them for all
+    /// mints.
+    fn requires_checked_instructions(&self) -> bool;
+
+    /// Return the length of a mint account without extensions.
+    #[inline(always)]
+    fn mint_len(&self) -> usize {
+        Mint::BASE_LEN
+    }
+
+    /// Return the length of a token account without extensions.
+    #[inline(always)]
+    fn account_len(&self) -> usize {
+        TokenAccount::BASE_LEN
+    }
+}
+
+/// The legacy SPL Token program.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct LegacyToken;
+
+impl TokenProgram for LegacyToken {
+    #[inline(always)]
+    fn id(&self) -> &Address {
+        &LEGACY_TOKEN_PROGRAM_ID
+    }
+
+    #[inline(always)]
+    fn requires_checked_instructions(&self) -> bool {
+        false
+    }
+}
+
+/// The Token-2022 program.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Token2022;
+
+impl TokenProgram for Token2022 {
+    #[inline(always)]
+    fn id(&self) -> &Address {
+        &crate::ID
+    }
+
+    #[inline(always)]
+    fn requires_checked_instructions(&self) -> bool {
+        true
+    }
+}
+
+/// A token program known at runtime.
+///
+/// Any program other than the legacy SPL Token program is assumed to require
+/// checked instructions.
+impl TokenProgram for Address {
+    #[inline(always)]
+    fn id(&self) -> &Address {
+        self
+    }
+
+    #[inline(always)]
+    fn requires_checked_instructions(&self) -> bool {
+        self != &LEGACY_TOKEN_PROGRAM_ID
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn token_programs_report_their_requirements() {
+        assert_eq!(LegacyToken.id(), &LEGACY_TOKEN_PROGRAM_ID);
+        assert!(!LegacyToken.requires_checked_instructions());
+        assert_eq!(Token2022.id(), &crate::ID);
+        assert!(Token2022.requires_checked_instructions());
+
+        assert!(!LEGACY_TOKEN_PROGRAM_ID.requires_checked_instructions());
+        assert!(crate::ID.requires_checked_instructions());
+
+        assert_eq!(LegacyToken.mint_len(), 82);
+        assert_eq!(Token2022.account_len(), 165);
+    }
+}
@@ -0,0 +1,529 @@
+use {
+    super::EXECUTE_DISCRIMINATOR,
+    core::slice::from_raw_parts,
+    solana_address::Address,
+    solana_program_error::{ProgramError, ProgramResult},
+};
+
+/// Length of the TLV header (8-byte type and 4-byte length).
+const TLV_HEADER_LEN: usize = 12;
+
+/// Length of the `ExtraAccountMeta` slice header (4-byte count).
+const SLICE_HEADER_LEN: usize = 4;
+
+/// Configuration of an extra account required by a transfer hook (35 bytes).
+///
+/// The `discriminator` determines how the address is resolved:
+///
+/// - `0`: `address_config` is the literal address of the account.
+/// - `1`: the account is a PDA of the hook program, derived from the seeds
+///   packed in `address_config`.
+/// - `2`: the address is read from instruction or account data, as described
+///   by `address_config`.
+/// - `128 + i`: the account is a PDA of the program at account index `i`,
+///   derived from the seeds packed in `address_config`.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct ExtraAccountMeta {
+    discriminator: u8,
+    address_config: [u8; 32],
+    is_signer: u8,
+    is_writable: u8,
+}
+
+impl ExtraAccountMeta {
+    pub const LEN: usize = core::mem::size_of::<ExtraAccountMeta>();
+
+    /// Discriminator of a literal address.
+    pub const LITERAL: u8 = 0;
+
+    /// Discriminator of a PDA of the hook program.
+    pub const PDA: u8 = 1;
+
+    /// Discriminator of an address read from instruction or account data.
+    pub const ADDRESS_DATA: u8 = 2;
+
+    /// Offset added to the account index of external PDA discriminators.
+    pub const EXTERNAL_PDA_OFFSET: u8 = 1 << 7;
+
+    /// Create an `ExtraAccountMeta` for a literal address.
+    #[inline(always)]
+    pub fn new_with_address(address: &Address, is_signer: bool, is_writable: bool) -> Self {
+        Self {
+            discriminator: Self::LITERAL,
+            address_config: address.to_bytes(),
+            is_signer: is_signer as u8,
+            is_writable: is_writable as u8,
+        }
+    }
+
+    /// Create an `ExtraAccountMeta` for a PDA of the hook program.
+    ///
+    /// Returns [`ProgramError::InvalidArgument`] if the packed seeds do not
+    /// fit in 32 bytes.
+    #[inline(always)]
+    pub fn new_with_seeds(
+        seeds: &[Seed],
+        is_signer: bool,
+        is_writable: bool,
+    ) -> Result<Self, ProgramError> {
+        Ok(Self {
+            discriminator: Self::PDA,
+            address_config: Seed::pack_into_address_config(seeds)?,
+            is_signer: is_signer as u8,
+            is_writable: is_writable as u8,
+        })
+    }
+
+    /// Create an `ExtraAccountMeta` for a PDA of the program at account index
+    /// `program_index`.
+    ///
+    /// Returns [`ProgramError::InvalidArgument`] if the index does not fit in
+    /// the discriminator or the packed seeds do not fit in 32 bytes.
+    #[inline(always)]
+    pub fn new_external_pda_with_seeds(
+        program_index: u8,
+        seeds: &[Seed],
+        is_signer: bool,
+        is_writable: bool,
+    ) -> Result<Self, ProgramError> {
+        if program_index >= Self::EXTERNAL_PDA_OFFSET {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        Ok(Self {
+            discriminator: Self::EXTERNAL_PDA_OFFSET + program_index,
+            address_config: Seed::pack_into_address_config(seeds)?,
+            is_signer: is_signer as u8,
+            is_writable: is_writable as u8,
+        })
+    }
+
+    #[inline(always)]
+    pub fn discriminator(&self) -> u8 {
+        self.discriminator
+    }
+
+    #[inline(always)]
+    pub fn address_config(&self) -> &[u8; 32] {
+        &self.address_config
+    }
+
+    #[inline(always)]
+    pub fn is_signer(&self) -> bool {
+        self.is_signer != 0
+    }
+
+    #[inline(always)]
+    pub fn is_writable(&self) -> bool {
+        self.is_writable != 0
+    }
+}
+
+/// Seed of a PDA described by an [`ExtraAccountMeta`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Seed<'a> {
+    /// Literal bytes.
+    Literal { bytes: &'a [u8] },
+
+    /// Slice of the `Execute` instruction data.
+    InstructionData { index: u8, length: u8 },
+
+    /// Address of the account at `index` of the `Execute` instruction.
+    AccountKey { index: u8 },
+
+    /// Slice of the data of the account at `account_index`.
+    AccountData {
+        account_index: u8,
+        data_index: u8,
+        length: u8,
+    },
+}
+
+impl<'a> Seed<'a> {
+    const UNINITIALIZED: u8 = 0;
+    const LITERAL: u8 = 1;
+    const INSTRUCTION_DATA: u8 = 2;
+    const ACCOUNT_KEY: u8 = 3;
+    const ACCOUNT_DATA: u8 = 4;
+
+    /// Return the length of the packed seed.
+    #[inline(always)]
+    pub const fn packed_len(&self) -> usize {
+        match self {
+            Seed::Literal { bytes } => 2 + bytes.len(),
+            Seed::InstructionData { .. } => 3,
+            Seed::AccountKey { .. } => 2,
+            Seed::AccountData { .. } => 4,
+        }
+    }
+
+    /// Pack the seed at the start of `destination`, returning the number of
+    /// bytes written.
+    ///
+    /// Returns [`ProgramError::InvalidArgument`] if the seed does not fit.
+    #[inline]
+    pub fn pack(&self, destination: &mut [u8]) -> Result<usize, ProgramError> {
+        let length = self.packed_len();
+
+        if destination.len() < length || length > 2 + u8::MAX as usize {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        match *self {
+            Seed::Literal { bytes } => {
+                destination[0] = Self::LITERAL;
+                destination[1] = bytes.len() as u8;
+                destination[2..length].copy_from_slice(bytes);
+            }
+            Seed::InstructionData { index, length } => {
+                destination[..3].copy_from_slice(&[Self::INSTRUCTION_DATA, index, length]);
+            }
+            Seed::AccountKey { index } => {
+                destination[..2].copy_from_slice(&[Self::ACCOUNT_KEY, index]);
+            }
+            Seed::AccountData {
+                account_index,
+                data_index,
+                length,
+            } => {
+                destination[..4].copy_from_slice(&[
+                    Self::ACCOUNT_DATA,
+                    account_index,
+                    data_index,
+                    length,
+                ]);
+            }
+        }
+
+        Ok(length)
+    }
+
+    /// Pack `seeds` into the 32-byte address configuration of an
+    /// `ExtraAccountMeta`.
+    #[inline]
+    fn pack_into_address_config(seeds: &[Seed]) -> Result<[u8; 32], ProgramError> {
+        let mut config = [0u8; 32];
+        let mut offset = 0;
+
+        for seed in seeds {
+            offset += seed.pack(&mut config[offset..])?;
+        }
+
+        Ok(config)
+    }
+
+    /// Unpack the seed at the start of `config`, returning it with the
+    /// remaining bytes.
+    ///
+    /// Returns `Ok(None)` at the end of the packed seeds.
+    #[inline]
+    pub fn unpack(config: &'a [u8]) -> Result<Option<(Self, &'a [u8])>, ProgramError> {
+        match config {
+            [] | [Self::UNINITIALIZED, ..] => Ok(None),
+            [Self::LITERAL, length, rest @ ..] if rest.len() >= *length as usize => {
+                let (bytes, rest) = rest.split_at(*length as usize);
+                Ok(Some((Seed::Literal { bytes }, rest)))
+            }
+            [Self::INSTRUCTION_DATA, index, length, rest @ ..] => Ok(Some((
+                Seed::InstructionData {
+                    index: *index,
+                    length: *length,
+                },
+                rest,
+            ))),
+            [Self::ACCOUNT_KEY, index, rest @ ..] => {
+                Ok(Some((Seed::AccountKey { index: *index }, rest)))
+            }
+            [Self::ACCOUNT_DATA, account_index, data_index, length, rest @ ..] => Ok(Some((
+                Seed::AccountData {
+                    account_index: *account_index,
+                    data_index: *data_index,
+                    length: *length,
+                },
+                rest,
+            ))),
+            _ => Err(ProgramError::InvalidAccountData),
+        }
+    }
+}
+
+/// Zero-copy view of the `ExtraAccountMeta` entries stored in a transfer hook
+/// validation account.
+#[derive(Clone, Copy, Debug)]
+pub struct ExtraAccountMetaList<'a> {
+    metas: &'a [ExtraAccountMeta],
+}
+
+impl<'a> ExtraAccountMetaList<'a> {
+    /// Parse the `ExtraAccountMetaList` from the data of a validation account.
+    ///
+    /// The data is a sequence of TLV entries; the list is stored in the entry
+    /// with the `Execute` instruction discriminator.
+    pub fn from_bytes(data: &'a [u8]) -> Result<Self, ProgramError> {
+        let mut offset = 0;
+
+        while offset + TLV_HEADER_LEN <= data.len() {
+            let length = u32::from_le_bytes(
+                data[offset + 8..offset + TLV_HEADER_LEN]
+                    .try_into()
+                    .map_err(|_| ProgramError::InvalidAccountData)?,
+            ) as usize;
+            let value_start = offset + TLV_HEADER_LEN;
+            let value_end = value_start
+                .checked_add(length)
+                .filter(|end| *end <= data.len())
+                .ok_or(ProgramError::InvalidAccountData)?;
+
+            if data[offset..offset + 8] == EXECUTE_DISCRIMINATOR {
+                return Self::from_value(&data[value_start..value_end]);
+            }
+
+            offset = value_end;
+        }
+
+        Err(ProgramError::InvalidAccountData)
+    }
+
+    /// Parse the list from a length-prefixed slice of `ExtraAccountMeta`s.
+    #[inline(always)]
+    pub(super) fn from_value(value: &'a [u8]) -> Result<Self, ProgramError> {
+        if value.len() < SLICE_HEADER_LEN {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        let count = u32::from_le_bytes([value[0], value[1], value[2], value[3]]) as usize;
+        let len = count
+            .checked_mul(ExtraAccountMeta::LEN)
+            .and_then(|len| len.checked_add(SLICE_HEADER_LEN))
+            .ok_or(ProgramError::InvalidAccountData)?;
+
+        if value.len() < len {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        // SAFETY: `ExtraAccountMeta` is repr(C), contains only `u8` values,
+        // has alignment 1 and all bit patterns are valid; `value` has at least
+        // `count` entries after the header.
+        let metas = unsafe {
+            from_raw_parts(
+                value.as_ptr().add(SLICE_HEADER_LEN) as *const ExtraAccountMeta,
+                count,
+            )
+        };
+
+        Ok(Self { metas })
+    }
+
+    /// Return the `ExtraAccountMeta` entries.
+    #[inline(always)]
+    pub fn metas(&self) -> &'a [ExtraAccountMeta] {
+        self.metas
+    }
+
+    #[inline(always)]
+    pub fn len(&self) -> usize {
+        self.metas.len()
+    }
+
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.metas.is_empty()
+    }
+
+    /// Return the size of a validation account holding `count`
+    /// `ExtraAccountMeta`s.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the size overflows a `usize`.
+    #[inline(always)]
+    pub const fn size_of(count: usize) -> usize {
+        match Self::checked_size_of(count) {
+            Some(size) => size,
+            None => panic!("size of the validation account overflows"),
+        }
+    }
+
+    /// Return the size of a validation account holding `count`
+    /// `ExtraAccountMeta`s, or `None` if it overflows a `usize`.
+    #[inline(always)]
+    const fn checked_size_of(count: usize) -> Option<usize> {
+        match count.checked_mul(ExtraAccountMeta::LEN) {
+            Some(len) => len.checked_add(TLV_HEADER_LEN + SLICE_HEADER_LEN),
+            None => None,
+        }
+    }
+
+    /// Write `metas` as the `ExtraAccountMetaList` of a validation account.
+    ///
+    /// The account data must be exactly [`Self::size_of`] `metas.len()`
+    /// bytes long; resize the account before updating a list with a different
+    /// number of entries.
+    ///
+    /// Returns [`ProgramError::InvalidAccountData`] if the data length does
+    /// not match.
+    #[inline]
+    pub fn write(data: &mut [u8], metas: &[ExtraAccountMeta]) -> ProgramResult {
+        if Self::checked_size_of(metas.len()) != Some(data.len()) {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        let length = (SLICE_HEADER_LEN + metas.len() * ExtraAccountMeta::LEN) as u32;
+
+        data[..8].copy_from_slice(&EXECUTE_DISCRIMINATOR);
+        data[8..TLV_HEADER_LEN].copy_from_slice(&length.to_le_bytes());
+        data[TLV_HEADER_LEN..TLV_HEADER_LEN + SLICE_HEADER_LEN]
+            .copy_from_slice(&(metas.len() as u32).to_le_bytes());
+
+        for (chunk, meta) in data[TLV_HEADER_LEN + SLICE_HEADER_LEN..]
+            .chunks_exact_mut(ExtraAccountMeta::LEN)
+            .zip(metas)
+        {
+            chunk[0] = meta.discriminator;
+            chunk[1..33].copy_from_slice(&meta.address_config);
+            chunk[33] = meta.is_signer;
+            chunk[34] = meta.is_writable;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unpack_seeds() {
+        let mut config = [0u8; 32];
+        config[..12].copy_from_slice(&[1, 3, b'f', b'o', b'o', 2, 8, 8, 3, 1, 4, 2]);
+        config[12..14].copy_from_slice(&[0, 32]);
+
+        let (seed, rest) = Seed::unpack(&config).unwrap().unwrap();
+        assert_eq!(seed, Seed::Literal { bytes: b"foo" });
+
+        let (seed, rest) = Seed::unpack(rest).unwrap().unwrap();
+        assert_eq!(
+            seed,
+            Seed::InstructionData {
+                index: 8,
+                length: 8
+            }
+        );
+
+        let (seed, rest) = Seed::unpack(rest).unwrap().unwrap();
+        assert_eq!(seed, Seed::AccountKey { index: 1 });
+
+        let (seed, rest) = Seed::unpack(rest).unwrap().unwrap();
+        assert_eq!(
+            seed,
+            Seed::AccountData {
+                account_index: 2,
+                data_index: 0,
+                length: 32
+            }
+        );
+
+        assert_eq!(Seed::unpack(rest), Ok(None));
+
+        // Literal longer than the remaining bytes.
+        assert_eq!(
+            Seed::unpack(&[1, 10, 0]),
+            Err(ProgramError::InvalidAccountData)
+        );
+    }
+
+    #[test]
+    fn test_extra_account_meta_list_from_bytes() {
+        let mut data = [0u8; 128];
+
+        // Unrelated TLV entry.
+        data[..8].copy_from_slice(&[1; 8]);
+        data[8..12].copy_from_slice(&4u32.to_le_bytes());
+
+        // `ExtraAccountMetaList` entry with 2 metas.
+        let offset = TLV_HEADER_LEN + 4;
+        data[offset..offset + 8].copy_from_slice(&EXECUTE_DISCRIMINATOR);
+        data[offset + 8..offset + 12].copy_from_slice(
+            &((SLICE_HEADER_LEN + 2 * ExtraAccountMeta::LEN) as u32).to_le_bytes(),
+        );
+        data[offset + 12..offset + 16].copy_from_slice(&2u32.to_le_bytes());
+
+        let meta = offset + 16;
+        data[meta] = ExtraAccountMeta::LITERAL;
+        data[meta + 1..meta + 33].copy_from_slice(&[7; 32]);
+        data[meta + 34] = 1;
+
+        let meta = meta + ExtraAccountMeta::LEN;
+        data[meta] = ExtraAccountMeta::EXTERNAL_PDA_OFFSET + 5;
+        data[meta + 33] = 1;
+
+        let end = meta + ExtraAccountMeta::LEN;
+        let list = ExtraAccountMetaList::from_bytes(&data[..end]).unwrap();
+
+        assert_eq!(list.len(), 2);
+        assert_eq!(list.metas()[0].address_config(), &[7; 32]);
+        assert!(!list.metas()[0].is_signer());
+        assert!(list.metas()[0].is_writable());
+        assert_eq!(list.metas()[1].discriminator(), 133);
+        assert!(list.metas()[1].is_signer());
+
+        // Round trip through `write`.
+        let mut written = [0u8; ExtraAccountMetaList::size_of(2)];
+        ExtraAccountMetaList::write(&mut written, list.metas()).unwrap();
+        assert_eq!(&written[..], &data[offset..end]);
+
+        // Truncated entry.
+        assert!(ExtraAccountMetaList::from_bytes(&data[..end - 1]).is_err());
+
+        // Missing entry.
+        assert!(ExtraAccountMetaList::from_bytes(&data[..offset]).is_err());
+
+        // Count overflowing the length of the entries.
+        let mut value = [0u8; SLICE_HEADER_LEN];
+        value[..4].copy_from_slice(&u32::MAX.to_le_bytes());
+        assert_eq!(
+            ExtraAccountMetaList::from_value(&value).err(),
+            Some(ProgramError::InvalidAccountData)
+        );
+        assert_eq!(ExtraAccountMetaList::checked_size_of(usize::MAX), None);
+    }
+
+    #[test]
+    fn test_new_with_seeds() {
+        let seeds = [
+            Seed::Literal { bytes: b"foo" },
+            Seed::InstructionData {
+                index: 8,
+                length: 8,
+            },
+            Seed::AccountKey { index: 1 },
+            Seed::AccountData {
+                account_index: 2,
+                data_index: 0,
+                length: 32,
+            },
+        ];
+
+        let meta = ExtraAccountMeta::new_with_seeds(&seeds, false, true).unwrap();
+        assert_eq!(meta.discriminator(), ExtraAccountMeta::PDA);
+        assert!(meta.is_writable());
+
+        let mut config = &meta.address_config()[..];
+        for expected in seeds {
+            let (seed, rest) = Seed::unpack(config).unwrap().unwrap();
+            assert_eq!(seed, expected);
+            config = rest;
+        }
+        assert_eq!(Seed::unpack(config), Ok(None));
+
+        let meta = ExtraAccountMeta::new_external_pda_with_seeds(5, &seeds, true, false).unwrap();
+        assert_eq!(meta.discriminator(), 133);
+
+        assert_eq!(
+            ExtraAccountMeta::new_with_seeds(&[Seed::Literal { bytes: &[0; 31] }], false, false),
+            Err(ProgramError::InvalidArgument)
+        );
+    }
+}
@@ -0,0 +1,148 @@
+use {
+    super::{ExtraAccountMeta, ExtraAccountMetaList, EXECUTE_DISCRIMINATOR},
+    solana_program_error::ProgramError,
+};
+
+/// First 8 bytes of
+/// `sha256("spl-transfer-hook-interface:initialize-extra-account-metas")`.
+pub const INITIALIZE_EXTRA_ACCOUNT_META_LIST_DISCRIMINATOR: [u8; 8] =
+    [43, 34, 13, 49, 167, 88, 235, 235];
+
+/// First 8 bytes of
+/// `sha256("spl-transfer-hook-interface:update-extra-account-metas")`.
+pub const UPDATE_EXTRA_ACCOUNT_META_LIST_DISCRIMINATOR: [u8; 8] =
+    [157, 105, 42, 146, 102, 85, 241, 174];
+
+/// Instructions of the transfer hook interface, as received by a hook
+/// program.
+#[derive(Clone, Copy, Debug)]
+pub enum TransferHookInstruction<'a> {
+    /// Runs additional transfer logic.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[]` Source account.
+    ///   1. `[]` Token mint.
+    ///   2. `[]` Destination account.
+    ///   3. `[]` Source account's owner/delegate.
+    ///   4. `[]` Validation account.
+    ///   5. `..5+M` `[]` `M` extra accounts.
+    Execute {
+        /// Amount of tokens to transfer.
+        amount: u64,
+    },
+
+    /// Initializes the extra account metas on a validation account.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[writable]` Validation account.
+    ///   1. `[]` Mint.
+    ///   2. `[signer]` Mint authority.
+    ///   3. `[]` System program.
+    InitializeExtraAccountMetaList {
+        /// List of `ExtraAccountMeta`s to write into the account.
+        extra_account_metas: &'a [ExtraAccountMeta],
+    },
+
+    /// Updates the extra account metas on a validation account by
+    /// overwriting the existing list.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[writable]` Validation account.
+    ///   1. `[]` Mint.
+    ///   2. `[signer]` Mint authority.
+    UpdateExtraAccountMetaList {
+        /// The new list of `ExtraAccountMeta`s to overwrite the existing
+        /// entries.
+        extra_account_metas: &'a [ExtraAccountMeta],
+    },
+}
+
+impl<'a> TransferHookInstruction<'a> {
+    /// Decode a transfer hook instruction from its data.
+    ///
+    /// Returns [`ProgramError::InvalidInstructionData`] if the discriminator
+    /// is unknown or the data is malformed.
+    pub fn unpack(data: &'a [u8]) -> Result<Self, ProgramError> {
+        let (discriminator, rest) = data
+            .split_first_chunk::<8>()
+            .ok_or(ProgramError::InvalidInstructionData)?;
+
+        match *discriminator {
+            EXECUTE_DISCRIMINATOR => {
+                let amount = rest
+                    .first_chunk::<8>()
+                    .ok_or(ProgramError::InvalidInstructionData)?;
+
+                Ok(Self::Execute {
+                    amount: u64::from_le_bytes(*amount),
+                })
+            }
+            INITIALIZE_EXTRA_ACCOUNT_META_LIST_DISCRIMINATOR => {
+                Ok(Self::InitializeExtraAccountMetaList {
+                    extra_account_metas: unpack_metas(rest)?,
+                })
+            }
+            UPDATE_EXTRA_ACCOUNT_META_LIST_DISCRIMINATOR => Ok(Self::UpdateExtraAccountMetaList {
+                extra_account_metas: unpack_metas(rest)?,
+            }),
+            _ => Err(ProgramError::InvalidInstructionData),
+        }
+    }
+}
+
+#[inline(always)]
+fn unpack_metas(data: &[u8]) -> Result<&[ExtraAccountMeta], ProgramError> {
+    ExtraAccountMetaList::from_value(data)
+        .map(|list| list.metas())
+        .map_err(|_| ProgramError::InvalidInstructionData)
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, solana_address::Address};
+
+    #[test]
+    fn test_unpack_execute() {
+        let mut data = [0u8; 16];
+        data[..8].copy_from_slice(&EXECUTE_DISCRIMINATOR);
+        data[8..].copy_from_slice(&42u64.to_le_bytes());
+
+        assert!(matches!(
+            TransferHookInstruction::unpack(&data),
+            Ok(TransferHookInstruction::Execute { amount: 42 })
+        ));
+        assert_eq!(
+            TransferHookInstruction::unpack(&data[..15]).err(),
+            Some(ProgramError::InvalidInstructionData)
+        );
+    }
+
+    #[test]
+    fn test_unpack_initialize_extra_account_meta_list() {
+        let meta =
+            ExtraAccountMeta::new_with_address(&Address::new_from_array([3; 32]), false, true);
+
+        let mut data = [0u8; 12 + ExtraAccountMeta::LEN];
+        data[..8].copy_from_slice(&INITIALIZE_EXTRA_ACCOUNT_META_LIST_DISCRIMINATOR);
+        data[8..12].copy_from_slice(&1u32.to_le_bytes());
+        data[12] = meta.discriminator();
+        data[13..45].copy_from_slice(meta.address_config());
+        data[46] = 1;
+
+        match TransferHookInstruction::unpack(&data) {
+            Ok(TransferHookInstruction::InitializeExtraAccountMetaList {
+                extra_account_metas,
+            }) => assert_eq!(extra_account_metas, &[meta]),
+            _ => panic!("unexpected instruction"),
+        }
+
+        data[..8].copy_from_slice(&UPDATE_EXTRA_ACCOUNT_META_LIST_DISCRIMINATOR);
+        assert!(matches!(
+            TransferHookInstruction::unpack(&data[..44]),
+            Err(ProgramError::InvalidInstructionData)
+        ));
+    }
+}
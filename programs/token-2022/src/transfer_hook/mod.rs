@@ -0,0 +1,31 @@
+//! Types of the transfer hook interface.
+//!
+//! Transfer hook programs store the extra accounts required by their
+//! `Execute` instruction in a validation account. The helpers in this module
+//! parse that account and resolve the extra accounts, so programs can forward
+//! transfers of mints with a transfer hook.
+//!
+//! Hook programs can use [`TransferHookInstruction`] to decode the
+//! instructions they receive and [`ExtraAccountMetaList::write`] to store
+//! their extra accounts in the validation account.
+//!
+//! Resolving the extra accounts requires deriving program addresses, which is
+//! only available on-chain or with the `curve25519` feature enabled.
+
+mod extra_account_meta;
+mod instruction;
+#[cfg(any(target_os = "solana", target_arch = "bpf", feature = "curve25519"))]
+mod resolve;
+
+#[cfg(any(target_os = "solana", target_arch = "bpf", feature = "curve25519"))]
+pub use resolve::*;
+pub use {extra_account_meta::*, instruction::*};
+
+/// Discriminator of the `Execute` instruction, also used as the TLV type of
+/// the `ExtraAccountMetaList` entry of validation accounts.
+///
+/// First 8 bytes of `sha256("spl-transfer-hook-interface:execute")`.
+pub const EXECUTE_DISCRIMINATOR: [u8; 8] = [105, 37, 101, 197, 75, 251, 102, 26];
+
+/// Seed used to derive the validation account address of a mint.
+pub const EXTRA_ACCOUNT_METAS_SEED: &[u8] = b"extra-account-metas";
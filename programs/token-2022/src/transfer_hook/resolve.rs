@@ -0,0 +1,423 @@
+use {
+    super::{ExtraAccountMeta, ExtraAccountMetaList, Seed, EXTRA_ACCOUNT_METAS_SEED},
+    core::{mem::MaybeUninit, slice::from_raw_parts},
+    solana_account_view::AccountView,
+    solana_address::{Address, MAX_SEEDS, MAX_SEED_LEN},
+    solana_instruction_view::{cpi::MAX_STATIC_CPI_ACCOUNTS, InstructionAccount},
+    solana_program_error::ProgramError,
+};
+
+/// Maximum number of extra accounts that can be resolved.
+///
+/// This leaves room for the 5 fixed accounts of the `Execute` instruction in
+/// a CPI with [`MAX_STATIC_CPI_ACCOUNTS`] accounts.
+pub const MAX_EXTRA_ACCOUNTS: usize = MAX_STATIC_CPI_ACCOUNTS - 5;
+
+/// Find the address and bump seed of the validation account of `mint` for
+/// the transfer hook program `program_id`.
+#[inline(always)]
+pub fn find_extra_account_metas_address(mint: &Address, program_id: &Address) -> (Address, u8) {
+    Address::find_program_address(&[EXTRA_ACCOUNT_METAS_SEED, mint.as_ref()], program_id)
+}
+
+/// Extra accounts resolved from an [`ExtraAccountMetaList`].
+///
+/// The instruction accounts use the signer and writable flags of their
+/// `ExtraAccountMeta`, and can be appended to the accounts of an `Execute`
+/// instruction or of a Token-2022 transfer (followed by the validation
+/// account and hook program).
+pub struct ExtraAccounts<'a> {
+    instruction_accounts: [MaybeUninit<InstructionAccount<'a>>; MAX_EXTRA_ACCOUNTS],
+    accounts: [MaybeUninit<&'a AccountView>; MAX_EXTRA_ACCOUNTS],
+    len: usize,
+}
+
+impl<'a> ExtraAccounts<'a> {
+    /// Resolve the extra accounts of `list`.
+    ///
+    /// - `program_id` is the transfer hook program.
+    /// - `instruction_data` is the data of the `Execute` instruction.
+    /// - `execute_accounts` are the fixed accounts of the `Execute`
+    ///   instruction (source, mint, destination, authority and validation
+    ///   account), used to resolve seeds referencing account indices.
+    /// - `available_accounts` are the accounts where the resolved addresses
+    ///   are looked up, typically the accounts of the current instruction.
+    ///
+    /// Returns [`ProgramError::NotEnoughAccountKeys`] if a resolved address
+    /// is not present in `available_accounts`.
+    pub fn resolve(
+        list: &ExtraAccountMetaList,
+        program_id: &Address,
+        instruction_data: &[u8],
+        execute_accounts: &[&'a AccountView],
+        available_accounts: &'a [AccountView],
+    ) -> Result<Self, ProgramError> {
+        if list.len() > MAX_EXTRA_ACCOUNTS {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        let mut resolved = Self {
+            instruction_accounts: [const { MaybeUninit::<InstructionAccount>::uninit() };
+                MAX_EXTRA_ACCOUNTS],
+            accounts: [const { MaybeUninit::<&AccountView>::uninit() }; MAX_EXTRA_ACCOUNTS],
+            len: 0,
+        };
+
+        for meta in list.metas() {
+            let address = match meta.discriminator() {
+                ExtraAccountMeta::LITERAL => Address::new_from_array(*meta.address_config()),
+                ExtraAccountMeta::PDA => {
+                    resolved.derive_address(meta, program_id, instruction_data, execute_accounts)?
+                }
+                ExtraAccountMeta::ADDRESS_DATA => {
+                    resolved.read_address(meta, instruction_data, execute_accounts)?
+                }
+                discriminator if discriminator >= ExtraAccountMeta::EXTERNAL_PDA_OFFSET => {
+                    let index = discriminator - ExtraAccountMeta::EXTERNAL_PDA_OFFSET;
+                    let program = resolved.account(execute_accounts, index)?;
+                    resolved.derive_address(
+                        meta,
+                        program.address(),
+                        instruction_data,
+                        execute_accounts,
+                    )?
+                }
+                _ => return Err(ProgramError::InvalidAccountData),
+            };
+
+            let account = available_accounts
+                .iter()
+                .find(|account| account.address() == &address)
+                .ok_or(ProgramError::NotEnoughAccountKeys)?;
+
+            resolved.instruction_accounts[resolved.len].write(InstructionAccount::new(
+                account.address(),
+                meta.is_writable(),
+                meta.is_signer(),
+            ));
+            resolved.accounts[resolved.len].write(account);
+            resolved.len += 1;
+        }
+
+        Ok(resolved)
+    }
+
+    /// Return the resolved instruction accounts.
+    #[inline(always)]
+    pub fn instruction_accounts(&self) -> &[InstructionAccount<'a>] {
+        // SAFETY: The first `len` instruction accounts are initialized.
+        unsafe { from_raw_parts(self.instruction_accounts.as_ptr() as _, self.len) }
+    }
+
+    /// Return the resolved account views.
+    #[inline(always)]
+    pub fn accounts(&self) -> &[&'a AccountView] {
+        // SAFETY: The first `len` accounts are initialized.
+        unsafe { from_raw_parts(self.accounts.as_ptr() as _, self.len) }
+    }
+
+    #[inline(always)]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Return the account at `index` of the `Execute` instruction, which must
+    /// be either a fixed account or an already resolved extra account.
+    #[inline(always)]
+    fn account(
+        &self,
+        execute_accounts: &[&'a AccountView],
+        index: u8,
+    ) -> Result<&'a AccountView, ProgramError> {
+        let index = index as usize;
+
+        if let Some(account) = execute_accounts.get(index) {
+            Ok(account)
+        } else {
+            self.accounts()
+                .get(index - execute_accounts.len())
+                .copied()
+                .ok_or(ProgramError::InvalidAccountData)
+        }
+    }
+
+    /// Derive the PDA of `program_id` from the seeds packed in `meta`.
+    fn derive_address(
+        &self,
+        meta: &ExtraAccountMeta,
+        program_id: &Address,
+        instruction_data: &[u8],
+        execute_accounts: &[&'a AccountView],
+    ) -> Result<Address, ProgramError> {
+        // Seed bytes are copied so that no account data borrow is held while
+        // deriving the address.
+        let mut buffer = [0u8; MAX_SEEDS * MAX_SEED_LEN];
+        let mut ranges = [(0usize, 0usize); MAX_SEEDS];
+        let mut count = 0;
+        let mut offset = 0;
+
+        let mut config = &meta.address_config()[..];
+
+        while let Some((seed, rest)) = Seed::unpack(config)? {
+            if count == MAX_SEEDS {
+                return Err(ProgramError::MaxSeedLengthExceeded);
+            }
+
+            let length = match seed {
+                Seed::Literal { bytes } => copy_seed(&mut buffer[offset..], bytes)?,
+                Seed::InstructionData { index, length } => {
+                    let start = index as usize;
+                    let bytes = instruction_data
+                        .get(start..start + length as usize)
+                        .ok_or(ProgramError::InvalidInstructionData)?;
+                    copy_seed(&mut buffer[offset..], bytes)?
+                }
+                Seed::AccountKey { index } => {
+                    let account = self.account(execute_accounts, index)?;
+                    copy_seed(&mut buffer[offset..], account.address().as_ref())?
+                }
+                Seed::AccountData {
+                    account_index,
+                    data_index,
+                    length,
+                } => {
+                    let account = self.account(execute_accounts, account_index)?;
+                    let data = account.try_borrow()?;
+                    let start = data_index as usize;
+                    let bytes = data
+                        .get(start..start + length as usize)
+                        .ok_or(ProgramError::InvalidAccountData)?;
+                    copy_seed(&mut buffer[offset..], bytes)?
+                }
+            };
+
+            ranges[count] = (offset, offset + length);
+            offset += length;
+            count += 1;
+            config = rest;
+        }
+
+        let mut seeds = [&[][..]; MAX_SEEDS];
+
+        for (seed, (start, end)) in seeds.iter_mut().zip(ranges[..count].iter()) {
+            *seed = &buffer[*start..*end];
+        }
+
+        Ok(Address::find_program_address(&seeds[..count], program_id).0)
+    }
+
+    /// Read an address from instruction or account data as described by
+    /// `meta`.
+    #[inline(always)]
+    fn read_address(
+        &self,
+        meta: &ExtraAccountMeta,
+        instruction_data: &[u8],
+        execute_accounts: &[&'a AccountView],
+    ) -> Result<Address, ProgramError> {
+        const INSTRUCTION_DATA: u8 = 1;
+        const ACCOUNT_DATA: u8 = 2;
+
+        let bytes = match *meta.address_config() {
+            [INSTRUCTION_DATA, index, ..] => read_address_bytes(instruction_data, index as usize)
+                .ok_or(ProgramError::InvalidInstructionData)?,
+            [ACCOUNT_DATA, account_index, data_index, ..] => {
+                let account = self.account(execute_accounts, account_index)?;
+                let data = account.try_borrow()?;
+                read_address_bytes(&data, data_index as usize)
+                    .ok_or(ProgramError::InvalidAccountData)?
+            }
+            _ => return Err(ProgramError::InvalidAccountData),
+        };
+
+        Ok(Address::new_from_array(bytes))
+    }
+}
+
+#[inline(always)]
+fn copy_seed(destination: &mut [u8], seed: &[u8]) -> Result<usize, ProgramError> {
+    if seed.len() > MAX_SEED_LEN {
+        return Err(ProgramError::MaxSeedLengthExceeded);
+    }
+
+    destination[..seed.len()].copy_from_slice(seed);
+
+    Ok(seed.len())
+}
+
+#[inline(always)]
+fn read_address_bytes(data: &[u8], index: usize) -> Option<[u8; 32]> {
+    data.get(index..index + 32)
+        .and_then(|bytes| bytes.try_into().ok())
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use {
+        super::*,
+        pinocchio::entrypoint::input::{AccountViews, InputAccount},
+        std::{vec, vec::Vec},
+    };
+
+    const HOOK_PROGRAM: Address = Address::new_from_array([9; 32]);
+
+    /// Data of the source account, used by account data seeds.
+    const SOURCE_DATA: [u8; 8] = [1, 2, 3, 4, 5, 6, 7, 8];
+
+    /// Create the 5 accounts of the `Execute` instruction followed by the
+    /// `extra` accounts.
+    fn account_views(extra: &[Address]) -> AccountViews {
+        let mut accounts = (1..=5u8)
+            .map(|i| {
+                let data = if i == 1 { &SOURCE_DATA[..] } else { &[] };
+                InputAccount::new(
+                    Address::new_from_array([i; 32]),
+                    Address::default(),
+                    0,
+                    data,
+                )
+            })
+            .collect::<Vec<_>>();
+        accounts.extend(
+            extra
+                .iter()
+                .map(|address| InputAccount::new(address.clone(), Address::default(), 0, &[])),
+        );
+
+        AccountViews::new(&accounts)
+    }
+
+    /// Resolve `metas` with the accounts of `views`.
+    fn resolve(
+        metas: &[ExtraAccountMeta],
+        views: &AccountViews,
+    ) -> Result<Vec<(Address, bool, bool)>, ProgramError> {
+        let mut data = vec![0; ExtraAccountMetaList::size_of(metas.len())];
+        ExtraAccountMetaList::write(&mut data, metas)?;
+        let list = ExtraAccountMetaList::from_bytes(&data)?;
+
+        let execute_accounts = [&views[0], &views[1], &views[2], &views[3], &views[4]];
+        let resolved = ExtraAccounts::resolve(&list, &HOOK_PROGRAM, &[], &execute_accounts, views)?;
+
+        assert_eq!(resolved.accounts().len(), resolved.len());
+        Ok(resolved
+            .instruction_accounts()
+            .iter()
+            .map(|account| {
+                (
+                    Address::new_from_array(account.address.to_bytes()),
+                    account.is_writable,
+                    account.is_signer,
+                )
+            })
+            .collect())
+    }
+
+    #[test]
+    fn test_resolve_literal() {
+        let address = Address::new_from_array([7; 32]);
+        let views = account_views(&[address.clone()]);
+
+        let resolved = resolve(
+            &[ExtraAccountMeta::new_with_address(&address, true, false)],
+            &views,
+        );
+
+        assert_eq!(resolved, Ok(vec![(address, false, true)]));
+    }
+
+    #[test]
+    fn test_resolve_pda() {
+        let mint = Address::new_from_array([2; 32]);
+        let (address, _) = Address::find_program_address(&[b"seed", mint.as_ref()], &HOOK_PROGRAM);
+        let views = account_views(&[address.clone()]);
+
+        let resolved = resolve(
+            &[ExtraAccountMeta::new_with_seeds(
+                &[
+                    Seed::Literal { bytes: b"seed" },
+                    Seed::AccountKey { index: 1 },
+                ],
+                false,
+                true,
+            )
+            .unwrap()],
+            &views,
+        );
+
+        assert_eq!(resolved, Ok(vec![(address, true, false)]));
+    }
+
+    #[test]
+    fn test_resolve_external_pda() {
+        let program = Address::new_from_array([8; 32]);
+        let (address, _) = Address::find_program_address(&[b"external"], &program);
+        let views = account_views(&[program.clone(), address.clone()]);
+
+        // The program is the first extra account, at index 5 of the
+        // `Execute` instruction.
+        let resolved = resolve(
+            &[
+                ExtraAccountMeta::new_with_address(&program, false, false),
+                ExtraAccountMeta::new_external_pda_with_seeds(
+                    5,
+                    &[Seed::Literal { bytes: b"external" }],
+                    false,
+                    true,
+                )
+                .unwrap(),
+            ],
+            &views,
+        );
+
+        assert_eq!(
+            resolved,
+            Ok(vec![(program, false, false), (address, true, false)])
+        );
+    }
+
+    #[test]
+    fn test_resolve_account_data_seed() {
+        let (address, _) = Address::find_program_address(&[&SOURCE_DATA[2..6]], &HOOK_PROGRAM);
+        let views = account_views(&[address.clone()]);
+
+        let resolved = resolve(
+            &[ExtraAccountMeta::new_with_seeds(
+                &[Seed::AccountData {
+                    account_index: 0,
+                    data_index: 2,
+                    length: 4,
+                }],
+                false,
+                false,
+            )
+            .unwrap()],
+            &views,
+        );
+
+        assert_eq!(resolved, Ok(vec![(address, false, false)]));
+    }
+
+    #[test]
+    fn test_resolve_missing_account() {
+        let views = account_views(&[]);
+
+        let resolved = resolve(
+            &[ExtraAccountMeta::new_with_address(
+                &Address::new_from_array([7; 32]),
+                false,
+                false,
+            )],
+            &views,
+        );
+
+        assert_eq!(resolved, Err(ProgramError::NotEnoughAccountKeys));
+    }
+}
@@ -0,0 +1,1372 @@
+//! Golden-file tests of the instruction layouts.
+//!
+//! Each test encodes a builder with canonical inputs and compares the
+//! resulting instruction with the fixture checked in `tests/fixtures`, which guards the offsets used to write the instruction
+//! data against accidental changes. After an intended layout change, the
+//! fixtures are regenerated with:
+//!
+//! ```bash
+//! UPDATE_FIXTURES=1 cargo test -p pinocchio-token-2022 --test fixtures
+//! ```
+
+use {
+    pinocchio::{
+        entrypoint::input::{AccountViews, InputAccount},
+        AccountView, Address,
+    },
+    pinocchio_token_2022::{
+        instructions::*,
+        state::ExtensionType,
+        token_program::{LegacyToken, Token2022},
+        ID,
+    },
+    solana_instruction_view::InstructionView,
+    std::{env, fmt::Write, fs, path::PathBuf},
+};
+
+/// Number of accounts available to the builders.
+const ACCOUNTS: usize = 16;
+
+/// Canonical amount, with distinct bytes to expose the byte order.
+const AMOUNT: u64 = 0x0102_0304_0506_0708;
+
+/// Canonical number of decimals.
+const DECIMALS: u8 = 9;
+
+/// Canonical address passed by value to the builders.
+const ADDRESS: Address = Address::new_from_array([0xaa; 32]);
+
+/// Another canonical address passed by value to the builders.
+const OTHER_ADDRESS: Address = Address::new_from_array([0xbb; 32]);
+
+/// Create the account views used as input of the builders.
+///
+/// The address of the account at index `i` is `[i + 1; 32]`.
+fn account_views() -> AccountViews {
+    let accounts = (1..=ACCOUNTS as u8)
+        .map(|i| InputAccount::new(Address::new_from_array([i; 32]), Address::default(), 0, &[]))
+        .collect::<Vec<_>>();
+
+    AccountViews::new(&accounts)
+}
+
+/// Encode an instruction with `f` and compare it with the fixture `name`.
+///
+/// The fixture is written instead when the `UPDATE_FIXTURES` environment
+/// variable is set.
+#[track_caller]
+fn assert_fixture(name: &str, f: impl FnOnce(&[AccountView]) -> String) {
+    let accounts = account_views();
+    let actual = f(&accounts);
+
+    let path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests")
+        .join("fixtures")
+        .join(format!("{name}.txt"));
+
+    if env::var_os("UPDATE_FIXTURES").is_some() {
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(&path, actual).unwrap();
+        return;
+    }
+
+    let expected = fs::read_to_string(&path).unwrap_or_else(|_| {
+        panic!(
+            "missing fixture {}, run with `UPDATE_FIXTURES=1` to create it",
+            path.display()
+        )
+    });
+    assert!(
+        actual == expected,
+        "layout of `{name}` does not match {}\n\nexpected:\n{expected}\nactual:\n{actual}",
+        path.display()
+    );
+}
+
+/// Render the instruction as text, with the data split in lines of 32 bytes.
+fn render(instruction: &InstructionView) -> String {
+    let mut output = String::new();
+
+    writeln!(output, "program: {}", instruction.program_id).unwrap();
+    for account in instruction.accounts {
+        writeln!(
+            output,
+            "account: {} {}{}",
+            account.address,
+            if account.is_writable {
+                "writable"
+            } else {
+                "readonly"
+            },
+            if account.is_signer { " signer" } else { "" },
+        )
+        .unwrap();
+    }
+    writeln!(output, "data: {} bytes", instruction.data.len()).unwrap();
+    for chunk in instruction.data.chunks(32) {
+        writeln!(output, "  {}", hex(chunk)).unwrap();
+    }
+
+    output
+}
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().fold(String::new(), |mut output, byte| {
+        write!(output, "{byte:02x}").unwrap();
+        output
+    })
+}
+
+#[test]
+fn amount_to_ui_amount() {
+    assert_fixture("amount_to_ui_amount", |accounts| {
+        AmountToUiAmount {
+            mint: &accounts[0],
+            amount: AMOUNT,
+            token_program: &ID,
+        }
+        .with_instruction(|instruction, _| render(instruction))
+    });
+}
+
+#[test]
+fn approve() {
+    assert_fixture("approve", |accounts| {
+        Approve {
+            source: &accounts[0],
+            delegate: &accounts[1],
+            authority: &accounts[2],
+            amount: AMOUNT,
+            token_program: &ID,
+        }
+        .with_instruction(|instruction, _| render(instruction))
+    });
+}
+
+#[test]
+fn approve_checked() {
+    assert_fixture("approve_checked", |accounts| {
+        ApproveChecked {
+            source: &accounts[0],
+            mint: &accounts[1],
+            delegate: &accounts[2],
+            authority: &accounts[3],
+            amount: AMOUNT,
+            decimals: DECIMALS,
+            token_program: &ID,
+        }
+        .with_instruction(|instruction, _| render(instruction))
+    });
+}
+
+#[test]
+fn burn() {
+    assert_fixture("burn", |accounts| {
+        Burn {
+            account: &accounts[0],
+            mint: &accounts[1],
+            authority: &accounts[2],
+            amount: AMOUNT,
+            token_program: &ID,
+        }
+        .with_instruction(|instruction, _| render(instruction))
+    });
+}
+
+#[test]
+fn burn_checked() {
+    assert_fixture("burn_checked", |accounts| {
+        BurnChecked {
+            account: &accounts[0],
+            mint: &accounts[1],
+            authority: &accounts[2],
+            amount: AMOUNT,
+            decimals: DECIMALS,
+            token_program: &ID,
+        }
+        .with_instruction(|instruction, _| render(instruction))
+    });
+}
+
+#[test]
+fn burn_tokens() {
+    assert_fixture("burn_tokens", |accounts| {
+        BurnTokens {
+            account: &accounts[0],
+            mint: &accounts[1],
+            authority: &accounts[2],
+            amount: AMOUNT,
+            decimals: DECIMALS,
+            token_program: &Token2022,
+        }
+        .with_instruction(|instruction, _| render(instruction))
+    });
+}
+
+#[test]
+fn burn_tokens_legacy() {
+    assert_fixture("burn_tokens_legacy", |accounts| {
+        BurnTokens {
+            account: &accounts[0],
+            mint: &accounts[1],
+            authority: &accounts[2],
+            amount: AMOUNT,
+            decimals: DECIMALS,
+            token_program: &LegacyToken,
+        }
+        .with_instruction(|instruction, _| render(instruction))
+    });
+}
+
+#[test]
+fn close_account() {
+    assert_fixture("close_account", |accounts| {
+        CloseAccount {
+            account: &accounts[0],
+            destination: &accounts[1],
+            authority: &accounts[2],
+            token_program: &ID,
+        }
+        .with_instruction(|instruction, _| render(instruction))
+    });
+}
+
+#[test]
+fn create_native_mint() {
+    assert_fixture("create_native_mint", |accounts| {
+        CreateNativeMint {
+            payer: &accounts[0],
+            native_mint: &accounts[1],
+            system_program: &accounts[2],
+            token_program: &ID,
+        }
+        .with_instruction(|instruction, _| render(instruction))
+    });
+}
+
+#[test]
+fn freeze_account() {
+    assert_fixture("freeze_account", |accounts| {
+        FreezeAccount {
+            account: &accounts[0],
+            mint: &accounts[1],
+            freeze_authority: &accounts[2],
+            token_program: &ID,
+        }
+        .with_instruction(|instruction, _| render(instruction))
+    });
+}
+
+#[test]
+fn get_account_data_size() {
+    assert_fixture("get_account_data_size", |accounts| {
+        GetAccountDataSize {
+            mint: &accounts[0],
+            extension_types: &[ExtensionType::ImmutableOwner, ExtensionType::MemoTransfer],
+            token_program: &ID,
+        }
+        .with_instruction(|instruction, _| render(instruction))
+        .unwrap()
+    });
+}
+
+#[test]
+fn initialize_account() {
+    assert_fixture("initialize_account", |accounts| {
+        InitializeAccount {
+            account: &accounts[0],
+            mint: &accounts[1],
+            owner: &accounts[2],
+            rent_sysvar: &accounts[3],
+            token_program: &ID,
+        }
+        .with_instruction(|instruction, _| render(instruction))
+    });
+}
+
+#[test]
+fn initialize_account_2() {
+    assert_fixture("initialize_account_2", |accounts| {
+        InitializeAccount2 {
+            account: &accounts[0],
+            mint: &accounts[1],
+            rent_sysvar: &accounts[2],
+            owner: &ADDRESS,
+            token_program: &ID,
+        }
+        .with_instruction(|instruction, _| render(instruction))
+    });
+}
+
+#[test]
+fn initialize_account_3() {
+    assert_fixture("initialize_account_3", |accounts| {
+        InitializeAccount3 {
+            account: &accounts[0],
+            mint: &accounts[1],
+            owner: &ADDRESS,
+            token_program: &ID,
+        }
+        .with_instruction(|instruction, _| render(instruction))
+    });
+}
+
+#[test]
+fn initialize_immutable_owner() {
+    assert_fixture("initialize_immutable_owner", |accounts| {
+        InitializeImmutableOwner {
+            account: &accounts[0],
+            token_program: &ID,
+        }
+        .with_instruction(|instruction, _| render(instruction))
+    });
+}
+
+#[test]
+fn initialize_mint() {
+    assert_fixture("initialize_mint", |accounts| {
+        InitializeMint {
+            mint: &accounts[0],
+            rent_sysvar: &accounts[1],
+            decimals: DECIMALS,
+            mint_authority: &ADDRESS,
+            freeze_authority: Some(&OTHER_ADDRESS),
+            token_program: &ID,
+        }
+        .with_instruction(|instruction, _| render(instruction))
+    });
+}
+
+#[test]
+fn initialize_mint_2() {
+    assert_fixture("initialize_mint_2", |accounts| {
+        InitializeMint2 {
+            mint: &accounts[0],
+            decimals: DECIMALS,
+            mint_authority: &ADDRESS,
+            freeze_authority: None,
+            token_program: &ID,
+        }
+        .with_instruction(|instruction, _| render(instruction))
+    });
+}
+
+#[test]
+fn initialize_multisig() {
+    assert_fixture("initialize_multisig", |accounts| {
+        InitializeMultisig {
+            multisig: &accounts[0],
+            rent_sysvar: &accounts[1],
+            signers: &[&accounts[2], &accounts[3], &accounts[4]],
+            m: 2,
+            token_program: &ID,
+        }
+        .with_instruction(|instruction, _| render(instruction))
+        .unwrap()
+    });
+}
+
+#[test]
+fn initialize_multisig_2() {
+    assert_fixture("initialize_multisig_2", |accounts| {
+        InitializeMultisig2 {
+            multisig: &accounts[0],
+            signers: &[&accounts[1], &accounts[2], &accounts[3]],
+            m: 2,
+            token_program: &ID,
+        }
+        .with_instruction(|instruction, _| render(instruction))
+        .unwrap()
+    });
+}
+
+#[test]
+fn initialize_non_transferable_mint() {
+    assert_fixture("initialize_non_transferable_mint", |accounts| {
+        InitializeNonTransferableMint {
+            mint: &accounts[0],
+            token_program: &ID,
+        }
+        .with_instruction(|instruction, _| render(instruction))
+    });
+}
+
+#[test]
+fn mint_to() {
+    assert_fixture("mint_to", |accounts| {
+        MintTo {
+            mint: &accounts[0],
+            account: &accounts[1],
+            mint_authority: &accounts[2],
+            amount: AMOUNT,
+            token_program: &ID,
+        }
+        .with_instruction(|instruction, _| render(instruction))
+    });
+}
+
+#[test]
+fn mint_to_checked() {
+    assert_fixture("mint_to_checked", |accounts| {
+        MintToChecked {
+            mint: &accounts[0],
+            account: &accounts[1],
+            mint_authority: &accounts[2],
+            amount: AMOUNT,
+            decimals: DECIMALS,
+            token_program: &ID,
+        }
+        .with_instruction(|instruction, _| render(instruction))
+    });
+}
+
+#[test]
+fn mint_tokens() {
+    assert_fixture("mint_tokens", |accounts| {
+        MintTokens {
+            mint: &accounts[0],
+            account: &accounts[1],
+            mint_authority: &accounts[2],
+            amount: AMOUNT,
+            decimals: DECIMALS,
+            token_program: &Token2022,
+        }
+        .with_instruction(|instruction, _| render(instruction))
+    });
+}
+
+#[test]
+fn mint_tokens_legacy() {
+    assert_fixture("mint_tokens_legacy", |accounts| {
+        MintTokens {
+            mint: &accounts[0],
+            account: &accounts[1],
+            mint_authority: &accounts[2],
+            amount: AMOUNT,
+            decimals: DECIMALS,
+            token_program: &LegacyToken,
+        }
+        .with_instruction(|instruction, _| render(instruction))
+    });
+}
+
+#[test]
+fn reallocate() {
+    assert_fixture("reallocate", |accounts| {
+        Reallocate {
+            account: &accounts[0],
+            payer: &accounts[1],
+            system_program: &accounts[2],
+            owner: &accounts[3],
+            multisig_signers: &[&accounts[4], &accounts[5]],
+            extension_types: &[ExtensionType::MemoTransfer, ExtensionType::CpiGuard],
+            token_program: &ID,
+        }
+        .with_instruction(|instruction, _| render(instruction))
+        .unwrap()
+    });
+}
+
+#[test]
+fn revoke() {
+    assert_fixture("revoke", |accounts| {
+        Revoke {
+            source: &accounts[0],
+            authority: &accounts[1],
+            token_program: &ID,
+        }
+        .with_instruction(|instruction, _| render(instruction))
+    });
+}
+
+#[test]
+fn set_authority() {
+    assert_fixture("set_authority", |accounts| {
+        SetAuthority {
+            account: &accounts[0],
+            authority: &accounts[1],
+            authority_type: AuthorityType::CloseMint,
+            new_authority: Some(&ADDRESS),
+            token_program: &ID,
+        }
+        .with_instruction(|instruction, _| render(instruction))
+    });
+}
+
+#[test]
+fn sync_native() {
+    assert_fixture("sync_native", |accounts| {
+        SyncNative {
+            native_token: &accounts[0],
+            token_program: &ID,
+        }
+        .with_instruction(|instruction, _| render(instruction))
+    });
+}
+
+#[test]
+fn thaw_account() {
+    assert_fixture("thaw_account", |accounts| {
+        ThawAccount {
+            account: &accounts[0],
+            mint: &accounts[1],
+            freeze_authority: &accounts[2],
+            token_program: &ID,
+        }
+        .with_instruction(|instruction, _| render(instruction))
+    });
+}
+
+#[test]
+fn transfer() {
+    assert_fixture("transfer", |accounts| {
+        Transfer {
+            from: &accounts[0],
+            to: &accounts[1],
+            authority: &accounts[2],
+            amount: AMOUNT,
+            token_program: &ID,
+        }
+        .with_instruction(|instruction, _| render(instruction))
+    });
+}
+
+#[test]
+fn transfer_checked() {
+    assert_fixture("transfer_checked", |accounts| {
+        TransferChecked {
+            from: &accounts[0],
+            mint: &accounts[1],
+            to: &accounts[2],
+            authority: &accounts[3],
+            amount: AMOUNT,
+            decimals: DECIMALS,
+            token_program: &ID,
+        }
+        .with_instruction(|instruction, _| render(instruction))
+    });
+}
+
+#[test]
+fn transfer_tokens() {
+    assert_fixture("transfer_tokens", |accounts| {
+        TransferTokens {
+            from: &accounts[0],
+            mint: &accounts[1],
+            to: &accounts[2],
+            authority: &accounts[3],
+            amount: AMOUNT,
+            decimals: DECIMALS,
+            token_program: &Token2022,
+        }
+        .with_instruction(|instruction, _| render(instruction))
+    });
+}
+
+#[test]
+fn transfer_tokens_legacy() {
+    assert_fixture("transfer_tokens_legacy", |accounts| {
+        TransferTokens {
+            from: &accounts[0],
+            mint: &accounts[1],
+            to: &accounts[2],
+            authority: &accounts[3],
+            amount: AMOUNT,
+            decimals: DECIMALS,
+            token_program: &LegacyToken,
+        }
+        .with_instruction(|instruction, _| render(instruction))
+    });
+}
+
+#[test]
+fn ui_amount_to_amount() {
+    assert_fixture("ui_amount_to_amount", |accounts| {
+        UiAmountToAmount {
+            mint: &accounts[0],
+            ui_amount: "1234.5678",
+            token_program: &ID,
+        }
+        .with_instruction(|instruction, _| render(instruction))
+        .unwrap()
+    });
+}
+
+#[test]
+fn unwrap_lamports() {
+    assert_fixture("unwrap_lamports", |accounts| {
+        UnwrapLamports {
+            source: &accounts[0],
+            destination: &accounts[1],
+            authority: &accounts[2],
+            multisig_signers: &[&accounts[4], &accounts[5]],
+            amount: Some(AMOUNT),
+            token_program: &ID,
+        }
+        .with_instruction(|instruction, _| render(instruction))
+        .unwrap()
+    });
+}
+
+#[test]
+fn withdraw_excess_lamports() {
+    assert_fixture("withdraw_excess_lamports", |accounts| {
+        WithdrawExcessLamports {
+            source: &accounts[0],
+            destination: &accounts[1],
+            authority: &accounts[2],
+            multisig_signers: &[&accounts[4], &accounts[5]],
+            token_program: &ID,
+        }
+        .with_instruction(|instruction, _| render(instruction))
+        .unwrap()
+    });
+}
+
+#[test]
+fn confidential_mint_burn_burn() {
+    assert_fixture("confidential_mint_burn_burn", |accounts| {
+        confidential_mint_burn::Burn {
+            account: &accounts[0],
+            mint: &accounts[1],
+            instructions_sysvar: Some(&accounts[2]),
+            equality_proof_context: None,
+            ciphertext_validity_proof_context: None,
+            range_proof_context: Some(&accounts[3]),
+            authority: &accounts[6],
+            multisig_signers: &[&accounts[4], &accounts[5]],
+            new_decryptable_available_balance: &[0xd1; 36],
+            burn_amount_auditor_ciphertext_lo: &[0xe1; 64],
+            burn_amount_auditor_ciphertext_hi: &[0xe2; 64],
+            equality_proof_instruction_offset: 1,
+            ciphertext_validity_proof_instruction_offset: -2,
+            range_proof_instruction_offset: 0,
+            token_program: &ID,
+        }
+        .with_instruction(|instruction, _| render(instruction))
+        .unwrap()
+    });
+}
+
+#[test]
+fn confidential_mint_burn_mint() {
+    assert_fixture("confidential_mint_burn_mint", |accounts| {
+        confidential_mint_burn::Mint {
+            account: &accounts[0],
+            mint: &accounts[1],
+            instructions_sysvar: Some(&accounts[2]),
+            equality_proof_context: None,
+            ciphertext_validity_proof_context: None,
+            range_proof_context: Some(&accounts[3]),
+            authority: &accounts[6],
+            multisig_signers: &[&accounts[4], &accounts[5]],
+            new_decryptable_supply: &[0xd1; 36],
+            mint_amount_auditor_ciphertext_lo: &[0xe1; 64],
+            mint_amount_auditor_ciphertext_hi: &[0xe2; 64],
+            equality_proof_instruction_offset: 1,
+            ciphertext_validity_proof_instruction_offset: -2,
+            range_proof_instruction_offset: 0,
+            token_program: &ID,
+        }
+        .with_instruction(|instruction, _| render(instruction))
+        .unwrap()
+    });
+}
+
+#[test]
+fn confidential_mint_burn_initialize_mint() {
+    assert_fixture("confidential_mint_burn_initialize_mint", |accounts| {
+        confidential_mint_burn::InitializeMint {
+            mint: &accounts[0],
+            supply_elgamal_pubkey: &[0xc1; 32],
+            decryptable_supply: &[0xd1; 36],
+            token_program: &ID,
+        }
+        .with_instruction(|instruction, _| render(instruction))
+    });
+}
+
+#[test]
+fn confidential_mint_burn_rotate_supply_elgamal_pubkey() {
+    assert_fixture(
+        "confidential_mint_burn_rotate_supply_elgamal_pubkey",
+        |accounts| {
+            confidential_mint_burn::RotateSupplyElGamalPubkey {
+                mint: &accounts[0],
+                proof_account: &accounts[1],
+                authority: &accounts[2],
+                multisig_signers: &[&accounts[4], &accounts[5]],
+                new_supply_elgamal_pubkey: &[0xc1; 32],
+                proof_instruction_offset: 1,
+                token_program: &ID,
+            }
+            .with_instruction(|instruction, _| render(instruction))
+            .unwrap()
+        },
+    );
+}
+
+#[test]
+fn confidential_mint_burn_update_decryptable_supply() {
+    assert_fixture(
+        "confidential_mint_burn_update_decryptable_supply",
+        |accounts| {
+            confidential_mint_burn::UpdateDecryptableSupply {
+                mint: &accounts[0],
+                authority: &accounts[1],
+                multisig_signers: &[&accounts[4], &accounts[5]],
+                new_decryptable_supply: &[0xd1; 36],
+                token_program: &ID,
+            }
+            .with_instruction(|instruction, _| render(instruction))
+            .unwrap()
+        },
+    );
+}
+
+#[test]
+fn confidential_transfer_initialize_mint() {
+    assert_fixture("confidential_transfer_initialize_mint", |accounts| {
+        confidential_transfer::InitializeMint {
+            mint: &accounts[0],
+            authority: Some(&ADDRESS),
+            auto_approve_new_accounts: true,
+            auditor_elgamal_pubkey: Some(&[0xc1; 32]),
+            token_program: &ID,
+        }
+        .with_instruction(|instruction, _| render(instruction))
+    });
+}
+
+#[test]
+fn confidential_transfer_update_mint() {
+    assert_fixture("confidential_transfer_update_mint", |accounts| {
+        confidential_transfer::UpdateMint {
+            mint: &accounts[0],
+            authority: &accounts[1],
+            auto_approve_new_accounts: false,
+            auditor_elgamal_pubkey: None,
+            token_program: &ID,
+        }
+        .with_instruction(|instruction, _| render(instruction))
+    });
+}
+
+#[test]
+fn confidential_transfer_fee_disable_harvest_to_mint() {
+    assert_fixture(
+        "confidential_transfer_fee_disable_harvest_to_mint",
+        |accounts| {
+            confidential_transfer_fee::DisableHarvestToMint {
+                mint: &accounts[0],
+                authority: &accounts[1],
+                multisig_signers: &[&accounts[4], &accounts[5]],
+                token_program: &ID,
+            }
+            .with_instruction(|instruction, _| render(instruction))
+            .unwrap()
+        },
+    );
+}
+
+#[test]
+fn confidential_transfer_fee_enable_harvest_to_mint() {
+    assert_fixture(
+        "confidential_transfer_fee_enable_harvest_to_mint",
+        |accounts| {
+            confidential_transfer_fee::EnableHarvestToMint {
+                mint: &accounts[0],
+                authority: &accounts[1],
+                multisig_signers: &[&accounts[4], &accounts[5]],
+                token_program: &ID,
+            }
+            .with_instruction(|instruction, _| render(instruction))
+            .unwrap()
+        },
+    );
+}
+
+#[test]
+fn confidential_transfer_fee_harvest_withheld_tokens_to_mint() {
+    assert_fixture(
+        "confidential_transfer_fee_harvest_withheld_tokens_to_mint",
+        |accounts| {
+            confidential_transfer_fee::HarvestWithheldTokensToMint {
+                mint: &accounts[0],
+                sources: [&accounts[1], &accounts[2]],
+                token_program: &ID,
+            }
+            .with_instruction(|instruction, _| render(instruction))
+            .unwrap()
+        },
+    );
+}
+
+#[test]
+fn confidential_transfer_fee_initialize_config() {
+    assert_fixture("confidential_transfer_fee_initialize_config", |accounts| {
+        confidential_transfer_fee::InitializeConfidentialTransferFeeConfig {
+            mint: &accounts[0],
+            authority: Some(&ADDRESS),
+            withdraw_withheld_authority_elgamal_pubkey: &[0xc1; 32],
+            token_program: &ID,
+        }
+        .with_instruction(|instruction, _| render(instruction))
+    });
+}
+
+#[test]
+fn confidential_transfer_fee_withdraw_withheld_tokens_from_accounts() {
+    assert_fixture(
+        "confidential_transfer_fee_withdraw_withheld_tokens_from_accounts",
+        |accounts| {
+            confidential_transfer_fee::WithdrawWithheldTokensFromAccounts {
+                mint: &accounts[0],
+                destination: &accounts[1],
+                proof_account: &accounts[2],
+                record_account: Some(&accounts[3]),
+                authority: &accounts[6],
+                multisig_signers: &[&accounts[4], &accounts[5]],
+                sources: [&accounts[7], &accounts[8]],
+                proof_instruction_offset: 0,
+                new_decryptable_available_balance: &[0xd1; 36],
+                token_program: &ID,
+            }
+            .with_instruction(|instruction, _| render(instruction))
+            .unwrap()
+        },
+    );
+}
+
+#[test]
+fn confidential_transfer_fee_withdraw_withheld_tokens_from_mint() {
+    assert_fixture(
+        "confidential_transfer_fee_withdraw_withheld_tokens_from_mint",
+        |accounts| {
+            confidential_transfer_fee::WithdrawWithheldTokensFromMint {
+                mint: &accounts[0],
+                destination: &accounts[1],
+                proof_account: &accounts[2],
+                record_account: None,
+                authority: &accounts[3],
+                multisig_signers: &[&accounts[4], &accounts[5]],
+                proof_instruction_offset: 1,
+                new_decryptable_available_balance: &[0xd1; 36],
+                token_program: &ID,
+            }
+            .with_instruction(|instruction, _| render(instruction))
+            .unwrap()
+        },
+    );
+}
+
+#[test]
+fn cpi_guard_disable() {
+    assert_fixture("cpi_guard_disable", |accounts| {
+        cpi_guard::Disable {
+            token_account: &accounts[0],
+            authority: &accounts[1],
+            signers: &[&accounts[4], &accounts[5]],
+            token_program: &ID,
+        }
+        .with_instruction(|instruction, _| render(instruction))
+        .unwrap()
+    });
+}
+
+#[test]
+fn cpi_guard_enable() {
+    assert_fixture("cpi_guard_enable", |accounts| {
+        cpi_guard::Enable {
+            token_account: &accounts[0],
+            authority: &accounts[1],
+            signers: &[&accounts[4], &accounts[5]],
+            token_program: &ID,
+        }
+        .with_instruction(|instruction, _| render(instruction))
+        .unwrap()
+    });
+}
+
+#[test]
+fn memo_transfer_disable() {
+    assert_fixture("memo_transfer_disable", |accounts| {
+        memo_transfer::Disable {
+            token_account: &accounts[0],
+            authority: &accounts[1],
+            signers: &[&accounts[4], &accounts[5]],
+            token_program: &ID,
+        }
+        .with_instruction(|instruction, _| render(instruction))
+        .unwrap()
+    });
+}
+
+#[test]
+fn memo_transfer_enable() {
+    assert_fixture("memo_transfer_enable", |accounts| {
+        memo_transfer::Enable {
+            token_account: &accounts[0],
+            authority: &accounts[1],
+            signers: &[&accounts[4], &accounts[5]],
+            token_program: &ID,
+        }
+        .with_instruction(|instruction, _| render(instruction))
+        .unwrap()
+    });
+}
+
+#[test]
+fn default_account_state_initialize() {
+    assert_fixture("default_account_state_initialize", |accounts| {
+        default_account_state::Initialize {
+            mint: &accounts[0],
+            state: 2,
+            token_program: &ID,
+        }
+        .with_instruction(|instruction, _| render(instruction))
+    });
+}
+
+#[test]
+fn default_account_state_update() {
+    assert_fixture("default_account_state_update", |accounts| {
+        default_account_state::Update {
+            mint: &accounts[0],
+            freeze_authority: &accounts[1],
+            signers: &[&accounts[4], &accounts[5]],
+            state: 1,
+            token_program: &ID,
+        }
+        .with_instruction(|instruction, _| render(instruction))
+        .unwrap()
+    });
+}
+
+#[test]
+fn group_member_pointer_initialize() {
+    assert_fixture("group_member_pointer_initialize", |accounts| {
+        group_member_pointer::Initialize {
+            mint: &accounts[0],
+            authority: Some(&ADDRESS),
+            member_address: Some(&OTHER_ADDRESS),
+            token_program: &ID,
+        }
+        .with_instruction(|instruction, _| render(instruction))
+    });
+}
+
+#[test]
+fn group_member_pointer_update() {
+    assert_fixture("group_member_pointer_update", |accounts| {
+        group_member_pointer::Update {
+            mint: &accounts[0],
+            authority: &accounts[1],
+            signers: &[&accounts[4], &accounts[5]],
+            member_address: Some(&OTHER_ADDRESS),
+            token_program: &ID,
+        }
+        .with_instruction(|instruction, _| render(instruction))
+        .unwrap()
+    });
+}
+
+#[test]
+fn group_pointer_initialize() {
+    assert_fixture("group_pointer_initialize", |accounts| {
+        group_pointer::Initialize {
+            mint: &accounts[0],
+            authority: Some(&ADDRESS),
+            group_address: Some(&OTHER_ADDRESS),
+            token_program: &ID,
+        }
+        .with_instruction(|instruction, _| render(instruction))
+    });
+}
+
+#[test]
+fn group_pointer_update() {
+    assert_fixture("group_pointer_update", |accounts| {
+        group_pointer::Update {
+            mint: &accounts[0],
+            authority: &accounts[1],
+            signers: &[&accounts[4], &accounts[5]],
+            group_address: Some(&OTHER_ADDRESS),
+            token_program: &ID,
+        }
+        .with_instruction(|instruction, _| render(instruction))
+        .unwrap()
+    });
+}
+
+#[test]
+fn metadata_pointer_initialize() {
+    assert_fixture("metadata_pointer_initialize", |accounts| {
+        metadata_pointer::Initialize {
+            mint: &accounts[0],
+            authority: Some(&ADDRESS),
+            metadata_address: Some(&OTHER_ADDRESS),
+            token_program: &ID,
+        }
+        .with_instruction(|instruction, _| render(instruction))
+    });
+}
+
+#[test]
+fn metadata_pointer_update() {
+    assert_fixture("metadata_pointer_update", |accounts| {
+        metadata_pointer::Update {
+            mint: &accounts[0],
+            authority: &accounts[1],
+            signers: &[&accounts[4], &accounts[5]],
+            metadata_address: Some(&OTHER_ADDRESS),
+            token_program: &ID,
+        }
+        .with_instruction(|instruction, _| render(instruction))
+        .unwrap()
+    });
+}
+
+#[test]
+fn interest_bearing_mint_initialize() {
+    assert_fixture("interest_bearing_mint_initialize", |accounts| {
+        interest_bearing_mint::Initialize {
+            mint: &accounts[0],
+            rate_authority: Some(&ADDRESS),
+            rate: -0x0102,
+            token_program: &ID,
+        }
+        .with_instruction(|instruction, _| render(instruction))
+    });
+}
+
+#[test]
+fn interest_bearing_mint_update() {
+    assert_fixture("interest_bearing_mint_update", |accounts| {
+        interest_bearing_mint::Update {
+            mint: &accounts[0],
+            authority: &accounts[1],
+            multisig_signers: &[&accounts[4], &accounts[5]],
+            rate: 0x0102,
+            token_program: &ID,
+        }
+        .with_instruction(|instruction, _| render(instruction))
+        .unwrap()
+    });
+}
+
+#[test]
+fn mint_close_authority_initialize() {
+    assert_fixture("mint_close_authority_initialize", |accounts| {
+        mint_close_authority::InitializeMintCloseAuthority {
+            mint: &accounts[0],
+            close_authority: Some(&ADDRESS),
+            token_program: &ID,
+        }
+        .with_instruction(|instruction, _| render(instruction))
+    });
+}
+
+#[test]
+fn pausable_initialize() {
+    assert_fixture("pausable_initialize", |accounts| {
+        pausable::Initialize {
+            mint: &accounts[0],
+            authority: &ADDRESS,
+            token_program: &ID,
+        }
+        .with_instruction(|instruction, _| render(instruction))
+    });
+}
+
+#[test]
+fn pausable_pause() {
+    assert_fixture("pausable_pause", |accounts| {
+        pausable::Pause {
+            mint: &accounts[0],
+            authority: &accounts[1],
+            multisig_signers: &[&accounts[4], &accounts[5]],
+            token_program: &ID,
+        }
+        .with_instruction(|instruction, _| render(instruction))
+        .unwrap()
+    });
+}
+
+#[test]
+fn pausable_resume() {
+    assert_fixture("pausable_resume", |accounts| {
+        pausable::Resume {
+            mint: &accounts[0],
+            authority: &accounts[1],
+            multisig_signers: &[&accounts[4], &accounts[5]],
+            token_program: &ID,
+        }
+        .with_instruction(|instruction, _| render(instruction))
+        .unwrap()
+    });
+}
+
+#[test]
+fn permanent_delegate_initialize() {
+    assert_fixture("permanent_delegate_initialize", |accounts| {
+        permanent_delegate::InitializePermanentDelegate {
+            mint: &accounts[0],
+            delegate: &ADDRESS,
+            token_program: &ID,
+        }
+        .with_instruction(|instruction, _| render(instruction))
+    });
+}
+
+#[test]
+fn permissioned_burn_burn() {
+    assert_fixture("permissioned_burn_burn", |accounts| {
+        permissioned_burn::Burn {
+            account: &accounts[0],
+            mint: &accounts[1],
+            permissioned_burn_authority: &accounts[2],
+            authority: &accounts[3],
+            multisig_signers: &[&accounts[4], &accounts[5]],
+            amount: AMOUNT,
+            token_program: &ID,
+        }
+        .with_instruction(|instruction, _| render(instruction))
+        .unwrap()
+    });
+}
+
+#[test]
+fn permissioned_burn_burn_checked() {
+    assert_fixture("permissioned_burn_burn_checked", |accounts| {
+        permissioned_burn::BurnChecked {
+            account: &accounts[0],
+            mint: &accounts[1],
+            permissioned_burn_authority: &accounts[2],
+            authority: &accounts[3],
+            multisig_signers: &[&accounts[4], &accounts[5]],
+            amount: AMOUNT,
+            decimals: DECIMALS,
+            token_program: &ID,
+        }
+        .with_instruction(|instruction, _| render(instruction))
+        .unwrap()
+    });
+}
+
+#[test]
+fn permissioned_burn_initialize() {
+    assert_fixture("permissioned_burn_initialize", |accounts| {
+        permissioned_burn::Initialize {
+            mint: &accounts[0],
+            authority: &ADDRESS,
+            token_program: &ID,
+        }
+        .with_instruction(|instruction, _| render(instruction))
+    });
+}
+
+#[test]
+fn scaled_ui_amount_initialize() {
+    assert_fixture("scaled_ui_amount_initialize", |accounts| {
+        scaled_ui_amount::Initialize {
+            mint_account: &accounts[0],
+            authority: Some(&ADDRESS),
+            multiplier: 1.5,
+            token_program: &ID,
+        }
+        .with_instruction(|instruction, _| render(instruction))
+    });
+}
+
+#[test]
+fn scaled_ui_amount_update_multiplier() {
+    assert_fixture("scaled_ui_amount_update_multiplier", |accounts| {
+        scaled_ui_amount::UpdateMultiplier {
+            mint_account: &accounts[0],
+            authority: &accounts[1],
+            signers: &[&accounts[4], &accounts[5]],
+            multiplier: 2.25,
+            effective_timestamp: -0x0102_0304,
+            token_program: &ID,
+        }
+        .with_instruction(|instruction, _| render(instruction))
+        .unwrap()
+    });
+}
+
+#[test]
+fn token_group_initialize_group() {
+    assert_fixture("token_group_initialize_group", |accounts| {
+        token_group::InitializeGroup {
+            group: &accounts[0],
+            mint: &accounts[1],
+            mint_authority: &accounts[2],
+            update_authority: Some(&ADDRESS),
+            max_size: AMOUNT,
+            token_program: &ID,
+        }
+        .with_instruction(|instruction, _| render(instruction))
+    });
+}
+
+#[test]
+fn token_group_initialize_member() {
+    assert_fixture("token_group_initialize_member", |accounts| {
+        token_group::InitializeMember {
+            member: &accounts[0],
+            member_mint: &accounts[1],
+            member_mint_authority: &accounts[2],
+            group: &accounts[3],
+            group_update_authority: &accounts[4],
+            token_program: &ID,
+        }
+        .with_instruction(|instruction, _| render(instruction))
+    });
+}
+
+#[test]
+fn token_group_update_group_authority() {
+    assert_fixture("token_group_update_group_authority", |accounts| {
+        token_group::UpdateGroupAuthority {
+            group: &accounts[0],
+            update_authority: &accounts[1],
+            new_authority: None,
+            token_program: &ID,
+        }
+        .with_instruction(|instruction, _| render(instruction))
+    });
+}
+
+#[test]
+fn token_group_update_group_max_size() {
+    assert_fixture("token_group_update_group_max_size", |accounts| {
+        token_group::UpdateGroupMaxSize {
+            group: &accounts[0],
+            update_authority: &accounts[1],
+            max_size: AMOUNT,
+            token_program: &ID,
+        }
+        .with_instruction(|instruction, _| render(instruction))
+    });
+}
+
+#[test]
+fn token_metadata_emit() {
+    assert_fixture("token_metadata_emit", |accounts| {
+        token_metadata::Emit {
+            metadata: &accounts[0],
+            start: Some(AMOUNT),
+            end: None,
+            token_program: &ID,
+        }
+        .with_instruction(|instruction, _| render(instruction))
+    });
+}
+
+#[test]
+fn token_metadata_initialize() {
+    assert_fixture("token_metadata_initialize", |accounts| {
+        token_metadata::Initialize {
+            metadata: &accounts[0],
+            update_authority: &accounts[1],
+            mint: &accounts[2],
+            mint_authority: &accounts[3],
+            name: "Pinocchio",
+            symbol: "PINO",
+            uri: "https://example.com/pinocchio.json",
+            token_program: &ID,
+        }
+        .with_instruction(|instruction, _| render(instruction))
+        .unwrap()
+    });
+}
+
+#[test]
+fn token_metadata_remove_key() {
+    assert_fixture("token_metadata_remove_key", |accounts| {
+        token_metadata::RemoveKey {
+            metadata: &accounts[0],
+            update_authority: &accounts[1],
+            idempotent: true,
+            key: "color",
+            token_program: &ID,
+        }
+        .with_instruction(|instruction, _| render(instruction))
+        .unwrap()
+    });
+}
+
+#[test]
+fn token_metadata_update_authority() {
+    assert_fixture("token_metadata_update_authority", |accounts| {
+        token_metadata::UpdateAuthority {
+            metadata: &accounts[0],
+            update_authority: &accounts[1],
+            new_authority: Some(&ADDRESS),
+            token_program: &ID,
+        }
+        .with_instruction(|instruction, _| render(instruction))
+    });
+}
+
+#[test]
+fn token_metadata_update_field() {
+    assert_fixture("token_metadata_update_field", |accounts| {
+        token_metadata::UpdateField {
+            metadata: &accounts[0],
+            update_authority: &accounts[1],
+            field: token_metadata::Field::Key("color"),
+            value: "blue",
+            token_program: &ID,
+        }
+        .with_instruction(|instruction, _| render(instruction))
+        .unwrap()
+    });
+}
+
+#[test]
+fn transfer_hook_execute() {
+    assert_fixture("transfer_hook_execute", |accounts| {
+        transfer_hook::Execute {
+            source: &accounts[0],
+            mint: &accounts[1],
+            destination: &accounts[2],
+            authority: &accounts[3],
+            extra_account_metas: &accounts[4],
+            extra_accounts: &[&accounts[5], &accounts[6]],
+            amount: AMOUNT,
+            program_id: &ADDRESS,
+        }
+        .with_instruction(|instruction, _| render(instruction))
+        .unwrap()
+    });
+}
+
+#[test]
+fn transfer_hook_initialize() {
+    assert_fixture("transfer_hook_initialize", |accounts| {
+        transfer_hook::InitializeTransferHook {
+            mint: &accounts[0],
+            authority: Some(&ADDRESS),
+            program_id: Some(&OTHER_ADDRESS),
+            token_program: &ID,
+        }
+        .with_instruction(|instruction, _| render(instruction))
+    });
+}
+
+#[test]
+fn transfer_hook_update() {
+    assert_fixture("transfer_hook_update", |accounts| {
+        transfer_hook::UpdateTransferHook {
+            mint: &accounts[0],
+            authority: &accounts[1],
+            signers: &[&accounts[4], &accounts[5]],
+            transfer_hook_program: None,
+            token_program: &ID,
+        }
+        .with_instruction(|instruction, _| render(instruction))
+        .unwrap()
+    });
+}
@@ -39,6 +39,16 @@ impl ApproveChecked<'_> {
 
     #[inline(always)]
     pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        self.with_instruction(|instruction, account_views| {
+            invoke_signed(instruction, account_views, signers)
+        })
+    }
+
+    #[inline(always)]
+    pub fn with_instruction<R>(
+        &self,
+        f: impl FnOnce(&InstructionView, &[&AccountView; 4]) -> R,
+    ) -> R {
         // Instruction accounts
         let instruction_accounts: [InstructionAccount; 4] = [
             InstructionAccount::writable(self.source.address()),
@@ -66,10 +76,9 @@ impl ApproveChecked<'_> {
             data: unsafe { from_raw_parts(instruction_data.as_ptr() as _, 10) },
         };
 
-        invoke_signed(
+        f(
             &instruction,
             &[self.source, self.mint, self.delegate, self.authority],
-            signers,
         )
     }
 }
@@ -30,6 +30,16 @@ impl CloseAccount<'_> {
 
     #[inline(always)]
     pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        self.with_instruction(|instruction, account_views| {
+            invoke_signed(instruction, account_views, signers)
+        })
+    }
+
+    #[inline(always)]
+    pub fn with_instruction<R>(
+        &self,
+        f: impl FnOnce(&InstructionView, &[&AccountView; 3]) -> R,
+    ) -> R {
         // Instruction accounts
         let instruction_accounts: [InstructionAccount; 3] = [
             InstructionAccount::writable(self.account.address()),
@@ -43,10 +53,9 @@ impl CloseAccount<'_> {
             data: &[9],
         };
 
-        invoke_signed(
+        f(
             &instruction,
             &[self.account, self.destination, self.authority],
-            signers,
         )
     }
 }
@@ -25,6 +25,14 @@ pub struct InitializeAccount<'a> {
 impl InitializeAccount<'_> {
     #[inline(always)]
     pub fn invoke(&self) -> ProgramResult {
+        self.with_instruction(invoke)
+    }
+
+    #[inline(always)]
+    pub fn with_instruction<R>(
+        &self,
+        f: impl FnOnce(&InstructionView, &[&AccountView; 4]) -> R,
+    ) -> R {
         // Instruction accounts
         let instruction_accounts: [InstructionAccount; 4] = [
             InstructionAccount::writable(self.account.address()),
@@ -39,7 +47,7 @@ impl InitializeAccount<'_> {
             data: &[1],
         };
 
-        invoke(
+        f(
             &instruction,
             &[self.account, self.mint, self.owner, self.rent_sysvar],
         )
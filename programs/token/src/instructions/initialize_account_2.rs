@@ -27,6 +27,14 @@ pub struct InitializeAccount2<'a> {
 impl InitializeAccount2<'_> {
     #[inline(always)]
     pub fn invoke(&self) -> ProgramResult {
+        self.with_instruction(invoke)
+    }
+
+    #[inline(always)]
+    pub fn with_instruction<R>(
+        &self,
+        f: impl FnOnce(&InstructionView, &[&AccountView; 3]) -> R,
+    ) -> R {
         // Instruction accounts
         let instruction_accounts: [InstructionAccount; 3] = [
             InstructionAccount::writable(self.account.address()),
@@ -50,6 +58,6 @@ impl InitializeAccount2<'_> {
             data: unsafe { from_raw_parts(instruction_data.as_ptr() as _, 33) },
         };
 
-        invoke(&instruction, &[self.account, self.mint, self.rent_sysvar])
+        f(&instruction, &[self.account, self.mint, self.rent_sysvar])
     }
 }
@@ -24,6 +24,14 @@ pub struct InitializeAccount3<'a> {
 impl InitializeAccount3<'_> {
     #[inline(always)]
     pub fn invoke(&self) -> ProgramResult {
+        self.with_instruction(invoke)
+    }
+
+    #[inline(always)]
+    pub fn with_instruction<R>(
+        &self,
+        f: impl FnOnce(&InstructionView, &[&AccountView; 2]) -> R,
+    ) -> R {
         // Instruction accounts
         let instruction_accounts: [InstructionAccount; 2] = [
             InstructionAccount::writable(self.account.address()),
@@ -46,6 +54,6 @@ impl InitializeAccount3<'_> {
             data: unsafe { from_raw_parts(instruction_data.as_ptr() as _, 33) },
         };
 
-        invoke(&instruction, &[self.account, self.mint])
+        f(&instruction, &[self.account, self.mint])
     }
 }
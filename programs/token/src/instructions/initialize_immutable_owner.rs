@@ -0,0 +1,43 @@
+use {
+    solana_account_view::AccountView,
+    solana_instruction_view::{cpi::invoke, InstructionAccount, InstructionView},
+    solana_program_error::ProgramResult,
+};
+
+/// Initialize the Immutable Owner extension for the given token account.
+///
+/// The legacy token program has no extensions: this instruction only checks
+/// that the account is not initialized yet, and is accepted so that clients
+/// can use the same instructions for both token programs.
+///
+/// ### Accounts:
+///   0. `[WRITE]` The account to initialize.
+pub struct InitializeImmutableOwner<'a> {
+    /// The account to initialize.
+    pub account: &'a AccountView,
+}
+
+impl InitializeImmutableOwner<'_> {
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.with_instruction(invoke)
+    }
+
+    #[inline(always)]
+    pub fn with_instruction<R>(
+        &self,
+        f: impl FnOnce(&InstructionView, &[&AccountView; 1]) -> R,
+    ) -> R {
+        // Instruction accounts
+        let instruction_accounts: [InstructionAccount; 1] =
+            [InstructionAccount::writable(self.account.address())];
+
+        let instruction = InstructionView {
+            program_id: &crate::ID,
+            accounts: &instruction_accounts,
+            data: &[22],
+        };
+
+        f(&instruction, &[self.account])
+    }
+}
@@ -28,6 +28,14 @@ pub struct InitializeMint<'a> {
 impl InitializeMint<'_> {
     #[inline(always)]
     pub fn invoke(&self) -> ProgramResult {
+        self.with_instruction(invoke)
+    }
+
+    #[inline(always)]
+    pub fn with_instruction<R>(
+        &self,
+        f: impl FnOnce(&InstructionView, &[&AccountView; 2]) -> R,
+    ) -> R {
         // Instruction accounts
         let instruction_accounts: [InstructionAccount; 2] = [
             InstructionAccount::writable(self.mint.address()),
@@ -67,6 +75,6 @@ impl InitializeMint<'_> {
             data: unsafe { from_raw_parts(instruction_data.as_ptr() as _, length) },
         };
 
-        invoke(&instruction, &[self.mint, self.rent_sysvar])
+        f(&instruction, &[self.mint, self.rent_sysvar])
     }
 }
@@ -32,6 +32,16 @@ where
 impl InitializeMultisig<'_, '_> {
     #[inline(always)]
     pub fn invoke(&self) -> ProgramResult {
+        self.with_instruction(|instruction, account_views| {
+            invoke_with_bounds::<{ 2 + MAX_MULTISIG_SIGNERS }>(instruction, account_views)
+        })?
+    }
+
+    #[inline(always)]
+    pub fn with_instruction<R>(
+        &self,
+        f: impl FnOnce(&InstructionView, &[&AccountView]) -> R,
+    ) -> Result<R, ProgramError> {
         let &Self {
             multisig,
             rent_sysvar,
@@ -98,8 +108,8 @@ impl InitializeMultisig<'_, '_> {
             account_view.write(signer);
         }
 
-        invoke_with_bounds::<{ 2 + MAX_MULTISIG_SIGNERS }>(&instruction, unsafe {
+        Ok(f(&instruction, unsafe {
             slice::from_raw_parts(acc_views.as_ptr() as _, num_accounts)
-        })
+        }))
     }
 }
@@ -27,6 +27,16 @@ where
 impl InitializeMultisig2<'_, '_> {
     #[inline(always)]
     pub fn invoke(&self) -> ProgramResult {
+        self.with_instruction(|instruction, account_views| {
+            invoke_with_bounds::<{ 1 + MAX_MULTISIG_SIGNERS }>(instruction, account_views)
+        })?
+    }
+
+    #[inline(always)]
+    pub fn with_instruction<R>(
+        &self,
+        f: impl FnOnce(&InstructionView, &[&AccountView]) -> R,
+    ) -> Result<R, ProgramError> {
         let &Self {
             multisig,
             signers,
@@ -88,8 +98,8 @@ impl InitializeMultisig2<'_, '_> {
             account_view.write(signer);
         }
 
-        invoke_with_bounds::<{ 1 + MAX_MULTISIG_SIGNERS }>(&instruction, unsafe {
+        Ok(f(&instruction, unsafe {
             slice::from_raw_parts(acc_views.as_ptr() as _, num_accounts)
-        })
+        }))
     }
 }
@@ -7,6 +7,7 @@ mod freeze_account;
 mod initialize_account;
 mod initialize_account_2;
 mod initialize_account_3;
+mod initialize_immutable_owner;
 mod initialize_mint;
 mod initialize_mint_2;
 mod initialize_multisig;
@@ -22,8 +23,8 @@ mod transfer_checked;
 
 pub use {
     approve::*, approve_checked::*, burn::*, burn_checked::*, close_account::*, freeze_account::*,
-    initialize_account::*, initialize_account_2::*, initialize_account_3::*, initialize_mint::*,
-    initialize_mint_2::*, initialize_multisig::*, initialize_multisig_2::*, mint_to::*,
-    mint_to_checked::*, revoke::*, set_authority::*, sync_native::*, thaw_account::*, transfer::*,
-    transfer_checked::*,
+    initialize_account::*, initialize_account_2::*, initialize_account_3::*,
+    initialize_immutable_owner::*, initialize_mint::*, initialize_mint_2::*,
+    initialize_multisig::*, initialize_multisig_2::*, mint_to::*, mint_to_checked::*, revoke::*,
+    set_authority::*, sync_native::*, thaw_account::*, transfer::*, transfer_checked::*,
 };
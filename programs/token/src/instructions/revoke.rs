@@ -27,6 +27,16 @@ impl Revoke<'_> {
 
     #[inline(always)]
     pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        self.with_instruction(|instruction, account_views| {
+            invoke_signed(instruction, account_views, signers)
+        })
+    }
+
+    #[inline(always)]
+    pub fn with_instruction<R>(
+        &self,
+        f: impl FnOnce(&InstructionView, &[&AccountView; 2]) -> R,
+    ) -> R {
         // Instruction accounts
         let instruction_accounts: [InstructionAccount; 2] = [
             InstructionAccount::writable(self.source.address()),
@@ -39,6 +49,6 @@ impl Revoke<'_> {
             data: &[5],
         };
 
-        invoke_signed(&instruction, &[self.source, self.authority], signers)
+        f(&instruction, &[self.source, self.authority])
     }
 }
@@ -18,6 +18,14 @@ pub struct SyncNative<'a> {
 impl SyncNative<'_> {
     #[inline(always)]
     pub fn invoke(&self) -> ProgramResult {
+        self.with_instruction(invoke)
+    }
+
+    #[inline(always)]
+    pub fn with_instruction<R>(
+        &self,
+        f: impl FnOnce(&InstructionView, &[&AccountView; 1]) -> R,
+    ) -> R {
         // Instruction accounts
         let instruction_accounts: [InstructionAccount; 1] =
             [InstructionAccount::writable(self.native_token.address())];
@@ -28,6 +36,6 @@ impl SyncNative<'_> {
             data: &[17],
         };
 
-        invoke(&instruction, &[self.native_token])
+        f(&instruction, &[self.native_token])
     }
 }
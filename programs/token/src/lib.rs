@@ -3,10 +3,40 @@
 pub mod instructions;
 pub mod state;
 
-use core::mem::MaybeUninit;
+use {
+    crate::{instructions::Burn, state::TokenAccount},
+    core::mem::MaybeUninit,
+    solana_account_view::AccountView,
+    solana_instruction_view::cpi::Signer,
+    solana_program_error::ProgramResult,
+};
 
 solana_address::declare_id!("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA");
 
+/// Burn the entire token balance of `account`.
+///
+/// This is the token equivalent of sending lamports to the incinerator: the
+/// tokens are removed from the supply of the `mint`. The `authority` must be
+/// the owner or delegate of the `account`; when it is a PDA, its signer seeds
+/// must be provided via the `signers`.
+#[inline(always)]
+pub fn burn_all(
+    account: &AccountView,
+    mint: &AccountView,
+    authority: &AccountView,
+    signers: &[Signer],
+) -> ProgramResult {
+    let amount = TokenAccount::from_account_view(account)?.amount();
+
+    Burn {
+        account,
+        mint,
+        authority,
+        amount,
+    }
+    .invoke_signed(signers)
+}
+
 const UNINIT_BYTE: MaybeUninit<u8> = MaybeUninit::<u8>::uninit();
 
 #[inline(always)]
@@ -20,3 +50,67 @@ fn write_bytes(destination: &mut [MaybeUninit<u8>], source: &[u8]) {
         core::ptr::copy_nonoverlapping(source.as_ptr(), destination.as_mut_ptr() as *mut u8, len);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use {
+        super::*,
+        pinocchio::{
+            entrypoint::input::{AccountViews, InputAccount},
+            error::ProgramError,
+            Address,
+        },
+        solana_instruction_view::cpi::Seed,
+        std::vec::Vec,
+    };
+
+    const ACCOUNT: Address = Address::new_from_array([1; 32]);
+
+    const MINT: Address = Address::new_from_array([2; 32]);
+
+    const AUTHORITY: Address = Address::new_from_array([3; 32]);
+
+    /// Create the token account, owned by `owner` and holding `amount`
+    /// tokens, the mint and the authority.
+    fn accounts(owner: Address, amount: u64) -> AccountViews {
+        let mut data = Vec::with_capacity(TokenAccount::LEN);
+        data.extend_from_slice(MINT.as_ref());
+        data.extend_from_slice(AUTHORITY.as_ref());
+        data.extend_from_slice(&amount.to_le_bytes());
+        data.resize(TokenAccount::LEN, 0);
+
+        AccountViews::new(&[
+            InputAccount::new(ACCOUNT, owner, 2_039_280, &data).writable(),
+            InputAccount::new(MINT, ID, 1_461_600, &[]).writable(),
+            InputAccount::new(AUTHORITY, Address::default(), 0, &[]).signer(),
+        ])
+    }
+
+    #[test]
+    fn test_burn_all() {
+        let accounts = accounts(ID, 1_000);
+
+        let seeds = [Seed::from(b"seed"), Seed::from(&[255])];
+        assert_eq!(
+            burn_all(
+                &accounts[0],
+                &accounts[1],
+                &accounts[2],
+                &[Signer::from(&seeds)]
+            ),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn test_burn_all_invalid_owner() {
+        let accounts = accounts(Address::new_from_array([4; 32]), 1_000);
+
+        assert_eq!(
+            burn_all(&accounts[0], &accounts[1], &accounts[2], &[]),
+            Err(ProgramError::InvalidAccountData)
+        );
+    }
+}
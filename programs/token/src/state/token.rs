@@ -90,6 +90,20 @@ impl TokenAccount {
         Ok(Self::from_bytes_unchecked(account_view.borrow_unchecked()))
     }
 
+    /// Return a `TokenAccount` from the given bytes.
+    ///
+    /// This method performs a length validation, requiring `bytes` to be
+    /// exactly `TokenAccount::LEN` (165) bytes long.
+    #[inline]
+    pub fn from_bytes(bytes: &[u8]) -> Result<&Self, ProgramError> {
+        if bytes.len() != Self::LEN {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        // SAFETY: `bytes` holds exactly `LEN` bytes and `TokenAccount` has an
+        // alignment of 1 byte.
+        Ok(unsafe { Self::from_bytes_unchecked(bytes) })
+    }
+
     /// Return a `TokenAccount` from the given bytes.
     ///
     /// # Safety
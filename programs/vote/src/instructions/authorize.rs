@@ -0,0 +1,81 @@
+use {
+    crate::state::VoteAuthorize,
+    pinocchio::{
+        cpi::{invoke_signed, Signer},
+        instruction::{InstructionAccount, InstructionView},
+        AccountView, Address, ProgramResult,
+    },
+};
+
+/// Authorize a key to send votes or issue a withdrawal.
+///
+/// ### Accounts:
+///   0. `[WRITE]` Vote account to be updated
+///   1. `[]` Clock sysvar
+///   2. `[SIGNER]` Vote or withdraw authority
+pub struct Authorize<'a, 'b> {
+    /// Vote account to be updated.
+    pub vote: &'a AccountView,
+
+    /// Clock sysvar.
+    ///
+    /// The address of the account must be
+    /// [`CLOCK_ID`](pinocchio::sysvars::clock::CLOCK_ID).
+    pub clock_sysvar: &'a AccountView,
+
+    /// Current vote or withdraw authority.
+    pub authority: &'a AccountView,
+
+    /// New authority.
+    pub new_authority: &'b Address,
+
+    /// Type of authority to update.
+    pub vote_authorize: VoteAuthorize,
+}
+
+impl Authorize<'_, '_> {
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    #[inline(always)]
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        self.with_instruction(|instruction, account_views| {
+            invoke_signed(instruction, account_views, signers)
+        })
+    }
+
+    #[inline(always)]
+    pub fn with_instruction<R>(
+        &self,
+        f: impl FnOnce(&InstructionView, &[&AccountView; 3]) -> R,
+    ) -> R {
+        // Instruction accounts
+        let instruction_accounts: [InstructionAccount; 3] = [
+            InstructionAccount::writable(self.vote.address()),
+            InstructionAccount::readonly(self.clock_sysvar.address()),
+            InstructionAccount::readonly_signer(self.authority.address()),
+        ];
+
+        // instruction data
+        // - [0..4  ]: instruction discriminator
+        // - [4..36 ]: new authority address
+        // - [36..40]: vote authorize
+        let mut instruction_data = [0; 40];
+        instruction_data[0] = 1;
+        instruction_data[4..36].copy_from_slice(self.new_authority.as_array());
+        instruction_data[36..40].copy_from_slice(&(self.vote_authorize as u32).to_le_bytes());
+
+        let instruction = InstructionView {
+            program_id: &crate::ID,
+            accounts: &instruction_accounts,
+            data: &instruction_data,
+        };
+
+        f(
+            &instruction,
+            &[self.vote, self.clock_sysvar, self.authority],
+        )
+    }
+}
@@ -0,0 +1,5 @@
+mod authorize;
+mod update_commission;
+mod withdraw;
+
+pub use {authorize::*, update_commission::*, withdraw::*};
@@ -0,0 +1,60 @@
+use pinocchio::{
+    cpi::{invoke_signed, Signer},
+    instruction::{InstructionAccount, InstructionView},
+    AccountView, ProgramResult,
+};
+
+/// Update the commission of the vote account.
+///
+/// Commission increases are only allowed during the first half of an epoch.
+///
+/// ### Accounts:
+///   0. `[WRITE]` Vote account to be updated
+///   1. `[SIGNER]` Withdraw authority
+pub struct UpdateCommission<'a> {
+    /// Vote account to be updated.
+    pub vote: &'a AccountView,
+
+    /// Withdraw authority.
+    pub withdraw_authority: &'a AccountView,
+
+    /// New commission, as a percentage of the rewards.
+    pub commission: u8,
+}
+
+impl UpdateCommission<'_> {
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    #[inline(always)]
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        self.with_instruction(|instruction, account_views| {
+            invoke_signed(instruction, account_views, signers)
+        })
+    }
+
+    #[inline(always)]
+    pub fn with_instruction<R>(
+        &self,
+        f: impl FnOnce(&InstructionView, &[&AccountView; 2]) -> R,
+    ) -> R {
+        // Instruction accounts
+        let instruction_accounts: [InstructionAccount; 2] = [
+            InstructionAccount::writable(self.vote.address()),
+            InstructionAccount::readonly_signer(self.withdraw_authority.address()),
+        ];
+
+        // instruction data
+        // - [0..4]: instruction discriminator
+        // - [4   ]: commission
+        let instruction = InstructionView {
+            program_id: &crate::ID,
+            accounts: &instruction_accounts,
+            data: &[5, 0, 0, 0, self.commission],
+        };
+
+        f(&instruction, &[self.vote, self.withdraw_authority])
+    }
+}
@@ -0,0 +1,70 @@
+use pinocchio::{
+    cpi::{invoke_signed, Signer},
+    instruction::{InstructionAccount, InstructionView},
+    AccountView, ProgramResult,
+};
+
+/// Withdraw lamports from the vote account.
+///
+/// ### Accounts:
+///   0. `[WRITE]` Vote account to withdraw from
+///   1. `[WRITE]` Recipient account
+///   2. `[SIGNER]` Withdraw authority
+pub struct Withdraw<'a> {
+    /// Vote account to withdraw from.
+    pub vote: &'a AccountView,
+
+    /// Recipient account.
+    pub recipient: &'a AccountView,
+
+    /// Withdraw authority.
+    pub withdraw_authority: &'a AccountView,
+
+    /// Amount of lamports to withdraw.
+    pub lamports: u64,
+}
+
+impl Withdraw<'_> {
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    #[inline(always)]
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        self.with_instruction(|instruction, account_views| {
+            invoke_signed(instruction, account_views, signers)
+        })
+    }
+
+    #[inline(always)]
+    pub fn with_instruction<R>(
+        &self,
+        f: impl FnOnce(&InstructionView, &[&AccountView; 3]) -> R,
+    ) -> R {
+        // Instruction accounts
+        let instruction_accounts: [InstructionAccount; 3] = [
+            InstructionAccount::writable(self.vote.address()),
+            InstructionAccount::writable(self.recipient.address()),
+            InstructionAccount::readonly_signer(self.withdraw_authority.address()),
+        ];
+
+        // instruction data
+        // - [0..4 ]: instruction discriminator
+        // - [4..12]: lamports amount
+        let mut instruction_data = [0; 12];
+        instruction_data[0] = 3;
+        instruction_data[4..12].copy_from_slice(&self.lamports.to_le_bytes());
+
+        let instruction = InstructionView {
+            program_id: &crate::ID,
+            accounts: &instruction_accounts,
+            data: &instruction_data,
+        };
+
+        f(
+            &instruction,
+            &[self.vote, self.recipient, self.withdraw_authority],
+        )
+    }
+}
@@ -0,0 +1,6 @@
+#![no_std]
+
+pub mod instructions;
+pub mod state;
+
+pinocchio::address::declare_id!("Vote111111111111111111111111111111111111111");
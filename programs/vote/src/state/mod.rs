@@ -0,0 +1,15 @@
+mod vote_state;
+
+pub use vote_state::*;
+
+/// Type of authority of a vote account.
+#[repr(u32)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum VoteAuthorize {
+    /// Authority allowed to vote.
+    Voter,
+
+    /// Authority allowed to withdraw from the vote account and update its
+    /// commission.
+    Withdrawer,
+}
@@ -0,0 +1,376 @@
+use {
+    crate::ID,
+    core::ops::Deref,
+    pinocchio::{
+        account::{AccountView, Ref},
+        error::ProgramError,
+        Address,
+    },
+};
+
+/// Version of a vote account.
+#[repr(u32)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum VoteStateVersion {
+    /// Vote state before version 1.14.11, not supported by [`VoteState`].
+    V0_23_5,
+
+    /// Vote state version 1.14.11, with votes stored as lockouts.
+    V1_14_11,
+
+    /// Current vote state version, with votes stored as landed votes.
+    Current,
+
+    /// Vote state version 4 (SIMD-0185), not supported by [`VoteState`].
+    V4,
+}
+
+impl TryFrom<u32> for VoteStateVersion {
+    type Error = ProgramError;
+
+    #[inline]
+    fn try_from(value: u32) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(VoteStateVersion::V0_23_5),
+            1 => Ok(VoteStateVersion::V1_14_11),
+            2 => Ok(VoteStateVersion::Current),
+            3 => Ok(VoteStateVersion::V4),
+            _ => Err(ProgramError::InvalidAccountData),
+        }
+    }
+}
+
+/// Errors returned when loading a [`VoteState`].
+///
+/// These errors are raised by this crate, not by the Vote program.
+#[repr(u32)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum VoteStateError {
+    /// The vote account uses a [`VoteStateVersion`] that is not supported by
+    /// [`VoteState`].
+    UnsupportedVersion,
+}
+
+impl From<VoteStateError> for ProgramError {
+    #[inline(always)]
+    fn from(error: VoteStateError) -> Self {
+        ProgramError::Custom(error as u32)
+    }
+}
+
+/// Offset of the node address.
+const NODE_PUBKEY_OFFSET: usize = 4;
+
+/// Offset of the authorized withdrawer address.
+const AUTHORIZED_WITHDRAWER_OFFSET: usize = NODE_PUBKEY_OFFSET + 32;
+
+/// Offset of the commission.
+const COMMISSION_OFFSET: usize = AUTHORIZED_WITHDRAWER_OFFSET + 32;
+
+/// Offset of the number of votes.
+const VOTES_LEN_OFFSET: usize = COMMISSION_OFFSET + 1;
+
+/// Offset of the first vote.
+const VOTES_OFFSET: usize = VOTES_LEN_OFFSET + 8;
+
+/// Vote account data.
+///
+/// The data of a vote account starts with:
+/// - `[0..4  ]`: version
+/// - `[4..36 ]`: node address
+/// - `[36..68]`: authorized withdrawer address
+/// - `[68    ]`: commission
+/// - `[69..77]`: number of votes
+/// - `[77..  ]`: votes, followed by the optional root slot
+///
+/// Each vote is 12 bytes long (slot and confirmation count) for
+/// [`VoteStateVersion::V1_14_11`], and 13 bytes long (latency, slot and
+/// confirmation count) for [`VoteStateVersion::Current`].
+///
+/// Only these two versions are supported. Vote accounts using
+/// [`VoteStateVersion::V0_23_5`] or [`VoteStateVersion::V4`], whose layout
+/// stores the commission and the votes at different offsets, are rejected
+/// with [`VoteStateError::UnsupportedVersion`].
+pub struct VoteState<T: Deref<Target = [u8]>> {
+    data: T,
+}
+
+impl<'a> VoteState<Ref<'a, [u8]>> {
+    /// Return a `VoteState` from the given account view.
+    ///
+    /// This method performs owner, version and length validation on
+    /// `AccountView`, safe borrowing the account data.
+    #[inline]
+    pub fn from_account_view(account_view: &'a AccountView) -> Result<Self, ProgramError> {
+        if !account_view.owned_by(&ID) {
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+        Self::new(account_view.try_borrow()?)
+    }
+}
+
+impl<'a> VoteState<&'a [u8]> {
+    /// Return a `VoteState` from the given account view.
+    ///
+    /// This method performs owner, version and length validation on
+    /// `AccountView`, but does not perform the borrow check.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that it is safe to borrow the account data (e.g.,
+    /// there are no mutable borrows of the account data).
+    #[inline]
+    pub unsafe fn from_account_view_unchecked(
+        account_view: &'a AccountView,
+    ) -> Result<Self, ProgramError> {
+        if account_view.owner() != &ID {
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+        Self::from_bytes(account_view.borrow_unchecked())
+    }
+
+    /// Return a `VoteState` from the given account data.
+    ///
+    /// The version and length of the data are validated, but the account
+    /// owner is not checked.
+    #[inline]
+    pub fn from_bytes(data: &'a [u8]) -> Result<Self, ProgramError> {
+        Self::new(data)
+    }
+}
+
+impl<T: Deref<Target = [u8]>> VoteState<T> {
+    /// Return a `VoteState` from the given data, validating its version and
+    /// length.
+    #[inline]
+    fn new(data: T) -> Result<Self, ProgramError> {
+        if data.len() < VOTES_OFFSET {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        let vote_len = match VoteStateVersion::try_from(u32::from_le_bytes([
+            data[0], data[1], data[2], data[3],
+        ]))? {
+            VoteStateVersion::V1_14_11 => 12,
+            VoteStateVersion::Current => 13,
+            VoteStateVersion::V0_23_5 | VoteStateVersion::V4 => {
+                return Err(VoteStateError::UnsupportedVersion.into())
+            }
+        };
+
+        let vote_state = Self { data };
+
+        let root_slot_offset = vote_state
+            .votes_len()
+            .checked_mul(vote_len)
+            .and_then(|votes| votes.checked_add(VOTES_OFFSET))
+            .ok_or(ProgramError::InvalidAccountData)?;
+
+        match vote_state.data.get(root_slot_offset) {
+            Some(0) => Ok(vote_state),
+            Some(1) if vote_state.data.len() >= root_slot_offset + 9 => Ok(vote_state),
+            _ => Err(ProgramError::InvalidAccountData),
+        }
+    }
+
+    /// Return the version of the vote account.
+    ///
+    /// The version is either [`VoteStateVersion::V1_14_11`] or
+    /// [`VoteStateVersion::Current`], since other versions are rejected when
+    /// the `VoteState` is loaded.
+    #[inline(always)]
+    pub fn version(&self) -> VoteStateVersion {
+        match u32::from_le_bytes(self.read_array(0)) {
+            1 => VoteStateVersion::V1_14_11,
+            _ => VoteStateVersion::Current,
+        }
+    }
+
+    /// Return the address of the validator identity.
+    #[inline(always)]
+    pub fn node_pubkey(&self) -> &Address {
+        // SAFETY: `data` has at least `VOTES_OFFSET` bytes and `Address` has
+        // an alignment of 1 byte.
+        unsafe { &*(self.data.as_ptr().add(NODE_PUBKEY_OFFSET) as *const Address) }
+    }
+
+    /// Return the authority allowed to withdraw from the vote account.
+    #[inline(always)]
+    pub fn authorized_withdrawer(&self) -> &Address {
+        // SAFETY: `data` has at least `VOTES_OFFSET` bytes and `Address` has
+        // an alignment of 1 byte.
+        unsafe { &*(self.data.as_ptr().add(AUTHORIZED_WITHDRAWER_OFFSET) as *const Address) }
+    }
+
+    /// Return the commission, as a percentage of the rewards.
+    #[inline(always)]
+    pub fn commission(&self) -> u8 {
+        self.data[COMMISSION_OFFSET]
+    }
+
+    /// Return the number of votes in the vote tower.
+    #[inline(always)]
+    pub fn votes_len(&self) -> usize {
+        u64::from_le_bytes(self.read_array(VOTES_LEN_OFFSET)) as usize
+    }
+
+    /// Return the slot of the vote at `index` in the vote tower, from the
+    /// oldest to the most recent vote.
+    ///
+    /// Returns `None` if `index` is out of bounds.
+    #[inline]
+    pub fn vote_slot(&self, index: usize) -> Option<u64> {
+        if index >= self.votes_len() {
+            return None;
+        }
+
+        let offset = match self.version() {
+            VoteStateVersion::Current => VOTES_OFFSET + index * 13 + 1,
+            _ => VOTES_OFFSET + index * 12,
+        };
+
+        Some(u64::from_le_bytes(self.read_array(offset)))
+    }
+
+    /// Return the slot of the most recent vote.
+    #[inline]
+    pub fn last_voted_slot(&self) -> Option<u64> {
+        self.votes_len()
+            .checked_sub(1)
+            .and_then(|index| self.vote_slot(index))
+    }
+
+    /// Return the root slot of the vote tower, if any.
+    #[inline]
+    pub fn root_slot(&self) -> Option<u64> {
+        let vote_len = match self.version() {
+            VoteStateVersion::Current => 13,
+            _ => 12,
+        };
+        let offset = VOTES_OFFSET + self.votes_len() * vote_len;
+
+        (self.data[offset] == 1).then(|| u64::from_le_bytes(self.read_array(offset + 1)))
+    }
+
+    /// Read `N` bytes starting at `offset`.
+    #[inline(always)]
+    fn read_array<const N: usize>(&self, offset: usize) -> [u8; N] {
+        let mut array = [0; N];
+        array.copy_from_slice(&self.data[offset..offset + N]);
+        array
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        pinocchio::entrypoint::input::{AccountViews, InputAccount},
+    };
+
+    fn build_vote_data(version: u32, votes: &[u64], root_slot: Option<u64>) -> [u8; 256] {
+        let vote_len = if version == 2 { 13 } else { 12 };
+        let mut data = [0u8; 256];
+        data[0..4].copy_from_slice(&version.to_le_bytes());
+        data[4..36].copy_from_slice(&[1; 32]);
+        data[36..68].copy_from_slice(&[2; 32]);
+        data[68] = 10;
+        data[69..77].copy_from_slice(&(votes.len() as u64).to_le_bytes());
+
+        let mut offset = 77;
+        for slot in votes {
+            let slot_offset = if version == 2 { offset + 1 } else { offset };
+            data[slot_offset..slot_offset + 8].copy_from_slice(&slot.to_le_bytes());
+            offset += vote_len;
+        }
+
+        if let Some(root_slot) = root_slot {
+            data[offset] = 1;
+            data[offset + 1..offset + 9].copy_from_slice(&root_slot.to_le_bytes());
+        }
+
+        data
+    }
+
+    #[test]
+    fn vote_state_reads_both_supported_versions() {
+        for version in [1, 2] {
+            let data = build_vote_data(version, &[100, 101, 102], Some(68));
+            let vote_state = VoteState::from_bytes(&data).unwrap();
+
+            assert_eq!(vote_state.version() as u32, version);
+            assert_eq!(vote_state.node_pubkey().as_array(), &[1; 32]);
+            assert_eq!(vote_state.authorized_withdrawer().as_array(), &[2; 32]);
+            assert_eq!(vote_state.commission(), 10);
+            assert_eq!(vote_state.votes_len(), 3);
+            assert_eq!(vote_state.vote_slot(0), Some(100));
+            assert_eq!(vote_state.vote_slot(3), None);
+            assert_eq!(vote_state.last_voted_slot(), Some(102));
+            assert_eq!(vote_state.root_slot(), Some(68));
+        }
+    }
+
+    #[test]
+    fn vote_state_without_votes() {
+        let data = build_vote_data(2, &[], None);
+        let vote_state = VoteState::from_bytes(&data).unwrap();
+
+        assert_eq!(vote_state.last_voted_slot(), None);
+        assert_eq!(vote_state.root_slot(), None);
+    }
+
+    #[test]
+    fn vote_state_rejects_invalid_data() {
+        for version in [0, 3] {
+            let data = build_vote_data(version, &[], None);
+            assert_eq!(
+                VoteState::from_bytes(&data).err(),
+                Some(VoteStateError::UnsupportedVersion.into())
+            );
+        }
+
+        let data = build_vote_data(4, &[], None);
+        assert_eq!(
+            VoteState::from_bytes(&data).err(),
+            Some(ProgramError::InvalidAccountData)
+        );
+
+        let data = build_vote_data(2, &[1, 2], Some(0));
+        assert!(VoteState::from_bytes(&data[..77 + 26 + 5]).is_err());
+        assert!(VoteState::from_bytes(&data[..76]).is_err());
+    }
+
+    #[test]
+    fn vote_state_from_account_view() {
+        let data = build_vote_data(2, &[100, 101], None);
+        let address = Address::new_from_array([3; 32]);
+        let accounts = AccountViews::new(&[
+            InputAccount::new(address.clone(), ID, 1_000_000, &data),
+            InputAccount::new(address, Address::default(), 1_000_000, &data),
+            InputAccount::new(Address::new_from_array([4; 32]), ID, 1_000_000, &data[..76]),
+        ]);
+
+        {
+            let vote_state = VoteState::from_account_view(&accounts[0]).unwrap();
+            assert_eq!(vote_state.last_voted_slot(), Some(101));
+
+            // The account data is borrowed while the vote state is alive.
+            assert!(accounts[0].try_borrow_mut().is_err());
+        }
+
+        let _data = accounts[0].try_borrow_mut().unwrap();
+        assert!(matches!(
+            VoteState::from_account_view(&accounts[0]),
+            Err(ProgramError::AccountBorrowFailed)
+        ));
+
+        assert!(matches!(
+            VoteState::from_account_view(&accounts[1]),
+            Err(ProgramError::InvalidAccountOwner)
+        ));
+        assert!(matches!(
+            VoteState::from_account_view(&accounts[2]),
+            Err(ProgramError::InvalidAccountData)
+        ));
+    }
+}
@@ -0,0 +1,63 @@
+use pinocchio::{
+    cpi::{invoke_signed, Signer},
+    instruction::{InstructionAccount, InstructionView},
+    AccountView, ProgramResult,
+};
+
+/// Close a proof context state account, transferring its lamports to the
+/// destination account.
+///
+/// ### Accounts:
+///   0. `[WRITE]` Proof context state account to close
+///   1. `[WRITE]` Destination account for lamports
+///   2. `[SIGNER]` Context state account authority
+pub struct CloseContextState<'a> {
+    /// Proof context state account to close.
+    pub context_state: &'a AccountView,
+
+    /// Destination account for lamports.
+    pub destination: &'a AccountView,
+
+    /// Context state account authority.
+    pub authority: &'a AccountView,
+}
+
+impl CloseContextState<'_> {
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    #[inline(always)]
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        self.with_instruction(|instruction, account_views| {
+            invoke_signed(instruction, account_views, signers)
+        })
+    }
+
+    #[inline(always)]
+    pub fn with_instruction<R>(
+        &self,
+        f: impl FnOnce(&InstructionView, &[&AccountView; 3]) -> R,
+    ) -> R {
+        // Instruction accounts
+        let instruction_accounts: [InstructionAccount; 3] = [
+            InstructionAccount::writable(self.context_state.address()),
+            InstructionAccount::writable(self.destination.address()),
+            InstructionAccount::readonly_signer(self.authority.address()),
+        ];
+
+        // instruction data
+        // - [0]: instruction discriminator
+        let instruction = InstructionView {
+            program_id: &crate::ID,
+            accounts: &instruction_accounts,
+            data: &[0],
+        };
+
+        f(
+            &instruction,
+            &[self.context_state, self.destination, self.authority],
+        )
+    }
+}
@@ -0,0 +1,4 @@
+mod close_context_state;
+mod verify_proof;
+
+pub use {close_context_state::*, verify_proof::*};
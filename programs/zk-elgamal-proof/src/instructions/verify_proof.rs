@@ -0,0 +1,160 @@
+use {
+    crate::ProofType,
+    pinocchio::{
+        cpi::{invoke_signed_with_bounds, Signer},
+        error::ProgramError,
+        instruction::{InstructionAccount, InstructionView},
+        AccountView, ProgramResult,
+    },
+};
+
+/// Maximum length of the proof data included in a [`VerifyProof`]
+/// instruction.
+///
+/// Proof data is read from transaction inputs, so it cannot be larger than a
+/// transaction.
+pub const MAX_PROOF_DATA_LEN: usize = 1232;
+
+/// Location of the proof to verify.
+pub enum ProofLocation<'a, 'b> {
+    /// Proof data included in the instruction data.
+    InstructionData(&'b [u8]),
+
+    /// Proof data stored in an account, starting at `offset`.
+    Account {
+        /// Account holding the proof data.
+        account: &'a AccountView,
+
+        /// Offset of the proof data in the account data.
+        offset: u32,
+    },
+}
+
+/// Accounts of the proof context state to create.
+pub struct ContextStateAccounts<'a> {
+    /// Uninitialized context state account, owned by the ZK ElGamal proof
+    /// program.
+    pub context_state: &'a AccountView,
+
+    /// Authority allowed to close the context state account.
+    pub authority: &'a AccountView,
+}
+
+/// Verify a zero-knowledge proof.
+///
+/// If context state accounts are provided, the proof context is stored in
+/// the context state account so that the proof can be consumed later by
+/// account instead of instruction introspection.
+///
+/// ### Accounts:
+///   * Proof data in instruction data
+///   0. `[WRITE]` (Optional) Uninitialized context state account
+///   1. `[]` (Optional) Context state account authority
+///
+///   * Proof data in an account
+///   0. `[]` Account holding the proof data
+///   1. `[WRITE]` (Optional) Uninitialized context state account
+///   2. `[]` (Optional) Context state account authority
+pub struct VerifyProof<'a, 'b> {
+    /// Type of the proof to verify.
+    pub proof_type: ProofType,
+
+    /// Location of the proof data.
+    pub proof: ProofLocation<'a, 'b>,
+
+    /// Context state accounts, if the proof context is to be stored.
+    pub context_state: Option<ContextStateAccounts<'a>>,
+}
+
+impl VerifyProof<'_, '_> {
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    #[inline(always)]
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        self.with_instruction(|instruction, account_views| {
+            invoke_signed_with_bounds::<3>(instruction, account_views, signers)
+        })?
+    }
+
+    #[inline(always)]
+    pub fn with_instruction<R>(
+        &self,
+        f: impl FnOnce(&InstructionView, &[&AccountView]) -> R,
+    ) -> Result<R, ProgramError> {
+        if self.proof_type == ProofType::Uninitialized {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        // instruction data
+        // - [0  ]: instruction discriminator (proof type)
+        // - [1..]: proof data, or offset of the proof data in the proof
+        //          account (4 bytes)
+        let mut instruction_data = [0; 1 + MAX_PROOF_DATA_LEN];
+        instruction_data[0] = self.proof_type as u8;
+
+        let (proof_account, data_len) = match self.proof {
+            ProofLocation::InstructionData(proof_data) => {
+                if proof_data.len() > MAX_PROOF_DATA_LEN {
+                    return Err(ProgramError::InvalidArgument);
+                }
+                instruction_data[1..1 + proof_data.len()].copy_from_slice(proof_data);
+                (None, 1 + proof_data.len())
+            }
+            ProofLocation::Account { account, offset } => {
+                instruction_data[1..5].copy_from_slice(&offset.to_le_bytes());
+                (Some(account), 5)
+            }
+        };
+
+        let data = &instruction_data[..data_len];
+
+        match (proof_account, &self.context_state) {
+            (None, None) => Ok(f(
+                &InstructionView {
+                    program_id: &crate::ID,
+                    accounts: &[],
+                    data,
+                },
+                &[],
+            )),
+            (None, Some(context_state)) => Ok(f(
+                &InstructionView {
+                    program_id: &crate::ID,
+                    accounts: &[
+                        InstructionAccount::writable(context_state.context_state.address()),
+                        InstructionAccount::readonly(context_state.authority.address()),
+                    ],
+                    data,
+                },
+                &[context_state.context_state, context_state.authority],
+            )),
+            (Some(proof_account), None) => Ok(f(
+                &InstructionView {
+                    program_id: &crate::ID,
+                    accounts: &[InstructionAccount::readonly(proof_account.address())],
+                    data,
+                },
+                &[proof_account],
+            )),
+            (Some(proof_account), Some(context_state)) => Ok(f(
+                &InstructionView {
+                    program_id: &crate::ID,
+                    accounts: &[
+                        InstructionAccount::readonly(proof_account.address()),
+                        InstructionAccount::writable(context_state.context_state.address()),
+                        InstructionAccount::readonly(context_state.authority.address()),
+                    ],
+                    data,
+                },
+                &[
+                    proof_account,
+                    context_state.context_state,
+                    context_state.authority,
+                ],
+            )),
+        }
+    }
+}
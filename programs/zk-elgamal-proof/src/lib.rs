@@ -0,0 +1,55 @@
+#![no_std]
+
+pub mod instructions;
+pub mod state;
+
+pinocchio::address::declare_id!("ZkE1Gama1Proof11111111111111111111111111111");
+
+/// Type of a zero-knowledge proof.
+///
+/// The value is used both as the discriminator of the verify instructions
+/// and as the proof type stored in context state accounts.
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ProofType {
+    /// Context state account is not initialized.
+    Uninitialized,
+    /// Proof that a ciphertext encrypts zero.
+    ZeroCiphertext,
+    /// Proof that two ciphertexts encrypt the same value.
+    CiphertextCiphertextEquality,
+    /// Proof that a ciphertext and a commitment hold the same value.
+    CiphertextCommitmentEquality,
+    /// Proof that an ElGamal public key is valid.
+    PubkeyValidity,
+    /// Proof that a committed value is a percentage of another, up to a cap.
+    PercentageWithCap,
+    /// Proof that committed values are in a 64-bit range.
+    BatchedRangeProofU64,
+    /// Proof that committed values are in a 128-bit range.
+    BatchedRangeProofU128,
+    /// Proof that committed values are in a 256-bit range.
+    BatchedRangeProofU256,
+    /// Proof that a grouped ciphertext with 2 handles is valid.
+    GroupedCiphertext2HandlesValidity,
+    /// Proof that two grouped ciphertexts with 2 handles are valid.
+    BatchedGroupedCiphertext2HandlesValidity,
+    /// Proof that a grouped ciphertext with 3 handles is valid.
+    GroupedCiphertext3HandlesValidity,
+    /// Proof that two grouped ciphertexts with 3 handles are valid.
+    BatchedGroupedCiphertext3HandlesValidity,
+}
+
+impl TryFrom<u8> for ProofType {
+    type Error = pinocchio::error::ProgramError;
+
+    #[inline]
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        if value <= ProofType::BatchedGroupedCiphertext3HandlesValidity as u8 {
+            // SAFETY: `value` is a valid discriminant of `ProofType`.
+            Ok(unsafe { core::mem::transmute::<u8, ProofType>(value) })
+        } else {
+            Err(pinocchio::error::ProgramError::InvalidAccountData)
+        }
+    }
+}
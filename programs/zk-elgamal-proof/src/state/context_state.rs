@@ -0,0 +1,222 @@
+use {
+    crate::{ProofType, ID},
+    core::ops::Deref,
+    pinocchio::{
+        account::{AccountView, Ref},
+        error::ProgramError,
+        Address,
+    },
+};
+
+/// Metadata of a proof context state account.
+#[repr(C)]
+pub struct ProofContextStateMeta {
+    /// Authority allowed to close the context state account.
+    context_state_authority: Address,
+
+    /// Type of the verified proof.
+    proof_type: u8,
+}
+
+impl ProofContextStateMeta {
+    /// The length of the `ProofContextStateMeta`, preceding the proof
+    /// context.
+    pub const LEN: usize = core::mem::size_of::<ProofContextStateMeta>();
+
+    /// Return the authority allowed to close the context state account.
+    #[inline(always)]
+    pub fn context_state_authority(&self) -> &Address {
+        &self.context_state_authority
+    }
+
+    /// Return the type of the verified proof.
+    #[inline(always)]
+    pub fn proof_type(&self) -> ProofType {
+        // SAFETY: The proof type is validated when the metadata is loaded.
+        unsafe { core::mem::transmute::<u8, ProofType>(self.proof_type) }
+    }
+}
+
+/// Proof context state account data.
+///
+/// The data of a proof context state account is composed of:
+/// - `[0..32]`: context state authority address
+/// - `[32   ]`: proof type
+/// - `[33.. ]`: proof context
+///
+/// The proof context holds the public inputs of the verified proof, whose
+/// layout depends on the proof type.
+pub struct ProofContextState<T: Deref<Target = [u8]>> {
+    data: T,
+}
+
+impl<'a> ProofContextState<Ref<'a, [u8]>> {
+    /// Return a `ProofContextState` from the given account view.
+    ///
+    /// This method performs owner and data validation on `AccountView`, safe
+    /// borrowing the account data.
+    #[inline]
+    pub fn from_account_view(account_view: &'a AccountView) -> Result<Self, ProgramError> {
+        if !account_view.owned_by(&ID) {
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+        Self::new(account_view.try_borrow()?)
+    }
+}
+
+impl<'a> ProofContextState<&'a [u8]> {
+    /// Return a `ProofContextState` from the given account view.
+    ///
+    /// This method performs owner and data validation on `AccountView`, but
+    /// does not perform the borrow check.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that it is safe to borrow the account data (e.g.,
+    /// there are no mutable borrows of the account data).
+    #[inline]
+    pub unsafe fn from_account_view_unchecked(
+        account_view: &'a AccountView,
+    ) -> Result<Self, ProgramError> {
+        if account_view.owner() != &ID {
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+        Self::from_bytes(account_view.borrow_unchecked())
+    }
+
+    /// Return a `ProofContextState` from the given account data.
+    ///
+    /// The proof type is validated and must not be
+    /// [`ProofType::Uninitialized`], but the account owner is not checked.
+    #[inline]
+    pub fn from_bytes(data: &'a [u8]) -> Result<Self, ProgramError> {
+        Self::new(data)
+    }
+}
+
+impl<T: Deref<Target = [u8]>> ProofContextState<T> {
+    /// Return a `ProofContextState` from the given data, validating its
+    /// proof type.
+    #[inline]
+    fn new(data: T) -> Result<Self, ProgramError> {
+        if data.len() < ProofContextStateMeta::LEN {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        if ProofType::try_from(data[32])? == ProofType::Uninitialized {
+            return Err(ProgramError::UninitializedAccount);
+        }
+
+        Ok(Self { data })
+    }
+
+    /// Return the metadata of the context state account.
+    #[inline(always)]
+    pub fn meta(&self) -> &ProofContextStateMeta {
+        // SAFETY: `data` holds at least `ProofContextStateMeta::LEN` bytes
+        // with a valid proof type, and `ProofContextStateMeta` has an
+        // alignment of 1 byte.
+        unsafe { &*(self.data.as_ptr() as *const ProofContextStateMeta) }
+    }
+
+    /// Return the proof context.
+    #[inline(always)]
+    pub fn proof_context(&self) -> &[u8] {
+        &self.data[ProofContextStateMeta::LEN..]
+    }
+
+    /// Return the proof context if the verified proof is of the expected
+    /// type.
+    ///
+    /// Returns [`ProgramError::InvalidAccountData`] if the proof type does not
+    /// match.
+    #[inline]
+    pub fn expect_proof_context(&self, proof_type: ProofType) -> Result<&[u8], ProgramError> {
+        if self.meta().proof_type() != proof_type {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        Ok(self.proof_context())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        pinocchio::entrypoint::input::{AccountViews, InputAccount},
+    };
+
+    #[test]
+    fn context_state_reads_meta_and_context() {
+        let mut data = [0u8; ProofContextStateMeta::LEN + 4];
+        data[0..32].copy_from_slice(&[5; 32]);
+        data[32] = ProofType::ZeroCiphertext as u8;
+        data[33..37].copy_from_slice(&[1, 2, 3, 4]);
+
+        let state = ProofContextState::from_bytes(&data).unwrap();
+        assert_eq!(state.meta().context_state_authority().as_array(), &[5; 32]);
+        assert_eq!(state.meta().proof_type(), ProofType::ZeroCiphertext);
+        assert_eq!(state.proof_context(), &[1, 2, 3, 4]);
+        assert_eq!(
+            state.expect_proof_context(ProofType::ZeroCiphertext),
+            Ok(&[1, 2, 3, 4][..])
+        );
+        assert_eq!(
+            state.expect_proof_context(ProofType::PubkeyValidity),
+            Err(ProgramError::InvalidAccountData)
+        );
+    }
+
+    #[test]
+    fn context_state_rejects_invalid_data() {
+        let mut data = [0u8; ProofContextStateMeta::LEN];
+        assert_eq!(
+            ProofContextState::from_bytes(&data).err(),
+            Some(ProgramError::UninitializedAccount)
+        );
+
+        data[32] = 13;
+        assert_eq!(
+            ProofContextState::from_bytes(&data).err(),
+            Some(ProgramError::InvalidAccountData)
+        );
+        assert!(ProofContextState::from_bytes(&data[..32]).is_err());
+    }
+
+    #[test]
+    fn context_state_from_account_view() {
+        let mut data = [0u8; ProofContextStateMeta::LEN + 4];
+        data[32] = ProofType::ZeroCiphertext as u8;
+        data[33..37].copy_from_slice(&[1, 2, 3, 4]);
+
+        let address = Address::new_from_array([3; 32]);
+        let accounts = AccountViews::new(&[
+            InputAccount::new(address.clone(), ID, 1_000_000, &data),
+            InputAccount::new(address, Address::default(), 1_000_000, &data),
+            InputAccount::new(Address::new_from_array([4; 32]), ID, 1_000_000, &data[..32]),
+        ]);
+
+        {
+            let state = ProofContextState::from_account_view(&accounts[0]).unwrap();
+            assert_eq!(state.proof_context(), &[1, 2, 3, 4]);
+
+            // The account data is borrowed while the state is alive.
+            assert!(accounts[0].try_borrow_mut().is_err());
+        }
+
+        let _data = accounts[0].try_borrow_mut().unwrap();
+        assert!(matches!(
+            ProofContextState::from_account_view(&accounts[0]),
+            Err(ProgramError::AccountBorrowFailed)
+        ));
+
+        assert!(matches!(
+            ProofContextState::from_account_view(&accounts[1]),
+            Err(ProgramError::InvalidAccountOwner)
+        ));
+        assert!(matches!(
+            ProofContextState::from_account_view(&accounts[2]),
+            Err(ProgramError::InvalidAccountData)
+        ));
+    }
+}
@@ -0,0 +1,3 @@
+mod context_state;
+
+pub use context_state::*;
@@ -0,0 +1,36 @@
+//! Adapters comparing the instructions encoded by the pinocchio builders with
+//! the ones encoded by the official SPL crates.
+//!
+//! The instructions encoded by the pinocchio builders through their
+//! `with_instruction` method are converted into a
+//! [`solana_instruction::Instruction`] with the `client` feature of
+//! `pinocchio`, so they can be compared with the instructions returned by the
+//! reference crates. Both use the same [`Address`] type.
+
+use {
+    pinocchio::{
+        entrypoint::input::{AccountViews, InputAccount},
+        Address,
+    },
+    std::vec::Vec,
+};
+
+/// Create an account view for each address.
+///
+/// The accounts are read-only, non-signer and empty, so the account metas of
+/// the encoded instruction only reflect the builder.
+pub fn account_views(addresses: &[[u8; 32]]) -> AccountViews {
+    let accounts = addresses
+        .iter()
+        .map(|address| {
+            InputAccount::new(
+                Address::new_from_array(*address),
+                Address::default(),
+                0,
+                &[],
+            )
+        })
+        .collect::<Vec<_>>();
+
+    AccountViews::new(&accounts)
+}
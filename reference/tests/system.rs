@@ -0,0 +1,139 @@
+use {
+    pinocchio::{client::to_instruction, Address},
+    pinocchio_reference_tests::account_views,
+    pinocchio_system::instructions::{
+        Allocate, Assign, CreateAccount, CreateAccountWithSeed, Transfer, TransferWithSeed,
+    },
+    proptest::prelude::*,
+    solana_system_interface::instruction as reference,
+};
+
+/// Seeds are valid up to 32 bytes.
+fn seed() -> impl Strategy<Value = String> {
+    "[a-zA-Z0-9]{0,32}"
+}
+
+proptest! {
+    #[test]
+    fn transfer(addresses: [[u8; 32]; 2], lamports: u64) {
+        let accounts = account_views(&addresses);
+        let [from, to] = addresses.map(Address::new_from_array);
+
+        let instruction = Transfer {
+            from: &accounts[0],
+            to: &accounts[1],
+            lamports,
+        }
+        .with_instruction(|instruction, _| to_instruction(instruction));
+
+        prop_assert_eq!(instruction, reference::transfer(&from, &to, lamports));
+    }
+
+    #[test]
+    fn create_account(addresses: [[u8; 32]; 2], lamports: u64, space: u64, owner: [u8; 32]) {
+        let accounts = account_views(&addresses);
+        let [from, to] = addresses.map(Address::new_from_array);
+        let owner = Address::new_from_array(owner);
+
+        let instruction = CreateAccount {
+            from: &accounts[0],
+            to: &accounts[1],
+            lamports,
+            space,
+            owner: &owner,
+        }
+        .with_instruction(|instruction, _| to_instruction(instruction));
+
+        prop_assert_eq!(
+            instruction,
+            reference::create_account(&from, &to, lamports, space, &owner)
+        );
+    }
+
+    #[test]
+    fn create_account_with_seed(
+        addresses: [[u8; 32]; 3],
+        seed in seed(),
+        lamports: u64,
+        space: u64,
+        owner: [u8; 32],
+    ) {
+        let accounts = account_views(&addresses);
+        let [from, to, base] = addresses.map(Address::new_from_array);
+        let owner = Address::new_from_array(owner);
+
+        let instruction = CreateAccountWithSeed {
+            from: &accounts[0],
+            to: &accounts[1],
+            base: Some(&accounts[2]),
+            seed: &seed,
+            lamports,
+            space,
+            owner: &owner,
+        }
+        .with_instruction(|instruction, _| to_instruction(instruction))
+        .unwrap();
+
+        prop_assert_eq!(
+            instruction,
+            reference::create_account_with_seed(&from, &to, &base, &seed, lamports, space, &owner)
+        );
+    }
+
+    #[test]
+    fn assign(address: [u8; 32], owner: [u8; 32]) {
+        let accounts = account_views(&[address]);
+        let address = Address::new_from_array(address);
+        let owner = Address::new_from_array(owner);
+
+        let instruction = Assign {
+            account: &accounts[0],
+            owner: &owner,
+        }
+        .with_instruction(|instruction, _| to_instruction(instruction));
+
+        prop_assert_eq!(instruction, reference::assign(&address, &owner));
+    }
+
+    #[test]
+    fn allocate(address: [u8; 32], space: u64) {
+        let accounts = account_views(&[address]);
+        let address = Address::new_from_array(address);
+
+        let instruction = Allocate {
+            account: &accounts[0],
+            space,
+        }
+        .with_instruction(|instruction, _| to_instruction(instruction));
+
+        prop_assert_eq!(instruction, reference::allocate(&address, space));
+    }
+
+    #[test]
+    fn transfer_with_seed(
+        addresses: [[u8; 32]; 3],
+        seed in seed(),
+        lamports: u64,
+        owner: [u8; 32],
+    ) {
+        let accounts = account_views(&addresses);
+        let [from, base, to] = addresses.map(Address::new_from_array);
+        let owner = Address::new_from_array(owner);
+
+        let instruction = TransferWithSeed {
+            from: &accounts[0],
+            base: &accounts[1],
+            to: &accounts[2],
+            lamports,
+            seed: &seed,
+            owner: &owner,
+        }
+        .with_instruction(|instruction, _| to_instruction(instruction))
+        .unwrap();
+
+        prop_assert_eq!(
+            instruction,
+            reference::transfer_with_seed(&from, &base, seed.clone(), &owner, &to, lamports)
+        );
+    }
+}
@@ -0,0 +1,488 @@
+use {
+    pinocchio::{client::to_instruction, Address},
+    pinocchio_reference_tests::account_views,
+    pinocchio_token_2022::instructions::{
+        Approve, ApproveChecked, AuthorityType, Burn, BurnChecked, CloseAccount, FreezeAccount,
+        InitializeAccount3, InitializeMint2, MintTo, MintToChecked, Revoke, SetAuthority,
+        SyncNative, ThawAccount, Transfer, TransferChecked,
+    },
+    proptest::prelude::*,
+    spl_token_2022_interface::{
+        inline_spl_token,
+        instruction::{self as reference, AuthorityType as ReferenceAuthorityType},
+    },
+};
+
+/// The builders target either the Token or the Token-2022 program.
+fn token_program() -> impl Strategy<Value = Address> {
+    prop_oneof![
+        Just(Address::new_from_array(pinocchio_token_2022::ID.to_bytes())),
+        Just(inline_spl_token::ID),
+    ]
+}
+
+/// Authority types supported by the builder.
+fn authority_type() -> impl Strategy<Value = ReferenceAuthorityType> {
+    prop_oneof![
+        Just(ReferenceAuthorityType::MintTokens),
+        Just(ReferenceAuthorityType::FreezeAccount),
+        Just(ReferenceAuthorityType::AccountOwner),
+        Just(ReferenceAuthorityType::CloseAccount),
+        Just(ReferenceAuthorityType::TransferFeeConfig),
+        Just(ReferenceAuthorityType::WithheldWithdraw),
+        Just(ReferenceAuthorityType::CloseMint),
+        Just(ReferenceAuthorityType::InterestRate),
+        Just(ReferenceAuthorityType::PermanentDelegate),
+        Just(ReferenceAuthorityType::ConfidentialTransferMint),
+        Just(ReferenceAuthorityType::TransferHookProgramId),
+        Just(ReferenceAuthorityType::ConfidentialTransferFeeConfig),
+        Just(ReferenceAuthorityType::MetadataPointer),
+        Just(ReferenceAuthorityType::GroupPointer),
+        Just(ReferenceAuthorityType::GroupMemberPointer),
+        Just(ReferenceAuthorityType::ScaledUiAmount),
+        Just(ReferenceAuthorityType::Pause),
+    ]
+}
+
+/// Convert a reference authority type into the builder one.
+fn to_authority_type(authority_type: &ReferenceAuthorityType) -> AuthorityType {
+    match authority_type {
+        ReferenceAuthorityType::MintTokens => AuthorityType::MintTokens,
+        ReferenceAuthorityType::FreezeAccount => AuthorityType::FreezeAccount,
+        ReferenceAuthorityType::AccountOwner => AuthorityType::AccountOwner,
+        ReferenceAuthorityType::CloseAccount => AuthorityType::CloseAccount,
+        ReferenceAuthorityType::TransferFeeConfig => AuthorityType::TransferFeeConfig,
+        ReferenceAuthorityType::WithheldWithdraw => AuthorityType::WithheldWithdraw,
+        ReferenceAuthorityType::CloseMint => AuthorityType::CloseMint,
+        ReferenceAuthorityType::InterestRate => AuthorityType::InterestRate,
+        ReferenceAuthorityType::PermanentDelegate => AuthorityType::PermanentDelegate,
+        ReferenceAuthorityType::ConfidentialTransferMint => AuthorityType::ConfidentialTransferMint,
+        ReferenceAuthorityType::TransferHookProgramId => AuthorityType::TransferHookProgramId,
+        ReferenceAuthorityType::ConfidentialTransferFeeConfig => {
+            AuthorityType::ConfidentialTransferFeeConfig
+        }
+        ReferenceAuthorityType::MetadataPointer => AuthorityType::MetadataPointer,
+        ReferenceAuthorityType::GroupPointer => AuthorityType::GroupPointer,
+        ReferenceAuthorityType::GroupMemberPointer => AuthorityType::GroupMemberPointer,
+        ReferenceAuthorityType::ScaledUiAmount => AuthorityType::ScaledUiAmount,
+        ReferenceAuthorityType::Pause => AuthorityType::Pause,
+        _ => unreachable!("unsupported authority type"),
+    }
+}
+
+proptest! {
+    #[test]
+    fn initialize_mint_2(
+        token_program in token_program(),
+        mint: [u8; 32],
+        decimals: u8,
+        mint_authority: [u8; 32],
+        freeze_authority: Option<[u8; 32]>,
+    ) {
+        let accounts = account_views(&[mint]);
+        let mint = Address::new_from_array(mint);
+        let mint_authority = Address::new_from_array(mint_authority);
+        let freeze_authority = freeze_authority.map(Address::new_from_array);
+
+        let instruction = InitializeMint2 {
+            mint: &accounts[0],
+            decimals,
+            mint_authority: &mint_authority,
+            freeze_authority: freeze_authority.as_ref(),
+            token_program: &token_program,
+        }
+        .with_instruction(|instruction, _| to_instruction(instruction));
+
+        prop_assert_eq!(
+            instruction,
+            reference::initialize_mint2(
+                &token_program,
+                &mint,
+                &mint_authority,
+                freeze_authority.as_ref(),
+                decimals,
+            )
+            .unwrap()
+        );
+    }
+
+    #[test]
+    fn initialize_account_3(
+        token_program in token_program(),
+        addresses: [[u8; 32]; 2],
+        owner: [u8; 32],
+    ) {
+        let accounts = account_views(&addresses);
+        let [account, mint] = addresses.map(Address::new_from_array);
+        let owner = Address::new_from_array(owner);
+
+        let instruction = InitializeAccount3 {
+            account: &accounts[0],
+            mint: &accounts[1],
+            owner: &owner,
+            token_program: &token_program,
+        }
+        .with_instruction(|instruction, _| to_instruction(instruction));
+
+        prop_assert_eq!(
+            instruction,
+            reference::initialize_account3(&token_program, &account, &mint, &owner).unwrap()
+        );
+    }
+
+    #[test]
+    fn transfer(token_program in token_program(), addresses: [[u8; 32]; 3], amount: u64) {
+        let accounts = account_views(&addresses);
+        let [from, to, authority] = addresses.map(Address::new_from_array);
+
+        let instruction = Transfer {
+            from: &accounts[0],
+            to: &accounts[1],
+            authority: &accounts[2],
+            amount,
+            token_program: &token_program,
+        }
+        .with_instruction(|instruction, _| to_instruction(instruction));
+
+        #[allow(deprecated)]
+        let expected =
+            reference::transfer(&token_program, &from, &to, &authority, &[], amount).unwrap();
+        prop_assert_eq!(instruction, expected);
+    }
+
+    #[test]
+    fn transfer_checked(
+        token_program in token_program(),
+        addresses: [[u8; 32]; 4],
+        amount: u64,
+        decimals: u8,
+    ) {
+        let accounts = account_views(&addresses);
+        let [from, mint, to, authority] = addresses.map(Address::new_from_array);
+
+        let instruction = TransferChecked {
+            from: &accounts[0],
+            mint: &accounts[1],
+            to: &accounts[2],
+            authority: &accounts[3],
+            amount,
+            decimals,
+            token_program: &token_program,
+        }
+        .with_instruction(|instruction, _| to_instruction(instruction));
+
+        prop_assert_eq!(
+            instruction,
+            reference::transfer_checked(
+                &token_program,
+                &from,
+                &mint,
+                &to,
+                &authority,
+                &[],
+                amount,
+                decimals,
+            )
+            .unwrap()
+        );
+    }
+
+    #[test]
+    fn approve(token_program in token_program(), addresses: [[u8; 32]; 3], amount: u64) {
+        let accounts = account_views(&addresses);
+        let [source, delegate, authority] = addresses.map(Address::new_from_array);
+
+        let instruction = Approve {
+            source: &accounts[0],
+            delegate: &accounts[1],
+            authority: &accounts[2],
+            amount,
+            token_program: &token_program,
+        }
+        .with_instruction(|instruction, _| to_instruction(instruction));
+
+        prop_assert_eq!(
+            instruction,
+            reference::approve(&token_program, &source, &delegate, &authority, &[], amount)
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn approve_checked(
+        token_program in token_program(),
+        addresses: [[u8; 32]; 4],
+        amount: u64,
+        decimals: u8,
+    ) {
+        let accounts = account_views(&addresses);
+        let [source, mint, delegate, authority] = addresses.map(Address::new_from_array);
+
+        let instruction = ApproveChecked {
+            source: &accounts[0],
+            mint: &accounts[1],
+            delegate: &accounts[2],
+            authority: &accounts[3],
+            amount,
+            decimals,
+            token_program: &token_program,
+        }
+        .with_instruction(|instruction, _| to_instruction(instruction));
+
+        prop_assert_eq!(
+            instruction,
+            reference::approve_checked(
+                &token_program,
+                &source,
+                &mint,
+                &delegate,
+                &authority,
+                &[],
+                amount,
+                decimals,
+            )
+            .unwrap()
+        );
+    }
+
+    #[test]
+    fn revoke(token_program in token_program(), addresses: [[u8; 32]; 2]) {
+        let accounts = account_views(&addresses);
+        let [source, authority] = addresses.map(Address::new_from_array);
+
+        let instruction = Revoke {
+            source: &accounts[0],
+            authority: &accounts[1],
+            token_program: &token_program,
+        }
+        .with_instruction(|instruction, _| to_instruction(instruction));
+
+        prop_assert_eq!(
+            instruction,
+            reference::revoke(&token_program, &source, &authority, &[]).unwrap()
+        );
+    }
+
+    #[test]
+    fn set_authority(
+        token_program in token_program(),
+        addresses: [[u8; 32]; 2],
+        reference_authority_type in authority_type(),
+        new_authority: Option<[u8; 32]>,
+    ) {
+        let accounts = account_views(&addresses);
+        let [account, authority] = addresses.map(Address::new_from_array);
+        let new_authority = new_authority.map(Address::new_from_array);
+        let authority_type = to_authority_type(&reference_authority_type);
+
+        let instruction = SetAuthority {
+            account: &accounts[0],
+            authority: &accounts[1],
+            authority_type,
+            new_authority: new_authority.as_ref(),
+            token_program: &token_program,
+        }
+        .with_instruction(|instruction, _| to_instruction(instruction));
+
+        prop_assert_eq!(
+            instruction,
+            reference::set_authority(
+                &token_program,
+                &account,
+                new_authority.as_ref(),
+                reference_authority_type,
+                &authority,
+                &[],
+            )
+            .unwrap()
+        );
+    }
+
+    #[test]
+    fn mint_to(token_program in token_program(), addresses: [[u8; 32]; 3], amount: u64) {
+        let accounts = account_views(&addresses);
+        let [mint, account, mint_authority] = addresses.map(Address::new_from_array);
+
+        let instruction = MintTo {
+            mint: &accounts[0],
+            account: &accounts[1],
+            mint_authority: &accounts[2],
+            amount,
+            token_program: &token_program,
+        }
+        .with_instruction(|instruction, _| to_instruction(instruction));
+
+        prop_assert_eq!(
+            instruction,
+            reference::mint_to(&token_program, &mint, &account, &mint_authority, &[], amount)
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn mint_to_checked(
+        token_program in token_program(),
+        addresses: [[u8; 32]; 3],
+        amount: u64,
+        decimals: u8,
+    ) {
+        let accounts = account_views(&addresses);
+        let [mint, account, mint_authority] = addresses.map(Address::new_from_array);
+
+        let instruction = MintToChecked {
+            mint: &accounts[0],
+            account: &accounts[1],
+            mint_authority: &accounts[2],
+            amount,
+            decimals,
+            token_program: &token_program,
+        }
+        .with_instruction(|instruction, _| to_instruction(instruction));
+
+        prop_assert_eq!(
+            instruction,
+            reference::mint_to_checked(
+                &token_program,
+                &mint,
+                &account,
+                &mint_authority,
+                &[],
+                amount,
+                decimals,
+            )
+            .unwrap()
+        );
+    }
+
+    #[test]
+    fn burn(token_program in token_program(), addresses: [[u8; 32]; 3], amount: u64) {
+        let accounts = account_views(&addresses);
+        let [account, mint, authority] = addresses.map(Address::new_from_array);
+
+        let instruction = Burn {
+            account: &accounts[0],
+            mint: &accounts[1],
+            authority: &accounts[2],
+            amount,
+            token_program: &token_program,
+        }
+        .with_instruction(|instruction, _| to_instruction(instruction));
+
+        prop_assert_eq!(
+            instruction,
+            reference::burn(&token_program, &account, &mint, &authority, &[], amount).unwrap()
+        );
+    }
+
+    #[test]
+    fn burn_checked(
+        token_program in token_program(),
+        addresses: [[u8; 32]; 3],
+        amount: u64,
+        decimals: u8,
+    ) {
+        let accounts = account_views(&addresses);
+        let [account, mint, authority] = addresses.map(Address::new_from_array);
+
+        let instruction = BurnChecked {
+            account: &accounts[0],
+            mint: &accounts[1],
+            authority: &accounts[2],
+            amount,
+            decimals,
+            token_program: &token_program,
+        }
+        .with_instruction(|instruction, _| to_instruction(instruction));
+
+        prop_assert_eq!(
+            instruction,
+            reference::burn_checked(
+                &token_program,
+                &account,
+                &mint,
+                &authority,
+                &[],
+                amount,
+                decimals,
+            )
+            .unwrap()
+        );
+    }
+
+    #[test]
+    fn close_account(token_program in token_program(), addresses: [[u8; 32]; 3]) {
+        let accounts = account_views(&addresses);
+        let [account, destination, authority] = addresses.map(Address::new_from_array);
+
+        let instruction = CloseAccount {
+            account: &accounts[0],
+            destination: &accounts[1],
+            authority: &accounts[2],
+            token_program: &token_program,
+        }
+        .with_instruction(|instruction, _| to_instruction(instruction));
+
+        prop_assert_eq!(
+            instruction,
+            reference::close_account(&token_program, &account, &destination, &authority, &[])
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn freeze_account(token_program in token_program(), addresses: [[u8; 32]; 3]) {
+        let accounts = account_views(&addresses);
+        let [account, mint, freeze_authority] = addresses.map(Address::new_from_array);
+
+        let instruction = FreezeAccount {
+            account: &accounts[0],
+            mint: &accounts[1],
+            freeze_authority: &accounts[2],
+            token_program: &token_program,
+        }
+        .with_instruction(|instruction, _| to_instruction(instruction));
+
+        prop_assert_eq!(
+            instruction,
+            reference::freeze_account(&token_program, &account, &mint, &freeze_authority, &[])
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn thaw_account(token_program in token_program(), addresses: [[u8; 32]; 3]) {
+        let accounts = account_views(&addresses);
+        let [account, mint, freeze_authority] = addresses.map(Address::new_from_array);
+
+        let instruction = ThawAccount {
+            account: &accounts[0],
+            mint: &accounts[1],
+            freeze_authority: &accounts[2],
+            token_program: &token_program,
+        }
+        .with_instruction(|instruction, _| to_instruction(instruction));
+
+        prop_assert_eq!(
+            instruction,
+            reference::thaw_account(&token_program, &account, &mint, &freeze_authority, &[])
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn sync_native(token_program in token_program(), native_token: [u8; 32]) {
+        let accounts = account_views(&[native_token]);
+        let native_token = Address::new_from_array(native_token);
+
+        let instruction = SyncNative {
+            native_token: &accounts[0],
+            token_program: &token_program,
+        }
+        .with_instruction(|instruction, _| to_instruction(instruction));
+
+        prop_assert_eq!(
+            instruction,
+            reference::sync_native(&token_program, &native_token).unwrap()
+        );
+    }
+}
@@ -0,0 +1,18 @@
+#!/usr/bin/env zx
+import 'zx/globals';
+import { cliArguments, workingDirectory } from './setup/shared.mts';
+
+const args = cliArguments();
+
+const programManifestPath = path.join(workingDirectory, 'benches', 'Cargo.toml');
+const harnessManifestPath = path.join(
+  workingDirectory,
+  'benches',
+  'harness',
+  'Cargo.toml'
+);
+
+await $`cargo-build-sbf --manifest-path ${programManifestPath} --features bpf-entrypoint`;
+
+$.env['SBF_OUT_DIR'] = path.join(workingDirectory, 'target', 'deploy');
+await $`cargo bench --manifest-path ${harnessManifestPath} ${args}`;
@@ -0,0 +1,16 @@
+#!/usr/bin/env zx
+import 'zx/globals';
+import {
+  cliArguments,
+  getToolchainArgument,
+  workingDirectory,
+} from './setup/shared.mts';
+
+const [folder, ...args] = cliArguments();
+
+const buildArgs = ['--target', 'wasm32-unknown-unknown', '--all-features', ...args];
+const toolchain = getToolchainArgument('build');
+
+const manifestPath = path.join(workingDirectory, folder, 'Cargo.toml');
+
+await $`cargo ${toolchain} build --manifest-path ${manifestPath} ${buildArgs}`;
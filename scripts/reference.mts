@@ -0,0 +1,11 @@
+#!/usr/bin/env zx
+import 'zx/globals';
+import { cliArguments, workingDirectory } from './setup/shared.mts';
+
+const args = cliArguments();
+
+const manifestPath = path.join(workingDirectory, 'reference', 'Cargo.toml');
+
+// The reference crates require a more recent toolchain than the one pinned
+// for the workspace.
+await $`cargo +stable test --manifest-path ${manifestPath} ${args}`;
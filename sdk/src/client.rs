@@ -0,0 +1,123 @@
+//! Conversions to off-chain instruction types.
+//!
+//! Instructions are described on-chain by [`InstructionView`] and
+//! [`InstructionAccount`], which borrow their addresses and data. The helpers
+//! in this module convert them into the owned [`Instruction`] and
+//! [`AccountMeta`] types used by off-chain clients and test harnesses (e.g.,
+//! LiteSVM or Mollusk), so the same instruction encoding can be shared between
+//! a program and its clients.
+//!
+//! No conversion is needed for addresses: `solana_pubkey::Pubkey` is a type
+//! alias of [`Address`].
+//!
+//! The instruction builders of the `pinocchio-*` program crates expose their
+//! encoding through a `with_instruction` method, which is also used by their
+//! `invoke*` methods, so their instructions can be converted as well:
+//!
+//! ```ignore
+//! use pinocchio::client::to_instruction;
+//! use pinocchio_system::instructions::Transfer;
+//!
+//! let instruction = Transfer {
+//!     from: &from,
+//!     to: &to,
+//!     lamports: 1_000_000,
+//! }
+//! .with_instruction(|instruction, _| to_instruction(instruction));
+//! ```
+//!
+//! Instructions built by hand can be converted directly:
+//!
+//! ```ignore
+//! use pinocchio::{
+//!     client::to_instruction,
+//!     instruction::{InstructionAccount, InstructionView},
+//! };
+//!
+//! let instruction_accounts = [
+//!     InstructionAccount::writable_signer(&from),
+//!     InstructionAccount::writable(&to),
+//! ];
+//!
+//! let instruction = to_instruction(&InstructionView {
+//!     program_id: &system_program::ID,
+//!     accounts: &instruction_accounts,
+//!     data: &instruction_data,
+//! });
+//! ```
+
+extern crate std;
+
+pub use solana_instruction::{AccountMeta, Instruction};
+use {
+    crate::{
+        instruction::{InstructionAccount, InstructionView},
+        Address,
+    },
+    std::vec::Vec,
+};
+
+/// Convert an [`InstructionAccount`] into an [`AccountMeta`].
+#[inline]
+pub fn to_account_meta(account: &InstructionAccount) -> AccountMeta {
+    AccountMeta {
+        pubkey: Address::new_from_array(account.address.to_bytes()),
+        is_signer: account.is_signer,
+        is_writable: account.is_writable,
+    }
+}
+
+/// Convert an [`InstructionView`] into an [`Instruction`].
+///
+/// The accounts and instruction data are copied, so the returned instruction
+/// does not borrow from the `instruction`.
+#[inline]
+pub fn to_instruction(instruction: &InstructionView) -> Instruction {
+    Instruction {
+        program_id: Address::new_from_array(instruction.program_id.to_bytes()),
+        accounts: instruction
+            .accounts
+            .iter()
+            .map(to_account_meta)
+            .collect::<Vec<_>>(),
+        data: instruction.data.to_vec(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, std::vec};
+
+    #[test]
+    fn test_to_instruction() {
+        let program_id = Address::new_from_array([1; 32]);
+        let from = Address::new_from_array([2; 32]);
+        let to = Address::new_from_array([3; 32]);
+        let authority = Address::new_from_array([4; 32]);
+
+        let instruction_accounts = [
+            InstructionAccount::writable(&from),
+            InstructionAccount::writable(&to),
+            InstructionAccount::readonly_signer(&authority),
+        ];
+
+        let instruction = to_instruction(&InstructionView {
+            program_id: &program_id,
+            accounts: &instruction_accounts,
+            data: &[3, 10, 0, 0, 0, 0, 0, 0, 0],
+        });
+
+        assert_eq!(
+            instruction,
+            Instruction::new_with_bytes(
+                program_id,
+                &[3, 10, 0, 0, 0, 0, 0, 0, 0],
+                vec![
+                    AccountMeta::new(from, false),
+                    AccountMeta::new(to, false),
+                    AccountMeta::new_readonly(authority, true),
+                ],
+            )
+        );
+    }
+}
@@ -0,0 +1,100 @@
+//! Cross-program invocation helpers for non-Solana targets.
+//!
+//! This module re-exports [`solana_instruction_view::cpi`], replacing the
+//! `invoke*` functions with stubs that return
+//! [`ProgramError::InvalidArgument`], since there is no runtime to invoke
+//! outside of Solana. This allows crates using the `cpi` helpers to be built
+//! for targets such as `wasm32-unknown-unknown`, while their instructions can
+//! still be encoded (e.g., with the `with_instruction` method of the
+//! instruction builders).
+//!
+//! The `*_unchecked` variants are not replaced and do nothing. Enable the
+//! `test-syscalls` feature to route the invocations to a registered
+//! `test_syscalls::Syscalls` implementation in tests instead.
+
+pub use solana_instruction_view::cpi::*;
+use {
+    crate::{error::ProgramError, instruction::InstructionView, AccountView, ProgramResult},
+    core::hint::black_box,
+};
+
+/// Invoke a cross-program instruction.
+///
+/// Always returns [`ProgramError::InvalidArgument`] on non-Solana targets.
+#[inline(always)]
+pub fn invoke<const ACCOUNTS: usize>(
+    instruction: &InstructionView,
+    account_views: &[&AccountView; ACCOUNTS],
+) -> ProgramResult {
+    invoke_signed(instruction, account_views, &[])
+}
+
+/// Invoke a cross-program instruction with signatures.
+///
+/// Always returns [`ProgramError::InvalidArgument`] on non-Solana targets.
+#[inline(always)]
+pub fn invoke_signed<const ACCOUNTS: usize>(
+    instruction: &InstructionView,
+    account_views: &[&AccountView; ACCOUNTS],
+    signers_seeds: &[Signer],
+) -> ProgramResult {
+    unsupported(instruction, account_views, signers_seeds)
+}
+
+/// Invoke a cross-program instruction with a maximum number of accounts.
+///
+/// Always returns [`ProgramError::InvalidArgument`] on non-Solana targets.
+#[inline(always)]
+pub fn invoke_with_bounds<const MAX_ACCOUNTS: usize>(
+    instruction: &InstructionView,
+    account_views: &[&AccountView],
+) -> ProgramResult {
+    unsupported(instruction, account_views, &[])
+}
+
+/// Invoke a cross-program instruction with signatures and a maximum number of
+/// accounts.
+///
+/// Always returns [`ProgramError::InvalidArgument`] on non-Solana targets.
+#[inline(always)]
+pub fn invoke_signed_with_bounds<const MAX_ACCOUNTS: usize>(
+    instruction: &InstructionView,
+    account_views: &[&AccountView],
+    signers_seeds: &[Signer],
+) -> ProgramResult {
+    unsupported(instruction, account_views, signers_seeds)
+}
+
+/// Invoke a cross-program instruction from a slice of `AccountView`s.
+///
+/// Always returns [`ProgramError::InvalidArgument`] on non-Solana targets.
+#[inline(always)]
+pub fn invoke_with_slice(
+    instruction: &InstructionView,
+    account_views: &[&AccountView],
+) -> ProgramResult {
+    unsupported(instruction, account_views, &[])
+}
+
+/// Invoke a cross-program instruction with signatures from a slice of
+/// `AccountView`s.
+///
+/// Always returns [`ProgramError::InvalidArgument`] on non-Solana targets.
+#[inline(always)]
+pub fn invoke_signed_with_slice(
+    instruction: &InstructionView,
+    account_views: &[&AccountView],
+    signers_seeds: &[Signer],
+) -> ProgramResult {
+    unsupported(instruction, account_views, signers_seeds)
+}
+
+#[inline(always)]
+fn unsupported(
+    instruction: &InstructionView,
+    account_views: &[&AccountView],
+    signers_seeds: &[Signer],
+) -> ProgramResult {
+    black_box((instruction, account_views, signers_seeds));
+    Err(ProgramError::InvalidArgument)
+}
@@ -0,0 +1,84 @@
+//! Instruction discriminators of a program.
+//!
+//! The [`discriminators!`](crate::discriminators) macro declares the table of
+//! discriminators of a program and fails the build when two of them collide.
+
+/// An instruction discriminator.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Discriminator {
+    /// Name of the instruction.
+    pub name: &'static str,
+
+    /// Bytes at the start of the instruction data.
+    pub value: &'static [u8],
+}
+
+impl Discriminator {
+    /// Indicate whether the instruction data starts with the discriminator.
+    #[inline(always)]
+    pub fn matches(&self, instruction_data: &[u8]) -> bool {
+        instruction_data.starts_with(self.value)
+    }
+}
+
+/// Indicate whether two discriminators collide.
+///
+/// Discriminators are matched against the start of the instruction data, so
+/// two discriminators collide when they are equal or when one is a prefix of
+/// the other.
+pub const fn collide(a: &[u8], b: &[u8]) -> bool {
+    let len = if a.len() < b.len() { a.len() } else { b.len() };
+    let mut i = 0;
+
+    while i < len {
+        if a[i] != b[i] {
+            return false;
+        }
+        i += 1;
+    }
+
+    true
+}
+
+/// Return the discriminator of `table` matching the instruction data.
+#[inline]
+pub fn find<'a>(table: &'a [Discriminator], instruction_data: &[u8]) -> Option<&'a Discriminator> {
+    table
+        .iter()
+        .find(|discriminator| discriminator.matches(instruction_data))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_collide() {
+        assert!(collide(&[1], &[1]));
+        assert!(collide(&[1], &[1, 2]));
+        assert!(collide(&[1, 2, 3], &[1, 2]));
+        assert!(collide(&[], &[1]));
+
+        assert!(!collide(&[1], &[2]));
+        assert!(!collide(&[1, 2], &[1, 3]));
+    }
+
+    #[test]
+    fn test_find() {
+        const TABLE: &[Discriminator] = &[
+            Discriminator {
+                name: "initialize",
+                value: &[0],
+            },
+            Discriminator {
+                name: "deposit",
+                value: &[1, 0],
+            },
+        ];
+
+        assert_eq!(find(TABLE, &[1, 0, 7]).map(|d| d.name), Some("deposit"));
+        assert_eq!(find(TABLE, &[0]).map(|d| d.name), Some("initialize"));
+        assert_eq!(find(TABLE, &[1]), None);
+        assert_eq!(find(TABLE, &[2, 0]), None);
+    }
+}
@@ -0,0 +1,437 @@
+//! Serialization of the program input buffer for testing entrypoints.
+//!
+//! The [`serialize_input`] function writes the program id, accounts and
+//! instruction data using the same byte layout that the SVM loader passes
+//! to the program `entrypoint`. This allows exercising
+//! [`process_entrypoint`](super::process_entrypoint),
+//! [`deserialize`] and custom entrypoints in `cargo test`
+//! without an SVM harness.
+//!
+//! ```ignore
+//! use pinocchio::{
+//!     entrypoint::{
+//!         input::{serialize_input, InputAccount},
+//!         process_entrypoint,
+//!     },
+//!     Address, MAX_TX_ACCOUNTS, SUCCESS,
+//! };
+//!
+//! let program_id = Address::new_from_array([1; 32]);
+//! let account = Address::new_from_array([2; 32]);
+//! let data = [0; 8];
+//!
+//! let mut input = serialize_input(
+//!     &program_id,
+//!     &[InputAccount::new(account, program_id.clone(), 1_000, &data).writable()],
+//!     &[0],
+//! );
+//!
+//! let result =
+//!     unsafe { process_entrypoint::<MAX_TX_ACCOUNTS>(input.as_mut_ptr(), process_instruction) };
+//! assert_eq!(result, SUCCESS);
+//! ```
+
+use {
+    super::{deserialize, NON_DUP_MARKER},
+    crate::{
+        account::MAX_PERMITTED_DATA_INCREASE, AccountView, Address, BPF_ALIGN_OF_U128,
+        MAX_TX_ACCOUNTS,
+    },
+    alloc::vec::Vec,
+    core::{
+        mem::{size_of, MaybeUninit},
+        ops::Deref,
+        slice::from_raw_parts,
+    },
+};
+
+/// An account to serialize in the input buffer.
+pub enum InputAccount<'a> {
+    /// A non-duplicated account.
+    Account {
+        /// Address of the account.
+        address: Address,
+
+        /// Program that owns the account.
+        owner: Address,
+
+        /// The lamports in the account.
+        lamports: u64,
+
+        /// Data of the account.
+        data: &'a [u8],
+
+        /// Indicates whether the account is a signer.
+        is_signer: bool,
+
+        /// Indicates whether the account is writable.
+        is_writable: bool,
+
+        /// Indicates whether the account is executable.
+        executable: bool,
+    },
+
+    /// A duplicate of the account at the specified index.
+    ///
+    /// The index must refer to a previous account in the input.
+    Duplicate(u8),
+}
+
+impl<'a> InputAccount<'a> {
+    /// Create a read-only, non-signer and non-executable account.
+    pub fn new(address: Address, owner: Address, lamports: u64, data: &'a [u8]) -> Self {
+        Self::Account {
+            address,
+            owner,
+            lamports,
+            data,
+            is_signer: false,
+            is_writable: false,
+            executable: false,
+        }
+    }
+
+    /// Mark the account as a signer.
+    ///
+    /// This has no effect on duplicated accounts.
+    pub fn signer(mut self) -> Self {
+        if let Self::Account { is_signer, .. } = &mut self {
+            *is_signer = true;
+        }
+        self
+    }
+
+    /// Mark the account as writable.
+    ///
+    /// This has no effect on duplicated accounts.
+    pub fn writable(mut self) -> Self {
+        if let Self::Account { is_writable, .. } = &mut self {
+            *is_writable = true;
+        }
+        self
+    }
+
+    /// Mark the account as executable.
+    ///
+    /// This has no effect on duplicated accounts.
+    pub fn executable(mut self) -> Self {
+        if let Self::Account { executable, .. } = &mut self {
+            *executable = true;
+        }
+        self
+    }
+}
+
+/// Input buffer serialized by [`serialize_input`].
+///
+/// The buffer is aligned to the BPF alignment of `u128` and must outlive any
+/// value deserialized from it.
+pub struct InputBuffer {
+    buffer: Vec<u64>,
+    len: usize,
+}
+
+impl InputBuffer {
+    /// Return a mutable pointer to the start of the input buffer.
+    ///
+    /// This is the pointer expected by the entrypoint functions.
+    pub fn as_mut_ptr(&mut self) -> *mut u8 {
+        self.buffer.as_mut_ptr() as *mut u8
+    }
+
+    /// Return the serialized bytes of the input buffer.
+    ///
+    /// Changes made by the program to the accounts are reflected in the
+    /// returned bytes.
+    pub fn as_bytes(&self) -> &[u8] {
+        // SAFETY: `len` is within the bounds of the `buffer` allocation.
+        unsafe { from_raw_parts(self.buffer.as_ptr() as *const u8, self.len) }
+    }
+}
+
+/// Serialize the program input buffer.
+///
+/// The returned buffer follows the layout of the SVM loader:
+///
+/// - number of accounts (`u64`)
+/// - for each account, either:
+///   - a non-duplicated account: [`NON_DUP_MARKER`], signer, writable and
+///     executable flags, 4 bytes of padding, address, owner, lamports
+///     (`u64`), data length (`u64`), data, [`MAX_PERMITTED_DATA_INCREASE`]
+///     bytes of padding aligned to `u128` and rent epoch (`u64`); or
+///   - a duplicated account: index of the original account followed by
+///     7 bytes of padding
+/// - instruction data length (`u64`) and instruction data
+/// - program id
+///
+/// # Panics
+///
+/// Panics if a duplicated account does not refer to a previous account.
+pub fn serialize_input(
+    program_id: &Address,
+    accounts: &[InputAccount],
+    instruction_data: &[u8],
+) -> InputBuffer {
+    let mut bytes = Vec::new();
+    bytes.extend_from_slice(&(accounts.len() as u64).to_le_bytes());
+
+    for (index, account) in accounts.iter().enumerate() {
+        match account {
+            InputAccount::Account {
+                address,
+                owner,
+                lamports,
+                data,
+                is_signer,
+                is_writable,
+                executable,
+            } => {
+                bytes.extend_from_slice(&[
+                    NON_DUP_MARKER,
+                    *is_signer as u8,
+                    *is_writable as u8,
+                    *executable as u8,
+                ]);
+                // resize delta
+                bytes.extend_from_slice(&[0; 4]);
+                bytes.extend_from_slice(address.as_ref());
+                bytes.extend_from_slice(owner.as_ref());
+                bytes.extend_from_slice(&lamports.to_le_bytes());
+                bytes.extend_from_slice(&(data.len() as u64).to_le_bytes());
+                bytes.extend_from_slice(data);
+
+                let padded_len =
+                    (bytes.len() + MAX_PERMITTED_DATA_INCREASE + (BPF_ALIGN_OF_U128 - 1))
+                        & !(BPF_ALIGN_OF_U128 - 1);
+                bytes.resize(padded_len, 0);
+                // rent epoch
+                bytes.extend_from_slice(&u64::MAX.to_le_bytes());
+            }
+            InputAccount::Duplicate(original) => {
+                assert!(
+                    (*original as usize) < index,
+                    "duplicated account must refer to a previous account"
+                );
+                bytes.extend_from_slice(&[*original, 0, 0, 0, 0, 0, 0, 0]);
+            }
+        }
+    }
+
+    bytes.extend_from_slice(&(instruction_data.len() as u64).to_le_bytes());
+    bytes.extend_from_slice(instruction_data);
+    bytes.extend_from_slice(program_id.as_ref());
+
+    let len = bytes.len();
+    let mut buffer = Vec::with_capacity(len.div_ceil(size_of::<u64>()));
+
+    for chunk in bytes.chunks(size_of::<u64>()) {
+        let mut word = [0u8; size_of::<u64>()];
+        word[..chunk.len()].copy_from_slice(chunk);
+        buffer.push(u64::from_ne_bytes(word));
+    }
+
+    InputBuffer { buffer, len }
+}
+
+/// Account views backed by a serialized input buffer.
+///
+/// This allows testing code that receives [`AccountView`]s, such as
+/// instruction handlers and CPI builders, without going through an
+/// entrypoint. The views dereference to a slice of [`AccountView`]s.
+///
+/// ```ignore
+/// use pinocchio::{entrypoint::input::{AccountViews, InputAccount}, Address};
+///
+/// let accounts = AccountViews::new(&[
+///     InputAccount::new(Address::new_from_array([1; 32]), Address::default(), 100, &[])
+///         .signer()
+///         .writable(),
+/// ]);
+///
+/// assert_eq!(accounts[0].lamports(), 100);
+/// ```
+pub struct AccountViews {
+    /// Input buffer backing the account views.
+    input: InputBuffer,
+
+    /// Account views deserialized from the input buffer.
+    views: Vec<AccountView>,
+}
+
+impl AccountViews {
+    /// Serialize the accounts and create a view for each of them.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there are more than [`MAX_TX_ACCOUNTS`] accounts or a
+    /// duplicated account does not refer to a previous account.
+    pub fn new(accounts: &[InputAccount]) -> Self {
+        assert!(accounts.len() <= MAX_TX_ACCOUNTS, "too many accounts");
+
+        let mut input = serialize_input(&Address::default(), accounts, &[]);
+        let mut views = [const { MaybeUninit::<AccountView>::uninit() }; MAX_TX_ACCOUNTS];
+
+        // SAFETY: The input buffer is serialized by `serialize_input` and is
+        // owned by the returned value, so it outlives the account views.
+        let (_, count, _) = unsafe { deserialize(input.as_mut_ptr(), &mut views) };
+        // SAFETY: The first `count` views are initialized by `deserialize`.
+        let views = unsafe { from_raw_parts(views.as_ptr() as *const AccountView, count) };
+
+        Self {
+            input,
+            views: views.to_vec(),
+        }
+    }
+
+    /// Return the serialized bytes of the input buffer.
+    ///
+    /// Changes made to the accounts through the views are reflected in the
+    /// returned bytes.
+    pub fn as_bytes(&self) -> &[u8] {
+        self.input.as_bytes()
+    }
+}
+
+impl Deref for AccountViews {
+    type Target = [AccountView];
+
+    fn deref(&self) -> &Self::Target {
+        &self.views
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::{
+            entrypoint::{deserialize, process_entrypoint},
+            error::ProgramError,
+            AccountView, ProgramResult, SUCCESS,
+        },
+        core::mem::MaybeUninit,
+    };
+
+    const PROGRAM_ID: Address = Address::new_from_array([5; 32]);
+
+    const UNINIT: MaybeUninit<AccountView> = MaybeUninit::<AccountView>::uninit();
+
+    #[test]
+    fn test_serialize_input() {
+        let data = [1, 2, 3];
+        let mut input = serialize_input(
+            &PROGRAM_ID,
+            &[
+                InputAccount::new(Address::new_from_array([1; 32]), PROGRAM_ID, 100, &data)
+                    .signer()
+                    .writable(),
+                InputAccount::new(
+                    Address::new_from_array([2; 32]),
+                    Address::new_from_array([3; 32]),
+                    200,
+                    &[],
+                )
+                .executable(),
+                InputAccount::Duplicate(0),
+            ],
+            &[7, 8],
+        );
+        let mut accounts = [UNINIT; 3];
+
+        let (program_id, count, instruction_data) =
+            unsafe { deserialize(input.as_mut_ptr(), &mut accounts) };
+
+        assert_eq!(program_id, &PROGRAM_ID);
+        assert_eq!(count, 3);
+        assert_eq!(instruction_data, &[7, 8]);
+
+        let first = unsafe { accounts[0].assume_init_ref() };
+        assert_eq!(first.address(), &Address::new_from_array([1; 32]));
+        assert!(first.owned_by(&PROGRAM_ID));
+        assert_eq!(first.lamports(), 100);
+        assert_eq!(&*first.try_borrow().unwrap(), &data);
+        assert!(first.is_signer());
+        assert!(first.is_writable());
+        assert!(!first.executable());
+
+        let second = unsafe { accounts[1].assume_init_ref() };
+        assert_eq!(second.address(), &Address::new_from_array([2; 32]));
+        assert!(second.owned_by(&Address::new_from_array([3; 32])));
+        assert_eq!(second.lamports(), 200);
+        assert_eq!(second.data_len(), 0);
+        assert!(!second.is_signer());
+        assert!(!second.is_writable());
+        assert!(second.executable());
+
+        let duplicated = unsafe { accounts[2].assume_init_ref() };
+        assert_eq!(duplicated, first);
+    }
+
+    #[test]
+    fn test_serialize_input_process_entrypoint() {
+        fn process_instruction(
+            program_id: &Address,
+            accounts: &[AccountView],
+            instruction_data: &[u8],
+        ) -> ProgramResult {
+            if program_id != &PROGRAM_ID || accounts.len() != 1 {
+                return Err(ProgramError::InvalidArgument);
+            }
+
+            let lamports = u64::from_le_bytes(
+                instruction_data
+                    .try_into()
+                    .map_err(|_| ProgramError::InvalidInstructionData)?,
+            );
+            accounts[0].set_lamports(lamports);
+
+            Ok(())
+        }
+
+        let mut input = serialize_input(
+            &PROGRAM_ID,
+            &[
+                InputAccount::new(Address::new_from_array([1; 32]), PROGRAM_ID, 100, &[])
+                    .writable(),
+            ],
+            &42u64.to_le_bytes(),
+        );
+
+        let result = unsafe { process_entrypoint::<1>(input.as_mut_ptr(), process_instruction) };
+        assert_eq!(result, SUCCESS);
+
+        // lamports are stored after the flags, padding, address and owner
+        let lamports = &input.as_bytes()[8 + 8 + 64..8 + 8 + 64 + 8];
+        assert_eq!(lamports, &42u64.to_le_bytes());
+
+        let result = unsafe { process_entrypoint::<1>(input.as_mut_ptr(), process_instruction) };
+        assert_eq!(result, SUCCESS);
+
+        let mut input = serialize_input(&PROGRAM_ID, &[], &[]);
+        let result = unsafe { process_entrypoint::<1>(input.as_mut_ptr(), process_instruction) };
+        assert_eq!(result, u64::from(ProgramError::InvalidArgument));
+    }
+
+    #[test]
+    fn test_account_views() {
+        let accounts = AccountViews::new(&[
+            InputAccount::new(Address::new_from_array([1; 32]), PROGRAM_ID, 100, &[1, 2])
+                .writable(),
+            InputAccount::new(Address::new_from_array([2; 32]), PROGRAM_ID, 200, &[]).signer(),
+            InputAccount::Duplicate(0),
+        ]);
+
+        assert_eq!(accounts.len(), 3);
+        assert_eq!(accounts[0].address(), &Address::new_from_array([1; 32]));
+        assert_eq!(&*accounts[0].try_borrow().unwrap(), &[1, 2]);
+        assert!(accounts[1].is_signer());
+        assert_eq!(accounts[2], accounts[0]);
+
+        accounts[0].set_lamports(42);
+        assert_eq!(accounts[2].lamports(), 42);
+        assert_eq!(
+            &accounts.as_bytes()[8 + 8 + 64..8 + 8 + 64 + 8],
+            &42u64.to_le_bytes()
+        );
+    }
+}
@@ -74,7 +74,7 @@ macro_rules! lazy_program_entrypoint {
                 input,
             )) {
                 Ok(_) => $crate::SUCCESS,
-                Err(error) => error.into(),
+                Err(error) => $crate::entrypoint::report_error(error),
             }
         }
     };
@@ -279,3 +279,31 @@ impl MaybeAccount {
         account
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::{entrypoint::input::serialize_input, test_syscalls::LogCapture, ProgramResult},
+    };
+
+    fn process_instruction(_context: InstructionContext) -> ProgramResult {
+        Err(ProgramError::InvalidAccountData)
+    }
+
+    crate::lazy_program_entrypoint!(process_instruction);
+
+    #[test]
+    fn test_entrypoint_error() {
+        let logs = LogCapture::new();
+        let mut input = serialize_input(&Address::new_from_array([1; 32]), &[], &[]);
+
+        let result = unsafe { entrypoint(input.as_mut_ptr()) };
+        assert_eq!(result, u64::from(ProgramError::InvalidAccountData));
+
+        #[cfg(feature = "log-errors")]
+        logs.assert_log_contains("Error: InvalidAccountData");
+        #[cfg(not(feature = "log-errors"))]
+        assert!(logs.logs().is_empty());
+    }
+}
@@ -1,6 +1,8 @@
 //! Macros and functions for defining the program entrypoint and setting up
 //! global handlers.
 
+#[cfg(feature = "test-utils")]
+pub mod input;
 pub mod lazy;
 
 #[cfg(feature = "alloc")]
@@ -9,6 +11,7 @@ pub use lazy::{InstructionContext, MaybeAccount};
 use {
     crate::{
         account::{AccountView, RuntimeAccount, MAX_PERMITTED_DATA_INCREASE},
+        error::ProgramError,
         Address, ProgramResult, BPF_ALIGN_OF_U128, MAX_TX_ACCOUNTS, SUCCESS,
     },
     core::{
@@ -30,6 +33,9 @@ pub const HEAP_LENGTH: usize = 32 * 1024;
 /// Maximum heap length in bytes that a program can request.
 pub const MAX_HEAP_LENGTH: u32 = 256 * 1024;
 
+/// Maximum size (in bytes) of a stack frame in SBF programs.
+pub const MAX_STACK_FRAME_SIZE: usize = 4 * 1024;
+
 /// Value used to indicate that a serialized account is not a duplicate.
 pub const NON_DUP_MARKER: u8 = u8::MAX;
 
@@ -72,6 +78,12 @@ const STATIC_ACCOUNT_DATA: usize = size_of::<RuntimeAccount>() + MAX_PERMITTED_D
 /// set to [`crate::MAX_TX_ACCOUNTS`]. If the program receives more accounts
 /// than the specified maximum, these accounts will be ignored.
 ///
+/// A third optional argument specifies the stack usage (in bytes) of the
+/// instruction handler, for programs that let it be inlined into the
+/// entrypoint. The build fails if the stack usage of the entrypoint for the
+/// maximum number of accounts, as computed by [`entrypoint_stack_size`], plus
+/// the stack usage of the handler exceeds [`MAX_STACK_FRAME_SIZE`].
+///
 /// [global allocator]: https://doc.rust-lang.org/stable/alloc/alloc/trait.GlobalAlloc.html
 /// [maximum number of accounts]: https://github.com/anza-xyz/agave/blob/ccabfcf84921977202fd06d3197cbcea83742133/runtime/src/bank.rs#L3207-L3219
 /// [panic handler]: https://doc.rust-lang.org/stable/core/panic/trait.PanicHandler.html
@@ -135,7 +147,10 @@ macro_rules! entrypoint {
         $crate::entrypoint!($process_instruction, { $crate::MAX_TX_ACCOUNTS });
     };
     ( $process_instruction:expr, $maximum:expr ) => {
-        $crate::program_entrypoint!($process_instruction, $maximum);
+        $crate::entrypoint!($process_instruction, $maximum, 0);
+    };
+    ( $process_instruction:expr, $maximum:expr, $handler_stack_size:expr ) => {
+        $crate::program_entrypoint!($process_instruction, $maximum, $handler_stack_size);
         $crate::default_allocator!();
         $crate::default_panic_handler!();
     };
@@ -164,12 +179,57 @@ macro_rules! entrypoint {
 /// to reduce the stack size requirement for the entrypoint, as the default is
 /// set to [`MAX_TX_ACCOUNTS`]. If the program receives more accounts than the
 /// specified maximum, these accounts will be ignored.
+///
+/// A third optional argument specifies the stack usage (in bytes) of the
+/// instruction handler, for programs that let it be inlined into the
+/// entrypoint. The build fails if the stack usage of the entrypoint for the
+/// maximum number of accounts, as computed by [`entrypoint_stack_size`], plus
+/// the stack usage of the handler exceeds [`MAX_STACK_FRAME_SIZE`]:
+///
+/// ```compile_fail
+/// use pinocchio::{program_entrypoint, AccountView, Address, ProgramResult};
+///
+/// // 64 accounts leave less than 4 KiB of stack for the handler.
+/// program_entrypoint!(process_instruction, 64, 4096);
+///
+/// pub fn process_instruction(
+///     program_id: &Address,
+///     accounts: &[AccountView],
+///     instruction_data: &[u8],
+/// ) -> ProgramResult {
+///     Ok(())
+/// }
+/// ```
+///
+/// ```no_run
+/// use pinocchio::{program_entrypoint, AccountView, Address, ProgramResult};
+///
+/// program_entrypoint!(process_instruction, 64, 2048);
+///
+/// pub fn process_instruction(
+///     program_id: &Address,
+///     accounts: &[AccountView],
+///     instruction_data: &[u8],
+/// ) -> ProgramResult {
+///     Ok(())
+/// }
+/// ```
 #[macro_export]
 macro_rules! program_entrypoint {
     ( $process_instruction:expr ) => {
         $crate::program_entrypoint!($process_instruction, { $crate::MAX_TX_ACCOUNTS });
     };
     ( $process_instruction:expr, $maximum:expr ) => {
+        $crate::program_entrypoint!($process_instruction, $maximum, 0);
+    };
+    ( $process_instruction:expr, $maximum:expr, $handler_stack_size:expr ) => {
+        // Ensure that the entrypoint and the handler fit in a stack frame.
+        const _: () = assert!(
+            $crate::entrypoint::entrypoint_stack_size($maximum) + $handler_stack_size
+                <= $crate::entrypoint::MAX_STACK_FRAME_SIZE,
+            "entrypoint and handler stack usage exceeds MAX_STACK_FRAME_SIZE"
+        );
+
         /// Program entrypoint.
         #[no_mangle]
         pub unsafe extern "C" fn entrypoint(input: *mut u8) -> u64 {
@@ -178,6 +238,19 @@ macro_rules! program_entrypoint {
     };
 }
 
+/// Return the worst-case stack usage (in bytes) of the entrypoint generated
+/// for a maximum of `max_accounts` accounts.
+///
+/// This accounts for the array of `AccountView`s and the values returned by
+/// the deserialization, which are allocated in the stack frame of the
+/// `entrypoint` function. It does not include the stack used by the
+/// instruction handler in case it is inlined into the entrypoint, which is
+/// given as the third argument of [`crate::program_entrypoint!`].
+#[inline(always)]
+pub const fn entrypoint_stack_size(max_accounts: usize) -> usize {
+    max_accounts * size_of::<MaybeUninit<AccountView>>() + size_of::<(&Address, usize, &[u8])>()
+}
+
 /// Entrypoint deserialization.
 ///
 /// This function inlines entrypoint deserialization for use in the
@@ -209,10 +282,25 @@ pub unsafe fn process_entrypoint<const MAX_ACCOUNTS: usize>(
         instruction_data,
     ) {
         Ok(()) => SUCCESS,
-        Err(error) => error.into(),
+        Err(error) => report_error(error),
     }
 }
 
+/// Convert the error returned by the instruction handler into the value
+/// returned to the runtime.
+///
+/// The error is logged when the `log-errors` feature is enabled. This is used
+/// by the entrypoint macros, since a `cfg` in a macro body is evaluated against
+/// the features of the crate invoking the macro.
+#[doc(hidden)]
+#[inline(always)]
+pub fn report_error(error: ProgramError) -> u64 {
+    #[cfg(feature = "log-errors")]
+    crate::log::log_error(&error);
+
+    error.into()
+}
+
 /// Align a pointer to the BPF alignment of [`u128`].
 macro_rules! align_pointer {
     ($ptr:ident) => {
@@ -1081,6 +1169,13 @@ mod tests {
         assert_duplicated_accounts(&accounts, 32);
     }
 
+    #[test]
+    fn test_entrypoint_stack_size() {
+        assert_eq!(entrypoint_stack_size(0), 32);
+        assert_eq!(entrypoint_stack_size(1), 40);
+        assert!(entrypoint_stack_size(MAX_TX_ACCOUNTS) <= MAX_STACK_FRAME_SIZE);
+    }
+
     #[test]
     fn test_bump_allocator() {
         // alloc the entire
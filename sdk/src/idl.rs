@@ -0,0 +1,533 @@
+//! Generation of the program IDL.
+//!
+//! The IDL is built from the types that implement [`IdlInstruction`] and
+//! [`IdlType`], which are usually derived with the macros of the same name,
+//! and follows the Anchor IDL specification (`0.1.0`). This is also the
+//! format that [Codama](https://github.com/codama-idl/codama) imports to
+//! render explorers and generate clients.
+//!
+//! The generation happens off-chain, typically in a binary of the program
+//! crate that is only built with the `idl-build` feature:
+//!
+//! ```ignore
+//! // src/bin/idl.rs
+//! use {my_program::{Deposit, Vault, ID}, pinocchio::idl::Idl};
+//!
+//! fn main() {
+//!     let idl = Idl::new("my_program", env!("CARGO_PKG_VERSION"), &ID)
+//!         .instruction::<Deposit>()
+//!         .account::<Vault>();
+//!
+//!     println!("{}", idl.to_json());
+//! }
+//! ```
+
+// Re-export for the code generated by the derive macros.
+#[doc(hidden)]
+pub use alloc::{boxed::Box, vec, vec::Vec};
+use {
+    crate::Address,
+    alloc::{
+        format,
+        string::{String, ToString},
+    },
+    core::fmt::Write,
+};
+
+/// Version of the Anchor IDL specification of the generated IDL.
+pub const IDL_SPEC: &str = "0.1.0";
+
+/// The type of an instruction argument or a field.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Type {
+    Bool,
+    U8,
+    U16,
+    U32,
+    U64,
+    U128,
+    I8,
+    I16,
+    I32,
+    I64,
+    I128,
+    /// Variable length byte array.
+    Bytes,
+    /// UTF-8 string.
+    String,
+    /// An address.
+    Pubkey,
+    /// An optional value.
+    Option(Box<Type>),
+    /// A variable length array.
+    Vec(Box<Type>),
+    /// A fixed size array.
+    Array(Box<Type>, usize),
+    /// A type defined in the `types` section of the IDL.
+    Defined(&'static str),
+}
+
+/// A named instruction argument or field.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Field {
+    /// Name of the field.
+    pub name: &'static str,
+
+    /// Type of the field.
+    pub ty: Type,
+}
+
+/// An account expected by an instruction.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AccountItem {
+    /// Name of the account.
+    pub name: &'static str,
+
+    /// Indicates whether the account must be writable.
+    pub writable: bool,
+
+    /// Indicates whether the account must be a signer.
+    pub signer: bool,
+
+    /// Indicates whether the account can be omitted.
+    pub optional: bool,
+
+    /// The expected address of the account, if fixed.
+    pub address: Option<Address>,
+}
+
+/// The accounts expected by an instruction.
+///
+/// This is implemented by the `Accounts` derive macro when the `idl-build`
+/// feature is enabled.
+pub trait IdlAccounts {
+    /// Return the accounts in the order they are expected.
+    fn idl_accounts() -> Vec<AccountItem>;
+}
+
+/// An instruction of the program.
+pub trait IdlInstruction {
+    /// Name of the instruction.
+    const NAME: &'static str;
+
+    /// Bytes at the start of the instruction data that identify the
+    /// instruction.
+    const DISCRIMINATOR: &'static [u8];
+
+    /// Return the accounts in the order they are expected.
+    fn idl_accounts() -> Vec<AccountItem>;
+
+    /// Return the arguments in the order they are serialized.
+    fn idl_args() -> Vec<Field>;
+}
+
+/// A type used by the program, either as an account state or as an
+/// instruction argument.
+pub trait IdlType {
+    /// Name of the type.
+    const NAME: &'static str;
+
+    /// Bytes at the start of the account data that identify the type, if the
+    /// type is an account state.
+    const DISCRIMINATOR: Option<&'static [u8]>;
+
+    /// Return the fields in the order they are serialized.
+    fn idl_fields() -> Vec<Field>;
+}
+
+/// IDL of a program.
+pub struct Idl {
+    name: String,
+    version: String,
+    address: Address,
+    instructions: Vec<Value>,
+    accounts: Vec<Value>,
+    types: Vec<Value>,
+}
+
+impl Idl {
+    /// Create an IDL with no instructions or types.
+    pub fn new(name: &str, version: &str, address: &Address) -> Self {
+        Self {
+            name: name.to_string(),
+            version: version.to_string(),
+            address: Address::new_from_array(address.to_bytes()),
+            instructions: Vec::new(),
+            accounts: Vec::new(),
+            types: Vec::new(),
+        }
+    }
+
+    /// Add an instruction.
+    pub fn instruction<T: IdlInstruction>(mut self) -> Self {
+        self.instructions.push(Value::Object(vec![
+            ("name", Value::from(T::NAME)),
+            ("discriminator", discriminator(T::DISCRIMINATOR)),
+            (
+                "accounts",
+                Value::Array(T::idl_accounts().iter().map(account_item).collect()),
+            ),
+            (
+                "args",
+                Value::Array(T::idl_args().iter().map(field).collect()),
+            ),
+        ]));
+        self
+    }
+
+    /// Add an account state.
+    ///
+    /// The type is added to both the `accounts` and `types` sections. Types
+    /// without a discriminator are listed with an empty one.
+    pub fn account<T: IdlType>(mut self) -> Self {
+        self.accounts.push(Value::Object(vec![
+            ("name", Value::from(T::NAME)),
+            (
+                "discriminator",
+                discriminator(T::DISCRIMINATOR.unwrap_or(&[])),
+            ),
+        ]));
+        self.ty::<T>()
+    }
+
+    /// Add a type used by instruction arguments or other types.
+    pub fn ty<T: IdlType>(mut self) -> Self {
+        self.types.push(Value::Object(vec![
+            ("name", Value::from(T::NAME)),
+            (
+                "type",
+                Value::Object(vec![
+                    ("kind", Value::from("struct")),
+                    (
+                        "fields",
+                        Value::Array(T::idl_fields().iter().map(field).collect()),
+                    ),
+                ]),
+            ),
+        ]));
+        self
+    }
+
+    /// Serialize the IDL as pretty-printed JSON.
+    pub fn to_json(&self) -> String {
+        let idl = Value::Object(vec![
+            ("address", Value::String(format!("{}", self.address))),
+            (
+                "metadata",
+                Value::Object(vec![
+                    ("name", Value::String(self.name.clone())),
+                    ("version", Value::String(self.version.clone())),
+                    ("spec", Value::from(IDL_SPEC)),
+                ]),
+            ),
+            ("instructions", Value::Array(self.instructions.clone())),
+            ("accounts", Value::Array(self.accounts.clone())),
+            ("types", Value::Array(self.types.clone())),
+        ]);
+
+        let mut json = String::new();
+        idl.write(&mut json, 0);
+        json
+    }
+}
+
+/// Minimal JSON value used to serialize the IDL.
+#[derive(Clone)]
+enum Value {
+    Bool(bool),
+    Number(u64),
+    String(String),
+    Array(Vec<Value>),
+    Object(Vec<(&'static str, Value)>),
+}
+
+impl From<&str> for Value {
+    fn from(value: &str) -> Self {
+        Value::String(value.to_string())
+    }
+}
+
+impl Value {
+    fn write(&self, out: &mut String, indent: usize) {
+        match self {
+            Value::Bool(value) => out.push_str(if *value { "true" } else { "false" }),
+            Value::Number(value) => {
+                let _ = write!(out, "{value}");
+            }
+            Value::String(value) => write_str(out, value),
+            Value::Array(values) if values.is_empty() => out.push_str("[]"),
+            // Arrays of numbers, e.g. discriminators, are kept in a single line.
+            Value::Array(values) if values.iter().all(|v| matches!(v, Value::Number(_))) => {
+                out.push('[');
+                for (i, value) in values.iter().enumerate() {
+                    if i > 0 {
+                        out.push_str(", ");
+                    }
+                    value.write(out, indent);
+                }
+                out.push(']');
+            }
+            Value::Array(values) => {
+                out.push('[');
+                for (i, value) in values.iter().enumerate() {
+                    out.push_str(if i > 0 { ",\n" } else { "\n" });
+                    push_indent(out, indent + 1);
+                    value.write(out, indent + 1);
+                }
+                out.push('\n');
+                push_indent(out, indent);
+                out.push(']');
+            }
+            Value::Object(entries) => {
+                out.push('{');
+                for (i, (key, value)) in entries.iter().enumerate() {
+                    out.push_str(if i > 0 { ",\n" } else { "\n" });
+                    push_indent(out, indent + 1);
+                    write_str(out, key);
+                    out.push_str(": ");
+                    value.write(out, indent + 1);
+                }
+                out.push('\n');
+                push_indent(out, indent);
+                out.push('}');
+            }
+        }
+    }
+}
+
+fn push_indent(out: &mut String, indent: usize) {
+    for _ in 0..indent {
+        out.push_str("  ");
+    }
+}
+
+fn write_str(out: &mut String, value: &str) {
+    out.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            c if (c as u32) < 0x20 => {
+                let _ = write!(out, "\\u{:04x}", c as u32);
+            }
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+}
+
+fn discriminator(bytes: &[u8]) -> Value {
+    Value::Array(bytes.iter().map(|b| Value::Number(*b as u64)).collect())
+}
+
+fn account_item(account: &AccountItem) -> Value {
+    let mut entries = vec![("name", Value::from(account.name))];
+
+    if account.writable {
+        entries.push(("writable", Value::Bool(true)));
+    }
+    if account.signer {
+        entries.push(("signer", Value::Bool(true)));
+    }
+    if account.optional {
+        entries.push(("optional", Value::Bool(true)));
+    }
+    if let Some(address) = &account.address {
+        entries.push(("address", Value::String(format!("{address}"))));
+    }
+
+    Value::Object(entries)
+}
+
+fn field(field: &Field) -> Value {
+    Value::Object(vec![
+        ("name", Value::from(field.name)),
+        ("type", ty(&field.ty)),
+    ])
+}
+
+fn ty(ty: &Type) -> Value {
+    match ty {
+        Type::Bool => Value::from("bool"),
+        Type::U8 => Value::from("u8"),
+        Type::U16 => Value::from("u16"),
+        Type::U32 => Value::from("u32"),
+        Type::U64 => Value::from("u64"),
+        Type::U128 => Value::from("u128"),
+        Type::I8 => Value::from("i8"),
+        Type::I16 => Value::from("i16"),
+        Type::I32 => Value::from("i32"),
+        Type::I64 => Value::from("i64"),
+        Type::I128 => Value::from("i128"),
+        Type::Bytes => Value::from("bytes"),
+        Type::String => Value::from("string"),
+        Type::Pubkey => Value::from("pubkey"),
+        Type::Option(inner) => Value::Object(vec![("option", self::ty(inner))]),
+        Type::Vec(inner) => Value::Object(vec![("vec", self::ty(inner))]),
+        Type::Array(inner, len) => Value::Object(vec![(
+            "array",
+            Value::Array(vec![self::ty(inner), Value::Number(*len as u64)]),
+        )]),
+        Type::Defined(name) => Value::Object(vec![(
+            "defined",
+            Value::Object(vec![("name", Value::from(*name))]),
+        )]),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PROGRAM_ID: Address = Address::new_from_array([1; 32]);
+
+    struct Deposit;
+
+    impl IdlInstruction for Deposit {
+        const NAME: &'static str = "deposit";
+
+        const DISCRIMINATOR: &'static [u8] = &[1];
+
+        fn idl_accounts() -> Vec<AccountItem> {
+            vec![
+                AccountItem {
+                    name: "payer",
+                    writable: true,
+                    signer: true,
+                    optional: false,
+                    address: None,
+                },
+                AccountItem {
+                    name: "system_program",
+                    writable: false,
+                    signer: false,
+                    optional: false,
+                    address: Some(Address::new_from_array([0; 32])),
+                },
+            ]
+        }
+
+        fn idl_args() -> Vec<Field> {
+            vec![
+                Field {
+                    name: "amount",
+                    ty: Type::U64,
+                },
+                Field {
+                    name: "memo",
+                    ty: Type::Option(Box::new(Type::Array(Box::new(Type::U8), 4))),
+                },
+            ]
+        }
+    }
+
+    struct Vault;
+
+    impl IdlType for Vault {
+        const NAME: &'static str = "Vault";
+
+        const DISCRIMINATOR: Option<&'static [u8]> = Some(&[7, 8]);
+
+        fn idl_fields() -> Vec<Field> {
+            vec![
+                Field {
+                    name: "authority",
+                    ty: Type::Pubkey,
+                },
+                Field {
+                    name: "config",
+                    ty: Type::Defined("Config"),
+                },
+            ]
+        }
+    }
+
+    #[test]
+    fn test_to_json() {
+        let json = Idl::new("vault", "0.1.0", &PROGRAM_ID)
+            .instruction::<Deposit>()
+            .account::<Vault>()
+            .to_json();
+
+        let expected = r#"{
+  "address": "4vJ9JU1bJJE96FWSJKvHsmmFADCg4gpZQff4P3bkLKi",
+  "metadata": {
+    "name": "vault",
+    "version": "0.1.0",
+    "spec": "0.1.0"
+  },
+  "instructions": [
+    {
+      "name": "deposit",
+      "discriminator": [1],
+      "accounts": [
+        {
+          "name": "payer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "system_program",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        },
+        {
+          "name": "memo",
+          "type": {
+            "option": {
+              "array": [
+                "u8",
+                4
+              ]
+            }
+          }
+        }
+      ]
+    }
+  ],
+  "accounts": [
+    {
+      "name": "Vault",
+      "discriminator": [7, 8]
+    }
+  ],
+  "types": [
+    {
+      "name": "Vault",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "authority",
+            "type": "pubkey"
+          },
+          {
+            "name": "config",
+            "type": {
+              "defined": {
+                "name": "Config"
+              }
+            }
+          }
+        ]
+      }
+    }
+  ]
+}"#;
+
+        assert_eq!(json, expected);
+    }
+
+    #[test]
+    fn test_escape() {
+        let mut out = String::new();
+        write_str(&mut out, "a\"b\\c\n\u{1}");
+        assert_eq!(out, r#""a\"b\\c\n\u0001""#);
+    }
+}
@@ -256,6 +256,18 @@
 //! pinocchio = { version = "0.10.0", default-features = false }
 //! ```
 //!
+//! ### `client`
+//!
+//! The `client` feature enables the [`client`] module, which converts
+//! instructions built with the `cpi` types into
+//! [`solana_instruction::Instruction`]. This allows off-chain clients and
+//! integration tests to reuse the same instruction encodings, including the
+//! ones of the `pinocchio-*` instruction builders through their
+//! `with_instruction` method. It implies the `cpi` feature.
+//! ```ignore
+//! pinocchio = { version = "0.10.0", features = ["client"] }
+//! ```
+//!
 //! ### `copy`
 //!
 //! The `copy` feature enables the derivation of the `Copy` trait for types. It
@@ -265,11 +277,87 @@
 //! ### `cpi`
 //!
 //! The `cpi` feature enables the cross-program invocation helpers, as well as
-//! types to define instructions and signer information.
+//! types to define instructions and signer information. On non-Solana
+//! targets, the `invoke*` helpers return
+//! [`ProgramError::InvalidArgument`](error::ProgramError::InvalidArgument)
+//! unless the `test-syscalls` feature is enabled.
 //! ```ignore
 //! pinocchio = { version = "0.10.0", features = ["cpi"] }
 //! ```
 //!
+//! ### `derive`
+//!
+//! The `derive` feature enables the [`Accounts`] derive macro, which
+//! generates the validation of the accounts of an instruction from the
+//! constraints declared on a struct of `&AccountView` fields. It also enables
+//! the [`IdlInstruction`] and [`IdlType`] derive macros used by the
+//! `idl-build` feature, which expand to nothing when it is disabled, and the
+//! [`discriminators!`] macro, which declares the table of instruction
+//! discriminators of a program and fails the build when two of them collide.
+//!
+//! When used together with the `cpi` feature, it also enables the
+//! [`declare_program!`] macro, which generates CPI builders and state types
+//! for an external program from its Anchor or Codama IDL.
+//! ```ignore
+//! pinocchio = { version = "0.10.0", features = ["derive"] }
+//! ```
+//!
+//! ### `idl-build`
+//!
+//! The `idl-build` feature enables the [`idl`] module, which generates the
+//! program IDL from the instruction and state structs annotated with the
+//! [`IdlInstruction`] and [`IdlType`] derive macros. The IDL follows the
+//! Anchor IDL specification, which can also be imported by Codama to render
+//! explorers and generate TypeScript clients. It implies the `derive` feature.
+//!
+//! The feature is intended for off-chain builds only, usually through a
+//! feature of the program crate that enables a generation binary:
+//! ```ignore
+//! [features]
+//! idl-build = ["pinocchio/idl-build"]
+//!
+//! [[bin]]
+//! name = "idl"
+//! required-features = ["idl-build"]
+//! ```
+//!
+//! The IDL is then printed by running `cargo run --bin idl --features idl-build`.
+//!
+//! ### `log-errors`
+//!
+//! The `log-errors` feature logs the name of the [`ProgramError`](error::ProgramError)
+//! returned by the program at the entrypoint boundary, e.g. `Error: InvalidAccountData`,
+//! instead of only the numeric error code reported by the runtime.
+//! ```ignore
+//! pinocchio = { version = "0.10.0", features = ["log-errors"] }
+//! ```
+//!
+//! ### `test-utils`
+//!
+//! The `test-utils` feature enables the [`entrypoint::input`] module, which
+//! serializes the program id, accounts and instruction data using the input
+//! buffer layout of the SVM loader. This allows exercising entrypoints in
+//! `cargo test` without an SVM harness.
+//! [`AccountViews`](entrypoint::input::AccountViews) creates account views
+//! backed by a serialized input buffer, to test code receiving accounts outside
+//! of an entrypoint. It should only be enabled for `dev-dependencies`.
+//! ```ignore
+//! [dev-dependencies]
+//! pinocchio = { version = "0.10.0", features = ["test-utils"] }
+//! ```
+//!
+//! ### `test-syscalls`
+//!
+//! The `test-syscalls` feature enables the [`test_syscalls`] module on
+//! non-Solana targets, which routes logging, sysvar and CPI syscalls to a
+//! per-thread [`Syscalls`](test_syscalls::Syscalls) implementation. This
+//! allows code paths that call syscalls to run in `cargo test`. It should
+//! only be enabled for `dev-dependencies`.
+//! ```ignore
+//! [dev-dependencies]
+//! pinocchio = { version = "0.10.0", features = ["test-syscalls"] }
+//! ```
+//!
 //! ## Advanced entrypoint configuration
 //!
 //! The symbols emitted by the entrypoint macros - program entrypoint, global
@@ -326,8 +414,26 @@
 #[cfg(feature = "alloc")]
 extern crate alloc;
 
+#[cfg(feature = "client")]
+pub mod client;
+// Stub the cross-program invocations on non-Solana targets.
+#[cfg(all(
+    feature = "cpi",
+    not(feature = "test-syscalls"),
+    not(any(target_os = "solana", target_arch = "bpf"))
+))]
+pub mod cpi;
+pub mod discriminator;
 pub mod entrypoint;
+#[cfg(feature = "idl-build")]
+pub mod idl;
+pub mod log;
 pub mod sysvars;
+#[cfg(all(
+    feature = "test-syscalls",
+    not(any(target_os = "solana", target_arch = "bpf"))
+))]
+pub mod test_syscalls;
 
 // Re-export the `solana_define_syscall` for downstream use.
 #[cfg(any(target_os = "solana", target_arch = "bpf"))]
@@ -341,9 +447,23 @@ pub use {
     solana_address::{self as address, Address},
     solana_program_error::{self as error, ProgramResult},
 };
+// Re-export the derive macros for downstream use.
+#[cfg(all(feature = "cpi", feature = "derive"))]
+pub use pinocchio_macros::declare_program;
+#[cfg(feature = "derive")]
+pub use pinocchio_macros::{discriminators, Accounts, IdlInstruction, IdlType};
 // Re-export the `solana_instruction_view` for downstream use.
 #[cfg(feature = "cpi")]
-pub use {solana_instruction_view as instruction, solana_instruction_view::cpi};
+pub use solana_instruction_view as instruction;
+#[cfg(all(feature = "cpi", any(target_os = "solana", target_arch = "bpf")))]
+pub use solana_instruction_view::cpi;
+// Route the cross-program invocations through the registered syscalls.
+#[cfg(all(
+    feature = "cpi",
+    feature = "test-syscalls",
+    not(any(target_os = "solana", target_arch = "bpf"))
+))]
+pub use test_syscalls::cpi;
 
 /// Maximum number of accounts that a transaction may process.
 ///
@@ -0,0 +1,181 @@
+//! Helpers to log the source location of errors before they are propagated.
+
+use {
+    super::{log_bytes, truncate_str, write_str, write_u64, MAX_FORMATTED_LEN, MAX_U64_DIGITS},
+    crate::error::ProgramError,
+    core::panic::Location,
+};
+
+/// Extension trait to add context to a [`ProgramError`] result.
+///
+/// When the result is an error, a message including the source location of
+/// the caller is logged before the error is returned unchanged. Successful
+/// results are not affected.
+///
+/// # Examples
+///
+/// ```
+/// use pinocchio::{error::ProgramError, log::ErrorContext, ProgramResult};
+///
+/// fn transfer() -> ProgramResult {
+///     Err(ProgramError::InsufficientFunds)
+/// }
+///
+/// fn process() -> ProgramResult {
+///     // Logs "transfer failed at src/lib.rs:<line>:<column>" on error.
+///     transfer().with_context("transfer failed")?;
+///     Ok(())
+/// }
+/// # assert_eq!(process(), Err(ProgramError::InsufficientFunds));
+/// ```
+pub trait ErrorContext {
+    /// Log `message` and the caller location if `self` is an error.
+    fn with_context(self, message: &str) -> Self;
+}
+
+impl<T> ErrorContext for Result<T, ProgramError> {
+    #[inline(always)]
+    #[track_caller]
+    fn with_context(self, message: &str) -> Self {
+        if self.is_err() {
+            let location = Location::caller();
+            log_error_location(message, location.file(), location.line(), location.column());
+        }
+        self
+    }
+}
+
+/// Log the source location of an error result before propagating it.
+///
+/// The macro evaluates to the given `Result` unchanged. When the result is an
+/// error, the current `file!()` and `line!()` are logged, optionally preceded
+/// by a message.
+///
+/// # Examples
+///
+/// ```
+/// use pinocchio::{error::ProgramError, trace_err, ProgramResult};
+///
+/// fn check(amount: u64) -> ProgramResult {
+///     if amount == 0 {
+///         return Err(ProgramError::InvalidArgument);
+///     }
+///     Ok(())
+/// }
+///
+/// fn process() -> ProgramResult {
+///     trace_err!(check(0))?;
+///     trace_err!(check(0), "invalid amount")?;
+///     Ok(())
+/// }
+/// # assert_eq!(process(), Err(ProgramError::InvalidArgument));
+/// ```
+#[macro_export]
+macro_rules! trace_err {
+    ( $result:expr ) => {
+        $crate::trace_err!($result, "error")
+    };
+    ( $result:expr, $message:expr ) => {
+        match $result {
+            ::core::result::Result::Ok(value) => ::core::result::Result::Ok(value),
+            ::core::result::Result::Err(error) => {
+                $crate::log::log_error_location($message, file!(), line!(), column!());
+                ::core::result::Result::Err(error)
+            }
+        }
+    };
+}
+
+/// Log an error message in the form `<message> at <file>:<line>:<column>`.
+///
+/// This function is used by the [`ErrorContext`] trait and the
+/// [`trace_err!`](crate::trace_err) macro.
+#[cold]
+#[doc(hidden)]
+pub fn log_error_location(message: &str, file: &str, line: u32, column: u32) {
+    let mut buffer = [0u8; MAX_FORMATTED_LEN];
+    let length = format_error_location(&mut buffer, message, file, line, column);
+
+    log_bytes(&buffer[..length]);
+}
+
+/// Format an error message in the form `<message> at <file>:<line>:<column>`.
+///
+/// The message, and then the file, are truncated on a character boundary if
+/// needed so that the line and column are always included. Returns the
+/// length of the formatted message.
+fn format_error_location(
+    buffer: &mut [u8; MAX_FORMATTED_LEN],
+    message: &str,
+    file: &str,
+    line: u32,
+    column: u32,
+) -> usize {
+    const SEPARATOR: &[u8] = b" at ";
+
+    // Formatted `:<line>:<column>` suffix.
+    let mut location = [0u8; 2 * (1 + MAX_U64_DIGITS)];
+    location[0] = b':';
+    let mut location_len = 1 + write_u64(&mut location[1..], line as u64);
+    location[location_len] = b':';
+    location_len += 1;
+    location_len += write_u64(&mut location[location_len..], column as u64);
+
+    let file_limit = MAX_FORMATTED_LEN - SEPARATOR.len() - location_len;
+    let file = truncate_str(file, file_limit);
+    let message = truncate_str(message, file_limit - file.len());
+
+    let mut offset = write_str(buffer, 0, message);
+    offset = write_str(buffer, offset, SEPARATOR);
+    offset = write_str(buffer, offset, file);
+    write_str(buffer, offset, &location[..location_len])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_error_location() {
+        let mut buffer = [0u8; MAX_FORMATTED_LEN];
+
+        let length = format_error_location(&mut buffer, "transfer failed", "src/lib.rs", 42, 9);
+        assert_eq!(&buffer[..length], b"transfer failed at src/lib.rs:42:9");
+
+        // Long messages are truncated so the location is always present.
+        let message = "\u{e9}".repeat(MAX_FORMATTED_LEN);
+        let length = format_error_location(&mut buffer, &message, "src/lib.rs", u32::MAX, 9);
+        assert!(length <= MAX_FORMATTED_LEN);
+        assert!(core::str::from_utf8(&buffer[..length]).is_ok());
+        assert!(buffer[..length].ends_with(b" at src/lib.rs:4294967295:9"));
+
+        // Long file names are truncated after the message.
+        let file = "\u{e9}".repeat(MAX_FORMATTED_LEN);
+        let length = format_error_location(&mut buffer, "failed", &file, 42, u32::MAX);
+        assert_eq!(length, MAX_FORMATTED_LEN);
+        assert!(core::str::from_utf8(&buffer[..length]).is_ok());
+        assert!(buffer[..length].starts_with(b" at \xc3\xa9"));
+        assert!(buffer[..length].ends_with(b":42:4294967295"));
+    }
+
+    #[test]
+    fn test_with_context() {
+        let result: Result<u8, ProgramError> = Ok(1);
+        assert_eq!(result.with_context("ok"), Ok(1));
+
+        let result: Result<u8, ProgramError> = Err(ProgramError::InvalidArgument);
+        assert_eq!(
+            result.with_context("failed"),
+            Err(ProgramError::InvalidArgument)
+        );
+    }
+
+    #[test]
+    fn test_trace_err() {
+        let result: Result<u8, ProgramError> = Err(ProgramError::InvalidSeeds);
+        assert_eq!(trace_err!(result), Err(ProgramError::InvalidSeeds));
+
+        let result: Result<u8, ProgramError> = Ok(2);
+        assert_eq!(trace_err!(result, "unreachable"), Ok(2));
+    }
+}
@@ -0,0 +1,79 @@
+//! Fixed-buffer base64 encoder.
+
+/// Standard base64 alphabet.
+const BASE64_ALPHABET: &[u8; 64] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+/// Return the length of the base64 (padded) representation of `len` bytes.
+#[inline(always)]
+pub(crate) const fn base64_encoded_len(len: usize) -> usize {
+    len.div_ceil(3) * 4
+}
+
+/// Encode `input` as padded base64 into `output`.
+///
+/// Returns the number of bytes written. The caller must ensure that `output`
+/// has at least [`base64_encoded_len`] bytes available.
+pub(crate) fn encode_base64(input: &[u8], output: &mut [u8]) -> usize {
+    let mut offset = 0;
+    let mut chunks = input.chunks_exact(3);
+
+    for chunk in &mut chunks {
+        let value = (chunk[0] as u32) << 16 | (chunk[1] as u32) << 8 | chunk[2] as u32;
+
+        output[offset] = BASE64_ALPHABET[(value >> 18) as usize & 0x3f];
+        output[offset + 1] = BASE64_ALPHABET[(value >> 12) as usize & 0x3f];
+        output[offset + 2] = BASE64_ALPHABET[(value >> 6) as usize & 0x3f];
+        output[offset + 3] = BASE64_ALPHABET[value as usize & 0x3f];
+        offset += 4;
+    }
+
+    match *chunks.remainder() {
+        [first] => {
+            let value = (first as u32) << 16;
+
+            output[offset] = BASE64_ALPHABET[(value >> 18) as usize & 0x3f];
+            output[offset + 1] = BASE64_ALPHABET[(value >> 12) as usize & 0x3f];
+            output[offset + 2] = b'=';
+            output[offset + 3] = b'=';
+            offset += 4;
+        }
+        [first, second] => {
+            let value = (first as u32) << 16 | (second as u32) << 8;
+
+            output[offset] = BASE64_ALPHABET[(value >> 18) as usize & 0x3f];
+            output[offset + 1] = BASE64_ALPHABET[(value >> 12) as usize & 0x3f];
+            output[offset + 2] = BASE64_ALPHABET[(value >> 6) as usize & 0x3f];
+            output[offset + 3] = b'=';
+            offset += 4;
+        }
+        _ => (),
+    }
+
+    offset
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encode_base64() {
+        let mut output = [0u8; 12];
+
+        for (input, expected) in [
+            (&b""[..], &b""[..]),
+            (b"f", b"Zg=="),
+            (b"fo", b"Zm8="),
+            (b"foo", b"Zm9v"),
+            (b"foob", b"Zm9vYg=="),
+            (b"fooba", b"Zm9vYmE="),
+            (b"foobar", b"Zm9vYmFy"),
+            (&[0xff, 0xfe, 0xfd], b"//79"),
+        ] {
+            let length = encode_base64(input, &mut output);
+            assert_eq!(length, base64_encoded_len(input.len()));
+            assert_eq!(&output[..length], expected);
+        }
+    }
+}
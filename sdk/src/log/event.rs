@@ -0,0 +1,505 @@
+//! Anchor-compatible event emission.
+//!
+//! Events are serialized using the [Borsh] encoding, prefixed with an 8-byte
+//! discriminator and logged through the `sol_log_data` syscall. The
+//! discriminator is derived from the first 8 bytes of
+//! `sha256("event:<EventName>")`, which is the same convention used by
+//! Anchor. This allows indexers and clients built for Anchor events to
+//! consume events emitted by pinocchio programs unchanged.
+//!
+//! [Borsh]: https://borsh.io
+
+use {
+    crate::{error::ProgramError, Address, ProgramResult},
+    core::mem::MaybeUninit,
+};
+
+/// Default maximum length (in bytes) of a serialized event, including the
+/// discriminator.
+///
+/// Use [`emit_with_bounds`] to emit events with a different maximum length.
+pub const DEFAULT_MAX_EVENT_LEN: usize = 256;
+
+/// Length of the event discriminator.
+pub const DISCRIMINATOR_LEN: usize = 8;
+
+/// Trait for types that can be emitted as events.
+///
+/// This trait is usually implemented by the [`event!`](crate::event) macro.
+pub trait Event {
+    /// The 8-byte discriminator of the event.
+    const DISCRIMINATOR: [u8; DISCRIMINATOR_LEN];
+
+    /// Serialize the event fields (without the discriminator).
+    fn serialize(&self, writer: &mut Writer) -> ProgramResult;
+}
+
+/// Trait for values that can be serialized using the Borsh encoding.
+pub trait Serialize {
+    /// Serialize the value into the writer.
+    fn serialize(&self, writer: &mut Writer) -> ProgramResult;
+}
+
+/// Writer over a fixed-size buffer.
+pub struct Writer<'a> {
+    /// Buffer to write to.
+    buffer: &'a mut [MaybeUninit<u8>],
+
+    /// Number of bytes written.
+    len: usize,
+}
+
+impl<'a> Writer<'a> {
+    /// Create a new writer over the given buffer.
+    #[inline(always)]
+    pub fn new(buffer: &'a mut [MaybeUninit<u8>]) -> Self {
+        Self { buffer, len: 0 }
+    }
+
+    /// Write the given bytes to the buffer.
+    ///
+    /// Returns an error if the buffer does not have enough space.
+    #[inline(always)]
+    pub fn write(&mut self, bytes: &[u8]) -> ProgramResult {
+        let end = self
+            .len
+            .checked_add(bytes.len())
+            .filter(|end| *end <= self.buffer.len())
+            .ok_or(ProgramError::InvalidArgument)?;
+
+        // SAFETY: The destination range was checked to be within the buffer
+        // and the source and destination do not overlap.
+        unsafe {
+            core::ptr::copy_nonoverlapping(
+                bytes.as_ptr(),
+                self.buffer.as_mut_ptr().add(self.len) as *mut u8,
+                bytes.len(),
+            );
+        }
+        self.len = end;
+
+        Ok(())
+    }
+
+    /// Return the number of bytes written.
+    #[inline(always)]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Indicates whether no bytes have been written.
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Return the bytes written.
+    #[inline(always)]
+    pub fn as_bytes(&self) -> &[u8] {
+        // SAFETY: The first `len` bytes of the buffer are initialized.
+        unsafe { core::slice::from_raw_parts(self.buffer.as_ptr() as *const u8, self.len) }
+    }
+}
+
+macro_rules! impl_serialize_for_primitive {
+    ( $($ty:ty),* ) => {
+        $(
+            impl Serialize for $ty {
+                #[inline(always)]
+                fn serialize(&self, writer: &mut Writer) -> ProgramResult {
+                    writer.write(&self.to_le_bytes())
+                }
+            }
+        )*
+    };
+}
+
+impl_serialize_for_primitive!(u8, u16, u32, u64, u128, i8, i16, i32, i64, i128);
+
+impl Serialize for bool {
+    #[inline(always)]
+    fn serialize(&self, writer: &mut Writer) -> ProgramResult {
+        writer.write(&[*self as u8])
+    }
+}
+
+impl Serialize for Address {
+    #[inline(always)]
+    fn serialize(&self, writer: &mut Writer) -> ProgramResult {
+        writer.write(self.as_ref())
+    }
+}
+
+impl<const N: usize> Serialize for [u8; N] {
+    #[inline(always)]
+    fn serialize(&self, writer: &mut Writer) -> ProgramResult {
+        writer.write(self)
+    }
+}
+
+impl Serialize for [u8] {
+    #[inline(always)]
+    fn serialize(&self, writer: &mut Writer) -> ProgramResult {
+        let len = u32::try_from(self.len()).map_err(|_| ProgramError::InvalidArgument)?;
+        writer.write(&len.to_le_bytes())?;
+        writer.write(self)
+    }
+}
+
+impl Serialize for str {
+    #[inline(always)]
+    fn serialize(&self, writer: &mut Writer) -> ProgramResult {
+        self.as_bytes().serialize(writer)
+    }
+}
+
+impl<T: Serialize + ?Sized> Serialize for &T {
+    #[inline(always)]
+    fn serialize(&self, writer: &mut Writer) -> ProgramResult {
+        (**self).serialize(writer)
+    }
+}
+
+impl<T: Serialize> Serialize for Option<T> {
+    #[inline(always)]
+    fn serialize(&self, writer: &mut Writer) -> ProgramResult {
+        match self {
+            Some(value) => {
+                writer.write(&[1])?;
+                value.serialize(writer)
+            }
+            None => writer.write(&[0]),
+        }
+    }
+}
+
+/// Emit an event using a buffer of [`DEFAULT_MAX_EVENT_LEN`] bytes.
+///
+/// Returns an error if the serialized event does not fit in the buffer.
+#[inline(always)]
+pub fn emit<E: Event>(event: &E) -> ProgramResult {
+    emit_with_bounds::<DEFAULT_MAX_EVENT_LEN, E>(event)
+}
+
+/// Emit an event using a buffer of `MAX_EVENT_LEN` bytes.
+///
+/// The `MAX_EVENT_LEN` includes the length of the discriminator. Returns an
+/// error if the serialized event does not fit in the buffer.
+#[inline(always)]
+pub fn emit_with_bounds<const MAX_EVENT_LEN: usize, E: Event>(event: &E) -> ProgramResult {
+    let mut buffer = [const { MaybeUninit::<u8>::uninit() }; MAX_EVENT_LEN];
+    let mut writer = Writer::new(&mut buffer);
+
+    writer.write(&E::DISCRIMINATOR)?;
+    event.serialize(&mut writer)?;
+
+    super::log_data(&[writer.as_bytes()]);
+
+    Ok(())
+}
+
+/// Compute the discriminator of an event with the given name.
+///
+/// The discriminator is the first 8 bytes of `sha256("event:<name>")`.
+pub const fn discriminator(name: &str) -> [u8; DISCRIMINATOR_LEN] {
+    let hash = sha256(b"event:", name.as_bytes());
+    let mut discriminator = [0u8; DISCRIMINATOR_LEN];
+    let mut i = 0;
+
+    while i < DISCRIMINATOR_LEN {
+        discriminator[i] = hash[i];
+        i += 1;
+    }
+
+    discriminator
+}
+
+/// Declare an event type.
+///
+/// The macro declares the struct and implements the [`Event`] trait for it,
+/// using the struct name to derive the discriminator. All fields must
+/// implement the [`Serialize`] trait; generic parameters and lifetimes are
+/// not supported.
+///
+/// # Examples
+///
+/// ```
+/// use pinocchio::{event, log::event::emit, Address, ProgramResult};
+///
+/// event! {
+///     /// Emitted when tokens are transferred.
+///     pub struct Transferred {
+///         pub from: Address,
+///         pub to: Address,
+///         pub amount: u64,
+///     }
+/// }
+///
+/// fn process(from: &Address, to: &Address, amount: u64) -> ProgramResult {
+///     emit(&Transferred {
+///         from: from.clone(),
+///         to: to.clone(),
+///         amount,
+///     })
+/// }
+/// # assert!(process(&Address::default(), &Address::default(), 1).is_ok());
+/// ```
+#[macro_export]
+macro_rules! event {
+    (
+        $(#[$attr:meta])*
+        $vis:vis struct $name:ident {
+            $(
+                $(#[$field_attr:meta])*
+                $field_vis:vis $field:ident : $ty:ty
+            ),* $(,)?
+        }
+    ) => {
+        $(#[$attr])*
+        $vis struct $name {
+            $(
+                $(#[$field_attr])*
+                $field_vis $field: $ty,
+            )*
+        }
+
+        impl $crate::log::event::Event for $name {
+            const DISCRIMINATOR: [u8; $crate::log::event::DISCRIMINATOR_LEN] =
+                $crate::log::event::discriminator(stringify!($name));
+
+            #[inline(always)]
+            fn serialize(&self, _writer: &mut $crate::log::event::Writer) -> $crate::ProgramResult {
+                $(
+                    $crate::log::event::Serialize::serialize(&self.$field, _writer)?;
+                )*
+                Ok(())
+            }
+        }
+    };
+}
+
+/// SHA-256 round constants.
+const K: [u32; 64] = [
+    0x428a2f98, 0x71374491, 0xb5c0fbcf, 0xe9b5dba5, 0x3956c25b, 0x59f111f1, 0x923f82a4, 0xab1c5ed5,
+    0xd807aa98, 0x12835b01, 0x243185be, 0x550c7dc3, 0x72be5d74, 0x80deb1fe, 0x9bdc06a7, 0xc19bf174,
+    0xe49b69c1, 0xefbe4786, 0x0fc19dc6, 0x240ca1cc, 0x2de92c6f, 0x4a7484aa, 0x5cb0a9dc, 0x76f988da,
+    0x983e5152, 0xa831c66d, 0xb00327c8, 0xbf597fc7, 0xc6e00bf3, 0xd5a79147, 0x06ca6351, 0x14292967,
+    0x27b70a85, 0x2e1b2138, 0x4d2c6dfc, 0x53380d13, 0x650a7354, 0x766a0abb, 0x81c2c92e, 0x92722c85,
+    0xa2bfe8a1, 0xa81a664b, 0xc24b8b70, 0xc76c51a3, 0xd192e819, 0xd6990624, 0xf40e3585, 0x106aa070,
+    0x19a4c116, 0x1e376c08, 0x2748774c, 0x34b0bcb5, 0x391c0cb3, 0x4ed8aa4a, 0x5b9cca4f, 0x682e6ff3,
+    0x748f82ee, 0x78a5636f, 0x84c87814, 0x8cc70208, 0x90befffa, 0xa4506ceb, 0xbef9a3f7, 0xc67178f2,
+];
+
+/// Compute the SHA-256 hash of the concatenation of `prefix` and `data` at
+/// compile time.
+///
+/// This is only intended to be used in constant contexts; programs should
+/// use the `sol_sha256` syscall to compute hashes at runtime.
+const fn sha256(prefix: &[u8], data: &[u8]) -> [u8; 32] {
+    let mut state: [u32; 8] = [
+        0x6a09e667, 0xbb67ae85, 0x3c6ef372, 0xa54ff53a, 0x510e527f, 0x9b05688c, 0x1f83d9ab,
+        0x5be0cd19,
+    ];
+
+    let length = prefix.len() + data.len();
+    // Message length plus the `0x80` terminator and the 8-byte length,
+    // rounded up to the block size.
+    let padded_length = (length + 9).div_ceil(64) * 64;
+    let bit_length = (length as u64).wrapping_mul(8);
+
+    let mut block_start = 0;
+
+    while block_start < padded_length {
+        let mut w = [0u32; 64];
+        let mut i = 0;
+
+        while i < 16 {
+            let mut word = 0u32;
+            let mut j = 0;
+
+            while j < 4 {
+                let index = block_start + i * 4 + j;
+
+                let byte = if index < prefix.len() {
+                    prefix[index]
+                } else if index < length {
+                    data[index - prefix.len()]
+                } else if index == length {
+                    0x80
+                } else if index >= padded_length - 8 {
+                    (bit_length >> ((padded_length - 1 - index) * 8)) as u8
+                } else {
+                    0
+                };
+
+                word = (word << 8) | byte as u32;
+                j += 1;
+            }
+
+            w[i] = word;
+            i += 1;
+        }
+
+        while i < 64 {
+            let s0 = w[i - 15].rotate_right(7) ^ w[i - 15].rotate_right(18) ^ (w[i - 15] >> 3);
+            let s1 = w[i - 2].rotate_right(17) ^ w[i - 2].rotate_right(19) ^ (w[i - 2] >> 10);
+            w[i] = w[i - 16]
+                .wrapping_add(s0)
+                .wrapping_add(w[i - 7])
+                .wrapping_add(s1);
+            i += 1;
+        }
+
+        let mut a = state[0];
+        let mut b = state[1];
+        let mut c = state[2];
+        let mut d = state[3];
+        let mut e = state[4];
+        let mut f = state[5];
+        let mut g = state[6];
+        let mut h = state[7];
+
+        i = 0;
+
+        while i < 64 {
+            let s1 = e.rotate_right(6) ^ e.rotate_right(11) ^ e.rotate_right(25);
+            let ch = (e & f) ^ (!e & g);
+            let t1 = h
+                .wrapping_add(s1)
+                .wrapping_add(ch)
+                .wrapping_add(K[i])
+                .wrapping_add(w[i]);
+            let s0 = a.rotate_right(2) ^ a.rotate_right(13) ^ a.rotate_right(22);
+            let maj = (a & b) ^ (a & c) ^ (b & c);
+            let t2 = s0.wrapping_add(maj);
+
+            h = g;
+            g = f;
+            f = e;
+            e = d.wrapping_add(t1);
+            d = c;
+            c = b;
+            b = a;
+            a = t1.wrapping_add(t2);
+
+            i += 1;
+        }
+
+        state[0] = state[0].wrapping_add(a);
+        state[1] = state[1].wrapping_add(b);
+        state[2] = state[2].wrapping_add(c);
+        state[3] = state[3].wrapping_add(d);
+        state[4] = state[4].wrapping_add(e);
+        state[5] = state[5].wrapping_add(f);
+        state[6] = state[6].wrapping_add(g);
+        state[7] = state[7].wrapping_add(h);
+
+        block_start += 64;
+    }
+
+    let mut hash = [0u8; 32];
+    let mut i = 0;
+
+    while i < 8 {
+        let bytes = state[i].to_be_bytes();
+        hash[i * 4] = bytes[0];
+        hash[i * 4 + 1] = bytes[1];
+        hash[i * 4 + 2] = bytes[2];
+        hash[i * 4 + 3] = bytes[3];
+        i += 1;
+    }
+
+    hash
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sha256() {
+        assert_eq!(
+            sha256(b"", b""),
+            [
+                0xe3, 0xb0, 0xc4, 0x42, 0x98, 0xfc, 0x1c, 0x14, 0x9a, 0xfb, 0xf4, 0xc8, 0x99, 0x6f,
+                0xb9, 0x24, 0x27, 0xae, 0x41, 0xe4, 0x64, 0x9b, 0x93, 0x4c, 0xa4, 0x95, 0x99, 0x1b,
+                0x78, 0x52, 0xb8, 0x55
+            ]
+        );
+        // Largest message that fits in a single block.
+        assert_eq!(
+            sha256(b"", &[b'a'; 55]),
+            [
+                0x9f, 0x43, 0x90, 0xf8, 0xd3, 0x0c, 0x2d, 0xd9, 0x2e, 0xc9, 0xf0, 0x95, 0xb6, 0x5e,
+                0x2b, 0x9a, 0xe9, 0xb0, 0xa9, 0x25, 0xa5, 0x25, 0x8e, 0x24, 0x1c, 0x9f, 0x1e, 0x91,
+                0x0f, 0x73, 0x43, 0x18
+            ]
+        );
+        // Smallest message that requires two blocks.
+        assert_eq!(
+            sha256(&[b'a'; 20], &[b'a'; 36]),
+            [
+                0xb3, 0x54, 0x39, 0xa4, 0xac, 0x6f, 0x09, 0x48, 0xb6, 0xd6, 0xf9, 0xe3, 0xc6, 0xaf,
+                0x0f, 0x5f, 0x59, 0x0c, 0xe2, 0x0f, 0x1b, 0xde, 0x70, 0x90, 0xef, 0x79, 0x70, 0x68,
+                0x6e, 0xc6, 0x73, 0x8a
+            ]
+        );
+        assert_eq!(
+            sha256(b"", &[b'a'; 100]),
+            [
+                0x28, 0x16, 0x59, 0x78, 0x88, 0xe4, 0xa0, 0xd3, 0xa3, 0x6b, 0x82, 0xb8, 0x33, 0x16,
+                0xab, 0x32, 0x68, 0x0e, 0xb8, 0xf0, 0x0f, 0x8c, 0xd3, 0xb9, 0x04, 0xd6, 0x81, 0x24,
+                0x6d, 0x28, 0x5a, 0x0e
+            ]
+        );
+    }
+
+    #[test]
+    fn test_discriminator() {
+        assert_eq!(
+            discriminator("Transferred"),
+            [21, 132, 239, 64, 146, 239, 166, 68]
+        );
+    }
+
+    #[test]
+    fn test_serialize_event() {
+        crate::event! {
+            struct Transferred {
+                from: Address,
+                amount: u64,
+                memo: Option<&'static str>,
+            }
+        }
+
+        let event = Transferred {
+            from: Address::new_from_array([7; 32]),
+            amount: 42,
+            memo: Some("hi"),
+        };
+
+        let mut buffer = [const { MaybeUninit::<u8>::uninit() }; 64];
+        let mut writer = Writer::new(&mut buffer);
+        event.serialize(&mut writer).unwrap();
+
+        let bytes = writer.as_bytes();
+        assert_eq!(bytes.len(), 32 + 8 + 1 + 4 + 2);
+        assert_eq!(&bytes[..32], &[7; 32]);
+        assert_eq!(&bytes[32..40], &42u64.to_le_bytes());
+        assert_eq!(&bytes[40..], &[1, 2, 0, 0, 0, b'h', b'i']);
+
+        // The event does not fit in a small buffer.
+        let mut buffer = [const { MaybeUninit::<u8>::uninit() }; 16];
+        let mut writer = Writer::new(&mut buffer);
+        assert_eq!(
+            event.serialize(&mut writer),
+            Err(ProgramError::InvalidArgument)
+        );
+
+        assert!(emit(&event).is_ok());
+        assert_eq!(
+            emit_with_bounds::<16, _>(&event),
+            Err(ProgramError::InvalidArgument)
+        );
+    }
+}
@@ -0,0 +1,432 @@
+//! Lightweight logging helpers built directly on top of the runtime log
+//! syscalls.
+//!
+//! The helpers in this module do not allocate; any formatting required is
+//! performed on fixed-size stack buffers.
+
+mod context;
+pub(crate) mod encoding;
+pub mod event;
+
+pub use context::{log_error_location, ErrorContext};
+use {
+    crate::error::ProgramError,
+    encoding::{base64_encoded_len, encode_base64},
+};
+
+#[cfg(any(target_os = "solana", target_arch = "bpf"))]
+use crate::syscalls::{
+    sol_log_, sol_log_64_, sol_log_compute_units_, sol_log_data, sol_remaining_compute_units,
+};
+
+/// Maximum length of a message formatted by the helpers of this module.
+///
+/// Longer messages are truncated.
+const MAX_FORMATTED_LEN: usize = 256;
+
+/// Maximum length of the return data of a program.
+const MAX_RETURN_DATA: usize = 1024;
+
+/// Maximum number of decimal digits required to represent an `u64` value.
+const MAX_U64_DIGITS: usize = 20;
+
+/// Log a message.
+#[inline(always)]
+pub fn log(message: &str) {
+    log_bytes(message.as_bytes());
+}
+
+/// Log a message represented by its raw bytes.
+///
+/// The bytes are expected to be valid UTF-8, otherwise the runtime will
+/// display them as a lossy string.
+#[inline(always)]
+pub(crate) fn log_bytes(message: &[u8]) {
+    #[cfg(any(target_os = "solana", target_arch = "bpf"))]
+    // SAFETY: The pointer and length are derived from a valid slice.
+    unsafe {
+        sol_log_(message.as_ptr(), message.len() as u64)
+    };
+
+    #[cfg(not(any(target_os = "solana", target_arch = "bpf")))]
+    {
+        #[cfg(feature = "test-syscalls")]
+        crate::test_syscalls::with(|syscalls| syscalls.log(message));
+
+        core::hint::black_box(message);
+    }
+}
+
+/// Log five `u64` values.
+///
+/// The values are displayed in hexadecimal by the runtime. This is the
+/// cheapest way to log numeric values since it does not require any
+/// formatting by the program.
+#[inline(always)]
+pub fn log_64(arg1: u64, arg2: u64, arg3: u64, arg4: u64, arg5: u64) {
+    #[cfg(any(target_os = "solana", target_arch = "bpf"))]
+    // SAFETY: The syscall only takes values as arguments.
+    unsafe {
+        sol_log_64_(arg1, arg2, arg3, arg4, arg5)
+    };
+
+    #[cfg(not(any(target_os = "solana", target_arch = "bpf")))]
+    {
+        #[cfg(feature = "test-syscalls")]
+        crate::test_syscalls::with(|syscalls| syscalls.log_64([arg1, arg2, arg3, arg4, arg5]));
+
+        core::hint::black_box((arg1, arg2, arg3, arg4, arg5));
+    }
+}
+
+/// Log data slices.
+///
+/// Each slice is displayed by the runtime as a base64-encoded string in a
+/// `Program data:` log message.
+#[inline(always)]
+pub fn log_data(data: &[&[u8]]) {
+    #[cfg(any(target_os = "solana", target_arch = "bpf"))]
+    // SAFETY: The pointer and length are derived from a valid slice of
+    // slices, which has the layout expected by the syscall.
+    unsafe {
+        sol_log_data(data.as_ptr() as *const u8, data.len() as u64)
+    };
+
+    #[cfg(not(any(target_os = "solana", target_arch = "bpf")))]
+    {
+        #[cfg(feature = "test-syscalls")]
+        crate::test_syscalls::with(|syscalls| syscalls.log_data(data));
+
+        core::hint::black_box(data);
+    }
+}
+
+/// Log the given bytes as a base64-encoded string.
+///
+/// The bytes are truncated to the maximum return data length (`1024` bytes).
+/// The encoded string is written to a fixed buffer on the stack, so this
+/// function uses about 1.4 KB of the 4 KB stack frame.
+///
+/// The message is logged as `Program log: <data>`. To set the return data
+/// of the instruction, which the runtime logs as `Program return:
+/// <program_id> <data>`, use `cpi::set_return_data` instead.
+pub fn log_base64(data: &[u8]) {
+    const MAX_LEN: usize = base64_encoded_len(MAX_RETURN_DATA);
+
+    let data = &data[..core::cmp::min(data.len(), MAX_RETURN_DATA)];
+    let mut buffer = [0u8; MAX_LEN];
+    let length = encode_base64(data, &mut buffer);
+
+    log_bytes(&buffer[..length]);
+}
+
+/// Return the canonical name of a [`ProgramError`] variant.
+///
+/// The name matches the variant identifier, e.g. `InvalidAccountData`. All
+/// custom errors are named `Custom`.
+pub const fn error_name(error: &ProgramError) -> &'static str {
+    match error {
+        ProgramError::Custom(_) => "Custom",
+        ProgramError::InvalidArgument => "InvalidArgument",
+        ProgramError::InvalidInstructionData => "InvalidInstructionData",
+        ProgramError::InvalidAccountData => "InvalidAccountData",
+        ProgramError::AccountDataTooSmall => "AccountDataTooSmall",
+        ProgramError::InsufficientFunds => "InsufficientFunds",
+        ProgramError::IncorrectProgramId => "IncorrectProgramId",
+        ProgramError::MissingRequiredSignature => "MissingRequiredSignature",
+        ProgramError::AccountAlreadyInitialized => "AccountAlreadyInitialized",
+        ProgramError::UninitializedAccount => "UninitializedAccount",
+        ProgramError::NotEnoughAccountKeys => "NotEnoughAccountKeys",
+        ProgramError::AccountBorrowFailed => "AccountBorrowFailed",
+        ProgramError::MaxSeedLengthExceeded => "MaxSeedLengthExceeded",
+        ProgramError::InvalidSeeds => "InvalidSeeds",
+        ProgramError::BorshIoError => "BorshIoError",
+        ProgramError::AccountNotRentExempt => "AccountNotRentExempt",
+        ProgramError::UnsupportedSysvar => "UnsupportedSysvar",
+        ProgramError::IllegalOwner => "IllegalOwner",
+        ProgramError::MaxAccountsDataAllocationsExceeded => "MaxAccountsDataAllocationsExceeded",
+        ProgramError::InvalidRealloc => "InvalidRealloc",
+        ProgramError::MaxInstructionTraceLengthExceeded => "MaxInstructionTraceLengthExceeded",
+        ProgramError::BuiltinProgramsMustConsumeComputeUnits => {
+            "BuiltinProgramsMustConsumeComputeUnits"
+        }
+        ProgramError::InvalidAccountOwner => "InvalidAccountOwner",
+        ProgramError::ArithmeticOverflow => "ArithmeticOverflow",
+        ProgramError::Immutable => "Immutable",
+        ProgramError::IncorrectAuthority => "IncorrectAuthority",
+    }
+}
+
+/// Log a [`ProgramError`] using its canonical name.
+///
+/// The message is in the form `Error: <name>`; custom errors also include
+/// their code, e.g. `Error: Custom(42)`.
+#[cold]
+pub fn log_error(error: &ProgramError) {
+    let mut buffer = [0u8; MAX_FORMATTED_LEN];
+    let length = format_error(&mut buffer, error);
+
+    log_bytes(&buffer[..length]);
+}
+
+/// Format a `Error: <name>` message for the given error.
+fn format_error(buffer: &mut [u8; MAX_FORMATTED_LEN], error: &ProgramError) -> usize {
+    let mut offset = write_str(buffer, 0, b"Error: ");
+    offset = write_str(buffer, offset, error_name(error).as_bytes());
+
+    if let ProgramError::Custom(code) = error {
+        offset = write_str(buffer, offset, b"(");
+        offset += write_u64(&mut buffer[offset..], *code as u64);
+        offset = write_str(buffer, offset, b")");
+    }
+
+    offset
+}
+
+/// Log the remaining compute units the program may consume.
+#[inline(always)]
+pub fn log_compute_units() {
+    #[cfg(any(target_os = "solana", target_arch = "bpf"))]
+    // SAFETY: The syscall does not take any arguments.
+    unsafe {
+        sol_log_compute_units_()
+    };
+
+    #[cfg(all(
+        feature = "test-syscalls",
+        not(any(target_os = "solana", target_arch = "bpf"))
+    ))]
+    crate::test_syscalls::with(|syscalls| syscalls.log_compute_units());
+}
+
+/// Return the remaining compute units the program may consume.
+///
+/// On non-Solana targets this function always returns `0`.
+#[inline(always)]
+pub fn remaining_compute_units() -> u64 {
+    #[cfg(any(target_os = "solana", target_arch = "bpf"))]
+    // SAFETY: The syscall does not take any arguments.
+    unsafe {
+        sol_remaining_compute_units()
+    }
+
+    #[cfg(all(
+        feature = "test-syscalls",
+        not(any(target_os = "solana", target_arch = "bpf"))
+    ))]
+    if let Some(units) = crate::test_syscalls::with(|syscalls| syscalls.remaining_compute_units()) {
+        return units;
+    }
+
+    #[cfg(not(any(target_os = "solana", target_arch = "bpf")))]
+    core::hint::black_box(0)
+}
+
+/// Scope guard that logs the compute units consumed between its creation
+/// and the moment it is dropped.
+///
+/// The guard records the remaining compute units when created and, once
+/// dropped, logs a message in the form `<label>: <n> CU`. The value reported
+/// includes the cost of the `sol_remaining_compute_units` syscall used to
+/// take the second measurement.
+///
+/// # Examples
+///
+/// ```
+/// use pinocchio::log::ComputeUnitsGuard;
+///
+/// {
+///     let _guard = ComputeUnitsGuard::new("transfer");
+///     // ...code to profile...
+/// } // logs "transfer: <n> CU"
+/// ```
+pub struct ComputeUnitsGuard<'a> {
+    /// Label included in the logged message.
+    label: &'a str,
+
+    /// Remaining compute units when the guard was created.
+    start: u64,
+}
+
+impl<'a> ComputeUnitsGuard<'a> {
+    /// Create a new guard, recording the current remaining compute units.
+    #[inline(always)]
+    pub fn new(label: &'a str) -> Self {
+        Self {
+            label,
+            start: remaining_compute_units(),
+        }
+    }
+}
+
+impl Drop for ComputeUnitsGuard<'_> {
+    fn drop(&mut self) {
+        let consumed = self.start.saturating_sub(remaining_compute_units());
+
+        let mut buffer = [0u8; MAX_FORMATTED_LEN];
+        let length = format_compute_units(&mut buffer, self.label, consumed);
+
+        log_bytes(&buffer[..length]);
+    }
+}
+
+/// Log the compute units consumed by a block of code.
+///
+/// The macro has two forms:
+///
+/// * `log_cu_usage!("label")` creates a [`ComputeUnitsGuard`] that logs the
+///   consumed compute units at the end of the enclosing scope.
+/// * `log_cu_usage!("label" => { ... })` measures only the given block and
+///   evaluates to the value of the block.
+///
+/// # Examples
+///
+/// ```
+/// use pinocchio::log_cu_usage;
+///
+/// let sum = log_cu_usage!("sum" => {
+///     (0..10u64).sum::<u64>()
+/// });
+/// assert_eq!(sum, 45);
+///
+/// fn process() {
+///     log_cu_usage!("process");
+///     // ...code to profile...
+/// } // logs "process: <n> CU"
+/// ```
+#[macro_export]
+macro_rules! log_cu_usage {
+    ( $label:expr ) => {
+        let __compute_units_guard = $crate::log::ComputeUnitsGuard::new($label);
+    };
+    ( $label:expr => $block:block ) => {{
+        let __compute_units_guard = $crate::log::ComputeUnitsGuard::new($label);
+        let __result = $block;
+        drop(__compute_units_guard);
+        __result
+    }};
+}
+
+/// Write the decimal representation of `value` to the start of `buffer`.
+///
+/// Returns the number of bytes written. The caller must ensure that the
+/// buffer has at least [`MAX_U64_DIGITS`] bytes available.
+#[inline]
+fn write_u64(buffer: &mut [u8], mut value: u64) -> usize {
+    let mut digits = [0u8; MAX_U64_DIGITS];
+    let mut offset = MAX_U64_DIGITS;
+
+    loop {
+        offset -= 1;
+        digits[offset] = b'0' + (value % 10) as u8;
+        value /= 10;
+
+        if value == 0 {
+            break;
+        }
+    }
+
+    let length = MAX_U64_DIGITS - offset;
+    buffer[..length].copy_from_slice(&digits[offset..]);
+    length
+}
+
+/// Write `source` to `buffer` at `offset`, truncating it to the available
+/// space.
+///
+/// Returns the offset after the written bytes.
+#[inline(always)]
+fn write_str(buffer: &mut [u8], offset: usize, source: &[u8]) -> usize {
+    let length = core::cmp::min(buffer.len().saturating_sub(offset), source.len());
+    buffer[offset..offset + length].copy_from_slice(&source[..length]);
+    offset + length
+}
+
+/// Return the bytes of `value` truncated to at most `limit` bytes on a
+/// character boundary.
+#[inline(always)]
+fn truncate_str(value: &str, limit: usize) -> &[u8] {
+    let mut length = core::cmp::min(value.len(), limit);
+
+    while !value.is_char_boundary(length) {
+        length -= 1;
+    }
+
+    &value.as_bytes()[..length]
+}
+
+/// Format a compute units message in the form `<label>: <n> CU`.
+///
+/// The label is truncated on a character boundary if needed so that the value
+/// is always included. Returns the length of the formatted message.
+fn format_compute_units(buffer: &mut [u8; MAX_FORMATTED_LEN], label: &str, value: u64) -> usize {
+    const SEPARATOR: &[u8] = b": ";
+    const SUFFIX: &[u8] = b" CU";
+
+    let label_limit = MAX_FORMATTED_LEN - SEPARATOR.len() - MAX_U64_DIGITS - SUFFIX.len();
+
+    let mut offset = write_str(buffer, 0, truncate_str(label, label_limit));
+    offset = write_str(buffer, offset, SEPARATOR);
+    offset += write_u64(&mut buffer[offset..], value);
+    write_str(buffer, offset, SUFFIX)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_write_u64() {
+        let mut buffer = [0u8; MAX_U64_DIGITS];
+
+        let length = write_u64(&mut buffer, 0);
+        assert_eq!(&buffer[..length], b"0");
+
+        let length = write_u64(&mut buffer, 1_400);
+        assert_eq!(&buffer[..length], b"1400");
+
+        let length = write_u64(&mut buffer, u64::MAX);
+        assert_eq!(&buffer[..length], b"18446744073709551615");
+    }
+
+    #[test]
+    fn test_format_compute_units() {
+        let mut buffer = [0u8; MAX_FORMATTED_LEN];
+
+        let length = format_compute_units(&mut buffer, "transfer", 2_750);
+        assert_eq!(&buffer[..length], b"transfer: 2750 CU");
+
+        // Long labels are truncated so the value is always present.
+        let label = [b'x'; MAX_FORMATTED_LEN];
+        let label = core::str::from_utf8(&label).unwrap();
+        let length = format_compute_units(&mut buffer, label, u64::MAX);
+        assert_eq!(length, MAX_FORMATTED_LEN);
+        assert!(buffer[..length].ends_with(b": 18446744073709551615 CU"));
+
+        // Multi-byte characters are not split by the truncation.
+        let label = "\u{e9}".repeat(MAX_FORMATTED_LEN);
+        let length = format_compute_units(&mut buffer, &label, u64::MAX);
+        assert_eq!(length, MAX_FORMATTED_LEN - 1);
+        assert!(core::str::from_utf8(&buffer[..length]).is_ok());
+        assert!(buffer[..length].ends_with(b": 18446744073709551615 CU"));
+    }
+
+    #[test]
+    fn test_format_error() {
+        let mut buffer = [0u8; MAX_FORMATTED_LEN];
+
+        let length = format_error(&mut buffer, &ProgramError::InvalidAccountData);
+        assert_eq!(&buffer[..length], b"Error: InvalidAccountData");
+
+        let length = format_error(&mut buffer, &ProgramError::Custom(42));
+        assert_eq!(&buffer[..length], b"Error: Custom(42)");
+    }
+
+    #[test]
+    fn test_log_cu_usage() {
+        let value = log_cu_usage!("block" => { 1 + 1 });
+        assert_eq!(value, 2);
+
+        log_cu_usage!("scope");
+    }
+}
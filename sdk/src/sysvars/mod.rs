@@ -9,6 +9,7 @@ use core::hint::black_box;
 pub mod clock;
 pub mod fees;
 pub mod instructions;
+pub mod recent_blockhashes;
 pub mod rent;
 pub mod slot_hashes;
 
@@ -16,12 +17,12 @@ pub mod slot_hashes;
 /// length of the sysvar data.
 //
 // Defined in the bpf loader as [`OFFSET_LENGTH_EXCEEDS_SYSVAR`](https://github.com/anza-xyz/agave/blob/master/programs/bpf_loader/src/syscalls/sysvar.rs#L172).
-const OFFSET_LENGTH_EXCEEDS_SYSVAR: u64 = 1;
+pub(crate) const OFFSET_LENGTH_EXCEEDS_SYSVAR: u64 = 1;
 
 /// Return value indicating that the sysvar was not found.
 //
 // Defined in the bpf loader as [`SYSVAR_NOT_FOUND`](https://github.com/anza-xyz/agave/blob/master/programs/bpf_loader/src/syscalls/sysvar.rs#L171).
-const SYSVAR_NOT_FOUND: u64 = 2;
+pub(crate) const SYSVAR_NOT_FOUND: u64 = 2;
 
 /// A type that holds sysvar data.
 pub trait Sysvar: Sized {
@@ -93,7 +94,10 @@ macro_rules! impl_sysvar_get {
             let result = {
                 // SAFETY: The allocation is valid for the size of `Self`.
                 unsafe { var_addr.write_bytes(0, size_of::<Self>()) };
-                core::hint::black_box(var_addr as *const _ as u64)
+                let sysvar_id = &$syscall_id;
+                let length = core::mem::size_of::<Self>() - $padding;
+                // SAFETY: The allocation is valid for the size of `Self`.
+                unsafe { $crate::sysvars::get_sysvar_host(sysvar_id, var_addr, 0, length) }
             };
 
             match result {
@@ -151,11 +155,51 @@ pub unsafe fn get_sysvar_unchecked(
 
     #[cfg(not(any(target_os = "solana", target_arch = "bpf")))]
     {
+        #[cfg(feature = "test-syscalls")]
+        if let Some(result) = crate::test_syscalls::with(|syscalls| {
+            // SAFETY: The caller guarantees that `dst` is valid for `len` bytes.
+            let dst = unsafe { core::slice::from_raw_parts_mut(dst, len) };
+            syscalls.get_sysvar(sysvar_id, dst, offset as u64)
+        }) {
+            return match result {
+                crate::SUCCESS => Ok(()),
+                OFFSET_LENGTH_EXCEEDS_SYSVAR => Err(ProgramError::InvalidArgument),
+                // Unexpected errors are folded into `UnsupportedSysvar`.
+                _ => Err(ProgramError::UnsupportedSysvar),
+            };
+        }
+
         black_box((dst, sysvar_id, offset, len));
         Ok(())
     }
 }
 
+/// Host replacement of the `sol_get_sysvar` syscall used by
+/// [`impl_sysvar_get!`].
+///
+/// The call is routed to the registered syscalls when the `test-syscalls`
+/// feature is enabled; otherwise, it returns a non-zero value.
+///
+/// # Safety
+///
+/// The caller must ensure that the `dst` pointer is valid and has enough space
+/// to hold the requested `len` bytes of data.
+#[cfg(not(any(target_os = "solana", target_arch = "bpf")))]
+#[doc(hidden)]
+pub unsafe fn get_sysvar_host(sysvar_id: &Address, dst: *mut u8, offset: u64, len: usize) -> u64 {
+    #[cfg(feature = "test-syscalls")]
+    if let Some(result) = crate::test_syscalls::with(|syscalls| {
+        // SAFETY: The caller guarantees that `dst` is valid for `len` bytes.
+        let dst = unsafe { core::slice::from_raw_parts_mut(dst, len) };
+        syscalls.get_sysvar(sysvar_id, dst, offset)
+    }) {
+        return result;
+    }
+
+    black_box((sysvar_id, offset, len));
+    black_box(dst as *const _ as u64)
+}
+
 /// Handler for retrieving a slice of sysvar data from the `sol_get_sysvar`
 /// syscall.
 #[inline(always)]
@@ -0,0 +1,26 @@
+//! The recent blockhashes sysvar.
+//!
+//! This sysvar is deprecated and its data should not be relied upon by
+//! programs. The account is still required by the durable nonce
+//! instructions of the system program, so only its ID is provided.
+
+use crate::Address;
+
+/// The ID of the recent blockhashes sysvar.
+pub const RECENT_BLOCKHASHES_ID: Address = Address::new_from_array([
+    6, 167, 213, 23, 25, 44, 86, 142, 224, 138, 132, 95, 115, 210, 151, 136, 207, 3, 92, 49, 69,
+    178, 26, 179, 68, 216, 6, 46, 169, 64, 0, 0,
+]);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_recent_blockhashes_id() {
+        assert_eq!(
+            RECENT_BLOCKHASHES_ID,
+            Address::from_str_const("SysvarRecentB1ockHashes11111111111111111111")
+        );
+    }
+}
@@ -323,9 +323,21 @@ impl SlotHashes<Box<[u8]>> {
         crate::sysvars::get_sysvar_unchecked(buffer_ptr, &SLOTHASHES_ID, 0, MAX_SIZE)?;
 
         // For tests on builds that don't actually fill the buffer.
-        #[cfg(not(any(target_os = "solana", target_arch = "bpf")))]
+        #[cfg(all(
+            not(any(target_os = "solana", target_arch = "bpf")),
+            not(feature = "test-syscalls")
+        ))]
         core::ptr::write_bytes(buffer_ptr, 0, NUM_ENTRIES_SIZE);
 
+        // Unless the data was provided by the registered syscalls.
+        #[cfg(all(
+            not(any(target_os = "solana", target_arch = "bpf")),
+            feature = "test-syscalls"
+        ))]
+        if !crate::test_syscalls::registered() {
+            core::ptr::write_bytes(buffer_ptr, 0, NUM_ENTRIES_SIZE);
+        }
+
         Ok(())
     }
 
@@ -0,0 +1,1062 @@
+//! Host implementations of the runtime syscalls.
+//!
+//! On non-Solana targets, syscalls are replaced by stubs that do nothing,
+//! which means that code paths logging messages, reading sysvars or invoking
+//! other programs cannot be exercised in `cargo test`. This module provides a
+//! per-thread registry of [`Syscalls`] implementations used instead of the
+//! stubs:
+//!
+//! ```ignore
+//! use pinocchio::{
+//!     sysvars::{clock::{Clock, CLOCK_ID}, Sysvar},
+//!     test_syscalls::{set_syscalls, HostSyscalls},
+//! };
+//!
+//! set_syscalls(HostSyscalls::default().with_sysvar(CLOCK_ID, &clock_bytes));
+//!
+//! let clock = Clock::get()?;
+//! ```
+//!
+//! The registry is thread local, so tests running in parallel do not
+//! interfere with each other. When no implementation is registered, the
+//! stubs behave as if the feature was disabled.
+//!
+//! The following syscalls are routed through the registry:
+//!
+//! - [`log`](crate::log::log), [`log_64`](crate::log::log_64),
+//!   [`log_data`](crate::log::log_data),
+//!   [`log_compute_units`](crate::log::log_compute_units) and
+//!   [`remaining_compute_units`](crate::log::remaining_compute_units), as well
+//!   as the helpers of the [`log`](crate::log) module built on top of them.
+//! - [`Sysvar::get`](crate::sysvars::Sysvar::get) for sysvars read through
+//!   `sol_get_sysvar`, and [`get_sysvar`](crate::sysvars::get_sysvar).
+//! - The `invoke*` functions of the [`cpi`] module, after the
+//!   validation of the accounts is performed. The `*_unchecked` variants are
+//!   not routed.
+//!
+//! Log messages emitted during a test can be recorded and asserted on with a
+//! [`LogCapture`], and cross-program invocations with an `InvokeRecorder`.
+//!
+//! Hashing is not covered since the crate does not provide wrappers for the
+//! hashing syscalls.
+
+extern crate std;
+
+#[cfg(feature = "cpi")]
+use crate::{
+    cpi::{Seed, Signer},
+    instruction::InstructionView,
+    AccountView, ProgramResult,
+};
+use {
+    crate::{
+        sysvars::{rent::RENT_ID, SYSVAR_NOT_FOUND},
+        Address,
+    },
+    alloc::{boxed::Box, rc::Rc, string::String, vec::Vec},
+    core::cell::RefCell,
+    std::{format, println},
+};
+
+/// Host implementation of the runtime syscalls.
+///
+/// All methods have a default implementation matching the behaviour of the
+/// stubs, so implementations only need to override the syscalls they use.
+pub trait Syscalls {
+    /// Log a message (`sol_log_`).
+    fn log(&mut self, message: &[u8]) {
+        let _ = message;
+    }
+
+    /// Log five `u64` values (`sol_log_64_`).
+    fn log_64(&mut self, values: [u64; 5]) {
+        let _ = values;
+    }
+
+    /// Log data slices (`sol_log_data`).
+    fn log_data(&mut self, data: &[&[u8]]) {
+        let _ = data;
+    }
+
+    /// Log the remaining compute units (`sol_log_compute_units_`).
+    fn log_compute_units(&mut self) {}
+
+    /// Return the remaining compute units (`sol_remaining_compute_units`).
+    fn remaining_compute_units(&mut self) -> u64 {
+        0
+    }
+
+    /// Copy `dst.len()` bytes of the sysvar data starting at `offset` into
+    /// `dst` (`sol_get_sysvar`).
+    ///
+    /// Return [`SUCCESS`](crate::SUCCESS) or the error code of the syscall.
+    fn get_sysvar(&mut self, sysvar_id: &Address, dst: &mut [u8], offset: u64) -> u64 {
+        let _ = (sysvar_id, dst, offset);
+        SYSVAR_NOT_FOUND
+    }
+
+    /// Invoke a cross-program instruction (`sol_invoke_signed_c`).
+    ///
+    /// This is called after the accounts of the instruction are validated.
+    #[cfg(feature = "cpi")]
+    fn invoke(
+        &mut self,
+        instruction: &InstructionView,
+        account_views: &[&AccountView],
+        signers: &[Signer],
+    ) -> ProgramResult {
+        let _ = (instruction, account_views, signers);
+        Ok(())
+    }
+}
+
+/// [`Syscalls`] implementation that prints log messages using the runtime
+/// format and serves sysvar data set by the test.
+#[derive(Default)]
+pub struct HostSyscalls {
+    compute_units: u64,
+    sysvars: Vec<(Address, Vec<u8>)>,
+}
+
+impl HostSyscalls {
+    /// Set the value returned as the remaining compute units.
+    pub fn with_compute_units(mut self, compute_units: u64) -> Self {
+        self.compute_units = compute_units;
+        self
+    }
+
+    /// Set the data of a sysvar.
+    ///
+    /// The data must follow the bincode serialization of the sysvar, which is
+    /// the layout returned by the `sol_get_sysvar` syscall.
+    pub fn with_sysvar(mut self, sysvar_id: Address, data: &[u8]) -> Self {
+        self.sysvars.retain(|(id, _)| id != &sysvar_id);
+        self.sysvars.push((sysvar_id, data.to_vec()));
+        self
+    }
+
+    /// Set the data of the `Rent` sysvar.
+    ///
+    /// The burn percent, which is no longer used by the runtime, is set to
+    /// `50`.
+    pub fn with_rent(self, lamports_per_byte: u64, exemption_threshold: f64) -> Self {
+        let mut data = Vec::with_capacity(17);
+        data.extend_from_slice(&lamports_per_byte.to_le_bytes());
+        data.extend_from_slice(&exemption_threshold.to_le_bytes());
+        data.push(50);
+
+        self.with_sysvar(RENT_ID, &data)
+    }
+}
+
+impl Syscalls for HostSyscalls {
+    fn log(&mut self, message: &[u8]) {
+        println!("{}", format_log(message));
+    }
+
+    fn log_64(&mut self, values: [u64; 5]) {
+        println!("{}", format_log_64(values));
+    }
+
+    fn log_data(&mut self, data: &[&[u8]]) {
+        println!("{}", format_log_data(data));
+    }
+
+    fn log_compute_units(&mut self) {
+        println!("{}", format_log_compute_units(self.compute_units));
+    }
+
+    fn remaining_compute_units(&mut self) -> u64 {
+        self.compute_units
+    }
+
+    fn get_sysvar(&mut self, sysvar_id: &Address, dst: &mut [u8], offset: u64) -> u64 {
+        let Some((_, data)) = self.sysvars.iter().find(|(id, _)| id == sysvar_id) else {
+            return SYSVAR_NOT_FOUND;
+        };
+
+        match (offset as usize)
+            .checked_add(dst.len())
+            .and_then(|end| data.get(offset as usize..end))
+        {
+            Some(data) => {
+                dst.copy_from_slice(data);
+                crate::SUCCESS
+            }
+            None => crate::sysvars::OFFSET_LENGTH_EXCEEDS_SYSVAR,
+        }
+    }
+}
+
+/// Format a `sol_log_` message as printed by the runtime.
+fn format_log(message: &[u8]) -> String {
+    format!("Program log: {}", String::from_utf8_lossy(message))
+}
+
+/// Format a `sol_log_64_` message as printed by the runtime.
+fn format_log_64(values: [u64; 5]) -> String {
+    let [a, b, c, d, e] = values;
+    format!("Program log: {a:#x}, {b:#x}, {c:#x}, {d:#x}, {e:#x}")
+}
+
+/// Format a `sol_log_data` message as printed by the runtime.
+fn format_log_data(data: &[&[u8]]) -> String {
+    let encoded = data
+        .iter()
+        .map(|data| {
+            let mut buffer = alloc::vec![0; crate::log::encoding::base64_encoded_len(data.len())];
+            let length = crate::log::encoding::encode_base64(data, &mut buffer);
+            buffer.truncate(length);
+            String::from_utf8_lossy(&buffer).into_owned()
+        })
+        .collect::<Vec<_>>();
+    format!("Program data: {}", encoded.join(" "))
+}
+
+/// Format a `sol_log_compute_units_` message as printed by the runtime.
+fn format_log_compute_units(compute_units: u64) -> String {
+    format!("Program consumption: {compute_units} units remaining")
+}
+
+std::thread_local! {
+    static SYSCALLS: RefCell<Option<Box<dyn Syscalls>>> = const { RefCell::new(None) };
+}
+
+/// Register the syscalls implementation of the current thread, returning the
+/// previous one.
+pub fn set_syscalls<S: Syscalls + 'static>(syscalls: S) -> Option<Box<dyn Syscalls>> {
+    SYSCALLS.with(|current| current.borrow_mut().replace(Box::new(syscalls)))
+}
+
+/// Remove the syscalls implementation of the current thread, returning it.
+pub fn reset_syscalls() -> Option<Box<dyn Syscalls>> {
+    SYSCALLS.with(|current| current.borrow_mut().take())
+}
+
+/// Indicate whether a syscalls implementation is registered in the current
+/// thread.
+pub(crate) fn registered() -> bool {
+    SYSCALLS.with(|current| current.borrow().is_some())
+}
+
+/// Call `f` with the syscalls implementation of the current thread.
+///
+/// Return `None` when no implementation is registered. The implementation is
+/// removed from the registry while `f` runs, so syscalls made from `f` (e.g.,
+/// by a program invoked through [`Syscalls::invoke`]) use the stubs.
+pub(crate) fn with<R>(f: impl FnOnce(&mut dyn Syscalls) -> R) -> Option<R> {
+    let mut syscalls = SYSCALLS.with(|current| current.borrow_mut().take())?;
+    let result = f(syscalls.as_mut());
+
+    SYSCALLS.with(|current| {
+        let mut current = current.borrow_mut();
+        // Keep any implementation registered by `f`.
+        if current.is_none() {
+            *current = Some(syscalls);
+        }
+    });
+
+    Some(result)
+}
+
+/// Records the messages logged in the current thread.
+///
+/// Creating a `LogCapture` registers a [`Syscalls`] implementation that
+/// records every log message using the format of the runtime (e.g.,
+/// `"Program log: hello"`) and forwards all syscalls to the implementation
+/// previously registered, if any. The previous implementation is restored
+/// when the `LogCapture` is dropped.
+///
+/// ```ignore
+/// use pinocchio::{log::log, test_syscalls::LogCapture};
+///
+/// let logs = LogCapture::new();
+///
+/// log("Instruction: Deposit");
+///
+/// logs.assert_log_contains("Instruction: Deposit");
+/// ```
+pub struct LogCapture {
+    state: Rc<RefCell<Capture>>,
+}
+
+/// State shared between a [`LogCapture`] and its registered syscalls.
+struct Capture {
+    logs: Vec<String>,
+    inner: Option<Box<dyn Syscalls>>,
+}
+
+/// [`Syscalls`] implementation registered by a [`LogCapture`].
+struct CaptureSyscalls(Rc<RefCell<Capture>>);
+
+impl LogCapture {
+    /// Start capturing the log messages of the current thread.
+    pub fn new() -> Self {
+        let state = Rc::new(RefCell::new(Capture {
+            logs: Vec::new(),
+            inner: reset_syscalls(),
+        }));
+        set_syscalls(CaptureSyscalls(state.clone()));
+
+        Self { state }
+    }
+
+    /// Return the messages logged since the capture started.
+    pub fn logs(&self) -> Vec<String> {
+        self.state.borrow().logs.clone()
+    }
+
+    /// Discard the messages logged so far.
+    pub fn clear(&self) {
+        self.state.borrow_mut().logs.clear();
+    }
+
+    /// Indicate whether any logged message contains `expected`.
+    pub fn contains(&self, expected: &str) -> bool {
+        self.state
+            .borrow()
+            .logs
+            .iter()
+            .any(|message| message.contains(expected))
+    }
+
+    /// Assert that a logged message contains `expected`.
+    ///
+    /// # Panics
+    ///
+    /// Panics with the list of logged messages if no message contains
+    /// `expected`.
+    #[track_caller]
+    pub fn assert_log_contains(&self, expected: &str) {
+        assert!(
+            self.contains(expected),
+            "no log message contains {expected:?}, logged messages: {:#?}",
+            self.state.borrow().logs
+        );
+    }
+}
+
+impl Default for LogCapture {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Drop for LogCapture {
+    fn drop(&mut self) {
+        reset_syscalls();
+
+        if let Some(inner) = self.state.borrow_mut().inner.take() {
+            SYSCALLS.with(|current| *current.borrow_mut() = Some(inner));
+        }
+    }
+}
+
+impl CaptureSyscalls {
+    /// Record `message` and forward the call to the inner implementation.
+    fn record(&mut self, message: String, f: impl FnOnce(&mut dyn Syscalls)) {
+        let mut state = self.0.borrow_mut();
+        state.logs.push(message);
+
+        if let Some(inner) = state.inner.as_mut() {
+            f(inner.as_mut());
+        }
+    }
+}
+
+impl Syscalls for CaptureSyscalls {
+    fn log(&mut self, message: &[u8]) {
+        self.record(format_log(message), |inner| inner.log(message));
+    }
+
+    fn log_64(&mut self, values: [u64; 5]) {
+        self.record(format_log_64(values), |inner| inner.log_64(values));
+    }
+
+    fn log_data(&mut self, data: &[&[u8]]) {
+        self.record(format_log_data(data), |inner| inner.log_data(data));
+    }
+
+    fn log_compute_units(&mut self) {
+        let compute_units = self.remaining_compute_units();
+        self.record(format_log_compute_units(compute_units), |inner| {
+            inner.log_compute_units()
+        });
+    }
+
+    fn remaining_compute_units(&mut self) -> u64 {
+        match self.0.borrow_mut().inner.as_mut() {
+            Some(inner) => inner.remaining_compute_units(),
+            None => 0,
+        }
+    }
+
+    fn get_sysvar(&mut self, sysvar_id: &Address, dst: &mut [u8], offset: u64) -> u64 {
+        match self.0.borrow_mut().inner.as_mut() {
+            Some(inner) => inner.get_sysvar(sysvar_id, dst, offset),
+            None => SYSVAR_NOT_FOUND,
+        }
+    }
+
+    #[cfg(feature = "cpi")]
+    fn invoke(
+        &mut self,
+        instruction: &InstructionView,
+        account_views: &[&AccountView],
+        signers: &[Signer],
+    ) -> ProgramResult {
+        match self.0.borrow_mut().inner.as_mut() {
+            Some(inner) => inner.invoke(instruction, account_views, signers),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Cross-program invocation recorded by an [`InvokeRecorder`].
+#[cfg(feature = "cpi")]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Invocation {
+    /// Address of the invoked program.
+    pub program_id: Address,
+
+    /// Accounts of the instruction.
+    pub accounts: Vec<InvokedAccount>,
+
+    /// Instruction data.
+    pub data: Vec<u8>,
+
+    /// Seeds of each signer of the invocation.
+    pub signers_seeds: Vec<Vec<Vec<u8>>>,
+}
+
+#[cfg(feature = "cpi")]
+impl Invocation {
+    /// Create an invocation of `instruction` without signers.
+    pub fn new(instruction: &InstructionView) -> Self {
+        Self {
+            program_id: Address::new_from_array(instruction.program_id.to_bytes()),
+            accounts: instruction
+                .accounts
+                .iter()
+                .map(|account| InvokedAccount {
+                    address: Address::new_from_array(account.address.to_bytes()),
+                    is_writable: account.is_writable,
+                    is_signer: account.is_signer,
+                })
+                .collect(),
+            data: instruction.data.to_vec(),
+            signers_seeds: Vec::new(),
+        }
+    }
+
+    /// Add a signer with the given seeds to the invocation.
+    pub fn with_signer(mut self, seeds: &[&[u8]]) -> Self {
+        self.signers_seeds
+            .push(seeds.iter().map(|seed| seed.to_vec()).collect());
+        self
+    }
+}
+
+/// Account of an [`Invocation`].
+#[cfg(feature = "cpi")]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct InvokedAccount {
+    /// Address of the account.
+    pub address: Address,
+
+    /// Indicates whether the account is writable.
+    pub is_writable: bool,
+
+    /// Indicates whether the account is a signer.
+    pub is_signer: bool,
+}
+
+/// Records the cross-program invocations issued in the current thread.
+///
+/// Creating an `InvokeRecorder` registers a [`Syscalls`] implementation that
+/// records the program id, accounts, data and signer seeds of every
+/// invocation, and forwards all syscalls to the implementation previously
+/// registered, if any. Invocations succeed when there is no previous
+/// implementation. The previous implementation is restored when the
+/// `InvokeRecorder` is dropped.
+///
+/// ```ignore
+/// use pinocchio::test_syscalls::{Invocation, InvokeRecorder};
+///
+/// let invokes = InvokeRecorder::new();
+///
+/// process_instruction(&program_id, &accounts, &data)?;
+///
+/// invokes.assert_invoked(&[Invocation::new(&expected).with_signer(&[b"vault", &[bump]])]);
+/// ```
+#[cfg(feature = "cpi")]
+pub struct InvokeRecorder {
+    state: Rc<RefCell<Recording>>,
+}
+
+/// State shared between an [`InvokeRecorder`] and its registered syscalls.
+#[cfg(feature = "cpi")]
+struct Recording {
+    invocations: Vec<Invocation>,
+    inner: Option<Box<dyn Syscalls>>,
+}
+
+/// [`Syscalls`] implementation registered by an [`InvokeRecorder`].
+#[cfg(feature = "cpi")]
+struct RecorderSyscalls(Rc<RefCell<Recording>>);
+
+#[cfg(feature = "cpi")]
+impl InvokeRecorder {
+    /// Start recording the invocations of the current thread.
+    pub fn new() -> Self {
+        let state = Rc::new(RefCell::new(Recording {
+            invocations: Vec::new(),
+            inner: reset_syscalls(),
+        }));
+        set_syscalls(RecorderSyscalls(state.clone()));
+
+        Self { state }
+    }
+
+    /// Return the invocations issued since the recording started.
+    pub fn invocations(&self) -> Vec<Invocation> {
+        self.state.borrow().invocations.clone()
+    }
+
+    /// Discard the invocations recorded so far.
+    pub fn clear(&self) {
+        self.state.borrow_mut().invocations.clear();
+    }
+
+    /// Assert that exactly the `expected` invocations were issued, in order.
+    ///
+    /// # Panics
+    ///
+    /// Panics with both lists of invocations if they differ.
+    #[track_caller]
+    pub fn assert_invoked(&self, expected: &[Invocation]) {
+        assert_eq!(
+            self.state.borrow().invocations,
+            expected,
+            "recorded invocations do not match the expected ones"
+        );
+    }
+}
+
+#[cfg(feature = "cpi")]
+impl Default for InvokeRecorder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "cpi")]
+impl Drop for InvokeRecorder {
+    fn drop(&mut self) {
+        reset_syscalls();
+
+        if let Some(inner) = self.state.borrow_mut().inner.take() {
+            SYSCALLS.with(|current| *current.borrow_mut() = Some(inner));
+        }
+    }
+}
+
+#[cfg(feature = "cpi")]
+impl RecorderSyscalls {
+    /// Forward a call to the inner implementation, returning `default` when
+    /// there is none.
+    fn forward<R>(&mut self, default: R, f: impl FnOnce(&mut dyn Syscalls) -> R) -> R {
+        match self.0.borrow_mut().inner.as_mut() {
+            Some(inner) => f(inner.as_mut()),
+            None => default,
+        }
+    }
+}
+
+#[cfg(feature = "cpi")]
+impl Syscalls for RecorderSyscalls {
+    fn log(&mut self, message: &[u8]) {
+        self.forward((), |inner| inner.log(message));
+    }
+
+    fn log_64(&mut self, values: [u64; 5]) {
+        self.forward((), |inner| inner.log_64(values));
+    }
+
+    fn log_data(&mut self, data: &[&[u8]]) {
+        self.forward((), |inner| inner.log_data(data));
+    }
+
+    fn log_compute_units(&mut self) {
+        self.forward((), |inner| inner.log_compute_units());
+    }
+
+    fn remaining_compute_units(&mut self) -> u64 {
+        self.forward(0, |inner| inner.remaining_compute_units())
+    }
+
+    fn get_sysvar(&mut self, sysvar_id: &Address, dst: &mut [u8], offset: u64) -> u64 {
+        self.forward(SYSVAR_NOT_FOUND, |inner| {
+            inner.get_sysvar(sysvar_id, dst, offset)
+        })
+    }
+
+    fn invoke(
+        &mut self,
+        instruction: &InstructionView,
+        account_views: &[&AccountView],
+        signers: &[Signer],
+    ) -> ProgramResult {
+        let mut invocation = Invocation::new(instruction);
+        invocation.signers_seeds = signers.iter().map(signer_seeds).collect();
+        self.0.borrow_mut().invocations.push(invocation);
+
+        self.forward(Ok(()), |inner| {
+            inner.invoke(instruction, account_views, signers)
+        })
+    }
+}
+
+/// Memory layout of a [`Signer`].
+///
+/// `Signer` is `repr(C)` to match the layout expected by
+/// `sol_invoke_signed_c`, but does not expose its seeds.
+#[cfg(feature = "cpi")]
+#[repr(C)]
+struct SignerLayout<'a> {
+    seeds: *const Seed<'a>,
+    len: u64,
+}
+
+// Assert that the size of `SignerLayout` matches the size of `Signer`.
+#[cfg(feature = "cpi")]
+const _ASSERT_SIGNER_LEN: () =
+    assert!(core::mem::size_of::<SignerLayout>() == core::mem::size_of::<Signer>());
+
+// Assert that the alignment of `SignerLayout` matches the alignment of `Signer`.
+#[cfg(feature = "cpi")]
+const _ASSERT_SIGNER_ALIGN: () =
+    assert!(core::mem::align_of::<SignerLayout>() == core::mem::align_of::<Signer>());
+
+/// Return a copy of the seeds of `signer`.
+#[cfg(feature = "cpi")]
+fn signer_seeds(signer: &Signer) -> Vec<Vec<u8>> {
+    // SAFETY: `Signer` and `SignerLayout` have the same `repr(C)` layout, and
+    // the seeds are valid while the signer is borrowed.
+    let seeds = unsafe {
+        let layout = &*(signer as *const Signer as *const SignerLayout);
+        core::slice::from_raw_parts(layout.seeds, layout.len as usize)
+    };
+    seeds.iter().map(|seed| seed.to_vec()).collect()
+}
+
+/// Cross-program invocation helpers routed through the registry.
+///
+/// This module re-exports [`solana_instruction_view::cpi`], replacing the
+/// `invoke*` functions with versions that call [`Syscalls::invoke`].
+#[cfg(feature = "cpi")]
+pub mod cpi {
+    pub use solana_instruction_view::cpi::*;
+    use {
+        crate::{instruction::InstructionView, AccountView, ProgramResult},
+        solana_instruction_view::cpi as view,
+    };
+
+    /// Invoke a cross-program instruction.
+    ///
+    /// See [`solana_instruction_view::cpi::invoke`].
+    #[inline(always)]
+    pub fn invoke<const ACCOUNTS: usize>(
+        instruction: &InstructionView,
+        account_views: &[&AccountView; ACCOUNTS],
+    ) -> ProgramResult {
+        invoke_signed(instruction, account_views, &[])
+    }
+
+    /// Invoke a cross-program instruction with signatures.
+    ///
+    /// See [`solana_instruction_view::cpi::invoke_signed`].
+    #[inline(always)]
+    pub fn invoke_signed<const ACCOUNTS: usize>(
+        instruction: &InstructionView,
+        account_views: &[&AccountView; ACCOUNTS],
+        signers_seeds: &[Signer],
+    ) -> ProgramResult {
+        view::invoke_signed(instruction, account_views, signers_seeds)?;
+        super::invoke(instruction, account_views, signers_seeds)
+    }
+
+    /// Invoke a cross-program instruction with a maximum number of accounts.
+    ///
+    /// See [`solana_instruction_view::cpi::invoke_with_bounds`].
+    #[inline(always)]
+    pub fn invoke_with_bounds<const MAX_ACCOUNTS: usize>(
+        instruction: &InstructionView,
+        account_views: &[&AccountView],
+    ) -> ProgramResult {
+        invoke_signed_with_bounds::<MAX_ACCOUNTS>(instruction, account_views, &[])
+    }
+
+    /// Invoke a cross-program instruction with signatures and a maximum
+    /// number of accounts.
+    ///
+    /// See [`solana_instruction_view::cpi::invoke_signed_with_bounds`].
+    #[inline(always)]
+    pub fn invoke_signed_with_bounds<const MAX_ACCOUNTS: usize>(
+        instruction: &InstructionView,
+        account_views: &[&AccountView],
+        signers_seeds: &[Signer],
+    ) -> ProgramResult {
+        view::invoke_signed_with_bounds::<MAX_ACCOUNTS>(instruction, account_views, signers_seeds)?;
+        super::invoke(instruction, account_views, signers_seeds)
+    }
+
+    /// Invoke a cross-program instruction from a slice of `AccountView`s.
+    ///
+    /// See [`solana_instruction_view::cpi::invoke_with_slice`].
+    #[inline(always)]
+    pub fn invoke_with_slice(
+        instruction: &InstructionView,
+        account_views: &[&AccountView],
+    ) -> ProgramResult {
+        invoke_signed_with_slice(instruction, account_views, &[])
+    }
+
+    /// Invoke a cross-program instruction with signatures from a slice of
+    /// `AccountView`s.
+    ///
+    /// See [`solana_instruction_view::cpi::invoke_signed_with_slice`].
+    #[inline(always)]
+    pub fn invoke_signed_with_slice(
+        instruction: &InstructionView,
+        account_views: &[&AccountView],
+        signers_seeds: &[Signer],
+    ) -> ProgramResult {
+        view::invoke_signed_with_slice(instruction, account_views, signers_seeds)?;
+        super::invoke(instruction, account_views, signers_seeds)
+    }
+}
+
+#[cfg(feature = "cpi")]
+fn invoke(
+    instruction: &InstructionView,
+    account_views: &[&AccountView],
+    signers: &[Signer],
+) -> ProgramResult {
+    with(|syscalls| syscalls.invoke(instruction, account_views, signers)).unwrap_or(Ok(()))
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::{
+            log::{log, remaining_compute_units},
+            sysvars::{
+                clock::{Clock, CLOCK_ID},
+                get_sysvar,
+                rent::Rent,
+                Sysvar,
+            },
+        },
+        alloc::vec,
+    };
+
+    #[derive(Default)]
+    struct Recorder {
+        logs: Rc<RefCell<Vec<Vec<u8>>>>,
+    }
+
+    impl Syscalls for Recorder {
+        fn log(&mut self, message: &[u8]) {
+            self.logs.borrow_mut().push(message.to_vec());
+        }
+    }
+
+    fn clock_bytes() -> Vec<u8> {
+        [1u64, 2, 3, 4, 5]
+            .iter()
+            .flat_map(|value| value.to_le_bytes())
+            .collect()
+    }
+
+    #[test]
+    fn test_log() {
+        let recorder = Recorder::default();
+        let logs = recorder.logs.clone();
+
+        // Without an implementation, logs are discarded.
+        log("discarded");
+
+        assert!(set_syscalls(recorder).is_none());
+        log("hello");
+        log("world");
+        assert!(reset_syscalls().is_some());
+
+        log("discarded");
+
+        assert_eq!(*logs.borrow(), vec![b"hello".to_vec(), b"world".to_vec()]);
+    }
+
+    #[test]
+    fn test_log_capture() {
+        let recorder = Recorder::default();
+        let recorded = recorder.logs.clone();
+        set_syscalls(recorder);
+
+        let logs = LogCapture::new();
+        log("hello");
+        crate::log::log_64(1, 2, 3, 4, 255);
+        crate::log::log_data(&[b"hi"]);
+
+        logs.assert_log_contains("Program log: hello");
+        logs.assert_log_contains("0xff");
+        assert!(!logs.contains("goodbye"));
+        assert_eq!(
+            logs.logs(),
+            vec![
+                "Program log: hello",
+                "Program log: 0x1, 0x2, 0x3, 0x4, 0xff",
+                "Program data: aGk=",
+            ]
+        );
+
+        logs.clear();
+        assert!(logs.logs().is_empty());
+        drop(logs);
+
+        // The previous implementation received the calls and is restored.
+        log("world");
+        assert!(reset_syscalls().is_some());
+        assert_eq!(
+            *recorded.borrow(),
+            vec![b"hello".to_vec(), b"world".to_vec()]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "no log message contains \"goodbye\"")]
+    fn test_assert_log_contains() {
+        let logs = LogCapture::new();
+        log("hello");
+
+        logs.assert_log_contains("goodbye");
+    }
+
+    #[test]
+    fn test_get_sysvar() {
+        assert_eq!(
+            Clock::get().err(),
+            Some(crate::error::ProgramError::UnsupportedSysvar)
+        );
+
+        set_syscalls(
+            HostSyscalls::default()
+                .with_compute_units(1_000)
+                .with_sysvar(CLOCK_ID, &clock_bytes()),
+        );
+
+        let clock = Clock::get().unwrap();
+        assert_eq!(clock.slot, 1);
+        assert_eq!(clock.unix_timestamp, 5);
+        assert_eq!(remaining_compute_units(), 1_000);
+
+        let mut epoch = [0; 8];
+        get_sysvar(&mut epoch, &CLOCK_ID, 16).unwrap();
+        assert_eq!(u64::from_le_bytes(epoch), 3);
+
+        assert_eq!(
+            get_sysvar(&mut epoch, &CLOCK_ID, 36).err(),
+            Some(crate::error::ProgramError::InvalidArgument)
+        );
+        assert_eq!(
+            get_sysvar(&mut epoch, &Address::new_from_array([9; 32]), 0).err(),
+            Some(crate::error::ProgramError::UnsupportedSysvar)
+        );
+
+        reset_syscalls();
+    }
+
+    #[test]
+    fn test_with_rent() {
+        set_syscalls(HostSyscalls::default().with_rent(3_480, 2.0));
+
+        let rent = Rent::get().unwrap();
+        assert_eq!(rent.try_minimum_balance(0), Ok(890_880));
+
+        reset_syscalls();
+    }
+
+    #[cfg(feature = "cpi")]
+    #[test]
+    fn test_invoke() {
+        use crate::{
+            entrypoint::{
+                deserialize,
+                input::{serialize_input, InputAccount},
+            },
+            error::ProgramError,
+            instruction::InstructionAccount,
+        };
+
+        struct Invoke {
+            calls: Rc<RefCell<Vec<Vec<u8>>>>,
+        }
+
+        impl Syscalls for Invoke {
+            fn invoke(
+                &mut self,
+                instruction: &InstructionView,
+                account_views: &[&AccountView],
+                _signers: &[Signer],
+            ) -> ProgramResult {
+                assert_eq!(instruction.program_id, &Address::new_from_array([1; 32]));
+                assert_eq!(account_views.len(), 1);
+
+                self.calls.borrow_mut().push(instruction.data.to_vec());
+                Err(ProgramError::Custom(7))
+            }
+        }
+
+        let program_id = Address::new_from_array([1; 32]);
+        let mut input = serialize_input(
+            &program_id,
+            &[InputAccount::new(
+                Address::new_from_array([2; 32]),
+                Address::new_from_array([1; 32]),
+                1,
+                &[],
+            )
+            .writable()],
+            &[],
+        );
+        let mut accounts = [const { core::mem::MaybeUninit::<AccountView>::uninit() }; 1];
+        unsafe { deserialize(input.as_mut_ptr(), &mut accounts) };
+        let account = unsafe { accounts[0].assume_init_ref() };
+
+        let instruction_accounts = [InstructionAccount::writable(account.address())];
+        let instruction = InstructionView {
+            program_id: &program_id,
+            accounts: &instruction_accounts,
+            data: &[3],
+        };
+
+        assert_eq!(crate::cpi::invoke(&instruction, &[account]), Ok(()));
+
+        let calls = Rc::new(RefCell::new(Vec::new()));
+        set_syscalls(Invoke {
+            calls: calls.clone(),
+        });
+        assert_eq!(
+            crate::cpi::invoke(&instruction, &[account]),
+            Err(ProgramError::Custom(7))
+        );
+        reset_syscalls();
+
+        assert_eq!(*calls.borrow(), vec![vec![3]]);
+    }
+
+    #[cfg(feature = "cpi")]
+    #[test]
+    fn test_invoke_recorder() {
+        use crate::{
+            cpi::{invoke, invoke_signed, Seed},
+            entrypoint::{
+                deserialize,
+                input::{serialize_input, InputAccount},
+            },
+            error::ProgramError,
+            instruction::InstructionAccount,
+        };
+
+        struct Failing;
+
+        impl Syscalls for Failing {
+            fn invoke(
+                &mut self,
+                _instruction: &InstructionView,
+                _account_views: &[&AccountView],
+                _signers: &[Signer],
+            ) -> ProgramResult {
+                Err(ProgramError::Custom(7))
+            }
+        }
+
+        let program_id = Address::new_from_array([1; 32]);
+        let mut input = serialize_input(
+            &program_id,
+            &[InputAccount::new(
+                Address::new_from_array([2; 32]),
+                Address::new_from_array([1; 32]),
+                1,
+                &[],
+            )
+            .writable()],
+            &[],
+        );
+        let mut accounts = [const { core::mem::MaybeUninit::<AccountView>::uninit() }; 1];
+        unsafe { deserialize(input.as_mut_ptr(), &mut accounts) };
+        let account = unsafe { accounts[0].assume_init_ref() };
+
+        let instruction_accounts = [InstructionAccount::writable(account.address())];
+        let instruction = InstructionView {
+            program_id: &program_id,
+            accounts: &instruction_accounts,
+            data: &[3],
+        };
+
+        let invokes = InvokeRecorder::new();
+
+        assert_eq!(invoke(&instruction, &[account]), Ok(()));
+        let seeds = [Seed::from(b"vault"), Seed::from(&[255])];
+        assert_eq!(
+            invoke_signed(&instruction, &[account], &[Signer::from(&seeds)]),
+            Ok(())
+        );
+
+        invokes.assert_invoked(&[
+            Invocation::new(&instruction),
+            Invocation::new(&instruction).with_signer(&[b"vault", &[255]]),
+        ]);
+        assert_eq!(
+            invokes.invocations()[0].accounts,
+            vec![InvokedAccount {
+                address: Address::new_from_array([2; 32]),
+                is_writable: true,
+                is_signer: false,
+            }]
+        );
+
+        invokes.clear();
+        assert!(invokes.invocations().is_empty());
+        drop(invokes);
+
+        // The previous implementation receives the invocations.
+        set_syscalls(Failing);
+        let invokes = InvokeRecorder::new();
+        assert_eq!(
+            invoke(&instruction, &[account]),
+            Err(ProgramError::Custom(7))
+        );
+        invokes.assert_invoked(&[Invocation::new(&instruction)]);
+        drop(invokes);
+
+        assert!(reset_syscalls().is_some());
+    }
+
+    #[cfg(feature = "cpi")]
+    #[test]
+    #[should_panic(expected = "recorded invocations do not match the expected ones")]
+    fn test_assert_invoked() {
+        let program_id = Address::new_from_array([1; 32]);
+        let instruction = InstructionView {
+            program_id: &program_id,
+            accounts: &[],
+            data: &[3],
+        };
+
+        let invokes = InvokeRecorder::new();
+
+        invokes.assert_invoked(&[Invocation::new(&instruction)]);
+    }
+}